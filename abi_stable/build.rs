@@ -15,6 +15,15 @@ fn main() {
     if Version::new(1, 39, 0) <= rver {
         println!("cargo:rustc-cfg=feature=\"rust_1_39\"");
     }
+    if Version::new(1, 40, 0) <= rver {
+        println!("cargo:rustc-cfg=feature=\"rust_1_40\"");
+    }
+    if Version::new(1, 75, 0) <= rver {
+        println!("cargo:rustc-cfg=feature=\"rust_1_75\"");
+    }
+    if Version::new(1, 78, 0) <= rver {
+        println!("cargo:rustc-cfg=feature=\"rust_1_78\"");
+    }
     let channel=rustc_version::version_meta().unwrap().channel;
     if let Channel::Nightly=channel {
         println!("cargo:rustc-cfg=feature=\"nightly_rust\"");
@@ -7,6 +7,7 @@ pub mod const_generics;
 pub mod extra_checks;
 pub mod get_static_equivalent;
 pub mod stable_abi_trait;
+pub mod strict_checking;
 
 
 mod layout_tests;
@@ -16,6 +17,7 @@ pub use self::{
     const_generics::{ConstGeneric,GetConstGenericVTable},
     get_static_equivalent::{GetStaticEquivalent_,GetStaticEquivalent},
     stable_abi_trait::{StableAbi,SharedStableAbi,AbiConsts,TypeLayoutCtor,GetTypeLayoutCtor},
+    strict_checking::{check_layout_strict,LayoutProblem,LayoutProblemKind},
 };
 
 #[doc(no_inline)]
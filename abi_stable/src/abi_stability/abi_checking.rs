@@ -257,7 +257,16 @@ impl AbiChecker {
             TLData::Enum(enum_)=>!enum_.exhaustiveness.is_exhaustive(),
             _=>false,
         };
+        // The fields of a prefix type that `other` doesn't have are allowed to be absent 
+        // if they are all `#[sabi(optional)]`.
+        let missing_are_optional=match (ctx,&t_data) {
+            (FieldContext::Fields,TLData::PrefixType(t_prefix))=>
+                t_prefix.fields.optional_fields().are_optional(o_fields.len(),t_fields.len()),
+            _=>false,
+        };
+
         match (t_fields.len().cmp(&o_fields.len()), is_prefix) {
+            (Ordering::Greater, true) if missing_are_optional => {}
             (Ordering::Greater, _) | (Ordering::Less, false) => {
                 push_err(
                     errs,
@@ -39,3 +39,6 @@ mod get_type_layout;
 
 #[cfg(test)]
 mod shared_types;
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod marker_types;
@@ -0,0 +1,114 @@
+use std::{
+    mem,
+    rc::Rc,
+};
+
+use crate::{
+    abi_stability::abi_checking::check_layout_compatibility,
+    marker_type::{Contravariant,Covariant,Invariant,RPhantomData},
+    StableAbi,
+};
+
+
+mod covariant_u32{
+    use super::*;
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Struct{
+        pub field:u32,
+        pub _marker:RPhantomData<Covariant<u32>>,
+    }
+}
+
+mod invariant_u32{
+    use super::*;
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Struct{
+        pub field:u32,
+        pub _marker:RPhantomData<Invariant<u32>>,
+    }
+}
+
+mod contravariant_u64{
+    use super::*;
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Struct{
+        pub field:u32,
+        pub _marker:RPhantomData<Contravariant<u64>>,
+    }
+}
+
+mod with_lifetime{
+    use super::*;
+    #[repr(C)]
+    #[derive(StableAbi)]
+    pub struct Struct<'a>{
+        pub field:&'a u32,
+        pub _marker:RPhantomData<Invariant<&'a u32>>,
+    }
+}
+
+
+#[test]
+fn rphantomdata_layout(){
+    assert_eq!(mem::size_of::<RPhantomData<Covariant<u64>>>(), 0);
+    assert_eq!(mem::align_of::<RPhantomData<Covariant<u64>>>(), 1);
+    assert_eq!(mem::size_of::<RPhantomData<Invariant<String>>>(), 0);
+    assert_eq!(mem::size_of::<RPhantomData<Contravariant<str>>>(), 0);
+
+    let covariant=<covariant_u32::Struct as StableAbi>::LAYOUT;
+    let invariant=<invariant_u32::Struct as StableAbi>::LAYOUT;
+    let contravariant_u64=<contravariant_u64::Struct as StableAbi>::LAYOUT;
+
+    // Variance is only relevant at compile-time.
+    assert_eq!(check_layout_compatibility(covariant,invariant),Ok(()));
+    assert_eq!(check_layout_compatibility(invariant,covariant),Ok(()));
+
+    // The type parameter of the variance marker is checked.
+    assert!(check_layout_compatibility(covariant,contravariant_u64).is_err());
+    assert!(check_layout_compatibility(contravariant_u64,invariant).is_err());
+
+    let with_lifetime=<with_lifetime::Struct<'static> as StableAbi>::LAYOUT;
+    assert_eq!(check_layout_compatibility(with_lifetime,with_lifetime),Ok(()));
+}
+
+
+#[test]
+fn rphantomdata_auto_traits(){
+    fn assert_send_sync<T:Send+Sync>(){}
+
+    // Unlike `PhantomData<Rc<()>>`,these are `Send+Sync`.
+    assert_send_sync::<RPhantomData<Covariant<Rc<()>>>>();
+    assert_send_sync::<RPhantomData<Contravariant<Rc<()>>>>();
+    assert_send_sync::<RPhantomData<Invariant<Rc<()>>>>();
+}
+
+
+#[test]
+fn rphantomdata_variance(){
+    // These only compile if `RPhantomData` has the variance of its marker type.
+
+    fn covariant<'a>(
+        x:RPhantomData<Covariant<&'static str>>
+    )->RPhantomData<Covariant<&'a str>>{
+        x
+    }
+
+    fn contravariant<'a>(
+        x:RPhantomData<Contravariant<&'a str>>
+    )->RPhantomData<Contravariant<&'static str>>{
+        x
+    }
+
+    fn invariant<'a>(
+        x:RPhantomData<Invariant<&'a str>>
+    )->RPhantomData<Invariant<&'a str>>{
+        x
+    }
+
+    assert_eq!(covariant(RPhantomData::NEW), RPhantomData::default());
+    assert_eq!(contravariant(RPhantomData::NEW), RPhantomData::default());
+    assert_eq!(invariant(RPhantomData::NEW), RPhantomData::default());
+}
@@ -386,6 +386,39 @@ fn prefix_is_same_size(){
 
 
 
+#[cfg_attr(not(miri),test)]
+fn prefix_metadata_is_compatible_with(){
+    let list=new_list();
+
+    for (i,this) in list.iter().cloned().enumerate() {
+        let this=PrefixTypeMetadata::from_layout(this).unwrap();
+        assert_eq!(this.field_count(),i+1);
+
+        assert!(this.is_compatible_with::<prefix0::Prefix>());
+        assert!(this.is_compatible_with::<prefix1::Prefix>());
+        assert!(this.is_compatible_with::<prefix2::Prefix>());
+        assert!(this.is_compatible_with::<prefix3::Prefix>());
+
+        assert!(!this.is_compatible_with::<prefix2_misaligned::Prefix>());
+        assert!(!this.is_compatible_with::<prefix2_different_prefix::Prefix>());
+        assert!(!this.is_compatible_with::<u32>());
+    }
+
+    {
+        let this=PrefixTypeMetadata::from_layout(<&prefix2_different_prefix::Prefix>::LAYOUT)
+            .unwrap();
+        assert!(this.is_compatible_with::<prefix2_different_prefix::Prefix>());
+        for pref in list.iter().cloned() {
+            assert!(!PrefixTypeMetadata::from_layout(pref).unwrap()
+                .is_compatible_with::<prefix2_different_prefix::Prefix>());
+        }
+        assert!(!this.is_compatible_with::<prefix0::Prefix>());
+    }
+
+    assert!(PrefixTypeMetadata::from_layout(<u32>::LAYOUT).is_none());
+    assert!(PrefixTypeMetadata::from_layout(<&u32>::LAYOUT).is_none());
+}
+
 #[cfg_attr(not(miri),test)]
 fn prefix_on_nonexistent_field() {
     let prefix0=
@@ -415,6 +448,177 @@ fn prefix_on_nonexistent_field() {
 
 
 
+/////////////////////////////////////////////////////////////////////////
+
+
+mod opt_fields_0 {
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Prefix")))]
+    pub struct PrefixVal {
+        #[sabi(last_prefix_field)]
+        pub field0: u8,
+        pub field1: u16,
+    }
+}
+
+mod opt_fields_1 {
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Prefix")))]
+    #[sabi(missing_field(panic))]
+    pub struct PrefixVal {
+        #[sabi(last_prefix_field)]
+        pub field0: u8,
+        pub field1: u16,
+        #[sabi(optional)]
+        pub field2: u32,
+    }
+}
+
+// The last field isn't optional,so it can't be absent.
+mod opt_fields_2 {
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Prefix")))]
+    pub struct PrefixVal {
+        #[sabi(last_prefix_field)]
+        pub field0: u8,
+        pub field1: u16,
+        #[sabi(optional)]
+        pub field2: u32,
+        pub field3: u64,
+    }
+}
+
+// What a version of `opt_fields_1` that was built with a version of abi_stable 
+// that doesn't know about `#[sabi(optional)]` looks like.
+mod opt_fields_old {
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Prefix")))]
+    pub struct PrefixVal {
+        #[sabi(last_prefix_field)]
+        pub field0: u8,
+        pub field1: u16,
+        pub field2: u32,
+    }
+}
+
+
+#[cfg_attr(not(miri),test)]
+fn prefix_optional_fields_layout(){
+    let opt_0=<&opt_fields_0::Prefix>::LAYOUT;
+    let opt_1=<&opt_fields_1::Prefix>::LAYOUT;
+    let opt_2=<&opt_fields_2::Prefix>::LAYOUT;
+
+    {
+        let metadata=PrefixTypeMetadata::from_layout(opt_1).unwrap();
+        assert!(!metadata.optional_fields.is_optional(0));
+        assert!(!metadata.optional_fields.is_optional(1));
+        assert!(metadata.optional_fields.is_optional(2));
+    }
+
+    for (this,other) in vec![ (opt_0,opt_1), (opt_1,opt_0), (opt_1,opt_2) ] {
+        let globals=CheckingGlobals::new();
+        check_layout_compatibility_with_globals(this,other,&globals)
+            .unwrap_or_else(|e| panic!("{:#?}",e) );
+    }
+
+    for (this,other) in vec![ (opt_2,opt_0), (opt_2,opt_1), (PREF_2,opt_0) ] {
+        let globals=CheckingGlobals::new();
+        let errs=check_layout_compatibility_with_globals(this,other,&globals)
+            .unwrap_err()
+            .flatten_errors();
+
+        assert!(
+            errs.iter().any(|err| matches!(AbiInstability::FieldCountMismatch{..}=err)),
+            "\nerrors:{:#?}\n",
+            errs,
+        );
+    }
+}
+
+
+// Optional fields must not change the layout of the type layout of prefix types,
+// since it's read by other versions of abi_stable when loading dynamic libraries.
+#[cfg_attr(not(miri),test)]
+fn prefix_optional_fields_old_layout(){
+    use crate::{
+        prefix_type::{FieldAccessibility,FieldConditionality},
+        type_layout::{CompTLFields,MonoTLPrefixType,TLFields,TLPrefixType},
+    };
+    use std::mem;
+
+    // The layout of the prefix type in the type layout,before optional fields were added.
+    #[repr(C)]
+    struct OldMonoTLPrefixType {
+        first_suffix_field:u8,
+        conditional_prefix_fields:FieldConditionality,
+        fields: CompTLFields,
+    }
+    #[repr(C)]
+    struct OldTLPrefixType {
+        first_suffix_field:u8,
+        conditional_prefix_fields:FieldConditionality,
+        fields: TLFields,
+        accessible_fields:FieldAccessibility,
+    }
+
+    assert_eq!(mem::size_of::<MonoTLPrefixType>(), mem::size_of::<OldMonoTLPrefixType>());
+    assert_eq!(mem::align_of::<MonoTLPrefixType>(), mem::align_of::<OldMonoTLPrefixType>());
+    assert_eq!(mem::size_of::<TLPrefixType>(), mem::size_of::<OldTLPrefixType>());
+    assert_eq!(mem::align_of::<TLPrefixType>(), mem::align_of::<OldTLPrefixType>());
+
+    let opt_1=<&opt_fields_1::Prefix>::LAYOUT;
+    let opt_old=<&opt_fields_old::Prefix>::LAYOUT;
+
+    let metadata=PrefixTypeMetadata::from_layout(opt_old).unwrap();
+    assert_eq!(metadata.optional_fields.bits(),0);
+
+    for (this,other) in vec![ (opt_1,opt_old), (opt_old,opt_1) ] {
+        let globals=CheckingGlobals::new();
+        check_layout_compatibility_with_globals(this,other,&globals)
+            .unwrap_or_else(|e| panic!("{:#?}",e) );
+    }
+
+    {
+        let old=opt_fields_old::PrefixVal{ field0:1, field1:2, field2:3 }.leak_into_prefix();
+        let value1:&opt_fields_1::Prefix=unsafe{ transmute_reference(old) };
+        assert_eq!(value1.field0(),1);
+        assert_eq!(value1.field1(),2);
+        assert_eq!(value1.field2(),crate::std_types::RSome(3));
+    }
+}
+
+
+#[cfg_attr(not(miri),test)]
+fn prefix_optional_fields_accessors(){
+    use crate::std_types::{RNone,RSome};
+
+    let opt_0=opt_fields_0::PrefixVal{ field0:1, field1:2 }.leak_into_prefix();
+    let opt_1=opt_fields_1::PrefixVal{ field0:1, field1:2, field2:3 }.leak_into_prefix();
+
+    {
+        let value1:&opt_fields_1::Prefix=unsafe{ transmute_reference(opt_0) };
+        assert_eq!(value1.field0(),1);
+        assert_eq!(value1.field1(),2);
+        assert_eq!(value1.field2(),RNone);
+    }
+    {
+        assert_eq!(opt_1.field0(),1);
+        assert_eq!(opt_1.field1(),2);
+        assert_eq!(opt_1.field2(),RSome(3));
+    }
+    {
+        let value2:&opt_fields_2::Prefix=unsafe{ transmute_reference(opt_1) };
+        assert_eq!(value2.field2(),RSome(3));
+        assert_eq!(value2.field3(),None);
+    }
+}
+
+
+
 /////////////////////////////////////////////////////////////////////////
 
 
@@ -6,6 +6,8 @@ use crate::{
     type_layout::TypeLayout,
 };
 
+use core_extensions::matches;
+
 
 macro_rules! declare_int_repr {
     ( 
@@ -192,6 +194,61 @@ fn check_discriminant_repr_enums(){
 }
 
 
+/// Enums that only differ in the integer type of the discriminant.
+#[test]
+fn check_discriminant_repr(){
+    let list=&[
+        <u8_repr_a::What as StableAbi>::LAYOUT,
+        <u16_repr_a::What as StableAbi>::LAYOUT,
+        <usize_repr_a::What as StableAbi>::LAYOUT,
+        <i8_repr_a::What as StableAbi>::LAYOUT,
+        <i16_repr_a::What as StableAbi>::LAYOUT,
+        <isize_repr_a::What as StableAbi>::LAYOUT,
+    ];
+
+    check_imcompatible_with_others(list,|errs|{
+        assert!(
+            errs.iter().any(|err| matches!(AbiInstability::ReprAttr{..}=err) ),
+            "\nerrors:{:#?}\n",
+            errs,
+        );
+    })
+}
+
+
+/// Enums that only differ in the value of the discriminants.
 #[test]
 fn check_discriminants(){
+    let list=&[
+        <i8_repr_a::What as StableAbi>::LAYOUT,
+        <i8_repr_b::What as StableAbi>::LAYOUT,
+        <i8_repr_c::What as StableAbi>::LAYOUT,
+        <i8_repr_d::What as StableAbi>::LAYOUT,
+    ];
+
+    check_imcompatible_with_others(list,|errs|{
+        assert!(
+            errs.iter().any(|err| matches!(AbiInstability::EnumDiscriminant{..}=err) ),
+            "\nerrors:{:#?}\n",
+            errs,
+        );
+        assert!(
+            !errs.iter().any(|err| matches!(AbiInstability::ReprAttr{..}=err) ),
+            "\nerrors:{:#?}\n",
+            errs,
+        );
+    });
+
+    let list=&[
+        <u8_repr_a::What as StableAbi>::LAYOUT,
+        <u8_repr_b::What as StableAbi>::LAYOUT,
+    ];
+
+    check_imcompatible_with_others(list,|errs|{
+        assert!(
+            errs.iter().any(|err| matches!(AbiInstability::EnumDiscriminant{..}=err) ),
+            "\nerrors:{:#?}\n",
+            errs,
+        );
+    });
 }
\ No newline at end of file
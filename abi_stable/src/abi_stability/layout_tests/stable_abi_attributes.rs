@@ -1,6 +1,10 @@
+use std::marker::PhantomData;
+
 use crate::{
     StableAbi,
-    abi_stability::check_layout_compatibility,
+    GetStaticEquivalent,
+    abi_stability::{check_layout_compatibility,check_layout_strict,LayoutProblemKind},
+    marker_type::UnsafeIgnoredType,
     type_layout::{TypeLayout,TLData},
 };
 
@@ -101,6 +105,87 @@ fn shared_stable_abi_attribute(){
 ////////////////////////////////////////////////////////////////////////////////
 
 
+/// A type that does not implement `StableAbi`.
+#[derive(GetStaticEquivalent)]
+pub struct NotStableAbi;
+
+/// Tests that `#[sabi(phantom_only_type_param="T")]` does not require `T:StableAbi`.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(phantom_only_type_param="T")]
+pub struct PhantomOnlyTypeParam<T>{
+    value:u32,
+    _marker:PhantomData<T>,
+}
+
+/// Tests that `PhantomData`s that mention other type parameters are not made opaque.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(phantom_only_type_param="T")]
+pub struct PhantomOnlyTypeParamMixed<T,U>{
+    _marker:PhantomData<T>,
+    _both:PhantomData<(T,U)>,
+}
+
+/// Tests that `#[sabi(phantom_type_param="T")]` keeps requiring `T:StableAbi`.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(phantom_type_param="T")]
+pub struct PhantomTypeParam<T>{
+    value:u32,
+    _marker:PhantomData<T>,
+}
+
+#[test]
+fn phantom_only_type_param_attribute(){
+    let layout=<PhantomOnlyTypeParam<NotStableAbi> as StableAbi>::LAYOUT;
+
+    let fields=match layout.data() {
+        TLData::Struct{fields}=>fields,
+        _=>unreachable!()
+    };
+    assert_eq!( fields.get(0).unwrap().layout().name(), "u32" );
+    assert_eq!( fields.get(1).unwrap().layout().name(), "OpaqueField" );
+
+    let phantom_fields=layout.phantom_fields();
+    assert_eq!( phantom_fields.len(), 1 );
+    assert_eq!( phantom_fields.get(0).unwrap().layout().name(), "OpaqueField" );
+
+    check_layout_compatibility(layout,layout).unwrap();
+
+    let layout=<PhantomOnlyTypeParamMixed<u8,u16> as StableAbi>::LAYOUT;
+
+    let fields=match layout.data() {
+        TLData::Struct{fields}=>fields,
+        _=>unreachable!()
+    };
+    assert_eq!( fields.get(0).unwrap().layout().name(), "OpaqueField" );
+    assert_eq!( fields.get(1).unwrap().layout().name(), "PhantomData" );
+
+    check_layout_compatibility(layout,layout).unwrap();
+}
+
+#[test]
+fn phantom_type_param_attribute(){
+    let layout=<PhantomTypeParam<u8> as StableAbi>::LAYOUT;
+
+    let fields=match layout.data() {
+        TLData::Struct{fields}=>fields,
+        _=>unreachable!()
+    };
+    assert_eq!( fields.get(1).unwrap().layout().name(), "PhantomData" );
+
+    let phantom_fields=layout.phantom_fields();
+    assert_eq!( phantom_fields.len(), 1 );
+    assert_eq!( phantom_fields.get(0).unwrap().layout().name(), "u8" );
+
+    check_layout_compatibility(layout,layout).unwrap();
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+
+
 #[test]
 fn is_sabi_opaque_fields(){
     let list:Vec<(&'static TypeLayout,Vec<Option<&'static str>>)>=vec![
@@ -203,3 +288,59 @@ fn different_opaque_fields() {
 
     // println!("taken {} to check all listed layouts", dur);
 }
+
+
+////////////////////////////////////////////////////////////////////////////////
+
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct StrictlyChecked{
+    value:u32,
+    func:extern "C" fn(&u32)->u64,
+}
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct UnsafeIgnored<T>{
+    value:u32,
+    _marker:UnsafeIgnoredType<T>,
+}
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct NestedMarkers{
+    checked:StrictlyChecked,
+    opaque:UnsafeOpaqueField0<u32,u32>,
+    sabi_opaque:*const UnsafeSabiOpaqueField0<u32,u32>,
+    func:extern "C" fn(UnsafeIgnored<u8>),
+}
+
+
+#[test]
+fn strict_checking() {
+    assert_eq!( check_layout_strict(<u32 as StableAbi>::LAYOUT), Ok(()) );
+    assert_eq!( check_layout_strict(<StrictlyChecked as StableAbi>::LAYOUT), Ok(()) );
+
+    let problems=check_layout_strict(<NestedMarkers as StableAbi>::LAYOUT).unwrap_err();
+
+    let found=problems.iter()
+        .map(|p| (p.containing_type().name(),p.field_name(),p.kind()) )
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        found,
+        vec![
+            ("UnsafeOpaqueField0",  "world",  LayoutProblemKind::OpaqueField),
+            ("UnsafeSabiOpaqueField0", "world", LayoutProblemKind::SabiOpaqueField),
+            ("UnsafeIgnored", "_marker", LayoutProblemKind::IgnoredType),
+        ]
+    );
+
+    for problem in &problems {
+        let path=problem.path();
+        assert_eq!( path[0].name(), "NestedMarkers" );
+        assert!( problem.to_string().contains(problem.field_name()) );
+    }
+    assert_eq!( problems[1].path().len(), 3 );
+}
@@ -477,6 +477,85 @@ fn different_zeroness() {
         .any(|err| matches!(AbiInstability::NonZeroness{..}=err)));
 }
 
+#[cfg_attr(not(miri),test)]
+fn nonzero_types() {
+    macro_rules! check_nonzero {
+        ( $( ($nonzero:ty,$int:ty) ),* $(,)* ) => ({
+            $({
+                let nonzero=<$nonzero>::LAYOUT;
+                let int=<$int>::LAYOUT;
+                assert!(nonzero.is_nonzero());
+                assert!(!int.is_nonzero());
+                assert_eq!(nonzero.size(),int.size());
+                assert_eq!(nonzero.alignment(),int.alignment());
+                assert_eq!(nonzero.size(),mem::size_of::<$nonzero>());
+
+                let opt=<Option<$nonzero>>::LAYOUT;
+                assert!(!opt.is_nonzero());
+                assert_eq!(opt.size(),int.size());
+
+                assert_sane_type_layout(nonzero);
+                assert_sane_type_layout(opt);
+                assert!(check_layout_compatibility(nonzero,int).is_err());
+            })*
+        })
+    }
+
+    check_nonzero!{
+        (num::NonZeroU8,u8),
+        (num::NonZeroU16,u16),
+        (num::NonZeroU32,u32),
+        (num::NonZeroU64,u64),
+        (num::NonZeroUsize,usize),
+        (num::NonZeroI8,i8),
+        (num::NonZeroI16,i16),
+        (num::NonZeroI32,i32),
+        (num::NonZeroI64,i64),
+        (num::NonZeroIsize,isize),
+    }
+
+    mod handles{
+        use std::num::NonZeroU32;
+        use crate::StableAbi;
+
+        #[repr(C)]
+        #[derive(StableAbi)]
+        pub struct Handle{
+            pub id:NonZeroU32,
+            pub generation:u32,
+        }
+    }
+
+    mod plain_handles{
+        use crate::StableAbi;
+
+        #[repr(C)]
+        #[derive(StableAbi)]
+        pub struct Handle{
+            pub id:u32,
+            pub generation:u32,
+        }
+    }
+
+    let handle=handles::Handle::LAYOUT;
+    let plain=plain_handles::Handle::LAYOUT;
+
+    assert_eq!(handle.size(),plain.size());
+    assert_eq!(handle.alignment(),plain.alignment());
+
+    let id_field=match handle.data() {
+        TLData::Struct{fields}=>fields.iter().next().unwrap(),
+        _=>unreachable!(),
+    };
+    assert_eq!(id_field.name(),"id");
+    assert!(id_field.layout().is_nonzero());
+    assert_eq!(id_field.layout().size(),mem::size_of::<u32>());
+    assert_eq!(id_field.layout().alignment(),mem::align_of::<u32>());
+
+    assert_sane_type_layout(handle);
+    assert_different_type_layout(handle,plain);
+}
+
 #[test]
 fn different_name() {
     let regular = regular::Rectangle::LAYOUT;
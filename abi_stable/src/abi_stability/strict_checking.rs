@@ -0,0 +1,248 @@
+/*!
+Auditing a `TypeLayout` for parts of it that are not checked for ABI compatibility.
+*/
+
+use std::{
+    collections::HashSet,
+    error::Error as ErrorTrait,
+    fmt::{self, Display},
+};
+
+use crate::type_layout::{TypeLayout, TLData, TLField, TLFields};
+
+
+/// What kind of unchecked marker a [`LayoutProblem`](./struct.LayoutProblem.html) is about.
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+pub enum LayoutProblemKind{
+    /// The field is an `UnsafeIgnoredType<_>`,
+    /// whose type parameter is not checked at all.
+    ///
+    /// This is what types with `#[sabi(unsafe_unconstrained(..))]` type parameters
+    /// use to store those type parameters.
+    IgnoredType,
+    /// The field uses `#[sabi(unsafe_opaque_field)]`/`#[sabi(unsafe_opaque_fields)]`,
+    /// so only its size and alignment are checked.
+    OpaqueField,
+    /// The field uses `#[sabi(unsafe_sabi_opaque_field)]`/`#[sabi(unsafe_sabi_opaque_fields)]`,
+    /// so only its size and alignment are checked.
+    SabiOpaqueField,
+}
+
+impl LayoutProblemKind{
+    fn from_layout(layout:&'static TypeLayout)->Option<Self>{
+        let (package,_)=layout.item_info().package_and_version();
+        if package!="abi_stable" {
+            return None;
+        }
+        match layout.name() {
+            "UnsafeIgnoredType"=>Some(LayoutProblemKind::IgnoredType),
+            "OpaqueField"=>Some(LayoutProblemKind::OpaqueField),
+            "SabiOpaqueField"=>Some(LayoutProblemKind::SabiOpaqueField),
+            _=>None,
+        }
+    }
+}
+
+impl Display for LayoutProblemKind{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        let s=match self {
+            LayoutProblemKind::IgnoredType=>
+                "is an UnsafeIgnoredType,whose type parameter is not checked",
+            LayoutProblemKind::OpaqueField=>
+                "is an opaque field,only its size and alignment are checked",
+            LayoutProblemKind::SabiOpaqueField=>
+                "is a sabi opaque field,only its size and alignment are checked",
+        };
+        f.write_str(s)
+    }
+}
+
+
+/// A field of a type,reachable from the layout passed to
+/// [`check_layout_strict`](./fn.check_layout_strict.html),
+/// which relies on an opaque/ignored marker.
+#[derive(Debug,Clone,PartialEq)]
+pub struct LayoutProblem{
+    path:Vec<&'static TypeLayout>,
+    field:&'static str,
+    kind:LayoutProblemKind,
+}
+
+impl LayoutProblem{
+    /// The types traversed to get to the field,
+    /// starting with the layout passed to `check_layout_strict`,
+    /// and ending with the type that declares the field.
+    pub fn path(&self)->&[&'static TypeLayout]{
+        &self.path
+    }
+
+    /// The type that declares the field.
+    pub fn containing_type(&self)->&'static TypeLayout{
+        self.path[self.path.len()-1]
+    }
+
+    /// The name of the field.
+    pub fn field_name(&self)->&'static str{
+        self.field
+    }
+
+    /// What kind of marker the field relies on.
+    pub fn kind(&self)->LayoutProblemKind{
+        self.kind
+    }
+}
+
+impl Display for LayoutProblem{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        for (i,layout) in self.path.iter().enumerate() {
+            if i!=0 {
+                f.write_str(" -> ")?;
+            }
+            Display::fmt(&layout.full_type(),f)?;
+        }
+        write!(f,"\n    the `{}` field {}",self.field,self.kind)
+    }
+}
+
+impl ErrorTrait for LayoutProblem{}
+
+
+/**
+Checks every type reachable from `layout`
+(through fields,type parameters,and function pointer parameters/return types),
+returning the fields that rely on an opaque/ignored marker,
+which means that `check_layout_compatibility` can't fully check them.
+
+The markers this reports are:
+
+- `UnsafeIgnoredType<_>`:
+    which is how types that use `#[sabi(unsafe_unconstrained(..))]`
+    store their unconstrained type parameters.
+
+- Fields using `#[sabi(unsafe_opaque_field)]` or `#[sabi(unsafe_opaque_fields)]`.
+
+- Fields using `#[sabi(unsafe_sabi_opaque_field)]` or `#[sabi(unsafe_sabi_opaque_fields)]`.
+
+Types that aren't `#[repr(C)]`/`#[repr(transparent)]`/`#[repr(Integer)]`
+can't derive `StableAbi`,
+so every type in the layout already has a representation with a stable layout.
+
+Every type is only visited once,
+the problems are reported with the first path found to the type that declares the field.
+
+# Example
+
+```
+use abi_stable::{
+    abi_stability::{check_layout_strict,LayoutProblemKind},
+    marker_type::UnsafeIgnoredType,
+    std_types::RVec,
+    StableAbi,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct Checked{
+    pub values:RVec<u32>,
+}
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct Unchecked{
+    pub value:u32,
+    pub ignored:UnsafeIgnoredType<String>,
+}
+
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct Outer{
+    pub checked:Checked,
+    pub list:RVec<Unchecked>,
+}
+
+assert_eq!( check_layout_strict(Checked::LAYOUT), Ok(()) );
+
+let problems=check_layout_strict(Outer::LAYOUT).unwrap_err();
+assert_eq!( problems.len(), 1 );
+assert_eq!( problems[0].field_name(), "ignored" );
+assert_eq!( problems[0].containing_type().name(), "Unchecked" );
+assert_eq!( problems[0].kind(), LayoutProblemKind::IgnoredType );
+
+```
+
+*/
+pub fn check_layout_strict(layout:&'static TypeLayout)->Result<(),Vec<LayoutProblem>>{
+    let mut checker=StrictChecker{
+        path:Vec::new(),
+        visited:HashSet::new(),
+        problems:Vec::new(),
+    };
+    checker.check_inner(layout);
+    if checker.problems.is_empty() {
+        Ok(())
+    }else{
+        Err(checker.problems)
+    }
+}
+
+
+struct StrictChecker{
+    path:Vec<&'static TypeLayout>,
+    visited:HashSet<*const TypeLayout>,
+    problems:Vec<LayoutProblem>,
+}
+
+impl StrictChecker{
+    fn check_inner(&mut self,layout:&'static TypeLayout){
+        if !self.visited.insert(layout as *const TypeLayout) {
+            return;
+        }
+
+        self.path.push(layout);
+
+        match layout.data() {
+             TLData::Primitive{..}
+            |TLData::Opaque
+            =>{}
+             TLData::Struct{fields}
+            |TLData::Union{fields}
+            =>self.check_fields(fields),
+            TLData::Enum(enum_)=>self.check_fields(enum_.fields),
+            TLData::PrefixType(prefix)=>self.check_fields(prefix.fields),
+        }
+
+        self.check_fields(layout.phantom_fields());
+
+        for param in layout.generics().type_params() {
+            self.check_inner(param.get());
+        }
+
+        self.path.pop();
+    }
+
+    fn check_fields(&mut self,fields:TLFields){
+        for field in fields.iter() {
+            self.check_field(&field);
+        }
+    }
+
+    fn check_field(&mut self,field:&TLField){
+        let field_layout=field.layout();
+
+        if let Some(kind)=LayoutProblemKind::from_layout(field_layout) {
+            self.problems.push(LayoutProblem{
+                path:self.path.clone(),
+                field:field.name(),
+                kind,
+            });
+        }else{
+            self.check_inner(field_layout);
+        }
+
+        for func in field.function_range().iter() {
+            for param in func.get_params_ret_iter() {
+                self.check_field(&param);
+            }
+        }
+    }
+}
@@ -37,7 +37,7 @@ pub use crate::{
     },
     std_types::{
         utypeid::new_utypeid,
-        RSome,RNone,
+        RSome,RNone,ROption,
     },
     type_layout::{
         _private_MonoTypeLayoutDerive,
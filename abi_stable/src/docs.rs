@@ -3,6 +3,7 @@ Documentation for macros,and guides.
 */
 
 pub mod get_static_equivalent;
+pub mod rflags;
 pub mod prefix_types;
 pub mod unsafe_code_guidelines;
 pub mod library_evolution;
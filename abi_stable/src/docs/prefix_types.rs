@@ -41,6 +41,10 @@ To override how to deal with nonexistent fields,
 use the `#[sabi(missing_field())]` attribute,
 applied to either the struct or the field.
 
+Fields that might be removed in a future version can be declared with `#[sabi(optional)]`,
+whose getters return `ROption<FieldType>`,
+and which don't cause errors when they are absent from the prefix type of another library.
+
 # Grammar Reference
 
 For the grammar reference,you can look at the documentation for 
@@ -0,0 +1,83 @@
+/*!
+
+The `RFlags` derive macro generates the usual operations of a set of bitflags,
+for a `#[repr(transparent)]` struct wrapping an integer.
+
+Combined with `#[derive(StableAbi)]`,
+the layout of the type is recorded as the layout of the wrapped integer,
+so it can be passed across ffi in place of that integer.
+
+The struct must have a single unnamed field,
+which must be a primitive integer type (eg:`u8`,`u32`,`usize`).
+
+# Generated items
+
+These inherent methods:
+
+- `const fn empty()->Self`: a flag set with no flags enabled.
+
+- `const fn from_bits(bits)->Self`: constructs the flag set from the integer.
+
+- `const fn bits(&self)`: returns the integer.
+
+- `const fn is_empty(&self)->bool`: whether no flags are enabled.
+
+- `const fn contains(&self,other:Self)->bool`: 
+    whether all the flags in `other` are enabled in `self`.
+
+- `const fn intersects(&self,other:Self)->bool`: 
+    whether any of the flags in `other` are enabled in `self`.
+
+- `fn insert(&mut self,other:Self)`: enables the flags in `other`.
+
+- `fn remove(&mut self,other:Self)`: disables the flags in `other`.
+
+- `fn toggle(&mut self,other:Self)`: toggles the flags in `other`.
+
+- `fn set(&mut self,other:Self,value:bool)`: 
+    enables or disables the flags in `other` depending on `value`.
+
+These operator traits:
+`BitOr`,`BitAnd`,`BitXor`,`Not`,`BitOrAssign`,`BitAndAssign`,`BitXorAssign`.
+
+The individual flags are declared by the user as associated constants.
+
+# Example
+
+```
+use abi_stable::{
+    RFlags,
+    StableAbi,
+};
+
+#[repr(transparent)]
+#[derive(StableAbi,RFlags,Debug,Copy,Clone,PartialEq,Eq)]
+pub struct Permissions(u32);
+
+impl Permissions{
+    pub const READ:Self=Self(1);
+    pub const WRITE:Self=Self(2);
+    pub const EXECUTE:Self=Self(4);
+}
+
+let mut perms=Permissions::READ|Permissions::WRITE;
+
+assert!(perms.contains(Permissions::READ));
+assert!(!perms.contains(Permissions::READ|Permissions::EXECUTE));
+assert!(perms.intersects(Permissions::READ|Permissions::EXECUTE));
+
+perms.remove(Permissions::WRITE);
+assert_eq!(perms,Permissions::READ);
+
+perms.toggle(Permissions::EXECUTE);
+assert_eq!(perms.bits(),0b101);
+
+perms.set(Permissions::READ,false);
+assert_eq!(perms,Permissions::EXECUTE);
+
+assert!(Permissions::empty().is_empty());
+assert_eq!(Permissions::from_bits(6),Permissions::WRITE|Permissions::EXECUTE);
+
+```
+
+*/
@@ -244,6 +244,10 @@ impl<'a> DeserializeEnum<'a,ValidTag_NE> for ValidTag_Interface{
             .deserialize_tag()(s.get_rstr())
             .into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'a>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
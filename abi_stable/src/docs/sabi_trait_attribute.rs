@@ -218,6 +218,13 @@ A constructor for the trait object,which takes a value that implements the trait
 
 This is equivalent to calling `Trait_TO::from_ptr` with `RBox::new(value)`.
 
+###  Trait_TO::from_inline_value 
+
+A constructor for the trait object,which takes a value that implements the trait,
+only generated when the `#[sabi(inline_size="Type")]` attribute is used.
+
+This is equivalent to calling `Trait_TO::from_ptr` with `RSmallBox::<_,Type>::new(value)`.
+
 ###  Trait_TO::from_sabi 
 
 Constructs the trait object from its underlying implementation,
@@ -287,6 +294,20 @@ you can still call the inherent versions of those methods on the trait object.
 This is useful to reduce compile-time overhead,
 and to allow users to declare a blanket(generic) implementation of the trait.
 
+###  #[sabi(impl_for_refs)]
+
+Implements the trait for references to types that implement the trait,
+forwarding every method to the referent:
+
+- `impl<T:?Sized+Trait> Trait for &mut T`:
+    output for traits without by-value(`self`) methods.
+
+- `impl<T:?Sized+Trait> Trait for &T`:
+    only output for traits where every method takes `&self`.
+
+Neither impl is output for traits with by-value(`self`) methods,
+since those methods can't be forwarded through a reference.
+
 ###  #[sabi(no_default_fallback)]
 
 Stops using default implementation of methods (from the trait declaration) 
@@ -308,6 +329,128 @@ Note that this does not expand the output of the
 Changes how the trait object is implemented to use `DynTrait` instead of `RObject`,
 this allows using more traits,with the (potential) cost of having more overhead.
 
+###  #[sabi(inline_size="Type")] 
+
+Generates the `Trait_TO::from_inline_value` constructor,
+which stores the value in an `RSmallBox<_,Type>` instead of an `RBox<_>`.
+
+Values that fit in `Type` (both size and alignment) are stored inline,
+avoiding the heap allocation,while larger values are stored in the heap.
+
+`Type` must implement `abi_stable::inline_storage::InlineStorage`,
+eg:`[usize;2]`,`[u64;4]`.
+
+Example:
+
+```
+use abi_stable::{
+    sabi_trait,
+    std_types::RString,
+};
+
+#[sabi_trait]
+#[sabi(inline_size="[usize;2]")]
+pub trait Greeter{
+    fn greet(&self,name:&str)->RString;
+}
+
+struct Hello;
+
+impl Greeter for Hello{
+    fn greet(&self,name:&str)->RString{
+        format!("Hello,{}!",name).into()
+    }
+}
+
+# fn main(){
+// `Hello` is zero-sized,so it's stored inline.
+let greeter=Greeter_TO::from_inline_value(Hello,sabi_trait::TU_Opaque);
+
+assert_eq!( greeter.greet("world").as_str(), "Hello,world!" );
+# }
+```
+
+# Method attributes.
+
+These are attributes for the generated trait,applied on methods.
+
+###  #[sabi(erase_return)]
+
+Allows a method to be declared as returning 
+`impl Iterator<Item=T>+Send+Sync` or `impl DoubleEndedIterator<Item=T>+Send+Sync`,
+erasing the returned iterator into a 
+`RIterator<'_,T>`/`RDoubleEndedIterator<'_,T>` respectively.
+
+The trait keeps the `impl Trait` return type,
+so implementors of the trait return any iterator that satisfies the bounds.
+The iterator is only erased in the vtable and in the inherent method of the trait object,
+whose return type is the `RIterator`/`RDoubleEndedIterator`.
+
+If the return type has neither the `Send` nor the `Sync` bound
+(eg:`impl Iterator<Item=T>`),
+the iterator is erased into a `RUnsendIterator<'_,T>`/`RUnsendDoubleEndedIterator<'_,T>`,
+which is neither `Send` nor `Sync`.
+Having only one of the `Send` and `Sync` bounds is an error,
+as is having any other bound.
+
+The lifetime bound of the `impl Trait` is used as the lifetime of the erased iterator,
+if none is specified it borrows from the `&self`/`&mut self` parameter
+(as though `+'_` was written).
+Methods taking `self` by value must specify the lifetime bound.
+
+Since `impl Trait` return types in traits were stabilized in Rust 1.75,
+this attribute requires Rust 1.75 or later.
+
+Example:
+
+This example is not tested,because it requires Rust 1.75,
+which is newer than the minimum Rust version supported by this crate.
+
+```ignore
+use abi_stable::{
+    sabi_trait,
+    std_types::RVec,
+};
+
+use std::rc::Rc;
+
+#[sabi_trait]
+pub trait Graph{
+    #[sabi(erase_return)]
+    fn neighbors(&self,node:u32)->impl Iterator<Item=u32>+Send+Sync;
+
+    #[sabi(erase_return)]
+    fn shared_neighbors(&self,node:u32)->impl Iterator<Item=u32>;
+}
+
+struct Adjacency(RVec<RVec<u32>>);
+
+impl Graph for Adjacency{
+    fn neighbors(&self,node:u32)->impl Iterator<Item=u32>+Send+Sync+'_ {
+        self.0[node as usize].iter().cloned()
+    }
+
+    fn shared_neighbors(&self,node:u32)->impl Iterator<Item=u32>+'_ {
+        // `Rc` is neither `Send` nor `Sync`,so neither is this iterator.
+        let neighbors=Rc::new(self.0[node as usize].clone());
+        (0..neighbors.len()).map(move|i| neighbors[i] )
+    }
+}
+
+# fn main(){
+let adjacency=Adjacency(vec![ vec![1,2].into(), vec![0].into(), RVec::new() ].into());
+let graph=Graph_TO::from_value(adjacency,sabi_trait::TU_Opaque);
+
+// This returns an `RIterator<'_,u32>`
+assert_eq!( graph.neighbors(0).collect::<Vec<u32>>(), vec![1,2] );
+assert_eq!( graph.neighbors(1).collect::<Vec<u32>>(), vec![0] );
+assert_eq!( graph.neighbors(2).next(), None );
+
+// This returns an `RUnsendIterator<'_,u32>`
+assert_eq!( graph.shared_neighbors(0).collect::<Vec<u32>>(), vec![1,2] );
+# }
+```
+
 # Associated types
 
 The only valid way to refer to associated types in the trait declaration is with 
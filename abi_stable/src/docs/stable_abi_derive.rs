@@ -21,6 +21,48 @@ for compatibility.
 Adds a virtual type parameter to the type layout constant,
 which is checked for compatibility.
 
+###  `#[sabi(phantom_only_type_param="TypeParameter")]` 
+
+Declares that a type parameter of the deriving type is only used inside of `PhantomData`s,
+so that it doesn't need to implement `StableAbi`:
+
+- Replaces the implicit `TypeParameter:StableAbi` constraint
+with a `TypeParameter:GetStaticEquivalent` constraint
+(unless another attribute changed the constraint).
+
+- The type parameter is stored as an opaque type in the type layout constant.
+
+- The `PhantomData` fields that mention the type parameter,
+and no other type parameter of the deriving type,
+are treated as opaque fields
+(as though they had the `#[sabi(unsafe_opaque_field)]` attribute).
+
+Any other use of the type parameter still requires it to implement `StableAbi`.
+
+Example:
+
+```
+use abi_stable::{StableAbi,GetStaticEquivalent};
+
+use std::marker::PhantomData;
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(phantom_only_type_param="T")]
+pub struct Handle<T>{
+    index:usize,
+    _marker:PhantomData<T>,
+}
+
+// This type does not implement StableAbi.
+#[derive(GetStaticEquivalent)]
+pub struct Document{
+    text:String,
+}
+
+let _=<Handle<Document> as StableAbi>::LAYOUT;
+```
+
 ###  `#[sabi(phantom_const_param="constant expression")]` 
 
 Adds a virtual const parameter to the type layout constant,
@@ -202,6 +244,24 @@ and won't be usable afterwards.
 
 - Error
 
+- IteratorSum:
+    enables `DynTrait::sum_erased`,requires the `Item` of the iterator to implement
+    `std::iter::Sum`.
+    this type will also have to implement `abi_stable::erased_types::IteratorItem`.
+
+- IteratorProduct:
+    enables `DynTrait::product_erased`,requires the `Item` of the iterator to implement
+    `std::iter::Product`.
+    this type will also have to implement `abi_stable::erased_types::IteratorItem`.
+
+- AsBytes: corresponds to `AsRef<[u8]>`,enables `DynTrait::sabi_as_bytes`.
+
+- AsStr: corresponds to `AsRef<str>`,enables `DynTrait::sabi_as_str`.
+
+- Extend: corresponds to `Extend<Item>`,enables `DynTrait::extend_from_erased`.
+    this type will also have to implement `abi_stable::erased_types::IteratorItem`,
+    to declare the `Item` type.
+
 <br>
 Examples:
 
@@ -316,6 +376,28 @@ which accessors are conditional for prefix fields.
 To do `#[sabi(accessible_if="<TypeParameter as Trait>::CONSTANT")]` you can use the 
 `#[sabi(prefix_bound="TypeParameter:Trait")]` helper attribute.
 
+###  `#[sabi(optional)]` 
+
+This is only valid for Prefix types,declared with `#[sabi(kind(Prefix(..)))]`,
+and can only be applied to fields after the one with the `#[sabi(last_prefix_field)]` attribute.
+
+Declares that the field may be absent,
+the accessor method for the field returns `ROption<FieldType>`,
+returning `RNone` if the field is absent,or if it's inaccessible,
+`RSome(field_value)` otherwise.
+
+When checking the layout of the prefix type,
+it's not an error for the type from the other dynlib/binary to not have 
+optional fields that come at the end of this type,
+regardless of which version is newer.
+This allows deprecated fields to be declared optional by newer versions of a library,
+so that they can be omitted later without breaking older users of that library.
+
+Optional fields never use the `#[sabi(missing_field( .. ))]` attribute applied to the struct,
+so `#[sabi(missing_field(panic))]` on the struct never causes the accessor 
+for an optional field to panic.
+Applying both `#[sabi(optional)]` and `#[sabi(missing_field( .. ))]` to a field is an error.
+
 ###  `#[sabi(refl(pub_getter=" function_name "))]` 
 
 Determines the public getter for a field used by reflection.
@@ -333,7 +415,8 @@ the default is that it returns an `Option<FieldType>`,
 returning None if the field is absent,Some(field_value) if it's present.
 
 If the attribute is on the struct,it's applied to all fields(this is overridable)
-after the `#[sabi(last_prefix_field)]` attribute.
+after the `#[sabi(last_prefix_field)]` attribute,
+except for the ones with the `#[sabi(optional)]` attribute.
 
 If the attribute is on a field,it's applied to that field only,
 overriding the setting on the struct.
@@ -33,6 +33,13 @@ pub mod trait_objects;
 
 pub mod type_info;
 
+pub mod type_erased_registry;
+
+#[cfg(feature="debug_diagnostics")]
+pub mod clone_diagnostics;
+
+pub mod erased_serializer;
+
 pub(crate) mod iterator;
 
 pub mod dyn_trait;
@@ -44,15 +51,21 @@ pub mod traits;
 
 
 pub use self::{
-    dyn_trait::{DynTrait, DynTraitBound},
-    vtable::{ GetVtable,InterfaceBound,VTableDT,enabled_traits },
+    dyn_trait::{DynTrait, DynTraitBound, clone_all, drop_all_homogeneous, sort_dyntraits},
+    interfaces::{
+        RIterator,RDoubleEndedIterator,
+        RUnsendIterator,RUnsendDoubleEndedIterator,
+    },
+    vtable::{ GetVtable,GetTryCloneVtable,InterfaceBound,VTableDT,enabled_traits },
     traits::{
-        ImplType, InterfaceType, 
-        DeserializeDyn, 
+        ImplType, InterfaceType, InterfaceSubsetOf, InterfaceNarrowableFrom,
+        DeserializeDyn, DeserializeDynError,
         SerializeImplType, SerializeProxyType, 
         IteratorItem,IteratorItemOrDefault,
     },
     type_info::TypeInfo,
+    type_erased_registry::TypeErasedRegistry,
+    erased_serializer::ErasedSerializer,
 };
 
 #[doc(no_inline)]
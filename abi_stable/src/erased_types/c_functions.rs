@@ -256,6 +256,53 @@ where
     }
 }
 
+//////////////////////////////////////////////////////////////////////////////////////
+////                        AsRef
+//////////////////////////////////////////////////////////////////////////////////////
+
+
+// These take an `ErasedObject<T>` so that `T:'a` is implied by the parameter type,
+// keeping `'a` late-bound,to be transmuted to `AsBytesFn`/`AsStrFn` in the vtable.
+pub(super) unsafe extern "C" fn as_bytes_impl<'a,T>(this:&'a ErasedObject<T>)->RSlice<'a,u8>
+where T:AsRef<[u8]>,
+{
+    extern_fn_panic_handling! {
+        let this=unsafe{ transmute_reference::<ErasedObject<T>,T>(this) };
+        this.as_ref().into()
+    }
+}
+
+pub(super) unsafe extern "C" fn as_str_impl<'a,T>(this:&'a ErasedObject<T>)->RStr<'a>
+where T:AsRef<str>,
+{
+    extern_fn_panic_handling! {
+        let this=unsafe{ transmute_reference::<ErasedObject<T>,T>(this) };
+        this.as_ref().into()
+    }
+}
+
+
+//////////////////////////////////////////////////////////////////////////////////////
+////                        Extend
+//////////////////////////////////////////////////////////////////////////////////////
+
+
+pub(super) unsafe extern "C" fn extend_impl<T,Item>(
+    this:&mut ErasedObject,
+    iter:RIterator<'_,Item>,
+)where
+    T:Extend<Item>,
+{
+    extern_fn_panic_handling! {
+        let this=unsafe{ transmute_mut_reference::<ErasedObject,T>(this) };
+        // Shortens the lifetime of the iterator to one that `Item` outlives,
+        // so that it implements `Iterator<Item=Item>`.
+        let iter:RIterator<'_,Item>=iter;
+        this.extend(iter);
+    }
+}
+
+
 //////////////////////////////////////////////////////////////////////////////////////
 ////                        fmt
 //////////////////////////////////////////////////////////////////////////////////////
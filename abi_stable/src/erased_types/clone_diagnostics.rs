@@ -0,0 +1,162 @@
+/*!
+Counts how many times `DynTrait`s of each erased type were cloned,
+to find unexpected clones of trait objects.
+
+This module is only available with the "debug_diagnostics" cargo feature,
+without it `DynTrait` doesn't count its clones.
+
+The counts are stored in a registry that is private to each copy of `abi_stable`,
+so a dynamic library counts the clones that it does,
+separately from the executable and other dynamic libraries.
+*/
+
+use std::collections::HashMap;
+
+use crate::{
+    erased_types::TypeInfo,
+    external_types::{RLazy,RMutex},
+    sabi_types::MaybeCmp,
+    std_types::UTypeId,
+};
+
+
+/// The key of a type in the clone registry.
+///
+/// Types that can't be unerased (because the `DynTrait` was constructed with
+/// one of the `from_borrowing_*` constructors) don't have a `UTypeId`,
+/// so they're identified by their name,size,and alignment instead.
+/// Type names are only available with the "rust_1_38" feature,
+/// without it all opaque types with the same size and alignment share a count.
+#[derive(Debug,Copy,Clone,PartialEq,Eq,Hash)]
+enum CloneCountKey{
+    Unerasable(UTypeId),
+    Opaque{
+        type_name:&'static str,
+        size:usize,
+        alignment:usize,
+    },
+}
+
+impl CloneCountKey{
+    fn new(info:&TypeInfo)->Self{
+        match info._uid.get() {
+            MaybeCmp::Just(id)=>CloneCountKey::Unerasable(id),
+            MaybeCmp::Nothing=>CloneCountKey::Opaque{
+                type_name:info.type_name.get().as_str(),
+                size:info.size,
+                alignment:info.alignment,
+            },
+        }
+    }
+}
+
+
+static CLONE_COUNTS:RLazy<RMutex<HashMap<CloneCountKey,usize>>>=RLazy::new();
+
+fn clone_counts()->&'static RMutex<HashMap<CloneCountKey,usize>>{
+    CLONE_COUNTS.get_or_init(|| RMutex::new(HashMap::new()) )
+}
+
+
+/// Records that a `DynTrait` of the type described by `info` was cloned.
+pub(crate) fn record_clone(info:&TypeInfo){
+    *clone_counts().lock().entry(CloneCountKey::new(info)).or_insert(0)+=1;
+}
+
+
+/**
+Gets how many times `DynTrait`s of the type described by `info` were cloned.
+
+`DynTrait::sabi_clone_count` is a more convenient way to call this function.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{
+        clone_diagnostics::clone_count,
+        interfaces::CloneInterface,
+        DynTrait,ImplType,TypeInfo,
+    },
+    impl_get_type_info,
+};
+
+#[derive(Clone)]
+struct Tracked;
+
+impl ImplType for Tracked {
+    type Interface=CloneInterface;
+    const INFO:&'static TypeInfo=impl_get_type_info! { Tracked };
+}
+
+let object=DynTrait::from_value(Tracked);
+assert_eq!( clone_count(Tracked::INFO), 0 );
+
+let _=object.clone();
+let _=object.clone();
+assert_eq!( clone_count(Tracked::INFO), 2 );
+
+```
+*/
+pub fn clone_count(info:&TypeInfo)->usize{
+    clone_counts()
+        .lock()
+        .get(&CloneCountKey::new(info))
+        .cloned()
+        .unwrap_or(0)
+}
+
+
+//////////////////////////////////////////////////////////////////////////////
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests{
+    use super::*;
+
+    use crate::{
+        erased_types::{interfaces::CloneInterface,DynTrait},
+        std_types::{RArc,RBox},
+    };
+
+    #[derive(Clone)]
+    struct Counted(u32);
+
+    #[derive(Clone)]
+    struct Borrowing<'a>(&'a u32);
+
+    #[derive(Clone)]
+    struct OtherBorrowing<'a>(&'a u32);
+
+    #[test]
+    fn counts_clones_per_type(){
+        let boxed=DynTrait::from_any_value(Counted(3),CloneInterface);
+        let arced=DynTrait::from_any_ptr(RArc::new(Counted(5)),CloneInterface);
+
+        assert_eq!(boxed.sabi_clone_count(),0);
+
+        let cloned:DynTrait<'_,RBox<()>,CloneInterface>=boxed.clone();
+        let _=cloned.clone();
+        let _=arced.clone();
+        let _=boxed.reborrow().clone();
+
+        assert_eq!(boxed.sabi_clone_count(),4);
+        assert_eq!(arced.sabi_clone_count(),4);
+        assert_eq!(cloned.sabi_clone_count(),4);
+    }
+
+    #[cfg(feature="rust_1_38")]
+    #[test]
+    fn counts_opaque_types_separately(){
+        let number=8;
+        let borrowing=DynTrait::from_borrowing_value(Borrowing(&number),CloneInterface);
+        let other=DynTrait::from_borrowing_value(OtherBorrowing(&number),CloneInterface);
+
+        let _=borrowing.clone();
+        let _=borrowing.clone();
+        let _=other.clone();
+
+        assert_eq!(borrowing.sabi_clone_count(),2);
+        assert_eq!(other.sabi_clone_count(),1);
+    }
+}
@@ -7,7 +7,8 @@ use std::{
     io,
     ops::DerefMut,
     marker::PhantomData,
-    mem::ManuallyDrop,
+    mem::{self,ManuallyDrop},
+    pin::Pin,
     ptr,
     rc::Rc,
 };
@@ -21,11 +22,11 @@ use crate::{
     abi_stability::SharedStableAbi,
     pointer_trait::{
         CanTransmuteElement,TransmuteElement,OwnedPointer,
-        GetPointerKind,PK_SmartPointer,PK_Reference,PointerKind,
+        GetPointerKind,PK_SmartPointer,PK_Reference,PointerKind,DefaultPointerKind,
     },
     marker_type::{ErasedObject,UnsafeIgnoredType}, 
-    sabi_types::{Constructor,MovePtr,RRef,StaticRef},
-    std_types::{RBox, RStr,RVec,RIoError},
+    sabi_types::{Constructor,MovePtr,PinnedPtr,RRef,StaticRef},
+    std_types::{RBox, RStr,RSlice,RVec,RIoError,RResult,RSome,RNone},
     type_level::{
         unerasability::{TU_Unerasable,TU_Opaque},
         impl_enum::{Implemented,Unimplemented},
@@ -38,10 +39,13 @@ use crate::std_types::Tuple2;
 
 use super::*;
 use super::{
-    c_functions::adapt_std_fmt,
+    c_functions::{adapt_std_fmt,cmp_ord},
+    iterator::{ErasedCmpFn,ErasedPredicate},
     trait_objects::*,
-    vtable::{GetVtable, VTable},
-    traits::{InterfaceFor,DeserializeDyn,GetSerializeProxyType},
+    vtable::{GetVtable, GetTryCloneVtable, VTable, enabled_traits::EnabledTraits},
+    traits::{
+        InterfaceFor,InterfaceNarrowableFrom,NarrowedImplType,DeserializeDyn,GetSerializeProxyType,
+    },
     IteratorItemOrDefault,
 };
 
@@ -117,6 +121,10 @@ These are the traits:
 
 - DoubleEndedIterator
 
+- IteratorSum/IteratorProduct: 
+    Used to sum/multiply the items of an iterator in a single call,
+    with the `sum_erased`/`product_erased` methods.
+
 - std::fmt::Write
 
 - std::io::Write
@@ -127,6 +135,16 @@ These are the traits:
 
 - std::io::BufRead
 
+- AsBytes/AsStr:
+    Corresponds to `AsRef<[u8]>`/`AsRef<str>`,
+    used to view the erased value as bytes/a string,
+    with the `sabi_as_bytes`/`sabi_as_str` methods.
+
+- Extend:
+    Corresponds to `Extend<Item>`,
+    used to extend the erased collection with an `RIterator<'_,Item>` in a single call,
+    with the `extend_from_erased` method.
+
 - Clone 
 
 - Display 
@@ -213,6 +231,35 @@ the binary or lib0 will cause the panic to happen if:
 - lib1 or lib2 attempt to call methods that require the traits that were added 
     to the InterfaceType,in versions of that interface that only they know about.
 
+To reject those `DynTrait`s instead of panicking,
+a dynamic library that receives a `DynTrait` from a sibling can check that
+the interface it was constructed with implements all the traits it expects,
+with `DynTrait::sabi_is_interface_compatible` 
+(or compare the tag returned by `DynTrait::sabi_interface_tag` itself):
+
+```
+use abi_stable::{
+    erased_types::interfaces::DebugDisplayInterface,
+    std_types::{RBox,RResult,ROk,RErr,RString},
+    DynTrait,
+};
+
+type Object=DynTrait<'static,RBox<()>,DebugDisplayInterface>;
+
+// The function that a sibling dynamic library would call.
+extern "C" fn describe(object:Object)->RResult<RString,Object>{
+    if !object.sabi_is_interface_compatible() {
+        return RErr(object);
+    }
+    ROk(format!("{}",object).into())
+}
+
+let object=DynTrait::from_any_value(3_u8,DebugDisplayInterface);
+
+assert_eq!( describe(object).unwrap().as_str(), "3" );
+
+```
+
 
 
 
@@ -270,6 +317,10 @@ impl<'borr> DeserializeDyn<'borr,FooInterfaceBox> for FooInterface {
             .deserialize_foo()(s.get_rstr())
             .into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'borr>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
@@ -634,6 +685,131 @@ impl<'a> IteratorItem<'a> for IteratorInterface{
             }
         }
 
+        /**
+Constructs the `DynTrait<_>` from a `T:ImplType`,
+with an interface that enables a subset of the traits in `T::Interface`.
+
+Like `from_value`,the `DynTrait<_>` can be unerased back into a `T`,
+with the better error messages that `ImplType` provides.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{DynTrait,ImplType,TypeInfo},
+    impl_get_type_info,
+    std_types::RBox,
+    StableAbi,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Clone,Debug,PartialEq))]
+pub struct WideInterface;
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Debug))]
+pub struct NarrowInterface;
+
+
+#[derive(Debug,Clone,PartialEq)]
+struct Point{
+    x:u32,
+    y:u32,
+}
+
+impl ImplType for Point {
+    type Interface=WideInterface;
+    const INFO:&'static TypeInfo=impl_get_type_info! { Point };
+}
+
+
+let object:DynTrait<'static,RBox<()>,NarrowInterface>=
+    DynTrait::from_value_as(Point{x:3,y:5},NarrowInterface);
+
+assert_eq!( format!("{:?}",object), "Point { x: 3, y: 5 }" );
+
+assert_eq!(
+    object.into_unerased_impltype::<Point>().ok().map(RBox::into_inner),
+    Some(Point{x:3,y:5}),
+);
+
+```
+
+This wouldn't compile,because `WideInterface` doesn't enable `Hash`:
+
+```compile_fail
+use abi_stable::{
+    erased_types::{DynTrait,ImplType,TypeInfo},
+    impl_get_type_info,
+    StableAbi,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Clone,Debug))]
+pub struct WideInterface;
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Debug,Hash))]
+pub struct NotNarrowInterface;
+
+
+#[derive(Debug,Clone,Hash)]
+struct Point{
+    x:u32,
+    y:u32,
+}
+
+impl ImplType for Point {
+    type Interface=WideInterface;
+    const INFO:&'static TypeInfo=impl_get_type_info! { Point };
+}
+
+
+let _=DynTrait::from_value_as(Point{x:3,y:5},NotNarrowInterface);
+
+```
+
+        */
+        pub fn from_value_as<T,I>(object: T,interface:I) -> DynTrait<'static,RBox<()>,I>
+        where
+            T: ImplType,
+            I:InterfaceBound,
+            NarrowedImplType<T,I>: GetVtable<'static,T,RBox<()>,RBox<T>,I>,
+        {
+            let object = RBox::new(object);
+            DynTrait::from_ptr_as(object,interface)
+        }
+
+        /// Constructs the `DynTrait<_>` from a pointer to a `T:ImplType`,
+        /// with an interface that enables a subset of the traits in `T::Interface`.
+        ///
+        /// For an example,look at [`from_value_as`](#method.from_value_as).
+        pub fn from_ptr_as<P, T,I>(
+            object: P,
+            _interface:I
+        ) -> DynTrait<'static,P::TransmutedPtr,I>
+        where
+            T: ImplType,
+            I:InterfaceBound,
+            NarrowedImplType<T,I>: GetVtable<'static,T,P::TransmutedPtr,P,I>,
+            P: Deref<Target = T>+CanTransmuteElement<()>+GetPointerKind,
+            P::TransmutedPtr:GetPointerKind,
+        {
+            DynTrait {
+                object: unsafe{
+                    ManuallyDrop::new(object.transmute_element::<()>())
+                },
+                vtable: <NarrowedImplType<T,I>>::_GET_INNER_VTABLE,
+                extra_value:(),
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            }
+        }
+
         /// Constructs the `DynTrait<_>` from a type that doesn't borrow anything.
         pub fn from_any_value<T,I>(object: T,interface:I) -> DynTrait<'static,RBox<()>,I>
         where
@@ -668,7 +844,282 @@ impl<'a> IteratorItem<'a> for IteratorInterface{
                 _marker2:UnsafeIgnoredType::DEFAULT,
             }
         }
+
+/**
+Constructs the `DynTrait<_>` from a `Box<dyn Trait>` that doesn't borrow anything,
+for traits that `Box<dyn Trait>` itself implements,
+eg:`Box<dyn Iterator<Item=T>>`,`Box<dyn Debug>`.
+
+This allows erasing trait objects created by code that doesn't use `abi_stable`,
+since the trait object itself can't be moved out of the `Box`.
+
+The `DynTrait` can be unerased back into the `Box<T>`.
+
+# Indirection
+
+The `Box<T>` is itself stored in an `RBox<_>`,
+so every method call goes through two pointers and two vtables:
+the vtable of the `DynTrait` and then the vtable of the `dyn Trait`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::{DebugInterface,IteratorInterface},
+    DynTrait,
+};
+
+use std::fmt::Debug;
+
+let debug:Box<dyn Debug>=Box::new(vec![3,5,8]);
+let object=DynTrait::from_any_value_boxed_dyn(debug,DebugInterface);
+assert_eq!( format!("{:?}",object), "[3, 5, 8]" );
+
+let iter:Box<dyn Iterator<Item=u32>+Send+Sync>=Box::new((0..4).map(|x| x*10 ));
+let mut object=DynTrait::from_any_value_boxed_dyn(iter,IteratorInterface::NEW);
+assert_eq!( object.next(), Some(0) );
+assert_eq!( object.collect::<Vec<u32>>(), vec![10,20,30] );
+
+```
+*/
+        pub fn from_any_value_boxed_dyn<T,I>(object: Box<T>,interface:I) -> DynTrait<'static,RBox<()>,I>
+        where
+            T:?Sized+'static,
+            I:InterfaceBound,
+            InterfaceFor<Box<T>,I,TU_Unerasable> : GetVtable<'static,Box<T>,RBox<()>,RBox<Box<T>>,I>,
+        {
+            DynTrait::from_any_value(object,interface)
+        }
         
+        /// Constructs the `DynTrait<_>` from a clonable type that doesn't borrow anything,
+        /// which can be cloned with [`sabi_try_clone`](#method.sabi_try_clone)
+        /// even if `I` doesn't require `Clone`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     std_types::{RBox,RString},
+        ///     DynTrait,
+        /// };
+        ///
+        /// struct NotClone;
+        ///
+        /// impl std::fmt::Debug for NotClone {
+        ///     fn fmt(&self,f:&mut std::fmt::Formatter<'_>)->std::fmt::Result{
+        ///         f.write_str("NotClone")
+        ///     }
+        /// }
+        ///
+        /// impl std::fmt::Display for NotClone {
+        ///     fn fmt(&self,f:&mut std::fmt::Formatter<'_>)->std::fmt::Result{
+        ///         f.write_str("NotClone")
+        ///     }
+        /// }
+        ///
+        /// let list:Vec<DynTrait<'static,RBox<()>,DebugDisplayInterface>>=vec![
+        ///     DynTrait::from_cloneable_value(RString::from("hello"),DebugDisplayInterface),
+        ///     DynTrait::from_any_value(NotClone,DebugDisplayInterface),
+        ///     DynTrait::from_cloneable_value(1337_u32,DebugDisplayInterface),
+        /// ];
+        ///
+        /// let cloned=list.iter()
+        ///     .filter_map(|x| x.sabi_try_clone().into_option() )
+        ///     .map(|x| x.to_string() )
+        ///     .collect::<Vec<String>>();
+        ///
+        /// assert_eq!(cloned,vec!["hello".to_string(),"1337".to_string()]);
+        ///
+        /// ```
+        pub fn from_cloneable_value<T,I>(object: T,interface:I) -> DynTrait<'static,RBox<()>,I>
+        where
+            T:Clone+'static,
+            I:InterfaceBound,
+            InterfaceFor<T,I,TU_Unerasable> : GetVtable<'static,T,RBox<()>,RBox<T>,I>,
+        {
+            let object = RBox::new(object);
+            DynTrait::from_cloneable_ptr(object,interface)
+        }
+
+        /// Constructs the `DynTrait<_>` from a clonable pointer to a 
+        /// type that doesn't borrow anything,
+        /// which can be cloned with [`sabi_try_clone`](#method.sabi_try_clone)
+        /// even if `I` doesn't require `Clone`.
+        pub fn from_cloneable_ptr<P, T,I>(
+            object: P,
+            _interface:I
+        ) -> DynTrait<'static,P::TransmutedPtr,I>
+        where
+            I:InterfaceBound,
+            T:'static,
+            InterfaceFor<T,I,TU_Unerasable>: GetVtable<'static,T,P::TransmutedPtr,P,I>,
+            P: Deref<Target = T>+CanTransmuteElement<()>+GetPointerKind+Clone,
+            P::TransmutedPtr:GetPointerKind,
+        {
+            DynTrait {
+                object: unsafe{
+                    ManuallyDrop::new(object.transmute_element::<()>())
+                },
+                vtable: <InterfaceFor<T,I,TU_Unerasable>>::_GET_TRY_CLONE_VTABLE,
+                extra_value:(),
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            }
+        }
+
+        /// Constructs the `DynTrait<_>` from a type that doesn't borrow anything,
+        /// pinning it inside of an `RBox`.
+        ///
+        /// Refer to [`from_pinned_ptr`](#method.from_pinned_ptr) 
+        /// for which methods are available for pinned `DynTrait<_>`s.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     sabi_types::PinnedPtr,
+        ///     std_types::RBox,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let object=DynTrait::from_pinned_value(3_u32,DebugDisplayInterface);
+        ///
+        /// assert_eq!( object.to_string(), "3" );
+        /// assert_eq!( object.as_unerased::<u32>().ok(), Some(&3) );
+        ///
+        /// let unerased:PinnedPtr<RBox<u32>>=object.into_unerased::<u32>().unwrap();
+        /// assert_eq!( *unerased.into_pin(), 3 );
+        ///
+        /// ```
+        pub fn from_pinned_value<T,I>(
+            object: T,
+            interface:I,
+        ) -> DynTrait<'static,PinnedPtr<RBox<()>>,I>
+        where
+            T:'static,
+            I:InterfaceBound,
+            InterfaceFor<T,I,TU_Unerasable> : 
+                GetVtable<'static,T,PinnedPtr<RBox<()>>,PinnedPtr<RBox<T>>,I>,
+        {
+            // The value was never observed by the caller at this address,
+            // so it's fine to pin it here.
+            let object = unsafe{ Pin::new_unchecked(RBox::new(object)) };
+            DynTrait::from_pinned_ptr(object,interface)
+        }
+
+        /// Constructs the `DynTrait<_>` from a pinned pointer to a 
+        /// type that doesn't borrow anything.
+        ///
+        /// The pointer is stored as a `PinnedPtr<P>`,
+        /// which only allows getting a shared reference to the erased value,
+        /// so these methods are not available for the returned `DynTrait<_>`,
+        /// since they would allow moving the erased value:
+        ///
+        /// - The methods that require `P:DerefMut`,
+        /// like `as_unerased_mut`/`sabi_replace_value`/`reborrow_mut`,
+        /// and the methods of the traits that take `&mut self`
+        /// (`Iterator`,`io::Write`,etc).
+        ///
+        /// - The methods that require `P:OwnedPointer`,like `sabi_with_value`.
+        ///
+        /// - `map_unerased`,because it's only implemented for `RBox<()>` pointers.
+        ///
+        /// Getting a shared reference to the erased value with `as_unerased`,
+        /// calling the methods of the traits that `I` requires that take `&self`,
+        /// and unerasing it into a `PinnedPtr<P>` with `into_unerased`
+        /// (which can be converted back into a `Pin<P>`) is still possible.
+        ///
+        /// Since the pinning is encoded in the pointer type,
+        /// the `DynTrait<_>` can be passed to dynamic libraries built with 
+        /// older versions of abi_stable,
+        /// as a `DynTrait<'static,PinnedPtr<P::TransmutedPtr>,I>`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     std_types::RBox,
+        ///     DynTrait,
+        /// };
+        ///
+        /// use std::{
+        ///     fmt,
+        ///     marker::PhantomPinned,
+        ///     pin::Pin,
+        /// };
+        ///
+        /// struct Unmovable{
+        ///     name:&'static str,
+        ///     _pin:PhantomPinned,
+        /// }
+        ///
+        /// impl fmt::Debug for Unmovable{
+        ///     fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        ///         fmt::Display::fmt(self,f)
+        ///     }
+        /// }
+        ///
+        /// impl fmt::Display for Unmovable{
+        ///     fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        ///         f.write_str(self.name)
+        ///     }
+        /// }
+        ///
+        /// let pinned:Pin<RBox<Unmovable>>=unsafe{
+        ///     Pin::new_unchecked(RBox::new(Unmovable{ name:"hello", _pin:PhantomPinned }))
+        /// };
+        ///
+        /// let object=DynTrait::from_pinned_ptr(pinned,DebugDisplayInterface);
+        ///
+        /// assert_eq!( object.to_string(), "hello" );
+        /// assert_eq!( object.as_unerased::<Unmovable>().unwrap().name, "hello" );
+        ///
+        /// let pinned:Pin<RBox<Unmovable>>=
+        ///     object.into_unerased::<Unmovable>().unwrap().into_pin();
+        /// assert_eq!( pinned.name, "hello" );
+        ///
+        /// ```
+        ///
+        /// Getting a mutable reference to the erased value doesn't compile:
+        ///
+        /// ```compile_fail
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let mut object=DynTrait::from_pinned_value(3_u32,DebugDisplayInterface);
+        ///
+        /// let _=object.as_unerased_mut::<u32>();
+        ///
+        /// ```
+        pub fn from_pinned_ptr<P, T,I>(
+            object: Pin<P>,
+            _interface:I
+        ) -> DynTrait<'static,PinnedPtr<P::TransmutedPtr>,I>
+        where
+            I:InterfaceBound,
+            T:'static,
+            InterfaceFor<T,I,TU_Unerasable>: 
+                GetVtable<'static,T,PinnedPtr<P::TransmutedPtr>,PinnedPtr<P>,I>,
+            P: Deref<Target = T>+CanTransmuteElement<()>+GetPointerKind,
+            P::TransmutedPtr:GetPointerKind,
+        {
+            let object=PinnedPtr::from_pin(object);
+            DynTrait {
+                object: unsafe{
+                    ManuallyDrop::new(object.transmute_element::<()>())
+                },
+                vtable: <InterfaceFor<T,I,TU_Unerasable>>::_GET_INNER_VTABLE,
+                extra_value:(),
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            }
+        }
+
         /// Constructs the `DynTrait<_>` from a value with a `'borr` borrow.
         ///
         /// Cannot unerase the DynTrait afterwards.
@@ -895,6 +1346,34 @@ fn main(){
             &self.extra_value
         }
 
+        /// Whether this `DynTrait<_>` was constructed with one of the 
+        /// `DynTrait::from_borrowing_*` constructors.
+        ///
+        /// `DynTrait`s constructed that way can't be unerased,
+        /// and are never considered to wrap the same type as another `DynTrait`
+        /// by `sabi_is_same_type`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let owned=DynTrait::from_any_value(3_u32,DebugDisplayInterface);
+        /// assert!( !owned.sabi_is_borrowing() );
+        /// assert!( owned.as_unerased::<u32>().is_ok() );
+        ///
+        /// let borrowing=DynTrait::from_borrowing_value(3_u32,DebugDisplayInterface);
+        /// assert!( borrowing.sabi_is_borrowing() );
+        ///
+        /// ```
+        #[inline]
+        pub fn sabi_is_borrowing(&self)->bool{
+            !self.sabi_vtable().type_info().is_unerasable()
+        }
+
         #[inline]
         pub(super) fn sabi_vtable<'a>(&self) -> &'a VTable<'borr,P,I>{
             unsafe {
@@ -907,6 +1386,59 @@ fn main(){
             self.vtable.get_raw() as usize
         }
 
+        #[inline]
+        pub(super) fn sabi_extra_value_mut(&mut self)->&mut EV{
+            &mut self.extra_value
+        }
+
+        /// Returns a raw pointer to the vtable of this `DynTrait<_>`,
+        /// for calling the functions in it manually.
+        ///
+        /// This is the same pointer that `DynTrait::sabi_into_raw_parts` returns.
+        ///
+        /// Dereferencing the returned pointer is unsafe,
+        /// the layout of the vtable is described below.
+        ///
+        /// # Vtable layout
+        ///
+        /// The vtable is a prefix type (described in the `prefix_types` docs),
+        /// and this points to the `WithMetadata_` (from the `prefix_type` module) that wraps it,
+        /// which contains the accessibility and layout of the fields of the vtable,
+        /// followed by the fields of the vtable themselves,which start with:
+        ///
+        /// - `type_info:&'static TypeInfo`:the `TypeInfo` of the erased type.
+        ///
+        /// - `drop_ptr:unsafe extern "C" fn(&mut P)`:the destructor for the pointer.
+        ///
+        /// These are followed by the functions for each trait,
+        /// which are only accessible if the trait was enabled in the 
+        /// `InterfaceType` that the `DynTrait<_>` was constructed with.
+        ///
+        /// For `#[sabi_trait]` trait objects,the vtable of the trait's methods is 
+        /// the one returned by `sabi_et_vtable`,not this one.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let object=DynTrait::from_any_value(3_u8,DebugDisplayInterface);
+        /// let other =DynTrait::from_any_value(5_u8,DebugDisplayInterface);
+        /// let string=DynTrait::from_any_value("hello",DebugDisplayInterface);
+        ///
+        /// // DynTraits constructed from the same type share the same vtable.
+        /// assert_eq!( object.sabi_raw_vtable(), other.sabi_raw_vtable() );
+        /// assert_ne!( object.sabi_raw_vtable(), string.sabi_raw_vtable() );
+        ///
+        /// ```
+        #[inline]
+        pub fn sabi_raw_vtable(&self) -> *const () {
+            self.vtable.get_raw() as *const ()
+        }
+
         /// Returns the address of the wrapped object.
         pub fn sabi_object_address(&self) -> usize
         where
@@ -961,9 +1493,126 @@ fn main(){
             OwnedPointer::with_move_ptr(self.sabi_into_erased_ptr(),f)
         }
 
+        /// Decomposes this `DynTrait` into the pointer to the erased value,
+        /// a pointer to its vtable,and the extra value.
+        ///
+        /// This is meant for storing the parts of `DynTrait`s in custom containers,
+        /// to later reconstruct them with `DynTrait::sabi_from_raw_parts`.
+        ///
+        /// # Safety
+        ///
+        /// The returned pointer does not drop the erased value correctly by itself,
+        /// it must be passed back to `DynTrait::sabi_from_raw_parts` to be dropped.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     std_types::RBox,
+        ///     DynTrait,
+        /// };
+        ///
+        /// type Object=DynTrait<'static,RBox<()>,DebugDisplayInterface>;
+        ///
+        /// let object:Object=DynTrait::from_any_value(1337_u32,DebugDisplayInterface);
+        ///
+        /// let (ptr,vtable,extra)=unsafe{ object.sabi_into_raw_parts() };
+        ///
+        /// let object=unsafe{ Object::sabi_from_raw_parts(ptr,vtable,extra) };
+        /// assert_eq!( object.to_string(), "1337" );
+        /// assert_eq!( object.as_unerased::<u32>().ok(), Some(&1337) );
+        ///
+        /// ```
+        pub unsafe fn sabi_into_raw_parts(self)->(P,*const (),EV){
+            let this=ManuallyDrop::new(self);
+            let object=ManuallyDrop::into_inner(ptr::read(&this.object));
+            let vtable=this.vtable.get_raw() as *const ();
+            let extra_value=ptr::read(&this.extra_value);
+            (object,vtable,extra_value)
+        }
+
+        /// Reconstructs a `DynTrait` from the parts returned by 
+        /// `DynTrait::sabi_into_raw_parts`.
+        ///
+        /// # Safety
+        ///
+        /// All the parts must come from the same call to `DynTrait::sabi_into_raw_parts`,
+        /// on a `DynTrait<'borr,P,I,EV>` with the same lifetime and type parameters as `Self`.
+        ///
+        /// In particular,the vtable must not come from a reborrowed `DynTrait`
+        /// (returned by `reborrow`/`reborrow_mut`),
+        /// since its functions only work with the reference it was reborrowed as.
+        ///
+        /// # Example
+        ///
+        /// For an example look at `DynTrait::sabi_into_raw_parts`.
+        pub unsafe fn sabi_from_raw_parts(object:P,vtable:*const (),extra_value:EV)->Self{
+            DynTrait{
+                object:ManuallyDrop::new(object),
+                vtable:StaticRef::from_raw(vtable as *const VTable<'borr,P,I>),
+                extra_value,
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            }
+        }
+
+        /// Replaces the extra value (usually an extra vtable) of this `DynTrait`,
+        /// keeping the erased value and the vtable of the `DynTrait` unchanged.
+        ///
+        /// The previous extra value is dropped.
+        ///
+        /// This is meant for libraries that add capabilities to
+        /// a `DynTrait` after it was constructed,without erasing the value again.
+        ///
+        /// # Safety
+        ///
+        /// This has the same requirements as the extra vtable passed to
+        /// `DynTrait::with_vtable`:
+        /// if `extra_vtable` contains functions that take the erased pointer/value,
+        /// they must be compatible with the type of the value erased in this `DynTrait`,
+        /// and with the `P` pointer type.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     DynTrait,
+        /// };
+        ///
+        /// let object=DynTrait::from_any_value(1337_u32,DebugDisplayInterface);
+        ///
+        /// let object=unsafe{ object.sabi_with_extra_vtable("first layer") };
+        /// assert_eq!( *object.sabi_extra_value(), "first layer" );
+        ///
+        /// let object=unsafe{ object.sabi_with_extra_vtable(100_u8) };
+        /// assert_eq!( *object.sabi_extra_value(), 100 );
+        /// assert_eq!( object.to_string(), "1337" );
+        /// assert_eq!( object.as_unerased::<u32>().ok(), Some(&1337) );
+        ///
+        /// ```
+        pub unsafe fn sabi_with_extra_vtable<EV2>(
+            self,
+            extra_vtable:EV2,
+        )->DynTrait<'borr,P,I,EV2>{
+            let mut this=ManuallyDrop::new(self);
+            let object=ptr::read(&this.object);
+            let vtable=this.vtable;
+            let new=DynTrait{
+                object,
+                vtable,
+                extra_value:extra_vtable,
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            };
+            ptr::drop_in_place(&mut this.extra_value);
+            new
+        }
+
+
+    }
 
-    }
-
 
     impl<'borr,P,I,EV> DynTrait<'borr,P,I,EV> 
     where
@@ -1017,6 +1666,84 @@ fn main(){
             }
         }
 
+        /// Unwraps the `DynTrait<_>` into a pointer of 
+        /// the concrete type that it was constructed with,
+        /// also allowing `T` to come from a different dynamic library/executable
+        /// than the one that constructed the `DynTrait<_>`.
+        ///
+        /// T is required to implement ImplType.
+        ///
+        /// This succeeds if the `TypeInfo` of `T` is the same one that the 
+        /// `DynTrait<_>` was constructed with (like `into_unerased_impltype`),
+        /// or if they have the same contents,
+        /// as determined by `TypeInfo::has_same_contents`
+        /// (which always returns false before Rust 1.38).
+        ///
+        /// # Safety
+        ///
+        /// **This trusts that two types with the same name,module,package,
+        /// package version,size,and alignment are the same type**,
+        /// the layout of the type itself is not compared,
+        /// because it is not stored in the `TypeInfo`.
+        ///
+        /// The caller must ensure that both dynamic libraries/executables
+        /// were compiled from the same source code for `T`,
+        /// with the same versions of the dependencies that `T` uses.
+        ///
+        /// # Errors
+        ///
+        /// This will return an error in any of these conditions:
+        ///
+        /// - The DynTrait was constructed using a `from_borrowing_*` method
+        ///
+        /// - `T` is not the concrete type this `DynTrait<_>` was constructed with,
+        /// nor a type with the same `TypeInfo` contents.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     DynTrait,ImplType,
+        ///     erased_types::TypeInfo,
+        ///     impl_get_type_info,
+        ///     std_types::RBox,
+        /// };
+        ///
+        /// #[derive(Debug,PartialEq)]
+        /// struct Counter(u32);
+        ///
+        /// impl ImplType for Counter {
+        ///     type Interface=();
+        ///     const INFO:&'static TypeInfo=impl_get_type_info! { Counter };
+        /// }
+        ///
+        /// let object=DynTrait::from_value(Counter(3));
+        ///
+        /// let unerased=unsafe{ object.sabi_into_unerased_cross_lib::<Counter>() };
+        /// assert_eq!( unerased.ok(), Some(RBox::new(Counter(3))) );
+        ///
+        /// ```
+        pub unsafe fn sabi_into_unerased_cross_lib<T>(
+            self
+        ) -> Result<P::TransmutedPtr, UneraseError<Self>>
+        where
+            P: CanTransmuteElement<T>,
+            P::Target:Sized,
+            T: ImplType,
+        {
+            let t_info = T::INFO;
+            let found_info = self.sabi_vtable().type_info();
+            if !found_info.is_compatible(t_info) && !found_info.has_same_contents(t_info) {
+                return Err(UneraseError {
+                    dyn_trait:self,
+                    expected_type_info:t_info,
+                    found_type_info:found_info,
+                });
+            }
+            let this=ManuallyDrop::new(self);
+            Ok(ptr::read(&*this.object).transmute_element::<T>())
+        }
+
         /// Unwraps the `DynTrait<_>` into a reference of 
         /// the concrete type that it was constructed with.
         ///
@@ -1163,6 +1890,59 @@ fn main(){
             unsafe { Ok(self.sabi_object_as_mut()) }
         }
 
+        /// Replaces the value that the `DynTrait<_>` wraps with `new`,
+        /// returning the previous value.
+        ///
+        /// This keeps the pointer (and its allocation) and the vtable,
+        /// which is why `T` is required to be the type the `DynTrait<_>` was constructed with.
+        ///
+        /// T is required to not borrow anything.
+        ///
+        /// # Errors
+        ///
+        /// This will return `new` inside the error in any of these conditions:
+        ///
+        /// - It is called in a dynamic library/binary outside
+        /// the one from which this `DynTrait<_>` was constructed.
+        ///
+        /// - The DynTrait was constructed using a `from_borrowing_*` method
+        ///
+        /// - `T` is not the concrete type this `DynTrait<_>` was constructed with.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        ///     std_types::{RBox,RString},
+        ///     DynTrait,
+        /// };
+        ///
+        /// let mut object:DynTrait<'static,RBox<()>,DebugDisplayInterface>=
+        ///     DynTrait::from_any_value(RString::from("hello"),DebugDisplayInterface);
+        ///
+        /// let prev=object.sabi_replace_value(RString::from("world")).unwrap();
+        /// assert_eq!(prev.as_str(),"hello");
+        /// assert_eq!(object.to_string(),"world");
+        ///
+        /// let err=object.sabi_replace_value(100_u32).unwrap_err();
+        /// assert_eq!(err.into_inner(),100);
+        /// assert_eq!(object.to_string(),"world");
+        ///
+        /// ```
+        pub fn sabi_replace_value<T>(&mut self,new:T) -> Result<T, UneraseError<T>>
+        where
+            P: DerefMut + CanTransmuteElement<T>,
+            Self:DynTraitBound<'borr>,
+            InterfaceFor<T,I,TU_Unerasable>: ImplType,
+        {
+            check_unerased!(
+                new,
+                self.sabi_check_same_destructor::<InterfaceFor<T,I,TU_Unerasable>,T>()
+            );
+            unsafe { Ok(mem::replace(self.sabi_object_as_mut::<T>(),new)) }
+        }
+
         /// Unwraps the `DynTrait<_>` into a pointer to T,
         /// without checking whether `T` is the type that the DynTrait was constructed with.
         ///
@@ -1213,6 +1993,98 @@ fn main(){
     }
 
 
+    impl<I,EV> DynTrait<'static,RBox<()>,I,EV> 
+    where
+        I:InterfaceBound,
+    {
+        /**
+Transforms the value this wraps with `f`,
+returning a `DynTrait<_>` that wraps the returned value.
+
+This is for transforming a `DynTrait<_>` in the same dynamic library/executable 
+that constructed it,
+by unerasing it into the concrete type it was constructed with,
+passing it to `f`,and then erasing the value that `f` returns.
+
+To transform a `DynTrait<_>` constructed in another dynamic library,
+that library must provide a function that does the transformation
+(ie:`extern "C" fn(DynTrait<'static,RBox<()>,I>)->DynTrait<'static,RBox<()>,I>`),
+in which it can call this method.
+
+The extra value is moved to the returned `DynTrait<_>`.
+
+# Errors
+
+This will return an error in any of these conditions:
+
+- It is called in a dynamic library/binary outside
+the one from which this `DynTrait<_>` was constructed.
+
+- The DynTrait was constructed using a `from_borrowing_*` method
+
+- `T` is not the concrete type this `DynTrait<_>` was constructed with.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::DebugDisplayInterface,
+    DynTrait,
+};
+
+let number=DynTrait::from_any_value(3_u32,DebugDisplayInterface);
+
+let text=number.map_unerased(|x:u32| format!("{}!",x*2) ).unwrap();
+assert_eq!( text.to_string(), "6!" );
+
+let text=text.map_unerased(|x:String| x.len() as u64 ).unwrap();
+assert_eq!( text.to_string(), "2" );
+
+// `text` doesn't wrap a `u32`,so it's returned in the error.
+let text=text.map_unerased(|x:u32| x ).unwrap_err().into_inner();
+assert_eq!( text.to_string(), "2" );
+
+```
+        */
+        pub fn map_unerased<T,U,F>(
+            self,
+            f:F,
+        ) -> Result<DynTrait<'static,RBox<()>,I,EV>, UneraseError<Self>>
+        where
+            T:'static,
+            U:'static,
+            F:FnOnce(T)->U,
+            Self:DynTraitBound<'static>,
+            InterfaceFor<T,I,TU_Unerasable>: ImplType,
+            InterfaceFor<U,I,TU_Unerasable>: GetVtable<'static,U,RBox<()>,RBox<U>,I>,
+        {
+            check_unerased!(
+                self,
+                self.sabi_check_same_destructor::<InterfaceFor<T,I,TU_Unerasable>,T>()
+            );
+            let (object,extra_value)=unsafe { 
+                let this=ManuallyDrop::new(self);
+                (
+                    ptr::read(&*this.object).transmute_element::<T>(),
+                    ptr::read(&this.extra_value),
+                )
+            };
+            
+            let object=RBox::new(f(RBox::into_inner(object)));
+
+            Ok(DynTrait {
+                object: unsafe{
+                    ManuallyDrop::new(object.transmute_element::<()>())
+                },
+                vtable: <InterfaceFor<U,I,TU_Unerasable>>::_GET_INNER_VTABLE,
+                extra_value,
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            })
+        }
+    }
+
+
     mod private_struct {
         pub struct PrivStruct;
     }
@@ -1240,10 +2112,34 @@ fn main(){
     {
         /// Creates a shared reborrow of this DynTrait.
         ///
+        /// This can also be called on a mutably reborrowed DynTrait 
+        /// (a `DynTrait<'borr,&mut (),I,EV>`),to get temporary shared access to it.
+        ///
         /// The reborrowed DynTrait cannot use these methods:
         /// 
         /// - DynTrait::default
         /// 
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{
+        ///     DynTrait,
+        ///     erased_types::interfaces::DebugDisplayInterface,
+        /// };
+        ///
+        /// let mut wrapped=DynTrait::from_any_value(100_u32,DebugDisplayInterface);
+        ///
+        /// let reborrow:DynTrait<'_,&(),DebugDisplayInterface>=wrapped.reborrow();
+        /// assert_eq!( reborrow.to_string(), "100" );
+        ///
+        /// let reborrow_mut:DynTrait<'_,&mut (),DebugDisplayInterface>=wrapped.reborrow_mut();
+        /// {
+        ///     let reborrow:DynTrait<'_,&(),DebugDisplayInterface>=reborrow_mut.reborrow();
+        ///     assert_eq!( format!("{:?}",reborrow), "100" );
+        /// }
+        /// assert_eq!( reborrow_mut.to_string(), "100" );
+        ///
+        /// ```
         pub fn reborrow<'re>(&'re self)->DynTrait<'borr,&'re (),I,EV> 
         where
             P:Deref<Target=()>,
@@ -1284,6 +2180,75 @@ fn main(){
                 _marker2:UnsafeIgnoredType::DEFAULT,
             }
         }
+
+        /// Creates a mutable reborrow of this DynTrait,
+        /// narrowing the interface to `I2`,which must enable a subset of the traits in `I`.
+        ///
+        /// This allows lending a view of the DynTrait that can only use some of its traits,
+        /// including the ones that require mutable access,like `io::Read`/`io::Write`.
+        ///
+        /// The returned DynTrait mutably borrows `self` for as long as it is alive,
+        /// so `self` can't be used until the narrowed view is dropped.
+        ///
+        /// The reborrowed DynTrait cannot use these methods:
+        /// 
+        /// - DynTrait::default
+        /// 
+        /// - DynTrait::clone
+        /// 
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::{DynTrait,StableAbi};
+        ///
+        /// use std::io::{Cursor,Read,Write};
+        ///
+        /// #[repr(C)]
+        /// #[derive(StableAbi)]
+        /// #[sabi(impl_InterfaceType(IoRead,IoWrite))]
+        /// pub struct ReadWriteInterface;
+        ///
+        /// #[repr(C)]
+        /// #[derive(StableAbi)]
+        /// #[sabi(impl_InterfaceType(IoRead))]
+        /// pub struct ReadInterface;
+        ///
+        /// fn read_byte(mut reader:DynTrait<'_,&mut (),ReadInterface>)->u8{
+        ///     let mut buffer=[0];
+        ///     reader.read_exact(&mut buffer).unwrap();
+        ///     buffer[0]
+        /// }
+        ///
+        /// let mut cursor=Cursor::new(vec![3,5,8]);
+        /// {
+        ///     let mut wrapped=DynTrait::from_borrowing_ptr(&mut cursor,ReadWriteInterface);
+        ///
+        ///     assert_eq!( read_byte(wrapped.reborrow_mut_as()), 3 );
+        ///     wrapped.write_all(&[13]).unwrap();
+        ///     assert_eq!( read_byte(wrapped.reborrow_mut_as()), 8 );
+        /// }
+        /// assert_eq!( cursor.into_inner(), vec![3,13,8] );
+        ///
+        /// ```
+        pub fn reborrow_mut_as<'re,I2>(&'re mut self)->DynTrait<'borr,&'re mut (),I2,EV> 
+        where
+            P:DerefMut<Target=()>,
+            I2:InterfaceBound+InterfaceNarrowableFrom<'borr,I>,
+            PrivStruct:ReborrowBounds<I2::Send,I2::Sync>,
+            EV:Copy,
+        {
+            let extra_value=*self.sabi_extra_value();
+            // Narrowing the interface is sound because the layout of the vtable
+            // doesn't depend on the interface,
+            // and every field accessible with `I2` is also accessible with `I`.
+            DynTrait {
+                object: ManuallyDrop::new(&mut **self.object),
+                vtable: unsafe{ self.vtable.transmute_ref() },
+                extra_value,
+                _marker:PhantomData,
+                _marker2:UnsafeIgnoredType::DEFAULT,
+            }
+        }
     }
 
 
@@ -1344,7 +2309,8 @@ let _=borrow.default();
  */
         pub fn default(&self) -> Self
         where
-            P: Deref + GetPointerKind<Kind=PK_SmartPointer>,
+            P: Deref + GetPointerKind,
+            P::Kind: DefaultPointerKind,
             I: InterfaceType<Default = Implemented<trait_marker::Default>>,
             EV:Copy,
         {
@@ -1374,7 +2340,9 @@ let _=borrow.default();
             I: DeserializeDyn<'de,Self>,
 
         {
+            let input=DeserializeDynError::truncate_input(I::proxy_input(&proxy));
             I::deserialize_dyn(proxy)
+                .map_err(|e| RBoxError::new(DeserializeDynError::new::<I>(input,e)) )
         }
     }
 
@@ -1421,6 +2389,8 @@ where
         unsafe{
             let vtable = self.sabi_vtable();
             let new = vtable.clone_ptr()(&*self.object);
+            #[cfg(feature="debug_diagnostics")]
+            clone_diagnostics::record_clone(vtable.type_info());
             self.from_new_ptr(new,*self.sabi_extra_value())
         }
     }
@@ -1434,6 +2404,8 @@ where
     EV:Copy+'borr,
 {
     fn clone_impl(&self) -> Self {
+        #[cfg(feature="debug_diagnostics")]
+        clone_diagnostics::record_clone(self.sabi_vtable().type_info());
         self.from_new_ptr(*self.object,*self.sabi_extra_value())
     }
 }
@@ -1469,95 +2441,437 @@ where
     }
 }
 
-//////////////////////
-
 
-impl<'borr,P, I,EV> Display for DynTrait<'borr,P,I,EV>
+impl<'borr,P, I,EV> DynTrait<'borr,P,I,EV>
 where
-    P: Deref+GetPointerKind,
-    I: InterfaceBound<Display = Implemented<trait_marker::Display>>,
+    P: Deref+GetPointerKind<Kind=PK_SmartPointer>,
+    I: InterfaceBound+'borr,
+    EV:Copy+'borr,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        unsafe{
-            adapt_std_fmt::<ErasedObject>(
-                self.sabi_erased_ref(),
-                self.sabi_vtable().display(), 
-                f
-            )
-        }
-    }
-}
+/**
+Attempts to clone this `DynTrait<_>`,
+returning `RNone` if the pointer can't be cloned.
 
-impl<'borr,P, I,EV> Debug for DynTrait<'borr,P,I,EV>
-where
-    P: Deref+GetPointerKind,
-    I: InterfaceBound<Debug = Implemented<trait_marker::Debug>>,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        unsafe{
-            adapt_std_fmt::<ErasedObject>(
-                self.sabi_erased_ref(),
-                self.sabi_vtable().debug(),
-                f
-            )
-        }
-    }
-}
+This clones the pointer if either:
 
-impl<'borr,P, I,EV> std::error::Error for DynTrait<'borr,P,I,EV>
-where
-    P: Deref+GetPointerKind,
-    I: InterfaceBound<
-        Display=Implemented<trait_marker::Display>,
-        Debug=Implemented<trait_marker::Debug>,
-        Error=Implemented<trait_marker::Error>,
-    >,
-{}
+- `I` requires `Clone`.
 
+- The `DynTrait<_>` was constructed with one of the 
+`DynTrait::from_cloneable_*` constructors.
 
+This is useful for collections of `DynTrait<_>` whose interface doesn't require `Clone`,
+where some of the values can be cloned.
 
-/**
-First it serializes a `DynTrait<_>` into a string by using 
-<ConcreteType as SerializeImplType>::serialize_impl,
-then it serializes the string.
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::DebugDisplayInterface,
+    std_types::RString,
+    DynTrait,
+};
+
+let cloneable=DynTrait::from_cloneable_value(RString::from("hello"),DebugDisplayInterface);
+let opaque=DynTrait::from_any_value(RString::from("world"),DebugDisplayInterface);
+
+let cloned=cloneable.sabi_try_clone().unwrap();
+assert_eq!( cloned.to_string(), "hello" );
+assert_eq!( cloned.sabi_try_clone().unwrap().to_string(), "hello" );
+
+assert!( opaque.sabi_try_clone().is_none() );
+
+```
 
 */
-impl<'borr,P, I,EV> Serialize for DynTrait<'borr,P,I,EV>
-where
-    P: Deref+GetPointerKind,
-    I: InterfaceBound<Serialize = Implemented<trait_marker::Serialize>>,
-    I: GetSerializeProxyType<'borr>,
-    I::ProxyType:Serialize,
-{
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        unsafe{
-            self.sabi_vtable().serialize()(&*(self.sabi_erased_ref() as *const ErasedObject))
-                .into_result()
-                .map_err(ser::Error::custom)?
-                .serialize(serializer)
+    pub fn sabi_try_clone(&self)->ROption<Self>{
+        match self.sabi_vtable().try_clone_ptr() {
+            Some(clone_ptr)=>unsafe{
+                let new=clone_ptr(&*self.object);
+                #[cfg(feature="debug_diagnostics")]
+                clone_diagnostics::record_clone(self.sabi_vtable().type_info());
+                RSome(self.from_new_ptr(new,*self.sabi_extra_value()))
+            },
+            None=>RNone,
         }
     }
 }
 
-/// First it Deserializes a string,then it deserializes into a 
-/// `DynTrait<_>`,by using `<I as DeserializeOwnedInterface>::deserialize_impl`.
-impl<'de,'borr:'de, P, I,EV> Deserialize<'de> for DynTrait<'borr,P,I,EV>
+
+impl<'borr,P, I,EV> DynTrait<'borr,P,I,EV>
 where
-    EV: 'borr,
-    P: Deref+GetPointerKind+'borr,
-    I: InterfaceBound+'borr,
-    I: DeserializeDyn<'de,Self>,
-    <I as DeserializeDyn<'de,Self>>::Proxy:Deserialize<'de>,
+    P: GetPointerKind,
+    I: InterfaceBound,
 {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+/**
+Gets the tag of the interface that this `DynTrait<_>` was constructed with,
+in the dynamic library/executable that constructed it.
+
+The tag of an interface is the set of traits that it requires,
+the same `<I as InterfaceBound>::EXTRA_CHECKS` value that `DynTrait<_>` 
+uses in its `#[sabi(extra_checks="...")]` attribute to check the interface
+when a dynamic library is loaded.
+
+This returns `RNone` if the `DynTrait<_>` was constructed by a 
+dynamic library that uses an older version of `abi_stable`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{
+        interfaces::DebugDisplayInterface,
+        InterfaceBound,
+    },
+    std_types::RSome,
+    DynTrait,
+};
+
+let object=DynTrait::from_any_value(3_u8,DebugDisplayInterface);
+
+assert_eq!(
+    object.sabi_interface_tag(),
+    RSome(<DebugDisplayInterface as InterfaceBound>::EXTRA_CHECKS),
+);
+
+```
+
+*/
+    pub fn sabi_interface_tag(&self)->ROption<EnabledTraits>{
+        self.sabi_vtable().enabled_traits()
+    }
+
+/**
+Whether the interface that this `DynTrait<_>` was constructed with
+implements every trait that `I` requires in this dynamic library/executable.
+
+This is meant to be called on `DynTrait<_>`s received from sibling dynamic libraries,
+before calling any method,
+as described in the "Passing DynTrait between dynamic libraries" section 
+of the `DynTrait` docs.
+
+This returns `false` if the traits that the interface required are unknown,
+because the `DynTrait<_>` was constructed by a dynamic library that 
+uses an older version of `abi_stable`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::DebugDisplayInterface,
+    DynTrait,
+};
+
+let object=DynTrait::from_any_value(3_u8,DebugDisplayInterface);
+
+assert!( object.sabi_is_interface_compatible() );
+
+```
+
+*/
+    pub fn sabi_is_interface_compatible(&self)->bool{
+        match self.sabi_interface_tag() {
+            RSome(found)=><I as InterfaceBound>::EXTRA_CHECKS.is_compatible_with(&found),
+            RNone=>false,
+        }
+    }
+
+/**
+Gets how many times `DynTrait<_>`s of the erased type were cloned,
+with `Clone::clone` or `DynTrait::sabi_try_clone`.
+
+This counts the clones done by every `DynTrait<_>` of the same erased type,
+regardless of their pointer or interface,
+in this dynamic library/executable.
+
+This is only available with the "debug_diagnostics" cargo feature.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::CloneInterface,
+    DynTrait,
+};
+
+#[derive(Clone)]
+struct Tracked;
+
+let object=DynTrait::from_any_value(Tracked,CloneInterface);
+let cloned=object.clone();
+let _=cloned.clone();
+
+assert_eq!( object.sabi_clone_count(), 2 );
+
+```
+
+*/
+    #[cfg(feature="debug_diagnostics")]
+    pub fn sabi_clone_count(&self)->usize{
+        clone_diagnostics::clone_count(self.sabi_vtable().type_info())
+    }
+}
+
+
+/**
+Clones every `DynTrait` in `slice` into a new `RVec`.
+
+The `RVec` is allocated with the exact capacity up front,
+and if cloning any of the elements panics,
+the elements that were already cloned are dropped before the panic continues.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{clone_all,interfaces::CloneEqInterface},
+    std_types::{RBox,RVec},
+    DynTrait,
+};
+
+let list:RVec<DynTrait<'static,RBox<()>,CloneEqInterface>>=
+    (0..3_u32).map(|x| DynTrait::from_any_value(x,CloneEqInterface) ).collect();
+
+let cloned=clone_all(&list);
+
+assert_eq!(cloned.len(),3);
+assert_eq!(cloned,list);
+
+```
+
+*/
+pub fn clone_all<'borr,P,I,EV>(slice:&[DynTrait<'borr,P,I,EV>])->RVec<DynTrait<'borr,P,I,EV>>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound,
+    DynTrait<'borr,P,I,EV>:Clone,
+{
+    let mut cloned=RVec::with_capacity(slice.len());
+    // If a clone panics,`cloned` is dropped while unwinding,
+    // dropping all the elements that were cloned before it.
+    for elem in slice {
+        cloned.push(elem.clone());
+    }
+    cloned
+}
+
+
+/**
+Drops every `DynTrait` in `vec`.
+
+If all the `DynTrait`s share the same vtable,
+this gets the destructor from the vtable once and calls it on every element,
+instead of getting it from each element's vtable.
+Otherwise it falls back to dropping each element individually.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{drop_all_homogeneous,interfaces::DebugDisplayInterface},
+    std_types::{RBox,RVec},
+    DynTrait,
+};
+
+use std::sync::Arc;
+
+let arc=Arc::new(0);
+
+let list:RVec<DynTrait<'static,RBox<()>,DebugDisplayInterface>>=
+    (0..10).map(|_| DynTrait::from_any_value(arc.clone(),DebugDisplayInterface) ).collect();
+
+assert_eq!(Arc::strong_count(&arc),11);
+
+drop_all_homogeneous(list);
+
+assert_eq!(Arc::strong_count(&arc),1);
+
+```
+
+*/
+pub fn drop_all_homogeneous<'borr,P,I,EV>(mut vec:RVec<DynTrait<'borr,P,I,EV>>)
+where
+    P: GetPointerKind,
+{
+    let first_vtable=match vec.first() {
+        Some(first)=>first.sabi_vtable_address(),
+        None=>return,
+    };
+
+    let is_homogeneous=vec.iter().all(|elem| elem.sabi_vtable_address()==first_vtable );
+
+    if !is_homogeneous || <P as GetPointerKind>::KIND!=PointerKind::SmartPointer {
+        // Each element is dropped with the destructor from its own vtable.
+        return;
+    }
+
+    let drop_ptr=vec[0].sabi_vtable().drop_ptr();
+
+    unsafe{
+        let len=vec.len();
+        // If dropping an extra value panics,the remaining elements are leaked.
+        vec.set_len(0);
+        let elems=vec.as_mut_ptr();
+        for i in 0..len {
+            let elem=&mut *elems.add(i);
+            drop_ptr(&mut *elem.object);
+            ptr::drop_in_place(elem.sabi_extra_value_mut());
+        }
+    }
+}
+
+/**
+Sorts `slice` in place with the `Ord` impl of the wrapped values.
+
+This orders `DynTrait`s the same way as `DynTrait::sabi_cmp`,
+so `DynTrait`s that wrap values of different types are ordered by
+the address of their vtables.
+
+If all the `DynTrait`s share the same vtable,
+this gets the comparison function from the vtable once and uses it for every comparison,
+instead of checking whether both elements wrap the same type in every comparison.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::sort_dyntraits,
+    std_types::{RBox,RVec},
+    DynTrait,
+    StableAbi,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Debug,PartialEq,Eq,PartialOrd,Ord))]
+pub struct OrdInterface;
+
+let mut list:RVec<DynTrait<'static,RBox<()>,OrdInterface>>=
+    [8_u32,3,13,5].iter().map(|&x| DynTrait::from_any_value(x,OrdInterface) ).collect();
+
+sort_dyntraits(&mut list);
+
+assert_eq!(
+    list.iter().map(|x| *x.as_unerased::<u32>().unwrap() ).collect::<Vec<_>>(),
+    vec![3,5,8,13],
+);
+
+```
+
+*/
+pub fn sort_dyntraits<'borr,P,I,EV>(slice:&mut [DynTrait<'borr,P,I,EV>])
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<Ord = Implemented<trait_marker::Ord>>,
+{
+    let first_vtable=match slice.first() {
+        Some(first)=>first.sabi_vtable_address(),
+        None=>return,
+    };
+
+    let is_homogeneous=slice.iter().all(|elem| elem.sabi_vtable_address()==first_vtable );
+
+    if !is_homogeneous {
+        slice.sort_by(|l,r| l.sabi_cmp(r).into() );
+        return;
+    }
+
+    let cmp=slice[0].sabi_vtable().cmp();
+
+    // unsafe: all the elements share the same vtable,so they wrap values of the same type.
+    slice.sort_by(|l,r| unsafe{ cmp(l.sabi_erased_ref(), r.sabi_erased_ref()).into() });
+}
+
+//////////////////////
+
+
+impl<'borr,P, I,EV> Display for DynTrait<'borr,P,I,EV>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<Display = Implemented<trait_marker::Display>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unsafe{
+            adapt_std_fmt::<ErasedObject>(
+                self.sabi_erased_ref(),
+                self.sabi_vtable().display(), 
+                f
+            )
+        }
+    }
+}
+
+impl<'borr,P, I,EV> Debug for DynTrait<'borr,P,I,EV>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<Debug = Implemented<trait_marker::Debug>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unsafe{
+            adapt_std_fmt::<ErasedObject>(
+                self.sabi_erased_ref(),
+                self.sabi_vtable().debug(),
+                f
+            )
+        }
+    }
+}
+
+impl<'borr,P, I,EV> std::error::Error for DynTrait<'borr,P,I,EV>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<
+        Display=Implemented<trait_marker::Display>,
+        Debug=Implemented<trait_marker::Debug>,
+        Error=Implemented<trait_marker::Error>,
+    >,
+{}
+
+
+
+/**
+First it serializes a `DynTrait<_>` into a string by using 
+<ConcreteType as SerializeImplType>::serialize_impl,
+then it serializes the string.
+
+*/
+impl<'borr,P, I,EV> Serialize for DynTrait<'borr,P,I,EV>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<Serialize = Implemented<trait_marker::Serialize>>,
+    I: GetSerializeProxyType<'borr>,
+    I::ProxyType:Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        unsafe{
+            self.sabi_vtable().serialize()(&*(self.sabi_erased_ref() as *const ErasedObject))
+                .into_result()
+                .map_err(ser::Error::custom)?
+                .serialize(serializer)
+        }
+    }
+}
+
+/// First it Deserializes a string,then it deserializes into a 
+/// `DynTrait<_>`,by using `<I as DeserializeOwnedInterface>::deserialize_impl`.
+impl<'de,'borr:'de, P, I,EV> Deserialize<'de> for DynTrait<'borr,P,I,EV>
+where
+    EV: 'borr,
+    P: Deref+GetPointerKind+'borr,
+    I: InterfaceBound+'borr,
+    I: DeserializeDyn<'de,Self>,
+    <I as DeserializeDyn<'de,Self>>::Proxy:Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let s = <<I as DeserializeDyn<'de,Self>>::Proxy>::deserialize(deserializer)?;
-        I::deserialize_dyn(s).map_err(de::Error::custom)
+        let input=DeserializeDynError::truncate_input(I::proxy_input(&s));
+        I::deserialize_dyn(s)
+            .map_err(|e| de::Error::custom(DeserializeDynError::new::<I>(input,e)) )
     }
 }
 
@@ -1626,33 +2940,263 @@ where
     }
 }
 
-impl<'borr,P, I,EV> Hash for DynTrait<'borr,P,I,EV>
+
+impl<'borr,P,I,EV> DynTrait<'borr,P,I,EV>
 where
     P: Deref+GetPointerKind,
-    I: InterfaceBound<Hash = Implemented<trait_marker::Hash>>,
+    I: InterfaceBound,
 {
-    fn hash<H>(&self, state: &mut H)
+    /// Whether `self` and `other` wrap a value of the same type,
+    /// treating `DynTrait`s that share a vtable as wrapping the same type,
+    /// even if they were constructed with `DynTrait::from_borrowing_*`.
+    fn sabi_shares_type_with<P2,EV2>(&self,other:&DynTrait<'borr,P2,I,EV2>)->bool
     where
-        H: Hasher,
+        P2: GetPointerKind,
     {
-        unsafe{
-            self.sabi_vtable().hash()(self.sabi_erased_ref(), HasherObject::new(state))
-        }
+        self.sabi_vtable_address()==other.sabi_vtable_address()||
+        self.sabi_vtable().type_info().is_compatible(other.sabi_vtable().type_info())
     }
-}
 
+/**
+Compares `self` and `other` with the `PartialEq` impl of the wrapped type,
+returning `RNone` if they wrap values of different types.
 
-//////////////////////////////////////////////////////////////////
-
+Unlike the `PartialEq` impl,this distinguishes values of different types
+(which are not comparable) from unequal values of the same type,
+and can be used with `DynTrait`s constructed with the
+`DynTrait::from_borrowing_*` constructors.
 
-impl<'borr,P, I,Item,EV> Iterator for DynTrait<'borr,P,I,EV>
-where
-    P: DerefMut+GetPointerKind,
-    I: IteratorItemOrDefault<'borr,Item=Item>,
-    I: InterfaceBound<Iterator = Implemented<trait_marker::Iterator>>,
-    Item:'borr,
-{
-    type Item=Item;
+# Example
+
+```
+use abi_stable::{
+    DynTrait,
+    StableAbi,
+    std_types::{RNone,RSome},
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,PartialEq))]
+pub struct PartialEqInterface;
+
+let (first,second,number)=(String::from("a"),String::from("b"),10_u32);
+
+let first=DynTrait::from_borrowing_value(&first,PartialEqInterface);
+let second=DynTrait::from_borrowing_value(&second,PartialEqInterface);
+let number=DynTrait::from_borrowing_value(&number,PartialEqInterface);
+
+assert_eq!( first.sabi_try_eq(&first), RSome(true) );
+assert_eq!( first.sabi_try_eq(&second), RSome(false) );
+assert_eq!( first.sabi_try_eq(&number), RNone );
+
+```
+*/
+    pub fn sabi_try_eq<P2,EV2>(&self,other:&DynTrait<'borr,P2,I,EV2>)->ROption<bool>
+    where
+        P2: Deref+GetPointerKind,
+        I: InterfaceBound<PartialEq = Implemented<trait_marker::PartialEq>>,
+    {
+        // unsafe: must check that the vtable is the same,otherwise return a sensible value.
+        if !self.sabi_shares_type_with(other) {
+            return RNone;
+        }
+
+        unsafe{
+            RSome(self.sabi_vtable().partial_eq()(self.sabi_erased_ref(), other.sabi_erased_ref()))
+        }
+    }
+
+/**
+Compares `self` and `other` with the `PartialOrd` impl of the wrapped type,
+returning the ffi-safe equivalent of `Option<Ordering>`.
+
+Unlike the `PartialOrd` impl,this can be used with `DynTrait`s
+constructed with the `DynTrait::from_borrowing_*` constructors.
+
+If the wrapped values are of different types,
+this orders them by the address of their vtables.
+
+# Example
+
+```
+use abi_stable::{
+    DynTrait,
+    StableAbi,
+    std_types::{RCmpOrdering,RSome},
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,PartialOrd))]
+pub struct PartialOrdInterface;
+
+let (left,right)=(String::from("a"),String::from("b"));
+
+let left=DynTrait::from_borrowing_value(&left,PartialOrdInterface);
+let right=DynTrait::from_borrowing_value(&right,PartialOrdInterface);
+
+assert_eq!( left.sabi_partial_cmp(&right), RSome(RCmpOrdering::Less) );
+assert_eq!( right.sabi_partial_cmp(&left), RSome(RCmpOrdering::Greater) );
+assert_eq!( left.sabi_partial_cmp(&left), RSome(RCmpOrdering::Equal) );
+
+```
+*/
+    pub fn sabi_partial_cmp<P2,EV2>(
+        &self,
+        other:&DynTrait<'borr,P2,I,EV2>,
+    )->ROption<RCmpOrdering>
+    where
+        P2: Deref+GetPointerKind,
+        I: InterfaceBound<PartialOrd = Implemented<trait_marker::PartialOrd>>,
+    {
+        // unsafe: must check that the vtable is the same,otherwise return a sensible value.
+        if !self.sabi_shares_type_with(other) {
+            return RSome(self.sabi_vtable_address().cmp(&other.sabi_vtable_address()).into());
+        }
+
+        unsafe{
+            self.sabi_vtable().partial_cmp()(self.sabi_erased_ref(), other.sabi_erased_ref())
+        }
+    }
+
+/**
+Compares `self` and `other` with the `Ord` impl of the wrapped type,
+returning the ffi-safe equivalent of `Ordering`.
+
+Unlike the `Ord` impl,this can be used with `DynTrait`s
+constructed with the `DynTrait::from_borrowing_*` constructors.
+
+If the wrapped values are of different types,
+this orders them by the address of their vtables.
+
+# Example
+
+```
+use abi_stable::{
+    DynTrait,
+    StableAbi,
+    std_types::RCmpOrdering,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Ord))]
+pub struct OrdInterface;
+
+let (left,right)=(vec![3,5],vec![3,5,8]);
+
+let left=DynTrait::from_borrowing_value(&left,OrdInterface);
+let right=DynTrait::from_borrowing_value(&right,OrdInterface);
+
+assert_eq!( left.sabi_cmp(&right), RCmpOrdering::Less );
+assert_eq!( right.sabi_cmp(&left), RCmpOrdering::Greater );
+assert_eq!( right.sabi_cmp(&right), RCmpOrdering::Equal );
+
+```
+*/
+    pub fn sabi_cmp<P2,EV2>(&self,other:&DynTrait<'borr,P2,I,EV2>)->RCmpOrdering
+    where
+        P2: Deref+GetPointerKind,
+        I: InterfaceBound<Ord = Implemented<trait_marker::Ord>>,
+    {
+        // unsafe: must check that the vtable is the same,otherwise return a sensible value.
+        if !self.sabi_shares_type_with(other) {
+            return self.sabi_vtable_address().cmp(&other.sabi_vtable_address()).into();
+        }
+
+        unsafe{
+            self.sabi_vtable().cmp()(self.sabi_erased_ref(), other.sabi_erased_ref())
+        }
+    }
+
+/**
+Gets a view of the erased value as bytes,with its `AsRef<[u8]>` impl.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::AsBytesInterface,
+    std_types::RSlice,
+    DynTrait,
+};
+
+let object=DynTrait::from_any_value(vec![3_u8,5,8],AsBytesInterface);
+
+let bytes:RSlice<'_,u8>=object.sabi_as_bytes();
+assert_eq!( bytes.as_slice(), &[3,5,8] );
+
+let object=DynTrait::from_any_value(String::from("hello"),AsBytesInterface);
+assert_eq!( object.sabi_as_bytes().as_slice(), b"hello" );
+
+```
+*/
+    pub fn sabi_as_bytes(&self)->RSlice<'_,u8>
+    where
+        I: InterfaceBound<AsBytes = Implemented<trait_marker::AsBytes>>,
+    {
+        unsafe{
+            self.sabi_vtable().as_bytes()(self.sabi_erased_ref())
+        }
+    }
+
+/**
+Gets a view of the erased value as a string,with its `AsRef<str>` impl.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::AsStrInterface,
+    std_types::{RStr,RString},
+    DynTrait,
+};
+
+let object=DynTrait::from_any_value(RString::from("hello"),AsStrInterface);
+
+let string:RStr<'_>=object.sabi_as_str();
+assert_eq!( string.as_str(), "hello" );
+
+```
+*/
+    pub fn sabi_as_str(&self)->RStr<'_>
+    where
+        I: InterfaceBound<AsStr = Implemented<trait_marker::AsStr>>,
+    {
+        unsafe{
+            self.sabi_vtable().as_str()(self.sabi_erased_ref())
+        }
+    }
+}
+
+impl<'borr,P, I,EV> Hash for DynTrait<'borr,P,I,EV>
+where
+    P: Deref+GetPointerKind,
+    I: InterfaceBound<Hash = Implemented<trait_marker::Hash>>,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        unsafe{
+            self.sabi_vtable().hash()(self.sabi_erased_ref(), HasherObject::new(state))
+        }
+    }
+}
+
+
+//////////////////////////////////////////////////////////////////
+
+
+impl<'borr,P, I,Item,EV> Iterator for DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: IteratorItemOrDefault<'borr,Item=Item>,
+    I: InterfaceBound<Iterator = Implemented<trait_marker::Iterator>>,
+    Item:'borr,
+{
+    type Item=Item;
 
     fn next(&mut self)->Option<Item>{
         unsafe{
@@ -1683,26 +3227,300 @@ where
         }
     }
 
-    fn last(mut self)->Option<Item>{
+    fn last(mut self)->Option<Item>{
+        unsafe{
+            let vtable=self.sabi_vtable();
+            (vtable.iter().last)(self.sabi_erased_mut()).into_rust()
+        }
+    }
+}
+
+
+impl<'borr,P, I,Item,EV> DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: IteratorItemOrDefault<'borr,Item=Item>,
+    I: InterfaceBound<Iterator = Implemented<trait_marker::Iterator>>,
+    Item:'borr,
+{
+/**
+Eagerly skips n elements from the iterator.
+
+This method is faster than using `Iterator::skip`.
+
+# Example
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::RVec,
+#     traits::IntoReprC,
+# };
+
+let mut iter=0..20;
+let mut wrapped=DynTrait::from_any_ptr(&mut iter,IteratorInterface::NEW);
+
+assert_eq!(wrapped.next(),Some(0));
+
+wrapped.skip_eager(2);
+
+assert_eq!(wrapped.next(),Some(3));
+assert_eq!(wrapped.next(),Some(4));
+assert_eq!(wrapped.next(),Some(5));
+
+wrapped.skip_eager(2);
+
+assert_eq!(wrapped.next(),Some(8));
+assert_eq!(wrapped.next(),Some(9));
+
+wrapped.skip_eager(9);
+
+assert_eq!(wrapped.next(),Some(19));
+assert_eq!(wrapped.next(),None    );
+
+
+
+```
+
+
+*/
+    pub fn skip_eager(&mut self, n: usize){
+        unsafe{
+            let vtable=self.sabi_vtable();
+            (vtable.iter().skip_eager)(self.sabi_erased_mut(),n);
+        }
+    }
+
+
+/**
+Extends the `RVec<Item>` with the `self` Iterator.
+
+Extends `buffer` with as many elements of the iterator as `taking` specifies:
+
+- RNone: Yields all elements.Use this with care,since Iterators can be infinite.
+
+- RSome(n): Yields n elements.
+
+###  Example 
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::{RVec,RSome},
+#     traits::IntoReprC,
+# };
+
+let mut wrapped=DynTrait::from_any_value(0.. ,IteratorInterface::NEW);
+
+let mut buffer=vec![ 101,102,103 ].into_c();
+wrapped.extending_rvec(&mut buffer,RSome(5));
+assert_eq!(
+    &buffer[..],
+    &*vec![101,102,103,0,1,2,3,4]
+);
+
+assert_eq!( wrapped.next(),Some(5));
+assert_eq!( wrapped.next(),Some(6));
+assert_eq!( wrapped.next(),Some(7));
+
+```
+*/
+    pub fn extending_rvec(&mut self,buffer:&mut RVec<Item>,taking:ROption<usize>){
+        unsafe{
+            let vtable=self.sabi_vtable();
+            (vtable.iter().extending_rvec)(self.sabi_erased_mut(),buffer,taking);
+        }
+    }
+
+/**
+Collects all the remaining elements of the iterator into an `RVec<Item>`,
+consuming them.
+
+This is done by the erased iterator itself,with a single call through the vtable,
+reserving capacity for the lower bound of `size_hint` before collecting the elements.
+
+If the vtable was constructed by an older version of `abi_stable`,
+this falls back to `DynTrait::extending_rvec`.
+
+Use this with care,since Iterators can be infinite.
+
+# Example
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::RVec,
+#     rvec,
+# };
+
+let mut wrapped=DynTrait::from_any_value(vec![3,5,8,13].into_iter(),IteratorInterface::NEW);
+
+assert_eq!( wrapped.next(), Some(3) );
+assert_eq!( wrapped.collect_rvec(), rvec![5,8,13] );
+assert_eq!( wrapped.next(), None );
+assert_eq!( wrapped.collect_rvec(), RVec::new() );
+
+```
+*/
+    pub fn collect_rvec(&mut self)->RVec<Item>{
+        unsafe{
+            let vtable=self.sabi_vtable();
+            match vtable.iter_ext().and_then(|x| x.collect_rvec() ) {
+                Some(collect_rvec)=>collect_rvec(self.sabi_erased_mut()),
+                None=>{
+                    let mut buffer=RVec::with_capacity(self.size_hint().0);
+                    self.extending_rvec(&mut buffer,RNone);
+                    buffer
+                }
+            }
+        }
+    }
+
+/**
+Returns the maximum element of the iterator,consuming all the remaining elements.
+
+This is computed by the erased iterator itself,with a single call through the vtable,
+instead of returning every element through the vtable as `Iterator::max` does.
+
+If the vtable was constructed by an older version of `abi_stable`,
+this falls back to `Iterator::max`.
+
+If several elements are equally maximum,the last one is returned.
+
+# Example
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::{RSome,RNone},
+# };
+
+let mut wrapped=DynTrait::from_any_value(vec![3,13,5,8].into_iter(),IteratorInterface::NEW);
+assert_eq!( wrapped.sabi_max(), RSome(13) );
+assert_eq!( wrapped.next(), None );
+
+let mut wrapped=DynTrait::from_any_value(0..0,IteratorInterface::NEW);
+assert_eq!( wrapped.sabi_max(), RNone );
+
+```
+*/
+    pub fn sabi_max(&mut self)->ROption<Item>
+    where
+        Item:Ord,
+    {
+        unsafe{
+            let vtable=self.sabi_vtable();
+            match vtable.iter_ext() {
+                Some(ext)=>(ext.max_by())(
+                    self.sabi_erased_mut(),
+                    ErasedCmpFn{func:cmp_ord::<Item>},
+                ),
+                None=>self.max().into_c(),
+            }
+        }
+    }
+
+/**
+Returns the minimum element of the iterator,consuming all the remaining elements.
+
+This is computed by the erased iterator itself,with a single call through the vtable,
+instead of returning every element through the vtable as `Iterator::min` does.
+
+If the vtable was constructed by an older version of `abi_stable`,
+this falls back to `Iterator::min`.
+
+If several elements are equally minimum,the first one is returned.
+
+# Example
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::{RSome,RNone},
+# };
+
+let mut wrapped=DynTrait::from_any_value(vec![8,3,13,5].into_iter(),IteratorInterface::NEW);
+assert_eq!( wrapped.sabi_min(), RSome(3) );
+assert_eq!( wrapped.next(), None );
+
+let mut wrapped=DynTrait::from_any_value(0..0,IteratorInterface::NEW);
+assert_eq!( wrapped.sabi_min(), RNone );
+
+```
+*/
+    pub fn sabi_min(&mut self)->ROption<Item>
+    where
+        Item:Ord,
+    {
+        unsafe{
+            let vtable=self.sabi_vtable();
+            match vtable.iter_ext() {
+                Some(ext)=>(ext.min_by())(
+                    self.sabi_erased_mut(),
+                    ErasedCmpFn{func:cmp_ord::<Item>},
+                ),
+                None=>self.min().into_c(),
+            }
+        }
+    }
+
+/**
+Searches for the first element of the iterator that satisfies the predicate,
+consuming the elements up to and including that element.
+
+The predicate is called inside the loop of the erased iterator,
+instead of returning every element through the vtable as `Iterator::find` does.
+
+If the vtable was constructed by an older version of `abi_stable`,
+this falls back to `Iterator::find`.
+
+# Example
+
+```
+# use abi_stable::{
+#     DynTrait,
+#     erased_types::interfaces::IteratorInterface,
+#     std_types::{RSome,RNone},
+# };
+
+let mut wrapped=DynTrait::from_any_value(vec![3,5,8,13,21].into_iter(),IteratorInterface::NEW);
+
+assert_eq!( wrapped.find_erased(|x| *x%2==0 ), RSome(8) );
+assert_eq!( wrapped.next(), Some(13) );
+
+assert_eq!( wrapped.find_erased(|x| *x==100 ), RNone );
+assert_eq!( wrapped.next(), None );
+
+```
+*/
+    pub fn find_erased<F>(&mut self,mut pred:F)->ROption<Item>
+    where
+        F:FnMut(&Item)->bool,
+    {
         unsafe{
             let vtable=self.sabi_vtable();
-            (vtable.iter().last)(self.sabi_erased_mut()).into_rust()
+            match vtable.iter_ext().and_then(|x| x.find() ) {
+                Some(find)=>find(self.sabi_erased_mut(),ErasedPredicate::new(&mut pred)),
+                None=>self.find(pred).into_c(),
+            }
         }
     }
-}
 
-
-impl<'borr,P, I,Item,EV> DynTrait<'borr,P,I,EV>
-where
-    P: DerefMut+GetPointerKind,
-    I: IteratorItemOrDefault<'borr,Item=Item>,
-    I: InterfaceBound<Iterator = Implemented<trait_marker::Iterator>>,
-    Item:'borr,
-{
 /**
-Eagerly skips n elements from the iterator.
+Searches for the first element of the iterator that satisfies the predicate,
+returning its index,
+consuming the elements up to and including that element.
 
-This method is faster than using `Iterator::skip`.
+The predicate is called inside the loop of the erased iterator,
+instead of returning every element through the vtable as `Iterator::position` does.
+
+If the vtable was constructed by an older version of `abi_stable`,
+this falls back to `Iterator::position`.
 
 # Example
 
@@ -1710,83 +3528,122 @@ This method is faster than using `Iterator::skip`.
 # use abi_stable::{
 #     DynTrait,
 #     erased_types::interfaces::IteratorInterface,
-#     std_types::RVec,
-#     traits::IntoReprC,
+#     std_types::{RSome,RNone},
 # };
 
-let mut iter=0..20;
-let mut wrapped=DynTrait::from_any_ptr(&mut iter,IteratorInterface::NEW);
+let mut wrapped=DynTrait::from_any_value(vec![3,5,8,13,21].into_iter(),IteratorInterface::NEW);
 
-assert_eq!(wrapped.next(),Some(0));
+assert_eq!( wrapped.position_erased(|x| *x%2==0 ), RSome(2) );
+assert_eq!( wrapped.position_erased(|x| *x>20 ), RSome(1) );
 
-wrapped.skip_eager(2);
+assert_eq!( wrapped.position_erased(|_| true ), RNone );
 
-assert_eq!(wrapped.next(),Some(3));
-assert_eq!(wrapped.next(),Some(4));
-assert_eq!(wrapped.next(),Some(5));
+```
+*/
+    pub fn position_erased<F>(&mut self,mut pred:F)->ROption<usize>
+    where
+        F:FnMut(&Item)->bool,
+    {
+        unsafe{
+            let vtable=self.sabi_vtable();
+            match vtable.iter_ext().and_then(|x| x.position() ) {
+                Some(position)=>
+                    position(self.sabi_erased_mut(),ErasedPredicate::new(&mut pred)),
+                None=>self.position(|x| pred(&x) ).into_c(),
+            }
+        }
+    }
 
-wrapped.skip_eager(2);
+/**
+Sums all the remaining elements of the iterator,consuming them.
 
-assert_eq!(wrapped.next(),Some(8));
-assert_eq!(wrapped.next(),Some(9));
+This is computed by the erased iterator itself with `Iterator::sum`,
+with a single call through the vtable,
+instead of returning every element through the vtable as `Iterator::sum` does.
 
-wrapped.skip_eager(9);
+This requires the `IteratorSum` trait to be enabled in the interface,
+which can only be done if the `Item` type implements `std::iter::Sum`.
 
-assert_eq!(wrapped.next(),Some(19));
-assert_eq!(wrapped.next(),None    );
+# Example
+
+```
+use abi_stable::{
+    StableAbi,
+    DynTrait,
+    erased_types::IteratorItem,
+};
 
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Iterator,IteratorSum))]
+pub struct SumInterface;
 
+impl<'a> IteratorItem<'a> for SumInterface{
+    type Item=u64;
+}
 
-```
+let mut wrapped=DynTrait::from_any_value(vec![3_u64,5,8,13].into_iter(),SumInterface);
+assert_eq!( wrapped.next(), Some(3) );
+assert_eq!( wrapped.sum_erased(), 26 );
+assert_eq!( wrapped.next(), None );
 
+assert_eq!( wrapped.sum_erased(), 0 );
 
+```
 */
-    pub fn skip_eager(&mut self, n: usize){
+    pub fn sum_erased(&mut self)->Item
+    where
+        I: InterfaceBound<IteratorSum = Implemented<trait_marker::IteratorSum>>,
+    {
         unsafe{
             let vtable=self.sabi_vtable();
-            (vtable.iter().skip_eager)(self.sabi_erased_mut(),n);
+            (vtable.iter_sum())(self.sabi_erased_mut())
         }
     }
 
-
 /**
-Extends the `RVec<Item>` with the `self` Iterator.
-
-Extends `buffer` with as many elements of the iterator as `taking` specifies:
+Multiplies all the remaining elements of the iterator,consuming them.
 
-- RNone: Yields all elements.Use this with care,since Iterators can be infinite.
+This is computed by the erased iterator itself with `Iterator::product`,
+with a single call through the vtable,
+instead of returning every element through the vtable as `Iterator::product` does.
 
-- RSome(n): Yields n elements.
+This requires the `IteratorProduct` trait to be enabled in the interface,
+which can only be done if the `Item` type implements `std::iter::Product`.
 
-###  Example 
+# Example
 
 ```
-# use abi_stable::{
-#     DynTrait,
-#     erased_types::interfaces::IteratorInterface,
-#     std_types::{RVec,RSome},
-#     traits::IntoReprC,
-# };
+use abi_stable::{
+    StableAbi,
+    DynTrait,
+    erased_types::IteratorItem,
+};
 
-let mut wrapped=DynTrait::from_any_value(0.. ,IteratorInterface::NEW);
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Iterator,IteratorProduct))]
+pub struct ProductInterface;
 
-let mut buffer=vec![ 101,102,103 ].into_c();
-wrapped.extending_rvec(&mut buffer,RSome(5));
-assert_eq!(
-    &buffer[..],
-    &*vec![101,102,103,0,1,2,3,4]
-);
+impl<'a> IteratorItem<'a> for ProductInterface{
+    type Item=u64;
+}
 
-assert_eq!( wrapped.next(),Some(5));
-assert_eq!( wrapped.next(),Some(6));
-assert_eq!( wrapped.next(),Some(7));
+let mut wrapped=DynTrait::from_any_value(1..=5_u64,ProductInterface);
+assert_eq!( wrapped.product_erased(), 120 );
+assert_eq!( wrapped.next(), None );
+
+assert_eq!( wrapped.product_erased(), 1 );
 
 ```
 */
-    pub fn extending_rvec(&mut self,buffer:&mut RVec<Item>,taking:ROption<usize>){
+    pub fn product_erased(&mut self)->Item
+    where
+        I: InterfaceBound<IteratorProduct = Implemented<trait_marker::IteratorProduct>>,
+    {
         unsafe{
             let vtable=self.sabi_vtable();
-            (vtable.iter().extending_rvec)(self.sabi_erased_mut(),buffer,taking);
+            (vtable.iter_product())(self.sabi_erased_mut())
         }
     }
 }
@@ -1871,6 +3728,89 @@ assert_eq!(
 //////////////////////////////////////////////////////////////////
 
 
+impl<'borr,P, I,Item,EV> DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: IteratorItemOrDefault<'borr,Item=Item>,
+    I: InterfaceBound<Extend = Implemented<trait_marker::Extend>>,
+    Item:'borr,
+{
+/**
+Extends the erased collection with all the elements of an erased iterator.
+
+The erased collection iterates over `iter` itself with `Extend::extend`,
+with a single call through the vtable,
+so the elements are passed directly from the iterator to the collection.
+
+This requires the `Extend` trait to be enabled in the interface,
+with the `Item` type declared with its `IteratorItem` impl,
+and `iter` must yield that same `Item` type.
+
+# Example
+
+```
+use abi_stable::{
+    StableAbi,
+    DynTrait,
+    erased_types::{IteratorItem,RIterator},
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Debug,Extend))]
+pub struct NumsInterface;
+
+impl<'a> IteratorItem<'a> for NumsInterface{
+    type Item=u32;
+}
+
+let mut wrapped=DynTrait::from_any_value(vec![3_u32,5],NumsInterface);
+
+wrapped.extend_from_erased(RIterator::from_iter(vec![8,13].into_iter()));
+assert_eq!( format!("{:?}",wrapped), "[3, 5, 8, 13]" );
+
+let list=[21,34];
+wrapped.extend_from_erased(RIterator::from_iter(list.iter().cloned()));
+assert_eq!( format!("{:?}",wrapped), "[3, 5, 8, 13, 21, 34]" );
+
+```
+
+The item type of the iterator must be the same as the one of the collection:
+
+```compile_fail
+use abi_stable::{
+    StableAbi,
+    DynTrait,
+    erased_types::{IteratorItem,RIterator},
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Debug,Extend))]
+pub struct NumsInterface;
+
+impl<'a> IteratorItem<'a> for NumsInterface{
+    type Item=u32;
+}
+
+let mut wrapped=DynTrait::from_any_value(vec![3_u32,5],NumsInterface);
+
+wrapped.extend_from_erased(RIterator::from_iter(vec![8_u64,13].into_iter()));
+
+```
+*/
+    pub fn extend_from_erased(&mut self,iter:RIterator<'_,Item>){
+        unsafe{
+            let vtable=self.sabi_vtable();
+            (vtable.extend())(self.sabi_erased_mut(),iter);
+        }
+    }
+}
+
+
+//////////////////////////////////////////////////////////////////
+
+
 impl<'borr,P,I,EV> fmtWrite for DynTrait<'borr,P,I,EV>
 where
     P: DerefMut+GetPointerKind,
@@ -1888,6 +3828,46 @@ where
     }
 }
 
+impl<'borr,P,I,EV> DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: InterfaceBound<FmtWrite = Implemented<trait_marker::FmtWrite>>,
+{
+/**
+Coerces this `DynTrait<_>` to a `&mut dyn fmt::Write`,
+to pass it to functions that take a `fmt::Write` trait object.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::FmtWriteInterface,
+    DynTrait,
+};
+
+use std::fmt::{self,Write};
+
+fn write_greeting(out:&mut dyn Write)->fmt::Result{
+    write!(out,"Hello, {}!","world")
+}
+
+let mut string=String::new();
+let mut wrapped=DynTrait::from_borrowing_ptr(&mut string,FmtWriteInterface);
+
+write_greeting(wrapped.as_fmt_write()).unwrap();
+drop(wrapped);
+
+assert_eq!( string, "Hello, world!" );
+
+```
+
+*/
+    #[inline]
+    pub fn as_fmt_write(&mut self)->&mut dyn fmtWrite{
+        self
+    }
+}
+
 
 
 //////////////////////////////////////////////////////////////////
@@ -1936,6 +3916,82 @@ where
     }
 }
 
+impl<'borr,P,I,EV> DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: InterfaceBound<IoWrite = Implemented<trait_marker::IoWrite>>,
+{
+/**
+Coerces this `DynTrait<_>` to a `&mut dyn io::Write`,
+to pass it to functions that take an `io::Write` trait object.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::IoWriteInterface,
+    DynTrait,
+};
+
+use std::io::{self,Write};
+
+fn write_greeting(out:&mut dyn Write)->io::Result<()>{
+    out.write_all(b"Hello")
+}
+
+let mut buffer=Vec::<u8>::new();
+let mut wrapped=DynTrait::from_borrowing_ptr(&mut buffer,IoWriteInterface);
+
+write_greeting(wrapped.as_io_write()).unwrap();
+drop(wrapped);
+
+assert_eq!( &buffer[..], b"Hello" );
+
+```
+
+*/
+    #[inline]
+    pub fn as_io_write(&mut self)->&mut dyn io::Write{
+        self
+    }
+
+/**
+Writes all the bytes in `buf`,returning an ffi-safe error if it fails.
+
+This is equivalent to `io::Write::write_all`,
+except that it takes and returns types from `abi_stable`,
+without converting the error to/from `std::io::Error`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::IoWriteInterface,
+    std_types::RSlice,
+    DynTrait,
+};
+
+let mut buffer=Vec::<u8>::new();
+let mut wrapped=DynTrait::from_borrowing_ptr(&mut buffer,IoWriteInterface);
+
+assert!( wrapped.sabi_write_all(RSlice::from_slice(b"Hello,")).is_ok() );
+assert!( wrapped.sabi_write_all(RSlice::from_slice(b" world")).is_ok() );
+drop(wrapped);
+
+assert_eq!( &buffer[..], b"Hello, world" );
+
+```
+
+*/
+    pub fn sabi_write_all(&mut self,buf:RSlice<'_,u8>)->RResult<(),RIoError>{
+        let vtable = self.sabi_vtable().io_write();
+
+        unsafe{
+            (vtable.write_all)(self.sabi_erased_mut(),buf)
+        }
+    }
+}
+
 
 /////////////
 
@@ -1963,6 +4019,50 @@ where
 
 }
 
+impl<'borr,P,I,EV> DynTrait<'borr,P,I,EV>
+where
+    P: DerefMut+GetPointerKind,
+    I: InterfaceBound<IoRead = Implemented<trait_marker::IoRead>>,
+{
+/**
+Coerces this `DynTrait<_>` to a `&mut dyn io::Read`,
+to pass it to functions that take an `io::Read` trait object.
+
+# Example
+
+```
+use abi_stable::{
+    DynTrait,
+    StableAbi,
+};
+
+use std::io::{self,Cursor,Read};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(IoRead))]
+struct ReadInterface;
+
+fn read_byte(input:&mut dyn Read)->io::Result<u8>{
+    let mut byte=[0];
+    input.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+let mut wrapped=DynTrait::from_any_value(Cursor::new(vec![3,5,8]),ReadInterface);
+
+assert_eq!( read_byte(wrapped.as_io_read()).unwrap(), 3 );
+assert_eq!( read_byte(wrapped.as_io_read()).unwrap(), 5 );
+
+```
+
+*/
+    #[inline]
+    pub fn as_io_read(&mut self)->&mut dyn io::Read{
+        self
+    }
+}
+
 
 /////////////
 
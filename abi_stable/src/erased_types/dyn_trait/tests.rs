@@ -6,7 +6,7 @@ use std::{
     collections::hash_map::DefaultHasher,
     fmt::{self, Display},
     hash::{Hash,Hasher},
-    
+    sync::Arc,
 };
 
 use serde::{Serialize};
@@ -16,15 +16,15 @@ use serde_json;
 #[allow(unused_imports)]
 use crate::{
     erased_types::{
-        DynTrait,ImplType, InterfaceType,IteratorItem,
+        DynTrait,DeserializeDynError,ImplType, InterfaceType,IteratorItem,
     },
     impl_get_type_info,
     type_level::bools::{False,True},
-    traits::IntoReprC,
+    traits::{IntoReprC,IntoReprRust},
     StableAbi,
     std_types::{
         RArc, RBox, RBoxError, RCow, RStr, RString,  StaticStr,
-        RNone,RSome,ROption,
+        RNone,RSome,ROption,RCmpOrdering,
     },
 };
 
@@ -91,6 +91,10 @@ impl<'a> DeserializeDyn<'a,VirtualFoo<'static>> for FooInterface {
             Err(e) => Err(RBoxError::new(e)),
         }
     }
+
+    fn proxy_input(s: &RString) -> Option<&str> {
+        Some(s.as_str())
+    }
 }
 
 type VirtualFoo<'a> = DynTrait<'a,RBox<()>,FooInterface>;
@@ -140,6 +144,162 @@ fn clone_test(){
     }
 }
 
+#[test]
+fn clone_all_test(){
+    let list=vec![new_wrapped(),Foo::<String>::default().piped(DynTrait::from_value)];
+
+    let cloned=clone_all(&list);
+    assert_eq!(cloned.capacity(),list.len());
+    assert_eq!(&cloned[..],&list[..]);
+
+    let reborrows=list.iter().map(|x| x.reborrow() ).collect::<Vec<_>>();
+    assert_eq!(&clone_all(&reborrows)[..],&reborrows[..]);
+
+    assert!(clone_all(&Vec::<VirtualFoo<'static>>::new()).is_empty());
+}
+
+#[test]
+fn drop_all_homogeneous_test(){
+    use crate::{
+        erased_types::interfaces::DebugDisplayInterface,
+        std_types::RVec,
+    };
+
+    let arc_a=Arc::new(0_u32);
+    let arc_b=Arc::new(0_i32);
+
+    let homogeneous=(0..10)
+        .map(|_| DynTrait::from_any_value(arc_a.clone(),DebugDisplayInterface) )
+        .collect::<RVec<_>>();
+    assert_eq!(Arc::strong_count(&arc_a),11);
+    drop_all_homogeneous(homogeneous);
+    assert_eq!(Arc::strong_count(&arc_a),1);
+
+    let mut heterogeneous=RVec::new();
+    for _ in 0..5 {
+        heterogeneous.push(DynTrait::from_any_value(arc_a.clone(),DebugDisplayInterface));
+        heterogeneous.push(DynTrait::from_any_value(arc_b.clone(),DebugDisplayInterface));
+    }
+    assert_eq!(Arc::strong_count(&arc_a),6);
+    assert_eq!(Arc::strong_count(&arc_b),6);
+    drop_all_homogeneous(heterogeneous);
+    assert_eq!(Arc::strong_count(&arc_a),1);
+    assert_eq!(Arc::strong_count(&arc_b),1);
+
+    drop_all_homogeneous(RVec::<VirtualFoo<'static>>::new());
+}
+
+#[test]
+fn sort_dyntraits_test(){
+    use crate::erased_types::sort_dyntraits;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,PartialEq,Eq,PartialOrd,Ord))]
+    struct OrdInterface;
+
+    type OrdObject=DynTrait<'static,RBox<()>,OrdInterface>;
+
+    let numbers=[21_u32,3,13,1,8,2,5,1];
+    let mut list=numbers.iter()
+        .map(|&x| DynTrait::from_any_value(x,OrdInterface) )
+        .collect::<Vec<OrdObject>>();
+
+    sort_dyntraits(&mut list);
+
+    let mut expected=numbers.to_vec();
+    expected.sort();
+    assert_eq!(
+        list.iter().map(|x| *x.as_unerased::<u32>().unwrap() ).collect::<Vec<_>>(),
+        expected,
+    );
+
+    // Values of different types are ordered by the address of their vtables,
+    // the same as the Ord impl.
+    let mut mixed=vec![
+        DynTrait::from_any_value(5_u32,OrdInterface),
+        DynTrait::from_any_value(String::from("b"),OrdInterface),
+        DynTrait::from_any_value(3_u32,OrdInterface),
+        DynTrait::from_any_value(String::from("a"),OrdInterface),
+    ];
+    sort_dyntraits(&mut mixed);
+    assert!(mixed.windows(2).all(|w| w[0]<=w[1] ));
+    assert!(mixed.windows(2).all(|w| w[0].sabi_vtable_address()<=w[1].sabi_vtable_address() ));
+
+    let (first_two,last_two)=mixed.split_at(2);
+    let (numbers,strings)=if first_two[0].as_unerased::<u32>().is_ok() {
+        (first_two,last_two)
+    }else{
+        (last_two,first_two)
+    };
+    assert_eq!(*numbers[0].as_unerased::<u32>().unwrap(),3);
+    assert_eq!(*numbers[1].as_unerased::<u32>().unwrap(),5);
+    assert_eq!(strings[0].as_unerased::<String>().unwrap().as_str(),"a");
+    assert_eq!(strings[1].as_unerased::<String>().unwrap().as_str(),"b");
+
+    sort_dyntraits(&mut Vec::<OrdObject>::new());
+}
+
+#[test]
+fn from_any_value_boxed_dyn_test(){
+    use crate::erased_types::interfaces::DEIteratorInterface;
+
+    use std::fmt::Debug;
+
+    {
+        let boxed:Box<dyn Debug+Send+Sync>=Box::new(Foo{l:3,r:5,name:"hello"});
+        let expected=format!("{:?}",boxed);
+        let object=DynTrait::from_any_value_boxed_dyn(boxed,DebugInterface);
+        assert_eq!(format!("{:?}",object),expected);
+
+        let unerased=object.into_unerased::<Box<dyn Debug+Send+Sync>>().unwrap();
+        assert_eq!(format!("{:?}",unerased),expected);
+    }
+    {
+        let iter:Box<dyn DoubleEndedIterator<Item=u32>+Send+Sync>=
+            Box::new((1..=5).map(|x| x*x ));
+        let mut object=DynTrait::from_any_value_boxed_dyn(iter,DEIteratorInterface::NEW);
+        assert_eq!(object.next(),Some(1));
+        assert_eq!(object.next_back(),Some(25));
+        assert_eq!(object.collect::<Vec<u32>>(),vec![4,9,16]);
+    }
+}
+
+#[test]
+fn as_bytes_as_str_test(){
+    use crate::{
+        erased_types::interfaces::{AsBytesInterface,AsStrInterface},
+        std_types::RSlice,
+    };
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,Debug,AsBytes,AsStr))]
+    struct BytesStrInterface;
+
+    {
+        let object=DynTrait::from_any_value(vec![0_u8,1,2,3],AsBytesInterface);
+        let bytes:RSlice<'_,u8>=object.sabi_as_bytes();
+        assert_eq!(bytes.as_slice(),&[0,1,2,3]);
+    }
+    {
+        let array=[5_u8,8,13];
+        let object=DynTrait::from_borrowing_value(&array[..],AsBytesInterface);
+        assert_eq!(object.sabi_as_bytes().as_slice(),&array[..]);
+    }
+    {
+        let string=String::from("hello 😀");
+        let object=DynTrait::from_borrowing_ptr(&string,AsStrInterface);
+        assert_eq!(object.sabi_as_str().as_str(),"hello 😀");
+        assert_eq!(object.sabi_as_str().as_ptr(),string.as_ptr());
+    }
+    {
+        let object=DynTrait::from_any_value(RString::from("world"),BytesStrInterface);
+        assert_eq!(object.sabi_as_str().as_str(),"world");
+        assert_eq!(object.sabi_as_bytes().as_slice(),b"world");
+    }
+}
+
 #[test]
 fn default_test(){
     let concrete=Foo::<String>::default();
@@ -211,6 +371,11 @@ fn fmt_test(){
         let reborrow=wrapped.reborrow_mut();
         debug_test!(reborrow);
     }
+    {
+        let reborrow_mut=wrapped.reborrow_mut();
+        let reborrow=reborrow_mut.reborrow();
+        debug_test!(reborrow);
+    }
 }
 
 
@@ -253,6 +418,55 @@ fn deserialize_test() {
 }
 
 
+#[test]
+fn deserialize_error_context() {
+    let err=VirtualFoo::deserialize_from_proxy("{\"l\":1000".into()).unwrap_err();
+    
+    let inner_err=serde_json::from_str::<Foo<String>>("{\"l\":1000").unwrap_err();
+
+    {
+        let err=err.downcast_ref::<DeserializeDynError>().unwrap();
+        assert_eq!(err.inner().to_string(), inner_err.to_string());
+        assert_eq!(err.input(), Some("{\"l\":1000"));
+        #[cfg(feature="rust_1_38")]
+        assert!(err.interface_name().contains("FooInterface"), "{}", err.interface_name());
+    }
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "failed to deserialize {} from `{{\"l\":1000`: {}",
+            crate::utils::get_type_name::<FooInterface>(),
+            inner_err,
+        ),
+    );
+
+    let json_ss=serde_json::to_string("{\"l\":1000").unwrap();
+    let serde_err=serde_json::from_str::<VirtualFoo<'static>>(&json_ss).unwrap_err();
+    assert!(serde_err.to_string().starts_with("failed to deserialize "), "{}", serde_err);
+    assert!(serde_err.to_string().contains(" from `{\"l\":1000`: "), "{}", serde_err);
+}
+
+
+#[test]
+fn deserialize_error_truncated_input() {
+    // 70 characters,each one taking 2 bytes.
+    let input="é".repeat(70);
+
+    let err=VirtualFoo::deserialize_from_proxy(input.as_str().into()).unwrap_err();
+    let err=err.downcast_ref::<DeserializeDynError>().unwrap();
+
+    let truncated=err.input().unwrap();
+    assert_eq!(truncated.chars().count(), 64);
+    assert_eq!(truncated, &input[..128]);
+    assert!(err.to_string().contains(&format!(" from `{}`: ",truncated)), "{}", err);
+
+    let short="ñ".repeat(64);
+    let err=VirtualFoo::deserialize_from_proxy(short.as_str().into()).unwrap_err();
+    let err=err.downcast_ref::<DeserializeDynError>().unwrap();
+    assert_eq!(err.input(), Some(short.as_str()));
+}
+
+
 #[test]
 fn serialize_test() {
 
@@ -434,6 +648,51 @@ fn from_any_test(){
 }
 
 
+#[test]
+fn from_value_as_test(){
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Clone,Debug))]
+    struct NarrowFooInterface;
+
+    let wrapped:DynTrait<'static,RBox<()>,NarrowFooInterface>=
+        DynTrait::from_value_as(new_foo(),NarrowFooInterface);
+
+    assert_eq!(format!("{:?}",wrapped),format!("{:?}",new_foo()));
+    assert_eq!(format!("{:?}",wrapped.clone()),format!("{:?}",new_foo()));
+
+    assert_eq!(
+        wrapped.into_unerased_impltype::<Foo<String>>().ok().map(RBox::into_inner),
+        Some(new_foo()),
+    );
+
+    // The type_info is the one from `Foo<String>`,not a generic one.
+    let wrapped=DynTrait::from_ptr_as(RArc::new(new_foo()),NarrowFooInterface);
+    let err=wrapped.into_unerased_impltype::<Foo<u32>>().unwrap_err();
+    assert!(format!("{:?}",err).contains("Foo"));
+    assert!(!format!("{:?}",err).contains("<unavailable>"));
+}
+
+
+#[test]
+fn is_borrowing_test(){
+    let mut owned=DynTrait::from_value(new_foo());
+    assert!(!owned.sabi_is_borrowing());
+    assert!(!owned.reborrow().sabi_is_borrowing());
+    assert!(!owned.reborrow_mut().sabi_is_borrowing());
+    assert!(!DynTrait::from_any_value(new_foo(),FooInterface).sabi_is_borrowing());
+    assert!(!DynTrait::from_any_ptr(RArc::new(new_foo()),FooInterface).sabi_is_borrowing());
+
+    let mut borrowing=DynTrait::from_borrowing_value(new_foo(),FooInterface);
+    assert!(borrowing.sabi_is_borrowing());
+    assert!(borrowing.reborrow().sabi_is_borrowing());
+    assert!(borrowing.reborrow_mut().sabi_is_borrowing());
+    assert!(
+        DynTrait::from_borrowing_ptr(RArc::new(new_foo()),FooInterface).sabi_is_borrowing()
+    );
+}
+
+
 
 #[test]
 fn to_any_test(){
@@ -484,8 +743,377 @@ fn to_any_test(){
 }
 
 
+#[test]
+fn replace_value_test(){
+    let mut wrapped=DynTrait::from_any_value(new_foo(),FooInterface);
+    let address=wrapped.sabi_erased_ref() as *const ErasedObject;
+
+    let replacement=Foo{l:3,r:5,name:"replaced".to_string()};
+    assert_eq!(wrapped.sabi_replace_value(replacement.clone()).unwrap(),new_foo());
+    assert_eq!(wrapped.as_unerased::<Foo<String>>().unwrap(),&replacement);
+    assert_eq!(wrapped.sabi_erased_ref() as *const ErasedObject,address);
+
+    assert_eq!(
+        wrapped.sabi_replace_value(Foo{l:0,r:0,name:RString::new()})
+            .unwrap_err()
+            .into_inner(),
+        Foo{l:0,r:0,name:RString::new()},
+    );
+    assert_eq!(
+        wrapped.reborrow_mut().sabi_replace_value(new_foo()).unwrap(),
+        replacement,
+    );
+    assert_eq!(wrapped.as_unerased::<Foo<String>>().unwrap(),&new_foo());
+}
 
 
+#[test]
+fn raw_parts_test(){
+    use crate::erased_types::interfaces::DebugDisplayInterface;
+
+    type Object=DynTrait<'static,RBox<()>,DebugDisplayInterface>;
+
+    let arc=Arc::new(100);
+    {
+        let wrapped:Object=DynTrait::from_any_value(arc.clone(),DebugDisplayInterface);
+        let address=wrapped.sabi_object_address();
+        let raw_vtable=wrapped.sabi_raw_vtable();
+        assert_eq!(raw_vtable as usize,wrapped.sabi_vtable_address());
+
+        let (ptr,vtable,extra_value)=unsafe{ wrapped.sabi_into_raw_parts() };
+        assert_eq!(vtable,raw_vtable);
+        assert_eq!(Arc::strong_count(&arc),2);
+
+        let wrapped=unsafe{ Object::sabi_from_raw_parts(ptr,vtable,extra_value) };
+        assert_eq!(wrapped.sabi_object_address(),address);
+        assert_eq!(wrapped.to_string(),"100");
+        assert_eq!(wrapped.as_unerased::<Arc<i32>>().unwrap(),&arc);
+        assert_eq!(Arc::strong_count(&arc),2);
+    }
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    {
+        let wrapped=DynTrait::from_value(new_foo());
+        let (ptr,vtable,extra_value)=unsafe{ wrapped.sabi_into_raw_parts() };
+        let wrapped:VirtualFoo<'static>=unsafe{ 
+            DynTrait::sabi_from_raw_parts(ptr,vtable,extra_value) 
+        };
+        assert_eq!(wrapped.as_unerased::<Foo<String>>().unwrap(),&new_foo());
+    }
+}
+
+
+#[test]
+fn with_extra_vtable_test(){
+    use crate::erased_types::interfaces::DebugDisplayInterface;
+
+    struct DoubleVTable{
+        double:unsafe fn(&ErasedObject)->u32,
+    }
+
+    unsafe fn double_u32(this:&ErasedObject)->u32{
+        *(this as *const ErasedObject as *const u32) * 2
+    }
+
+    static DOUBLE_VTABLE:DoubleVTable=DoubleVTable{ double:double_u32 };
+
+    let arc=Arc::new(());
+
+    let object=DynTrait::from_any_value(21_u32,DebugDisplayInterface);
+    let address=object.sabi_object_address();
+    let vtable=object.sabi_raw_vtable();
+
+    let object=unsafe{ object.sabi_with_extra_vtable(arc.clone()) };
+    assert_eq!(Arc::strong_count(&arc),2);
+
+    let object=unsafe{ object.sabi_with_extra_vtable(&DOUBLE_VTABLE) };
+    // The previous extra value was dropped.
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    assert_eq!(object.sabi_object_address(),address);
+    assert_eq!(object.sabi_raw_vtable(),vtable);
+    assert_eq!(object.to_string(),"21");
+    assert_eq!(format!("{:?}",object),"21");
+
+    let doubled=unsafe{ (object.sabi_extra_value().double)(object.sabi_erased_ref()) };
+    assert_eq!(doubled,42);
+
+    let object=unsafe{ object.sabi_with_extra_vtable(()) };
+    assert_eq!(object.as_unerased::<u32>().ok(),Some(&21));
+}
+
+
+#[test]
+fn sabi_try_eq_test(){
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,PartialEq))]
+    struct PartialEqInterface;
+
+    let strings=["aaa","bbb","aaa"].iter().map(|s| s.to_string() ).collect::<Vec<_>>();
+    let wrapped=strings.iter()
+        .map(|s| DynTrait::from_borrowing_value(s,PartialEqInterface) )
+        .collect::<Vec<_>>();
+
+    for (i,left) in wrapped.iter().enumerate() {
+        for (j,right) in wrapped.iter().enumerate() {
+            assert_eq!(left.sabi_try_eq(right),RSome(strings[i]==strings[j]));
+        }
+    }
+
+    // Wrapping values of different types,which are not comparable.
+    let number=100_u32;
+    let other=DynTrait::from_borrowing_value(&number,PartialEqInterface);
+    assert_eq!(wrapped[0].sabi_try_eq(&other),RNone);
+    assert_eq!(other.sabi_try_eq(&wrapped[0]),RNone);
+    assert_eq!(other.sabi_try_eq(&other),RSome(true));
+
+    // Owned DynTraits agree with the PartialEq impl when the types are the same.
+    let owned=vec![
+        DynTrait::from_any_value(String::from("aaa"),PartialEqInterface),
+        DynTrait::from_any_value(String::from("bbb"),PartialEqInterface),
+        DynTrait::from_any_value(0_u8,PartialEqInterface),
+    ];
+    assert_eq!(owned[0].sabi_try_eq(&owned[1]),RSome(owned[0]==owned[1]));
+    assert_eq!(owned[0].sabi_try_eq(&owned[0]),RSome(true));
+    assert_eq!(owned[0].sabi_try_eq(&owned[2]),RNone);
+    assert!(owned[0]!=owned[2]);
+}
+
+
+#[test]
+fn sabi_cmp_test(){
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,Ord))]
+    struct OrdInterface;
+
+    let strings=["aaa","bbb","ccc"].iter().map(|s| s.to_string() ).collect::<Vec<_>>();
+    let wrapped=strings.iter()
+        .map(|s| DynTrait::from_borrowing_value(s,OrdInterface) )
+        .collect::<Vec<_>>();
+
+    for (i,left) in wrapped.iter().enumerate() {
+        for (j,right) in wrapped.iter().enumerate() {
+            let expected=i.cmp(&j);
+            assert_eq!(left.sabi_cmp(right),RCmpOrdering::from(expected));
+            assert_eq!(left.sabi_partial_cmp(right),RSome(RCmpOrdering::from(expected)));
+        }
+    }
+
+    // Wrapping values of different types,which are ordered by the address of the vtable.
+    let number=100_u32;
+    let other=DynTrait::from_borrowing_value(&number,OrdInterface);
+    let ordering=wrapped[0].sabi_cmp(&other);
+    assert_ne!(ordering,RCmpOrdering::Equal);
+    assert_eq!(other.sabi_cmp(&wrapped[0]).into_ordering(),ordering.into_ordering().reverse());
+    assert_eq!(wrapped[1].sabi_cmp(&other),ordering);
+    assert_eq!(wrapped[0].sabi_partial_cmp(&other),RSome(ordering));
+
+    // Comparing owned DynTraits gives the same result as the std traits.
+    let owned=strings.iter()
+        .map(|s| DynTrait::from_any_value(s.clone(),OrdInterface) )
+        .collect::<Vec<_>>();
+    assert_eq!(owned[0].sabi_cmp(&owned[2]).into_ordering(),owned[0].cmp(&owned[2]));
+    assert_eq!(
+        owned[2].sabi_partial_cmp(&owned[1]).map(RCmpOrdering::into_ordering).into_rust(),
+        owned[2].partial_cmp(&owned[1]),
+    );
+}
+
+
+
+
+
+/// Simulates `Foo<String>` being declared in another dynamic library,
+/// compiled from the same source code.
+#[repr(transparent)]
+#[derive(Debug,PartialEq)]
+struct ForeignFoo(Foo<String>);
+
+impl ImplType for ForeignFoo {
+    type Interface = FooInterface;
+    const INFO:&'static crate::erased_types::TypeInfo=&crate::erased_types::TypeInfo{
+        _uid:crate::sabi_types::Constructor(
+            crate::std_types::utypeid::some_utypeid::<ForeignFoo>
+        ),
+        ..*<Foo<String> as ImplType>::INFO
+    };
+}
+
+#[test]
+fn into_unerased_cross_lib(){
+    let wrapped=DynTrait::from_value(new_foo());
+
+    unsafe{
+        assert_eq!(
+            wrapped.clone().sabi_into_unerased_cross_lib::<Foo<String>>().unwrap(),
+            RBox::new(new_foo())
+        );
+        #[cfg(feature="rust_1_38")]
+        assert_eq!(
+            wrapped.clone().sabi_into_unerased_cross_lib::<ForeignFoo>().unwrap(),
+            RBox::new(ForeignFoo(new_foo()))
+        );
+        // The type name doesn't include generic arguments before Rust 1.38,
+        // so `TypeInfo::has_same_contents` always returns false.
+        #[cfg(not(feature="rust_1_38"))]
+        assert_eq!(
+            wrapped.clone().sabi_into_unerased_cross_lib::<ForeignFoo>().map_err(drop),
+            Err(())
+        );
+        assert_eq!(
+            wrapped.clone().sabi_into_unerased_cross_lib::<Foo<RString>>().map_err(drop),
+            Err(())
+        );
+    }
+
+    {
+        let info=<Foo<String> as ImplType>::INFO;
+        assert_eq!(
+            info.has_same_contents(<ForeignFoo as ImplType>::INFO),
+            cfg!(feature="rust_1_38"),
+        );
+        // Only the generic arguments differ,and both types have the same size.
+        assert!(!info.has_same_contents(<Foo<RString> as ImplType>::INFO));
+    }
+
+    // The regular unerasing methods don't consider them the same type.
+    assert_eq!(
+        wrapped.into_unerased_impltype::<ForeignFoo>().map_err(drop),
+        Err(())
+    );
+
+    // DynTraits constructed with `from_borrowing_*` can't be unerased.
+    let borrowing=DynTrait::from_borrowing_value(new_foo(),FooInterface);
+    assert!(borrowing.sabi_is_borrowing());
+    unsafe{
+        assert_eq!(
+            borrowing.sabi_into_unerased_cross_lib::<Foo<String>>().map_err(drop),
+            Err(())
+        );
+    }
+}
+
+#[test]
+fn try_clone_test(){
+    use crate::erased_types::interfaces::DebugDisplayInterface;
+
+    let arc=Arc::new(100);
+    {
+        let cloneable=DynTrait::from_cloneable_value(arc.clone(),DebugDisplayInterface);
+        let opaque=DynTrait::from_any_value(arc.clone(),DebugDisplayInterface);
+        assert_eq!(Arc::strong_count(&arc),3);
+
+        let cloned=cloneable.sabi_try_clone().unwrap();
+        assert_eq!(Arc::strong_count(&arc),4);
+        assert_eq!(cloned.to_string(),"100");
+        assert_eq!(cloned.as_unerased::<Arc<i32>>().unwrap(),&arc);
+        assert_ne!(cloned.sabi_object_address(),cloneable.sabi_object_address());
+
+        // Clones of clones are also cloneable.
+        let cloned_again=cloned.sabi_try_clone().unwrap();
+        assert_eq!(Arc::strong_count(&arc),5);
+        drop(cloned_again);
+
+        assert!(opaque.sabi_try_clone().is_none());
+        assert_eq!(Arc::strong_count(&arc),4);
+    }
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    {
+        // Interfaces that require Clone can always be cloned
+        let wrapped=DynTrait::from_value(new_foo());
+        let cloned=wrapped.sabi_try_clone().unwrap();
+        assert_eq!(cloned.as_unerased::<Foo<String>>().unwrap(),&new_foo());
+    }
+}
+
+#[test]
+fn map_unerased_test(){
+    use crate::{
+        erased_types::interfaces::DebugDisplayInterface,
+        type_level::unerasability::TU_Unerasable,
+    };
+
+    let arc=Arc::new(100);
+    {
+        let wrapped=DynTrait::from_any_value(arc.clone(),DebugDisplayInterface);
+        assert_eq!(Arc::strong_count(&arc),2);
+
+        let mapped=wrapped.map_unerased(|x:Arc<i32>| *x+1 ).unwrap();
+        assert_eq!(Arc::strong_count(&arc),1);
+        assert_eq!(mapped.to_string(),"101");
+        assert_eq!(mapped.as_unerased::<i32>().unwrap(),&101);
+
+        // Not the type that `mapped` wraps.
+        let mapped=mapped.map_unerased(|x:u32| x ).unwrap_err().into_inner();
+        assert_eq!(mapped.to_string(),"101");
+    }
+    {
+        let wrapped=DynTrait::<'static,RBox<()>,DebugDisplayInterface,_>::
+            with_extra_value::<_,TU_Unerasable>(RBox::new(arc.clone()),"extra");
+        
+        let mapped=wrapped.map_unerased(|x:Arc<i32>| x.to_string() ).unwrap();
+        assert_eq!(Arc::strong_count(&arc),1);
+        assert_eq!(mapped.to_string(),"100");
+        assert_eq!(mapped.sabi_extra_value(),&"extra");
+    }
+    {
+        let borrowing=DynTrait::from_borrowing_value(10_u32,DebugDisplayInterface);
+        assert!(borrowing.map_unerased(|x:u32| x ).is_err());
+    }
+}
+
+#[test]
+fn hash_eq_map_keys(){
+    use crate::{
+        erased_types::interfaces::HashEqInterface,
+        std_types::RHashMap,
+    };
+
+    type Key=DynTrait<'static,RBox<()>,HashEqInterface>;
+
+    fn hash_of(key:&Key)->u64{
+        let mut hasher=DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let u32_key=||DynTrait::from_any_value(10_u32,HashEqInterface);
+    let i32_key=||DynTrait::from_any_value(10_i32,HashEqInterface);
+    let str_key=||DynTrait::from_any_value(RString::from("10"),HashEqInterface);
+
+    // Equal values of the same type compare equal and hash identically.
+    assert_eq!(u32_key(),u32_key());
+    assert_eq!(hash_of(&u32_key()),hash_of(&u32_key()));
+    assert_eq!(hash_of(&str_key()),hash_of(&str_key()));
+
+    // Values of different types never compare equal,even if they hash identically.
+    assert_eq!(hash_of(&u32_key()),hash_of(&i32_key()));
+    assert_ne!(u32_key(),i32_key());
+    assert_ne!(u32_key(),str_key());
+    assert_ne!(
+        DynTrait::from_any_value(10_u32,HashEqInterface),
+        DynTrait::from_any_value(11_u32,HashEqInterface),
+    );
+
+    let mut map=RHashMap::<Key,u32>::new();
+    assert_eq!(map.insert(u32_key(),0),RNone);
+    assert_eq!(map.insert(i32_key(),1),RNone);
+    assert_eq!(map.insert(str_key(),2),RNone);
+    assert_eq!(map.len(),3);
+
+    assert_eq!(map.get(&u32_key()),Some(&0));
+    assert_eq!(map.get(&i32_key()),Some(&1));
+    assert_eq!(map.get(&str_key()),Some(&2));
+    assert_eq!(map.get(&DynTrait::from_any_value(10_u64,HashEqInterface)),None);
+
+    assert_eq!(map.insert(i32_key(),10),RSome(1));
+    assert_eq!(map.len(),3);
+    assert_eq!(map.get(&i32_key()),Some(&10));
+    assert_eq!(map.get(&u32_key()),Some(&0));
+}
 
 //////////////////////////////////////////////////////////////////////
 
@@ -562,6 +1190,10 @@ mod borrowing{
                 Err(e) => Err(RBoxError::new(e)),
             }
         }
+
+        fn proxy_input(s: &RStr<'borr>) -> Option<&str> {
+            Some(s.as_str())
+        }
     }
 
 
@@ -710,6 +1342,29 @@ mod borrowing{
         (n,Some(n))
     }
 
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,Iterator))]
+    struct ForwardIterInterface;
+
+    impl<'a> IteratorItem<'a> for ForwardIterInterface{
+        type Item=&'a str;
+    }
+
+    #[test]
+    fn iterator_reborrow_mut_as(){
+        let s="line0\nline1\nline2\nline3".to_string();
+
+        let mut iter=iterator_from_lines(&s);
+        assert_eq!(iter.next_back(),Some("line3"));
+        {
+            let mut narrowed=iter.reborrow_mut_as::<ForwardIterInterface>();
+            assert_eq!(narrowed.next(),Some("line0"));
+            assert_eq!(narrowed.size_hint(),exact_size_hint(2));
+        }
+        assert_eq!(iter.collect::<Vec<&str>>(),vec!["line1","line2"]);
+    }
+
     #[test]
     fn iterator_collect(){
         let s="line0\nline1\nline2".to_string();
@@ -721,6 +1376,23 @@ mod borrowing{
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn riterator(){
+        use crate::erased_types::{RIterator,RDoubleEndedIterator};
+
+        let s="line0\nline1\nline2".to_string();
+
+        let mut iter=RIterator::from_iter(s.lines());
+        assert_eq!(iter.next(),Some("line0"));
+        assert_eq!(iter.collect::<Vec<&str>>(),vec!["line1","line2"]);
+
+        let mut iter=RDoubleEndedIterator::from_iter(s.lines());
+        assert_eq!(iter.next_back(),Some("line2"));
+        assert_eq!(iter.next(),Some("line0"));
+        assert_eq!(iter.next_back(),Some("line1"));
+        assert_eq!(iter.next(),None);
+    }
+
     #[test]
     fn iterator_next(){
         let s="line0\nline1\nline2".to_string();
@@ -766,6 +1438,30 @@ mod borrowing{
         assert_eq!(iterator_from_lines(&s).skip(4).count(),0);
     }
 
+    #[test]
+    fn iterator_collect_rvec(){
+        use crate::std_types::RVec;
+
+        let s="line0\nline1\nline2\nline3".to_string();
+
+        let mut iter=iterator_from_lines(&s);
+        assert_eq!(iter.next(),Some("line0"));
+        let collected=iter.collect_rvec();
+        assert_eq!(&collected[..],&["line1","line2","line3"][..]);
+        assert_eq!(collected.capacity(),3);
+        assert_eq!(iter.next(),None);
+        assert_eq!(iter.size_hint(),exact_size_hint(0));
+        assert_eq!(iter.collect_rvec(),RVec::new());
+
+        // Iterators with an inexact size_hint are still fully consumed.
+        let mut iter=DynTrait::from_borrowing_value(
+            s.lines().filter(|l| !l.ends_with('2') ),
+            IterInterface,
+        );
+        assert_eq!(&iter.collect_rvec()[..],&["line0","line1","line3"][..]);
+        assert_eq!(iter.next(),None);
+    }
+
 
     #[test]
     fn iterator_last(){
@@ -824,6 +1520,109 @@ mod borrowing{
     }
 
 
+    #[test]
+    fn iterator_sabi_max_min(){
+        let s="line1\nline2\nline0".to_string();
+
+        assert_eq!(iterator_from_lines(&s).sabi_max(),RSome("line2"));
+        assert_eq!(iterator_from_lines(&s).sabi_min(),RSome("line0"));
+        assert_eq!(iterator_from_lines("").sabi_max(),RNone);
+        assert_eq!(iterator_from_lines("").sabi_min(),RNone);
+    }
+
+
+    #[test]
+    fn iterator_find_position_erased(){
+        let s="line0\nline1\nline2\nline3".to_string();
+
+        {
+            let mut iter=iterator_from_lines(&s);
+            let mut calls=0;
+            assert_eq!(
+                iter.find_erased(|x|{ calls+=1; x.ends_with('1') }),
+                RSome("line1")
+            );
+            assert_eq!(calls,2);
+            assert_eq!(iter.next(),Some("line2"));
+            assert_eq!(iter.find_erased(|x| x.ends_with('1') ),RNone);
+            assert_eq!(iter.next(),None);
+        }
+        {
+            let mut iter=iterator_from_lines(&s);
+            assert_eq!(iter.position_erased(|x| x.ends_with('2') ),RSome(2));
+            assert_eq!(iter.position_erased(|_| true ),RSome(0));
+            assert_eq!(iter.position_erased(|_| true ),RNone);
+        }
+    }
+
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,Iterator,IteratorSum,IteratorProduct))]
+    struct NumIterInterface;
+
+    impl<'a> IteratorItem<'a> for NumIterInterface{
+        type Item=u64;
+    }
+
+    #[test]
+    fn iterator_sum_product_erased(){
+        let new_iter=|list:Vec<u64>|{
+            DynTrait::from_any_value(list.into_iter(),NumIterInterface)
+        };
+
+        {
+            let mut iter=new_iter(vec![3,5,8,13]);
+            assert_eq!(iter.next(),Some(3));
+            assert_eq!(iter.sum_erased(),26);
+            assert_eq!(iter.next(),None);
+            assert_eq!(iter.sum_erased(),0);
+        }
+        {
+            let mut iter=new_iter(vec![2,3,5,7]);
+            assert_eq!(iter.product_erased(),210);
+            assert_eq!(iter.product_erased(),1);
+        }
+
+        assert!(<NumIterInterface as InterfaceBound>::IteratorSum);
+        assert!(<NumIterInterface as InterfaceBound>::IteratorProduct);
+        assert!(!<IterInterface as InterfaceBound>::IteratorSum);
+        assert!(!<IterInterface as InterfaceBound>::IteratorProduct);
+    }
+
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,Debug,Extend))]
+    struct ExtendInterface;
+
+    impl<'a> IteratorItem<'a> for ExtendInterface{
+        type Item=&'a str;
+    }
+
+    #[test]
+    fn extend_from_erased(){
+        use crate::erased_types::RIterator;
+
+        let text=String::from("foo bar baz");
+        let mut list=Vec::<&str>::new();
+        {
+            let mut wrapped=DynTrait::from_borrowing_ptr(&mut list,ExtendInterface);
+
+            wrapped.extend_from_erased(RIterator::from_iter(text.split(' ')));
+            assert_eq!(format!("{:?}",wrapped),r#"["foo", "bar", "baz"]"#);
+
+            wrapped.extend_from_erased(RIterator::from_iter(std::iter::empty()));
+            wrapped.extend_from_erased(RIterator::from_iter(vec!["qux"].into_iter()));
+        }
+        assert_eq!(list,vec!["foo","bar","baz","qux"]);
+
+        assert!(<ExtendInterface as InterfaceBound>::Extend);
+        assert!(!<ExtendInterface as InterfaceBound>::Iterator);
+        assert!(!<IterInterface as InterfaceBound>::Extend);
+    }
+
+
     ////////////////
 
 
@@ -1016,6 +1815,75 @@ mod borrowing{
         );
     }
     
+    #[test]
+    fn trait_object_adaptors(){
+        use std::{
+            fmt::{self,Write as FmtWrite},
+            io::{self,Read,Write,Cursor},
+        };
+
+        fn write_fmt_obj(out:&mut dyn FmtWrite)->fmt::Result{
+            out.write_str("Hello")?;
+            write!(out,", {}",10)
+        }
+
+        fn write_io_obj(out:&mut dyn Write)->io::Result<()>{
+            out.write_all(&[0,1,2])?;
+            out.flush()
+        }
+
+        fn read_io_obj(input:&mut dyn Read)->io::Result<Vec<u8>>{
+            let mut out=vec![0;2];
+            input.read_exact(&mut out)?;
+            Ok(out)
+        }
+
+        let mut s=String::new();
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut s,FmtInterface);
+            write_fmt_obj(wrapped.as_fmt_write()).unwrap();
+        }
+        assert_eq!(&*s,"Hello, 10");
+
+        let mut buff=Cursor::new(vec![0;5]);
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut buff,IoInterface);
+            write_io_obj(wrapped.as_io_write()).unwrap();
+        }
+        assert_eq!(&buff.get_ref()[..],&[0,1,2,0,0][..]);
+
+        buff.set_position(1);
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut buff,IoInterface);
+            assert_eq!(read_io_obj(wrapped.as_io_read()).unwrap(),vec![1,2]);
+            assert_eq!(read_io_obj(wrapped.as_io_read()).unwrap(),vec![0,0]);
+            read_io_obj(wrapped.as_io_read()).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn sabi_write_all(){
+        use std::io::{Cursor,ErrorKind};
+
+        use crate::std_types::{RIoErrorKind,RSlice};
+
+        let mut buff=Cursor::new(vec![0u8;5].into_boxed_slice());
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut buff,IoInterface);
+            assert!(wrapped.sabi_write_all(RSlice::from_slice(&[3,4])).is_ok());
+            assert!(wrapped.sabi_write_all(RSlice::from_slice(&[5])).is_ok());
+            assert!(wrapped.sabi_write_all(RSlice::from_slice(&[])).is_ok());
+        }
+        assert_eq!(&buff.get_ref()[..],&[3,4,5,0,0][..]);
+
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut buff,IoInterface);
+            let err=wrapped.sabi_write_all(RSlice::from_slice(&[6,7,8])).unwrap_err();
+            assert_eq!(err.kind(),RIoErrorKind::from(ErrorKind::WriteZero));
+        }
+        assert_eq!(&buff.get_ref()[..],&[3,4,5,6,7][..]);
+    }
+
     #[repr(C)]
     #[derive(StableAbi)]
     #[sabi(impl_InterfaceType(Send,Sync,IoRead,IoBufRead))]
@@ -1076,7 +1944,162 @@ mod borrowing{
             assert_eq!(&out[..8],&[1,2,3,4,8,9,10,7][..] );
         }
     }
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(impl_InterfaceType(Send,Sync,IoSeek,IoRead))]
+    struct IoReadSeekInterface;
+
+    #[test]
+    fn reborrow_mut_as(){
+        use std::io::{Read,Seek,SeekFrom,Write,Cursor};
+
+        fn read_from(mut reader:DynTrait<'_,&mut (),IoReadSeekInterface>,pos:u64)->u8{
+            let mut out=[0];
+            reader.seek(SeekFrom::Start(pos)).unwrap();
+            reader.read_exact(&mut out).unwrap();
+            out[0]
+        }
+
+        let mut buff=vec![0,1,2,3,4,5].piped(Cursor::new);
+        {
+            let mut wrapped=DynTrait::from_any_ptr(&mut buff,IoInterface);
+
+            assert_eq!(read_from(wrapped.reborrow_mut_as(),4),4);
+            wrapped.write_all(&[10,11]).unwrap();
+            assert_eq!(read_from(wrapped.reborrow_mut_as(),2),2);
+            wrapped.write_all(&[20]).unwrap();
+            assert_eq!(read_from(wrapped.reborrow_mut_as(),5),10);
+        }
+        assert_eq!(buff.into_inner(),vec![0,1,2,20,4,10,11]);
+    }
 }
 
 
 
+
+#[test]
+fn interface_compatibility(){
+    use crate::erased_types::{
+        enabled_traits::{EnabledTraits,auto_trait_mask,regular_trait_mask},
+        interfaces::DebugDisplayInterface,
+        InterfaceBound,
+    };
+
+    let debug_traits=<DebugInterface as InterfaceBound>::EXTRA_CHECKS;
+    let foo_traits=<FooInterface as InterfaceBound>::EXTRA_CHECKS;
+
+    {
+        let object=DynTrait::from_any_value(3_u8,DebugInterface);
+        assert_eq!(object.sabi_interface_tag(),RSome(debug_traits));
+        assert!(object.sabi_is_interface_compatible());
+
+        let object=DynTrait::from_any_value(3_u8,DebugDisplayInterface);
+        assert_eq!(
+            object.sabi_interface_tag(),
+            RSome(<DebugDisplayInterface as InterfaceBound>::EXTRA_CHECKS),
+        );
+        assert!(object.sabi_is_interface_compatible());
+
+        let object=new_wrapped();
+        assert_eq!(object.sabi_interface_tag(),RSome(foo_traits));
+        assert!(object.sabi_is_interface_compatible());
+    }
+
+    let send_sync=auto_trait_mask::Send|auto_trait_mask::Sync;
+    let debug=EnabledTraits{ auto_traits:send_sync, regular_traits:regular_trait_mask::Debug };
+    let debug_display=EnabledTraits{
+        regular_traits:regular_trait_mask::Debug|regular_trait_mask::Display,
+        ..debug
+    };
+    assert_eq!(debug,debug_traits);
+
+    // Implementing more traits than expected is fine.
+    assert!(debug.is_compatible_with(&debug));
+    assert!(debug.is_compatible_with(&debug_display));
+    assert!(!debug_display.is_compatible_with(&debug));
+    
+    // Auto traits must match exactly.
+    let debug_send=EnabledTraits{ auto_traits:auto_trait_mask::Send, ..debug };
+    assert!(!debug.is_compatible_with(&debug_send));
+    assert!(!debug_send.is_compatible_with(&debug));
+    assert!(!debug.is_compatible_with(&foo_traits));
+}
+
+#[test]
+fn pinned_test(){
+    use std::{
+        marker::PhantomPinned,
+        pin::Pin,
+    };
+
+    use crate::{
+        erased_types::interfaces::DebugDisplayInterface,
+        sabi_types::PinnedPtr,
+    };
+
+    /// A type that stores its own address,to check that it's never moved.
+    struct SelfAddress{
+        address:usize,
+        _pin:PhantomPinned,
+    }
+
+    impl SelfAddress{
+        fn is_at_address(&self)->bool{
+            self.address==(self as *const Self as usize)
+        }
+    }
+
+    impl fmt::Debug for SelfAddress{
+        fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+            fmt::Display::fmt(self,f)
+        }
+    }
+
+    impl Display for SelfAddress{
+        fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+            write!(f,"{}",self.is_at_address())
+        }
+    }
+
+    let mut boxed=RBox::new(SelfAddress{ address:0, _pin:PhantomPinned });
+    boxed.address=(&*boxed) as *const SelfAddress as usize;
+    let pinned=unsafe{ Pin::new_unchecked(boxed) };
+
+    let object=DynTrait::from_pinned_ptr(pinned,DebugDisplayInterface);
+    assert_eq!(object.to_string(),"true");
+    assert_eq!(format!("{:?}",object),"true");
+    assert!(object.as_unerased::<SelfAddress>().unwrap().is_at_address());
+
+    {
+        let reborrow=object.reborrow();
+        assert_eq!(reborrow.to_string(),"true");
+        assert!(reborrow.into_unerased::<SelfAddress>().unwrap().is_at_address());
+    }
+
+    let object=object.into_unerased::<u32>().unwrap_err().into_inner();
+    let object:PinnedPtr<RBox<SelfAddress>>=object.into_unerased::<SelfAddress>().unwrap();
+    assert!(object.is_at_address());
+    assert!(object.into_pin().as_ref().get_ref().is_at_address());
+
+    {
+        let arc=Arc::new(10_u32);
+
+        let object=DynTrait::from_pinned_value(arc.clone(),DebugDisplayInterface);
+        assert_eq!(Arc::strong_count(&arc),2);
+        assert_eq!(object.as_unerased::<Arc<u32>>().unwrap(),&arc);
+        drop(object);
+        assert_eq!(Arc::strong_count(&arc),1);
+
+        let object=DynTrait::from_pinned_value(arc.clone(),DebugDisplayInterface);
+        let (ptr,vtable,extra_value)=unsafe{ object.sabi_into_raw_parts() };
+        assert_eq!(Arc::strong_count(&arc),2);
+        let object:DynTrait<'static,PinnedPtr<RBox<()>>,DebugDisplayInterface>=unsafe{
+            DynTrait::sabi_from_raw_parts(ptr,vtable,extra_value)
+        };
+        assert_eq!(object.to_string(),"10");
+        drop(object);
+        assert_eq!(Arc::strong_count(&arc),1);
+    }
+}
+
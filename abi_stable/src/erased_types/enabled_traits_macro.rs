@@ -47,12 +47,24 @@ macro_rules! declare_enabled_traits {
 
 
         #[repr(C)]
-        #[derive(Copy,Clone,StableAbi)]
+        #[derive(Copy,Clone,PartialEq,Eq,StableAbi)]
         pub struct EnabledTraits{
             pub auto_traits:u16,
             pub regular_traits:u64,
         }
 
+        impl EnabledTraits{
+            /// Whether a value whose interface implements the `found` traits 
+            /// can be used where an interface implementing the `self` traits is expected.
+            ///
+            /// This requires the auto traits to be exactly the same,
+            /// and the regular traits in `self` to be a subset of the ones in `found`.
+            pub fn is_compatible_with(&self,found:&EnabledTraits)->bool{
+                self.auto_traits==found.auto_traits &&
+                (self.regular_traits&found.regular_traits)==self.regular_traits
+            }
+        }
+
         impl Debug for EnabledTraits{
             fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
                 use self::debug_impl_details::{EnabledAutoTraits,EnabledRegularTraits};
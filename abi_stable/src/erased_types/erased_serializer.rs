@@ -0,0 +1,676 @@
+/*!
+Contains `ErasedSerializer`,
+which allows serializing values with a serialization format from another dynamic library.
+*/
+
+use std::ops::DerefMut;
+
+use serde::{
+    ser::{
+        self,
+        SerializeMap,SerializeSeq,SerializeStruct,SerializeStructVariant,
+        SerializeTuple,SerializeTupleStruct,SerializeTupleVariant,
+    },
+    Serialize,
+};
+
+use crate::{
+    pointer_trait::GetPointerKind,
+    sabi_trait,
+    std_types::{RBoxError,ROption,RResult,RSlice,RSome,RStr},
+};
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+
+/**
+An ffi-safe serialization format,which receives the serde data model as a stream of events.
+
+This is implemented by a serialization format (usually in a dynamic library),
+and is used by wrapping a `SerializerBackend_TO` in an `ErasedSerializer`,
+which implements `serde::Serializer`.
+
+Types that wrap another value (`Option`,newtype structs,newtype variants)
+first call the method for the wrapper type,then serialize the wrapped value.
+
+Compound types (sequences,tuples,maps,structs) call `begin_compound`,
+then serialize each element/entry/field preceded by a call to
+`serialize_element`/`serialize_key`+`serialize_value`/`serialize_field`,
+and finally call `end_compound`.
+
+All the methods return an `RBoxError` on failure,
+which is propagated to the caller of `Serialize::serialize`.
+
+# Example
+
+For an example of implementing and using this trait
+look at the docs for [`ErasedSerializer`](./struct.ErasedSerializer.html).
+
+*/
+#[sabi_trait]
+pub trait SerializerBackend{
+    fn serialize_bool(&mut self,v:bool)->RResult<(),RBoxError>;
+
+    fn serialize_i8(&mut self,v:i8)->RResult<(),RBoxError>;
+
+    fn serialize_i16(&mut self,v:i16)->RResult<(),RBoxError>;
+
+    fn serialize_i32(&mut self,v:i32)->RResult<(),RBoxError>;
+
+    fn serialize_i64(&mut self,v:i64)->RResult<(),RBoxError>;
+
+    /// Serializes an `i128`,passed as its lower and upper 64 bits,
+    /// which can be converted back with `((upper as i128) << 64) | (lower as i128)`.
+    fn serialize_i128(&mut self,lower:u64,upper:i64)->RResult<(),RBoxError>;
+
+    fn serialize_u8(&mut self,v:u8)->RResult<(),RBoxError>;
+
+    fn serialize_u16(&mut self,v:u16)->RResult<(),RBoxError>;
+
+    fn serialize_u32(&mut self,v:u32)->RResult<(),RBoxError>;
+
+    fn serialize_u64(&mut self,v:u64)->RResult<(),RBoxError>;
+
+    /// Serializes a `u128`,passed as its lower and upper 64 bits,
+    /// which can be converted back with `((upper as u128) << 64) | (lower as u128)`.
+    fn serialize_u128(&mut self,lower:u64,upper:u64)->RResult<(),RBoxError>;
+
+    /// Serializes an `f32`,
+    /// passed as its bits (which can be converted back with `f32::from_bits`).
+    fn serialize_f32(&mut self,bits:u32)->RResult<(),RBoxError>;
+
+    /// Serializes an `f64`,
+    /// passed as its bits (which can be converted back with `f64::from_bits`).
+    fn serialize_f64(&mut self,bits:u64)->RResult<(),RBoxError>;
+
+    /// Serializes a `char`,passed as its unicode code point.
+    fn serialize_char(&mut self,v:u32)->RResult<(),RBoxError>;
+
+    fn serialize_str(&mut self,v:RStr<'_>)->RResult<(),RBoxError>;
+
+    fn serialize_bytes(&mut self,v:RSlice<'_,u8>)->RResult<(),RBoxError>;
+
+    fn serialize_none(&mut self)->RResult<(),RBoxError>;
+
+    /// Called before serializing the value inside of a `Some`.
+    fn serialize_some(&mut self)->RResult<(),RBoxError>;
+
+    fn serialize_unit(&mut self)->RResult<(),RBoxError>;
+
+    fn serialize_unit_struct(&mut self,name:RStr<'static>)->RResult<(),RBoxError>;
+
+    fn serialize_unit_variant(
+        &mut self,
+        name:RStr<'static>,
+        variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>;
+
+    /// Called before serializing the value inside of a newtype struct.
+    fn serialize_newtype_struct(&mut self,name:RStr<'static>)->RResult<(),RBoxError>;
+
+    /// Called before serializing the value inside of a newtype variant,
+    /// `end_newtype_variant` is called after serializing the value.
+    fn serialize_newtype_variant(
+        &mut self,
+        name:RStr<'static>,
+        variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>;
+
+    /// Called after serializing the value inside of a newtype variant.
+    fn end_newtype_variant(&mut self)->RResult<(),RBoxError>;
+
+    /// Starts serializing a compound type,
+    /// which ends with a call to `end_compound`.
+    fn begin_compound(&mut self,compound:RCompound)->RResult<(),RBoxError>;
+
+    /// Called before serializing an element of a sequence,tuple,
+    /// tuple struct,or tuple variant.
+    fn serialize_element(&mut self)->RResult<(),RBoxError>;
+
+    /// Called before serializing a key of a map.
+    fn serialize_key(&mut self)->RResult<(),RBoxError>;
+
+    /// Called before serializing a value of a map,after its key was serialized.
+    fn serialize_value(&mut self)->RResult<(),RBoxError>;
+
+    /// Called before serializing the value of a field of a struct,or struct variant.
+    fn serialize_field(&mut self,key:RStr<'static>)->RResult<(),RBoxError>;
+
+    /// Called instead of `serialize_field` for fields that are skipped.
+    fn skip_field(&mut self,key:RStr<'static>)->RResult<(),RBoxError>;
+
+    /// Ends serializing the compound type started by the last `begin_compound` call.
+    fn end_compound(&mut self)->RResult<(),RBoxError>;
+}
+
+
+/// Describes the compound type that `SerializerBackend::begin_compound` starts serializing.
+#[repr(C)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,StableAbi)]
+pub struct RCompound{
+    pub kind:RCompoundKind,
+    /// The name of the struct/enum,this is empty for sequences,tuples,and maps.
+    pub name:RStr<'static>,
+    /// The index of the variant,this is 0 for everything other than variants.
+    pub variant_index:u32,
+    /// The name of the variant,this is empty for everything other than variants.
+    pub variant:RStr<'static>,
+    /// The amount of elements/entries/fields,if it's known.
+    pub len:ROption<usize>,
+}
+
+
+/// The kinds of compound types in the serde data model.
+#[repr(u8)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq,StableAbi)]
+pub enum RCompoundKind{
+    Seq,
+    Tuple,
+    TupleStruct,
+    TupleVariant,
+    Map,
+    Struct,
+    StructVariant,
+}
+
+
+impl RCompound{
+    fn new(kind:RCompoundKind,len:ROption<usize>)->Self{
+        Self{
+            kind,
+            name:RStr::from(""),
+            variant_index:0,
+            variant:RStr::from(""),
+            len,
+        }
+    }
+
+    fn with_name(mut self,name:&'static str)->Self{
+        self.name=RStr::from(name);
+        self
+    }
+
+    fn with_variant(mut self,variant_index:u32,variant:&'static str)->Self{
+        self.variant_index=variant_index;
+        self.variant=RStr::from(variant);
+        self
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/**
+An adaptor from a `SerializerBackend_TO` to `serde::Serializer`,
+allowing `Serialize` types to be serialized with a format from another dynamic library.
+
+`&mut ErasedSerializer<_>` is the type that implements `serde::Serializer`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::erased_serializer::{
+        ErasedSerializer,RCompound,SerializerBackend,SerializerBackend_TO,
+    },
+    sabi_trait::TU_Opaque,
+    std_types::{RBoxError,ROk,RResult,RSlice,RStr},
+};
+
+use serde::Serialize;
+
+/// A format that writes every value on its own line,
+/// which would usually be defined in a dynamic library.
+#[derive(Default)]
+struct Lines{
+    out:String,
+}
+
+impl Lines{
+    fn line(&mut self,line:&str)->RResult<(),RBoxError>{
+        self.out.push_str(line);
+        self.out.push('\n');
+        ROk(())
+    }
+}
+
+impl SerializerBackend for Lines{
+    fn serialize_bool(&mut self,v:bool)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_i8(&mut self,v:i8)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_i16(&mut self,v:i16)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_i32(&mut self,v:i32)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_i64(&mut self,v:i64)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_i128(&mut self,lower:u64,upper:i64)->RResult<(),RBoxError>{
+        self.line(&(((upper as i128) << 64) | (lower as i128)).to_string())
+    }
+    fn serialize_u8(&mut self,v:u8)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_u16(&mut self,v:u16)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_u32(&mut self,v:u32)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_u64(&mut self,v:u64)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_u128(&mut self,lower:u64,upper:u64)->RResult<(),RBoxError>{
+        self.line(&(((upper as u128) << 64) | (lower as u128)).to_string())
+    }
+    fn serialize_f32(&mut self,bits:u32)->RResult<(),RBoxError>{
+        self.line(&f32::from_bits(bits).to_string())
+    }
+    fn serialize_f64(&mut self,bits:u64)->RResult<(),RBoxError>{
+        self.line(&f64::from_bits(bits).to_string())
+    }
+    fn serialize_char(&mut self,v:u32)->RResult<(),RBoxError>{ self.line(&v.to_string()) }
+    fn serialize_str(&mut self,v:RStr<'_>)->RResult<(),RBoxError>{ self.line(v.as_str()) }
+    fn serialize_bytes(&mut self,v:RSlice<'_,u8>)->RResult<(),RBoxError>{
+        self.line(&format!("{:?}",v.as_slice()))
+    }
+    fn serialize_none(&mut self)->RResult<(),RBoxError>{ self.line("none") }
+    fn serialize_some(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit(&mut self)->RResult<(),RBoxError>{ self.line("()") }
+    fn serialize_unit_struct(&mut self,name:RStr<'static>)->RResult<(),RBoxError>{
+        self.line(name.as_str())
+    }
+    fn serialize_unit_variant(
+        &mut self,
+        _name:RStr<'static>,
+        _variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        self.line(variant.as_str())
+    }
+    fn serialize_newtype_struct(&mut self,_name:RStr<'static>)->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn serialize_newtype_variant(
+        &mut self,
+        _name:RStr<'static>,
+        _variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        self.line(variant.as_str())
+    }
+    fn end_newtype_variant(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn begin_compound(&mut self,compound:RCompound)->RResult<(),RBoxError>{
+        self.line(&format!("begin {:?} {}",compound.kind,compound.name))
+    }
+    fn serialize_element(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_key(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_value(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_field(&mut self,key:RStr<'static>)->RResult<(),RBoxError>{
+        self.line(&format!("{}:",key))
+    }
+    fn skip_field(&mut self,_key:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn end_compound(&mut self)->RResult<(),RBoxError>{ self.line("end") }
+}
+
+#[derive(Serialize)]
+struct Point{
+    x:i32,
+    y:i32,
+}
+
+let mut lines=Lines::default();
+{
+    let backend=SerializerBackend_TO::from_ptr(&mut lines,TU_Opaque);
+    let mut serializer=ErasedSerializer::new(backend);
+
+    Point{x:3,y:5}.serialize(&mut serializer).unwrap();
+    vec![true,false].serialize(&mut serializer).unwrap();
+}
+
+assert_eq!(
+    lines.out,
+    "begin Struct Point\nx:\n3\ny:\n5\nend\n\
+     begin Seq \ntrue\nfalse\nend\n",
+);
+
+```
+
+*/
+pub struct ErasedSerializer<'borr,P>
+where
+    P:GetPointerKind,
+{
+    backend:SerializerBackend_TO<'borr,P>,
+}
+
+
+impl<'borr,P> ErasedSerializer<'borr,P>
+where
+    P:GetPointerKind,
+{
+    /// Constructs an `ErasedSerializer` from a `SerializerBackend` trait object.
+    pub fn new(backend:SerializerBackend_TO<'borr,P>)->Self{
+        Self{backend}
+    }
+
+    /// Gets the wrapped `SerializerBackend` trait object.
+    pub fn into_inner(self)->SerializerBackend_TO<'borr,P>{
+        self.backend
+    }
+}
+
+
+impl<'a,'borr,P> ser::Serializer for &'a mut ErasedSerializer<'borr,P>
+where
+    P:GetPointerKind+DerefMut<Target=()>,
+{
+    type Ok=();
+    type Error=RBoxError;
+    type SerializeSeq=Self;
+    type SerializeTuple=Self;
+    type SerializeTupleStruct=Self;
+    type SerializeTupleVariant=Self;
+    type SerializeMap=Self;
+    type SerializeStruct=Self;
+    type SerializeStructVariant=Self;
+
+    fn serialize_bool(self,v:bool)->Result<(),RBoxError>{
+        self.backend.serialize_bool(v).into_result()
+    }
+
+    fn serialize_i8(self,v:i8)->Result<(),RBoxError>{
+        self.backend.serialize_i8(v).into_result()
+    }
+
+    fn serialize_i16(self,v:i16)->Result<(),RBoxError>{
+        self.backend.serialize_i16(v).into_result()
+    }
+
+    fn serialize_i32(self,v:i32)->Result<(),RBoxError>{
+        self.backend.serialize_i32(v).into_result()
+    }
+
+    fn serialize_i64(self,v:i64)->Result<(),RBoxError>{
+        self.backend.serialize_i64(v).into_result()
+    }
+
+    fn serialize_i128(self,v:i128)->Result<(),RBoxError>{
+        self.backend.serialize_i128(v as u64,(v >> 64) as i64).into_result()
+    }
+
+    fn serialize_u8(self,v:u8)->Result<(),RBoxError>{
+        self.backend.serialize_u8(v).into_result()
+    }
+
+    fn serialize_u16(self,v:u16)->Result<(),RBoxError>{
+        self.backend.serialize_u16(v).into_result()
+    }
+
+    fn serialize_u32(self,v:u32)->Result<(),RBoxError>{
+        self.backend.serialize_u32(v).into_result()
+    }
+
+    fn serialize_u64(self,v:u64)->Result<(),RBoxError>{
+        self.backend.serialize_u64(v).into_result()
+    }
+
+    fn serialize_u128(self,v:u128)->Result<(),RBoxError>{
+        self.backend.serialize_u128(v as u64,(v >> 64) as u64).into_result()
+    }
+
+    fn serialize_f32(self,v:f32)->Result<(),RBoxError>{
+        self.backend.serialize_f32(v.to_bits()).into_result()
+    }
+
+    fn serialize_f64(self,v:f64)->Result<(),RBoxError>{
+        self.backend.serialize_f64(v.to_bits()).into_result()
+    }
+
+    fn serialize_char(self,v:char)->Result<(),RBoxError>{
+        self.backend.serialize_char(v as u32).into_result()
+    }
+
+    fn serialize_str(self,v:&str)->Result<(),RBoxError>{
+        self.backend.serialize_str(v.into()).into_result()
+    }
+
+    fn serialize_bytes(self,v:&[u8])->Result<(),RBoxError>{
+        self.backend.serialize_bytes(v.into()).into_result()
+    }
+
+    fn serialize_none(self)->Result<(),RBoxError>{
+        self.backend.serialize_none().into_result()
+    }
+
+    fn serialize_some<T>(self,value:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_some().into_result()?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self)->Result<(),RBoxError>{
+        self.backend.serialize_unit().into_result()
+    }
+
+    fn serialize_unit_struct(self,name:&'static str)->Result<(),RBoxError>{
+        self.backend.serialize_unit_struct(name.into()).into_result()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name:&'static str,
+        variant_index:u32,
+        variant:&'static str,
+    )->Result<(),RBoxError>{
+        self.backend
+            .serialize_unit_variant(name.into(),variant_index,variant.into())
+            .into_result()
+    }
+
+    fn serialize_newtype_struct<T>(self,name:&'static str,value:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_newtype_struct(name.into()).into_result()?;
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name:&'static str,
+        variant_index:u32,
+        variant:&'static str,
+        value:&T,
+    )->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend
+            .serialize_newtype_variant(name.into(),variant_index,variant.into())
+            .into_result()?;
+        value.serialize(&mut *self)?;
+        self.backend.end_newtype_variant().into_result()
+    }
+
+    fn serialize_seq(self,len:Option<usize>)->Result<Self,RBoxError>{
+        self.begin_compound(RCompound::new(RCompoundKind::Seq,len.into()))
+    }
+
+    fn serialize_tuple(self,len:usize)->Result<Self,RBoxError>{
+        self.begin_compound(RCompound::new(RCompoundKind::Tuple,RSome(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name:&'static str,
+        len:usize,
+    )->Result<Self,RBoxError>{
+        let compound=RCompound::new(RCompoundKind::TupleStruct,RSome(len))
+            .with_name(name);
+        self.begin_compound(compound)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name:&'static str,
+        variant_index:u32,
+        variant:&'static str,
+        len:usize,
+    )->Result<Self,RBoxError>{
+        let compound=RCompound::new(RCompoundKind::TupleVariant,RSome(len))
+            .with_name(name)
+            .with_variant(variant_index,variant);
+        self.begin_compound(compound)
+    }
+
+    fn serialize_map(self,len:Option<usize>)->Result<Self,RBoxError>{
+        self.begin_compound(RCompound::new(RCompoundKind::Map,len.into()))
+    }
+
+    fn serialize_struct(
+        self,
+        name:&'static str,
+        len:usize,
+    )->Result<Self,RBoxError>{
+        let compound=RCompound::new(RCompoundKind::Struct,RSome(len))
+            .with_name(name);
+        self.begin_compound(compound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name:&'static str,
+        variant_index:u32,
+        variant:&'static str,
+        len:usize,
+    )->Result<Self,RBoxError>{
+        let compound=RCompound::new(RCompoundKind::StructVariant,RSome(len))
+            .with_name(name)
+            .with_variant(variant_index,variant);
+        self.begin_compound(compound)
+    }
+}
+
+
+impl<'borr,P> ErasedSerializer<'borr,P>
+where
+    P:GetPointerKind+DerefMut<Target=()>,
+{
+    fn begin_compound(&mut self,compound:RCompound)->Result<&mut Self,RBoxError>{
+        self.backend.begin_compound(compound).into_result()?;
+        Ok(self)
+    }
+
+    fn compound_element<T>(&mut self,value:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_element().into_result()?;
+        value.serialize(self)
+    }
+
+    fn compound_field<T>(&mut self,key:&'static str,value:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_field(key.into()).into_result()?;
+        value.serialize(self)
+    }
+
+    fn end_compound(&mut self)->Result<(),RBoxError>{
+        self.backend.end_compound().into_result()
+    }
+}
+
+
+macro_rules! impl_compound_elements {
+    ( $( $trait_:ident :: $method:ident ),* $(,)* ) => (
+        $(
+            impl<'a,'borr,P> $trait_ for &'a mut ErasedSerializer<'borr,P>
+            where
+                P:GetPointerKind+DerefMut<Target=()>,
+            {
+                type Ok=();
+                type Error=RBoxError;
+
+                fn $method<T>(&mut self,value:&T)->Result<(),RBoxError>
+                where
+                    T:?Sized+Serialize,
+                {
+                    self.compound_element(value)
+                }
+
+                fn end(self)->Result<(),RBoxError>{
+                    self.end_compound()
+                }
+            }
+        )*
+    )
+}
+
+impl_compound_elements!{
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+}
+
+
+macro_rules! impl_compound_fields {
+    ( $( $trait_:ident ),* $(,)* ) => (
+        $(
+            impl<'a,'borr,P> $trait_ for &'a mut ErasedSerializer<'borr,P>
+            where
+                P:GetPointerKind+DerefMut<Target=()>,
+            {
+                type Ok=();
+                type Error=RBoxError;
+
+                fn serialize_field<T>(
+                    &mut self,
+                    key:&'static str,
+                    value:&T,
+                )->Result<(),RBoxError>
+                where
+                    T:?Sized+Serialize,
+                {
+                    self.compound_field(key,value)
+                }
+
+                fn skip_field(&mut self,key:&'static str)->Result<(),RBoxError>{
+                    self.backend.skip_field(key.into()).into_result()
+                }
+
+                fn end(self)->Result<(),RBoxError>{
+                    self.end_compound()
+                }
+            }
+        )*
+    )
+}
+
+impl_compound_fields!{ SerializeStruct, SerializeStructVariant }
+
+
+impl<'a,'borr,P> SerializeMap for &'a mut ErasedSerializer<'borr,P>
+where
+    P:GetPointerKind+DerefMut<Target=()>,
+{
+    type Ok=();
+    type Error=RBoxError;
+
+    fn serialize_key<T>(&mut self,key:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_key().into_result()?;
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T>(&mut self,value:&T)->Result<(),RBoxError>
+    where
+        T:?Sized+Serialize,
+    {
+        self.backend.serialize_value().into_result()?;
+        value.serialize(&mut **self)
+    }
+
+    fn end(self)->Result<(),RBoxError>{
+        self.end_compound()
+    }
+}
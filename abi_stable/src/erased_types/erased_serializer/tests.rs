@@ -0,0 +1,479 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+use serde::ser::Error as _;
+
+use crate::{
+    sabi_trait::prelude::TU_Opaque,
+    std_types::{RErr,ROk,RString},
+};
+
+
+/// A minimal JSON serializer,used to compare its output with serde_json.
+#[derive(Default)]
+struct JsonWriter{
+    out:String,
+    /// Whether the next element of each nested compound type is its first one.
+    is_first:Vec<bool>,
+    closers:Vec<&'static str>,
+    compounds:Vec<RCompound>,
+}
+
+impl JsonWriter{
+    fn push(&mut self,s:&str)->RResult<(),RBoxError>{
+        self.out.push_str(s);
+        ROk(())
+    }
+
+    fn comma(&mut self)->RResult<(),RBoxError>{
+        if let Some(is_first)=self.is_first.last_mut() {
+            if !*is_first {
+                self.out.push(',');
+            }
+            *is_first=false;
+        }
+        ROk(())
+    }
+}
+
+impl SerializerBackend for JsonWriter{
+    fn serialize_bool(&mut self,v:bool)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_i8(&mut self,v:i8)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_i16(&mut self,v:i16)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_i32(&mut self,v:i32)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_i64(&mut self,v:i64)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_i128(&mut self,lower:u64,upper:i64)->RResult<(),RBoxError>{
+        self.push(&(((upper as i128) << 64) | (lower as i128)).to_string())
+    }
+    fn serialize_u8(&mut self,v:u8)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_u16(&mut self,v:u16)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_u32(&mut self,v:u32)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_u64(&mut self,v:u64)->RResult<(),RBoxError>{
+        self.push(&v.to_string())
+    }
+    fn serialize_u128(&mut self,lower:u64,upper:u64)->RResult<(),RBoxError>{
+        self.push(&(((upper as u128) << 64) | (lower as u128)).to_string())
+    }
+    fn serialize_f32(&mut self,bits:u32)->RResult<(),RBoxError>{
+        self.push(&f32::from_bits(bits).to_string())
+    }
+    fn serialize_f64(&mut self,bits:u64)->RResult<(),RBoxError>{
+        self.push(&f64::from_bits(bits).to_string())
+    }
+    fn serialize_char(&mut self,v:u32)->RResult<(),RBoxError>{
+        let c=std::char::from_u32(v).unwrap();
+        self.push(&format!("\"{}\"",c))
+    }
+    fn serialize_str(&mut self,v:RStr<'_>)->RResult<(),RBoxError>{
+        self.push(&format!("\"{}\"",v))
+    }
+    fn serialize_bytes(&mut self,v:RSlice<'_,u8>)->RResult<(),RBoxError>{
+        let list=v.iter().map(|x| x.to_string() ).collect::<Vec<_>>();
+        self.push(&format!("[{}]",list.join(",")))
+    }
+    fn serialize_none(&mut self)->RResult<(),RBoxError>{
+        self.push("null")
+    }
+    fn serialize_some(&mut self)->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn serialize_unit(&mut self)->RResult<(),RBoxError>{
+        self.push("null")
+    }
+    fn serialize_unit_struct(&mut self,_name:RStr<'static>)->RResult<(),RBoxError>{
+        self.push("null")
+    }
+    fn serialize_unit_variant(
+        &mut self,
+        _name:RStr<'static>,
+        _variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        self.push(&format!("\"{}\"",variant))
+    }
+    fn serialize_newtype_struct(&mut self,_name:RStr<'static>)->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn serialize_newtype_variant(
+        &mut self,
+        _name:RStr<'static>,
+        _variant_index:u32,
+        variant:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        self.push(&format!("{{\"{}\":",variant))
+    }
+    fn end_newtype_variant(&mut self)->RResult<(),RBoxError>{
+        self.push("}")
+    }
+    fn begin_compound(&mut self,compound:RCompound)->RResult<(),RBoxError>{
+        let (opener,closer)=match compound.kind {
+            RCompoundKind::Seq|RCompoundKind::Tuple|RCompoundKind::TupleStruct=>
+                ("[".to_string(),"]"),
+            RCompoundKind::TupleVariant=>
+                (format!("{{\"{}\":[",compound.variant),"]}"),
+            RCompoundKind::Map|RCompoundKind::Struct=>
+                ("{".to_string(),"}"),
+            RCompoundKind::StructVariant=>
+                (format!("{{\"{}\":{{",compound.variant),"}}"),
+        };
+        self.is_first.push(true);
+        self.closers.push(closer);
+        self.compounds.push(compound);
+        self.push(&opener)
+    }
+    fn serialize_element(&mut self)->RResult<(),RBoxError>{
+        self.comma()
+    }
+    fn serialize_key(&mut self)->RResult<(),RBoxError>{
+        self.comma()
+    }
+    fn serialize_value(&mut self)->RResult<(),RBoxError>{
+        self.push(":")
+    }
+    fn serialize_field(&mut self,key:RStr<'static>)->RResult<(),RBoxError>{
+        self.comma()?;
+        self.push(&format!("\"{}\":",key))
+    }
+    fn skip_field(&mut self,_key:RStr<'static>)->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn end_compound(&mut self)->RResult<(),RBoxError>{
+        self.is_first.pop();
+        let closer=self.closers.pop().unwrap();
+        self.push(closer)
+    }
+}
+
+
+#[derive(Serialize)]
+struct Id(u32);
+
+#[derive(Serialize)]
+struct Marker;
+
+#[derive(Serialize)]
+struct Pair(u8,i8);
+
+#[derive(Serialize)]
+enum Mode{
+    Fast,
+    Slow{delay:u32},
+    Tuple(u8,bool),
+    Wrapped(i64),
+}
+
+#[derive(Serialize)]
+struct Config{
+    name:RString,
+    id:Id,
+    marker:Marker,
+    pair:Pair,
+    ports:Vec<u16>,
+    ratio:f64,
+    small_ratio:f32,
+    big:i128,
+    big_unsigned:u128,
+    tag:Option<char>,
+    missing:Option<u8>,
+    unit:(),
+    tuple:(u8,bool,&'static str),
+    #[serde(with="serde_bytes_impl")]
+    bytes:Vec<u8>,
+    modes:Vec<Mode>,
+    flags:BTreeMap<&'static str,bool>,
+}
+
+mod serde_bytes_impl{
+    pub fn serialize<S>(bytes:&[u8],serializer:S)->Result<S::Ok,S::Error>
+    where
+        S:serde::Serializer,
+    {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn make_config()->Config{
+    let mut flags=BTreeMap::new();
+    flags.insert("verbose",true);
+    flags.insert("color",false);
+
+    Config{
+        name:"hello".into(),
+        id:Id(7),
+        marker:Marker,
+        pair:Pair(1,-1),
+        ports:vec![80,443],
+        ratio:0.5,
+        small_ratio:0.25,
+        big:-(1_i128 << 100),
+        big_unsigned:(1_u128 << 127) + 5,
+        tag:Some('x'),
+        missing:None,
+        unit:(),
+        tuple:(3,false,"world"),
+        bytes:vec![0,1,255],
+        modes:vec![
+            Mode::Fast,
+            Mode::Slow{delay:100},
+            Mode::Tuple(5,true),
+            Mode::Wrapped(-20),
+        ],
+        flags,
+    }
+}
+
+
+#[test]
+fn same_output_as_serde_json(){
+    let config=make_config();
+
+    let mut writer=JsonWriter::default();
+    {
+        let backend=SerializerBackend_TO::from_ptr(&mut writer,TU_Opaque);
+        let mut serializer=ErasedSerializer::new(backend);
+        config.serialize(&mut serializer).unwrap();
+    }
+
+    assert_eq!(writer.out,serde_json::to_string(&config).unwrap());
+    assert!(writer.is_first.is_empty());
+    assert!(writer.closers.is_empty());
+}
+
+
+#[test]
+fn compound_descriptions(){
+    let mut writer=JsonWriter::default();
+    {
+        let backend=SerializerBackend_TO::from_ptr(&mut writer,TU_Opaque);
+        let mut serializer=ErasedSerializer::new(backend);
+        make_config().serialize(&mut serializer).unwrap();
+    }
+
+    let compounds=&writer.compounds;
+    assert_eq!(compounds.len(),8);
+
+    assert_eq!(compounds[0].kind,RCompoundKind::Struct);
+    assert_eq!(compounds[0].name.as_str(),"Config");
+    assert_eq!(compounds[0].len,RSome(16));
+
+    assert_eq!(compounds[1].kind,RCompoundKind::TupleStruct);
+    assert_eq!(compounds[1].name.as_str(),"Pair");
+    assert_eq!(compounds[1].len,RSome(2));
+
+    assert_eq!(compounds[2].kind,RCompoundKind::Seq);
+    assert_eq!(compounds[2].name.as_str(),"");
+    assert_eq!(compounds[2].len,RSome(2));
+
+    assert_eq!(compounds[3].kind,RCompoundKind::Tuple);
+    assert_eq!(compounds[3].len,RSome(3));
+
+    assert_eq!(compounds[4].kind,RCompoundKind::Seq);
+    assert_eq!(compounds[4].len,RSome(4));
+
+    assert_eq!(compounds[5].kind,RCompoundKind::StructVariant);
+    assert_eq!(compounds[5].name.as_str(),"Mode");
+    assert_eq!(compounds[5].variant_index,1);
+    assert_eq!(compounds[5].variant.as_str(),"Slow");
+
+    assert_eq!(compounds[6].kind,RCompoundKind::TupleVariant);
+    assert_eq!(compounds[6].variant_index,2);
+    assert_eq!(compounds[6].variant.as_str(),"Tuple");
+    assert_eq!(compounds[6].len,RSome(2));
+
+    assert_eq!(compounds[7].kind,RCompoundKind::Map);
+    assert_eq!(compounds[7].len,RSome(2));
+}
+
+
+/// A backend that fails when serializing strings.
+struct NoStrings;
+
+impl SerializerBackend for NoStrings{
+    fn serialize_bool(&mut self,_:bool)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_i8(&mut self,_:i8)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_i16(&mut self,_:i16)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_i32(&mut self,_:i32)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_i64(&mut self,_:i64)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_i128(&mut self,_:u64,_:i64)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_u8(&mut self,_:u8)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_u16(&mut self,_:u16)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_u32(&mut self,_:u32)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_u64(&mut self,_:u64)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_u128(&mut self,_:u64,_:u64)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_f32(&mut self,_:u32)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_f64(&mut self,_:u64)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_char(&mut self,_:u32)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_str(&mut self,v:RStr<'_>)->RResult<(),RBoxError>{
+        RErr(RBoxError::custom(format_args!("strings aren't supported:{}",v)))
+    }
+    fn serialize_bytes(&mut self,_:RSlice<'_,u8>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_none(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_some(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit_struct(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit_variant(
+        &mut self,
+        _:RStr<'static>,
+        _:u32,
+        _:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn serialize_newtype_struct(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_newtype_variant(
+        &mut self,
+        _:RStr<'static>,
+        _:u32,
+        _:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn end_newtype_variant(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn begin_compound(&mut self,_:RCompound)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_element(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_key(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_value(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_field(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn skip_field(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn end_compound(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+}
+
+
+struct AlwaysFails;
+
+impl Serialize for AlwaysFails{
+    fn serialize<S>(&self,_:S)->Result<S::Ok,S::Error>
+    where
+        S:serde::Serializer,
+    {
+        Err(S::Error::custom("custom error"))
+    }
+}
+
+
+#[test]
+fn propagates_errors(){
+    let mut serializer=ErasedSerializer::new(SerializerBackend_TO::from_value(NoStrings,TU_Opaque));
+
+    vec![(1,true)].serialize(&mut serializer).unwrap();
+
+    let err=vec![("hello",3)].serialize(&mut serializer).unwrap_err();
+    assert_eq!(err.to_string(),"strings aren't supported:hello");
+
+    let err=Some(AlwaysFails).serialize(&mut serializer).unwrap_err();
+    assert_eq!(err.to_string(),"custom error");
+    assert_eq!(format!("{:?}",err),"custom error");
+}
+
+
+/// A backend that records which method each scalar was serialized with.
+#[derive(Default)]
+struct Scalars{
+    calls:Vec<String>,
+}
+
+impl Scalars{
+    fn call(&mut self,method:&str,v:&dyn std::fmt::Display)->RResult<(),RBoxError>{
+        self.calls.push(format!("{}:{}",method,v));
+        ROk(())
+    }
+}
+
+impl SerializerBackend for Scalars{
+    fn serialize_bool(&mut self,v:bool)->RResult<(),RBoxError>{ self.call("bool",&v) }
+    fn serialize_i8(&mut self,v:i8)->RResult<(),RBoxError>{ self.call("i8",&v) }
+    fn serialize_i16(&mut self,v:i16)->RResult<(),RBoxError>{ self.call("i16",&v) }
+    fn serialize_i32(&mut self,v:i32)->RResult<(),RBoxError>{ self.call("i32",&v) }
+    fn serialize_i64(&mut self,v:i64)->RResult<(),RBoxError>{ self.call("i64",&v) }
+    fn serialize_i128(&mut self,lower:u64,upper:i64)->RResult<(),RBoxError>{
+        self.call("i128",&(((upper as i128) << 64) | (lower as i128)))
+    }
+    fn serialize_u8(&mut self,v:u8)->RResult<(),RBoxError>{ self.call("u8",&v) }
+    fn serialize_u16(&mut self,v:u16)->RResult<(),RBoxError>{ self.call("u16",&v) }
+    fn serialize_u32(&mut self,v:u32)->RResult<(),RBoxError>{ self.call("u32",&v) }
+    fn serialize_u64(&mut self,v:u64)->RResult<(),RBoxError>{ self.call("u64",&v) }
+    fn serialize_u128(&mut self,lower:u64,upper:u64)->RResult<(),RBoxError>{
+        self.call("u128",&(((upper as u128) << 64) | (lower as u128)))
+    }
+    fn serialize_f32(&mut self,bits:u32)->RResult<(),RBoxError>{
+        self.call("f32",&f32::from_bits(bits))
+    }
+    fn serialize_f64(&mut self,bits:u64)->RResult<(),RBoxError>{
+        self.call("f64",&f64::from_bits(bits))
+    }
+    fn serialize_char(&mut self,v:u32)->RResult<(),RBoxError>{ self.call("char",&v) }
+    fn serialize_str(&mut self,v:RStr<'_>)->RResult<(),RBoxError>{ self.call("str",&v) }
+    fn serialize_bytes(&mut self,_:RSlice<'_,u8>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_none(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_some(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit_struct(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_unit_variant(
+        &mut self,
+        _:RStr<'static>,
+        _:u32,
+        _:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn serialize_newtype_struct(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_newtype_variant(
+        &mut self,
+        _:RStr<'static>,
+        _:u32,
+        _:RStr<'static>,
+    )->RResult<(),RBoxError>{
+        ROk(())
+    }
+    fn end_newtype_variant(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn begin_compound(&mut self,_:RCompound)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_element(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_key(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_value(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+    fn serialize_field(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn skip_field(&mut self,_:RStr<'static>)->RResult<(),RBoxError>{ ROk(()) }
+    fn end_compound(&mut self)->RResult<(),RBoxError>{ ROk(()) }
+}
+
+
+#[test]
+fn scalars_keep_their_width(){
+    let mut scalars=Scalars::default();
+    {
+        let backend=SerializerBackend_TO::from_ptr(&mut scalars,TU_Opaque);
+        let mut serializer=ErasedSerializer::new(backend);
+
+        (-1_i8,-2_i16,-3_i32,-4_i64).serialize(&mut serializer).unwrap();
+        (1_u8,2_u16,3_u32,4_u64).serialize(&mut serializer).unwrap();
+        (i128::min_value(),u128::max_value()).serialize(&mut serializer).unwrap();
+        (0.5_f32,0.25_f64).serialize(&mut serializer).unwrap();
+    }
+
+    assert_eq!(
+        scalars.calls,
+        vec![
+            "i8:-1","i16:-2","i32:-3","i64:-4",
+            "u8:1","u16:2","u32:3","u64:4",
+            "i128:-170141183460469231731687303715884105728",
+            "u128:340282366920938463463374607431768211455",
+            "f32:0.5","f64:0.25",
+        ],
+    );
+}
@@ -1,6 +1,11 @@
 use super::*;
 use std::marker::PhantomData;
 
+use crate::{
+    erased_types::traits::InterfaceFor,
+    std_types::RBox,
+};
+
 #[repr(C)]
 #[derive(StableAbi)]
 #[sabi(impl_InterfaceType(Send,Sync,Clone))]
@@ -31,6 +36,57 @@ pub struct DefaultInterface;
 pub struct PartialEqInterface;
 
 
+//////////////////////////////////////////////
+
+/**
+An `InterfaceType` for `DynTrait`s that can be used as the keys of an `RHashMap`/`HashMap`.
+
+`DynTrait`s wrapping values of different types never compare equal,
+so values of different types can be keys in the same map,
+even if they would hash identically.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::interfaces::HashEqInterface,
+    std_types::{RBox,RHashMap,RSome,RString},
+    DynTrait,
+};
+
+type Key=DynTrait<'static,RBox<()>,HashEqInterface>;
+
+fn key<T>(value:T)->Key
+where
+    T:std::fmt::Debug+std::hash::Hash+Eq+Send+Sync+'static
+{
+    DynTrait::from_any_value(value,HashEqInterface)
+}
+
+let mut map=RHashMap::<Key,u32>::new();
+
+map.insert(key(10_u32),0);
+map.insert(key(10_i32),1);
+map.insert(key(RString::from("10")),2);
+
+assert_eq!( map.len(), 3 );
+assert_eq!( map.get(&key(10_u32)), Some(&0) );
+assert_eq!( map.get(&key(10_i32)), Some(&1) );
+assert_eq!( map.get(&key(RString::from("10"))), Some(&2) );
+assert_eq!( map.get(&key(10_u64)), None );
+
+assert_eq!( map.insert(key(10_u32),100), RSome(0) );
+assert_eq!( map.len(), 3 );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,Debug,Hash,Eq))]
+pub struct HashEqInterface;
+
+
 //////////////////////////////////////////////
 
 #[repr(C)]
@@ -55,6 +111,34 @@ pub struct DebugDisplayInterface;
 
 //////////////////////////////////////////////
 
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Display))]
+pub struct DisplayInterface;
+
+//////////////////////////////////////////////
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Debug))]
+pub struct DebugInterface;
+
+//////////////////////////////////////////////
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,AsBytes))]
+pub struct AsBytesInterface;
+
+//////////////////////////////////////////////
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync,AsStr))]
+pub struct AsStrInterface;
+
+//////////////////////////////////////////////
+
 
 #[repr(C)]
 #[derive(StableAbi)]
@@ -70,6 +154,52 @@ impl<'a,T:'a> IteratorItem<'a> for IteratorInterface<T>{
 }
 
 
+/**
+An ffi-safe type-erased `Iterator<Item=T>+Send+Sync+'a`,
+which can be returned from functions exported by dynamic libraries.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::RIterator,
+    std_types::RString,
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+fn numbered(prefix:&RString,count:u32)->RIterator<'_,RString>{
+    RIterator::from_iter( (0..count).map(move|i| format!("{}{}",prefix,i).into() ) )
+}
+
+let prefix=RString::from("item_");
+let items=numbered(&prefix,3).collect::<Vec<RString>>();
+
+assert_eq!(
+    items.iter().map(|s| s.as_str() ).collect::<Vec<&str>>(),
+    vec!["item_0","item_1","item_2"],
+);
+
+```
+
+*/
+pub type RIterator<'a,T>=DynTrait<'a,RBox<()>,IteratorInterface<T>>;
+
+impl<'a,T:'a> RIterator<'a,T>{
+    /// Constructs an `RIterator` from any `Iterator<Item=T>+Send+Sync+'a`.
+    ///
+    /// The `RIterator` can't be unerased back into the type of the iterator.
+    pub fn from_iter<I>(iter:I)->Self
+    where
+        I:Iterator<Item=T>+Send+Sync+'a,
+        InterfaceFor<I,IteratorInterface<T>,TU_Opaque>:
+            GetVtable<'a,I,RBox<()>,RBox<I>,IteratorInterface<T>>,
+    {
+        DynTrait::from_borrowing_value(iter,IteratorInterface::NEW)
+    }
+}
+
+
 //////////////////////////////////////////////
 
 
@@ -87,3 +217,149 @@ impl<'a,T:'a> IteratorItem<'a> for DEIteratorInterface<T>{
     type Item=T;
 }
 
+
+/**
+An ffi-safe type-erased `DoubleEndedIterator<Item=T>+Send+Sync+'a`,
+which can be returned from functions exported by dynamic libraries.
+
+# Example
+
+```
+use abi_stable::erased_types::RDoubleEndedIterator;
+
+let iter=RDoubleEndedIterator::from_iter( vec![3,5,8,13].into_iter() );
+
+assert_eq!( iter.rev().collect::<Vec<u32>>(), vec![13,8,5,3] );
+
+```
+
+*/
+pub type RDoubleEndedIterator<'a,T>=DynTrait<'a,RBox<()>,DEIteratorInterface<T>>;
+
+impl<'a,T:'a> RDoubleEndedIterator<'a,T>{
+    /// Constructs an `RDoubleEndedIterator` from any 
+    /// `DoubleEndedIterator<Item=T>+Send+Sync+'a`.
+    ///
+    /// The `RDoubleEndedIterator` can't be unerased back into the type of the iterator.
+    pub fn from_iter<I>(iter:I)->Self
+    where
+        I:DoubleEndedIterator<Item=T>+Send+Sync+'a,
+        InterfaceFor<I,DEIteratorInterface<T>,TU_Opaque>:
+            GetVtable<'a,I,RBox<()>,RBox<I>,DEIteratorInterface<T>>,
+    {
+        DynTrait::from_borrowing_value(iter,DEIteratorInterface::NEW)
+    }
+}
+
+
+//////////////////////////////////////////////
+
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Iterator))]
+pub struct UnsendIteratorInterface<T>(PhantomData<T>);
+
+impl<T> UnsendIteratorInterface<T>{
+    pub const NEW:Self=Self(PhantomData);
+}
+
+impl<'a,T:'a> IteratorItem<'a> for UnsendIteratorInterface<T>{
+    type Item=T;
+}
+
+
+/**
+An ffi-safe type-erased `Iterator<Item=T>+'a`,
+which can be returned from functions exported by dynamic libraries.
+
+This is the version of `RIterator` for iterators that aren't `Send+Sync`,
+which means that it isn't `Send+Sync` either.
+
+# Example
+
+```
+use abi_stable::erased_types::RUnsendIterator;
+
+use std::rc::Rc;
+
+let shared=Rc::new(vec![3,5,8]);
+let iter=RUnsendIterator::from_iter( (0..shared.len()).map(move|i| shared[i]*2 ) );
+
+assert_eq!( iter.collect::<Vec<u32>>(), vec![6,10,16] );
+
+```
+
+*/
+pub type RUnsendIterator<'a,T>=DynTrait<'a,RBox<()>,UnsendIteratorInterface<T>>;
+
+impl<'a,T:'a> RUnsendIterator<'a,T>{
+    /// Constructs an `RUnsendIterator` from any `Iterator<Item=T>+'a`.
+    ///
+    /// The `RUnsendIterator` can't be unerased back into the type of the iterator.
+    pub fn from_iter<I>(iter:I)->Self
+    where
+        I:Iterator<Item=T>+'a,
+        InterfaceFor<I,UnsendIteratorInterface<T>,TU_Opaque>:
+            GetVtable<'a,I,RBox<()>,RBox<I>,UnsendIteratorInterface<T>>,
+    {
+        DynTrait::from_borrowing_value(iter,UnsendIteratorInterface::NEW)
+    }
+}
+
+
+//////////////////////////////////////////////
+
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(DoubleEndedIterator))]
+pub struct UnsendDEIteratorInterface<T>(PhantomData<T>);
+
+impl<T> UnsendDEIteratorInterface<T>{
+    pub const NEW:Self=Self(PhantomData);
+}
+
+impl<'a,T:'a> IteratorItem<'a> for UnsendDEIteratorInterface<T>{
+    type Item=T;
+}
+
+
+/**
+An ffi-safe type-erased `DoubleEndedIterator<Item=T>+'a`,
+which can be returned from functions exported by dynamic libraries.
+
+This is the version of `RDoubleEndedIterator` for iterators that aren't `Send+Sync`,
+which means that it isn't `Send+Sync` either.
+
+# Example
+
+```
+use abi_stable::erased_types::RUnsendDoubleEndedIterator;
+
+use std::rc::Rc;
+
+let shared=Rc::new(vec![3,5,8]);
+let iter=RUnsendDoubleEndedIterator::from_iter( (0..shared.len()).map(move|i| shared[i] ) );
+
+assert_eq!( iter.rev().collect::<Vec<u32>>(), vec![8,5,3] );
+
+```
+
+*/
+pub type RUnsendDoubleEndedIterator<'a,T>=DynTrait<'a,RBox<()>,UnsendDEIteratorInterface<T>>;
+
+impl<'a,T:'a> RUnsendDoubleEndedIterator<'a,T>{
+    /// Constructs an `RUnsendDoubleEndedIterator` from any 
+    /// `DoubleEndedIterator<Item=T>+'a`.
+    ///
+    /// The `RUnsendDoubleEndedIterator` can't be unerased back into the type of the iterator.
+    pub fn from_iter<I>(iter:I)->Self
+    where
+        I:DoubleEndedIterator<Item=T>+'a,
+        InterfaceFor<I,UnsendDEIteratorInterface<T>,TU_Opaque>:
+            GetVtable<'a,I,RBox<()>,RBox<I>,UnsendDEIteratorInterface<T>>,
+    {
+        DynTrait::from_borrowing_value(iter,UnsendDEIteratorInterface::NEW)
+    }
+}
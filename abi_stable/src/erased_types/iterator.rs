@@ -1,4 +1,5 @@
 use std::{
+    iter::{Sum,Product},
     marker::PhantomData,
 };
 
@@ -6,8 +7,10 @@ use std::{
 
 use crate::{
     const_utils::Transmuter,
-    std_types::{RVec,ROption,RSome,RNone,Tuple2},
+    std_types::{RVec,ROption,RSome,RNone,RCmpOrdering,Tuple2},
     marker_type::ErasedObject,
+    prefix_type::{PrefixTypeTrait,WithMetadata},
+    sabi_types::StaticRef,
     utils::{transmute_reference,transmute_mut_reference},
     traits::IntoReprC,
 };
@@ -148,6 +151,201 @@ where I:Iterator
 
 
 
+////////////////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+
+/// A function that compares two type-erased items of an iterator.
+///
+/// This is a wrapper type because `StableAbi` types can't have
+/// function pointers as parameters of function pointers.
+#[repr(transparent)]
+#[derive(StableAbi)]
+pub struct ErasedCmpFn{
+    pub(super) func:unsafe extern "C" fn(&ErasedObject,&ErasedObject)->RCmpOrdering,
+}
+
+impl Copy for ErasedCmpFn{}
+impl Clone for ErasedCmpFn{
+    fn clone(&self)->Self{
+        *self
+    }
+}
+
+
+/// A type-erased `&mut FnMut(&Item)->bool` closure,
+/// used to run a predicate inside the loop of an erased iterator.
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct ErasedPredicate<'a,Item>{
+    closure:&'a mut ErasedObject,
+    func:unsafe extern "C" fn(&mut ErasedObject,&Item)->bool,
+}
+
+impl<'a,Item> ErasedPredicate<'a,Item>{
+    pub(super) fn new<F>(closure:&'a mut F)->Self
+    where
+        F:FnMut(&Item)->bool,
+    {
+        Self{
+            closure:unsafe{ transmute_mut_reference::<F,ErasedObject>(closure) },
+            func:call_predicate::<F,Item>,
+        }
+    }
+
+    fn call(&mut self,item:&Item)->bool{
+        unsafe{ (self.func)(self.closure,item) }
+    }
+}
+
+unsafe extern "C" fn call_predicate<F,Item>(closure:&mut ErasedObject,item:&Item)->bool
+where
+    F:FnMut(&Item)->bool,
+{
+    extern_fn_panic_handling! {
+        let closure=transmute_mut_reference::<ErasedObject,F>(closure);
+        closure(item)
+    }
+}
+
+
+/// Iterator methods that were added after `IteratorFns`,
+/// this is a prefix type so that more methods can be added in minor versions.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(
+    kind(Prefix(prefix_struct="IteratorExtFns")),
+    missing_field(panic),
+)]
+pub struct IteratorExtFnsVal<Item>{
+    pub(super) max_by:unsafe extern "C" fn(&mut ErasedObject,ErasedCmpFn)->ROption<Item>,
+    #[sabi(last_prefix_field)]
+    pub(super) min_by:unsafe extern "C" fn(&mut ErasedObject,ErasedCmpFn)->ROption<Item>,
+    #[sabi(missing_field(option))]
+    pub(super) find:
+        unsafe extern "C" fn(&mut ErasedObject,ErasedPredicate<'_,Item>)->ROption<Item>,
+    #[sabi(missing_field(option))]
+    pub(super) position:
+        unsafe extern "C" fn(&mut ErasedObject,ErasedPredicate<'_,Item>)->ROption<usize>,
+    #[sabi(missing_field(option))]
+    pub(super) collect_rvec:unsafe extern "C" fn(&mut ErasedObject)->RVec<Item>,
+}
+
+
+///////////////////////////////////////////////////////////////////////////////////
+
+
+pub struct MakeIteratorExtFns<I>(PhantomData<unsafe extern "C" fn()->I>);
+
+impl<I> MakeIteratorExtFns<I>
+where I:Iterator
+{
+    const VTABLE:StaticRef<WithMetadata<IteratorExtFnsVal<I::Item>>>=unsafe{
+        StaticRef::from_raw(&WithMetadata::new(
+            PrefixTypeTrait::METADATA,
+            IteratorExtFnsVal{
+                max_by:max_by::<I>,
+                min_by:min_by::<I>,
+                find:find::<I>,
+                position:position::<I>,
+                collect_rvec:collect_rvec::<I>,
+            },
+        ))
+    };
+
+    pub(super) const NEW:StaticRef<IteratorExtFns<()>>=unsafe{
+        WithMetadata::as_prefix(Self::VTABLE).transmute_ref()
+    };
+}
+
+
+///////////////////////////////////////////////////////////////////////////////////
+
+
+pub(super) unsafe extern "C" fn max_by<I>(this:&mut ErasedObject,cmp:ErasedCmpFn)->ROption<I::Item>
+where I:Iterator
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.max_by(|l,r|{
+            (cmp.func)(transmute_reference(l),transmute_reference(r)).into_ordering()
+        }).into_c()
+    }
+}
+
+pub(super) unsafe extern "C" fn min_by<I>(this:&mut ErasedObject,cmp:ErasedCmpFn)->ROption<I::Item>
+where I:Iterator
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.min_by(|l,r|{
+            (cmp.func)(transmute_reference(l),transmute_reference(r)).into_ordering()
+        }).into_c()
+    }
+}
+
+
+pub(super) unsafe extern "C" fn find<I>(
+    this:&mut ErasedObject,
+    mut pred:ErasedPredicate<'_,I::Item>,
+)->ROption<I::Item>
+where I:Iterator
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.find(|x| pred.call(x) ).into_c()
+    }
+}
+
+pub(super) unsafe extern "C" fn position<I>(
+    this:&mut ErasedObject,
+    mut pred:ErasedPredicate<'_,I::Item>,
+)->ROption<usize>
+where I:Iterator
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.position(|x| pred.call(&x) ).into_c()
+    }
+}
+
+
+pub(super) unsafe extern "C" fn collect_rvec<I>(this:&mut ErasedObject)->RVec<I::Item>
+where I:Iterator
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        let mut vec=RVec::with_capacity(this.size_hint().0);
+        vec.extend(this);
+        vec
+    }
+}
+
+
+pub(super) unsafe extern "C" fn sum<I>(this:&mut ErasedObject)->I::Item
+where
+    I:Iterator,
+    I::Item:Sum,
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.sum::<I::Item>()
+    }
+}
+
+pub(super) unsafe extern "C" fn product<I>(this:&mut ErasedObject)->I::Item
+where
+    I:Iterator,
+    I::Item:Product,
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,I>(this);
+        this.product::<I::Item>()
+    }
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////////
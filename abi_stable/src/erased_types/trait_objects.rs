@@ -12,6 +12,7 @@ use core_extensions::prelude::*;
 use super::{c_functions::*, *};
 
 use crate::{
+    erased_types::interfaces::{DebugInterface,DisplayInterface},
     marker_type::ErasedObject,
     std_types::RBox,
     pointer_trait::TransmuteElement,
@@ -19,6 +20,10 @@ use crate::{
 
 
 
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+
 /////////////////////////////////////////////////////////////
 
 
@@ -118,6 +123,104 @@ impl Debug for DebugDisplayObject{
 
 
 
+/////////////////////////////////////////////////////////////
+
+
+/// An ffi-safe equivalent of `&dyn Display`,
+/// which borrows a value for the `'a` lifetime.
+///
+/// This is useful for passing a value that is only formatted
+/// (eg: the message of an error) across the ffi boundary without allocating.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::erased_types::trait_objects::RDisplay;
+///
+/// let value=100_u32;
+/// let display=RDisplay::new(&value);
+///
+/// assert_eq!( display.to_string(), "100" );
+/// assert_eq!( format!("{:>5}",display), "  100" );
+///
+/// ```
+#[repr(transparent)]
+#[derive(StableAbi)]
+pub struct RDisplay<'a>{
+    inner:DynTrait<'a,&'a (),DisplayInterface>,
+}
+
+impl<'a> RDisplay<'a>{
+    /// Constructs an `RDisplay` from a reference to a `Display` type.
+    pub fn new<T>(value:&'a T)->Self
+    where T:Display+'a
+    {
+        RDisplay{
+            inner:DynTrait::from_borrowing_ptr(value,DisplayInterface),
+        }
+    }
+}
+
+impl<'a> Display for RDisplay<'a>{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Display::fmt(&self.inner,f)
+    }
+}
+
+impl<'a> Debug for RDisplay<'a>{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_tuple("RDisplay")
+         .field(&format_args!("{}",self.inner))
+         .finish()
+    }
+}
+
+
+/////////////////////////////////////////////////////////////
+
+
+/// An ffi-safe equivalent of `&dyn Debug`,
+/// which borrows a value for the `'a` lifetime.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::erased_types::trait_objects::RDebug;
+///
+/// let value=vec![3,5,8];
+/// let debug=RDebug::new(&value);
+///
+/// assert_eq!( format!("{:?}",debug), "[3, 5, 8]" );
+/// assert_eq!( format!("{:#?}",debug), format!("{:#?}",value) );
+///
+/// ```
+#[repr(transparent)]
+#[derive(StableAbi)]
+pub struct RDebug<'a>{
+    inner:DynTrait<'a,&'a (),DebugInterface>,
+}
+
+impl<'a> RDebug<'a>{
+    /// Constructs an `RDebug` from a reference to a `Debug` type.
+    pub fn new<T>(value:&'a T)->Self
+    where T:Debug+'a
+    {
+        RDebug{
+            inner:DynTrait::from_borrowing_ptr(value,DebugInterface),
+        }
+    }
+}
+
+impl<'a> Debug for RDebug<'a>{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Debug::fmt(&self.inner,f)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////
+
+
 struct NoFmt;
 
 impl Display for NoFmt{
@@ -0,0 +1,61 @@
+use super::*;
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+
+/// A type that is neither `Send` nor `Sync`,
+/// and which counts how many times it was formatted.
+struct Counted{
+    times:Rc<Cell<u32>>,
+}
+
+impl Display for Counted{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        self.times.set(self.times.get()+1);
+        write!(f,"display {}",self.times.get())
+    }
+}
+
+impl Debug for Counted{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        self.times.set(self.times.get()+1);
+        write!(f,"debug {}",self.times.get())
+    }
+}
+
+
+#[test]
+fn rdisplay(){
+    let counted=Counted{ times:Rc::new(Cell::new(0)) };
+    {
+        let display=RDisplay::new(&counted);
+        assert_eq!(display.to_string(),"display 1");
+        assert_eq!(format!("{:?}",display),"RDisplay(display 2)");
+    }
+    assert_eq!(counted.times.get(),2);
+
+    let text=String::from("hello");
+    let display=RDisplay::new(&text);
+    assert_eq!(format!("{:<7}|",display),"hello  |");
+    assert_eq!(format!("{:.3}",display),"hel");
+}
+
+
+#[test]
+fn rdebug(){
+    let counted=Counted{ times:Rc::new(Cell::new(0)) };
+    {
+        let debug=RDebug::new(&counted);
+        assert_eq!(format!("{:?}",debug),"debug 1");
+        assert_eq!(format!("{:?}",debug),"debug 2");
+    }
+    assert_eq!(counted.times.get(),2);
+
+    let list=vec![Some(1),None];
+    let debug=RDebug::new(&list);
+    assert_eq!(format!("{:?}",debug),format!("{:?}",list));
+    assert_eq!(format!("{:#?}",debug),format!("{:#?}",list));
+}
@@ -3,11 +3,17 @@
 Traits for types wrapped in `DynTrait<_>`
 */
 
-use std::{mem,marker::PhantomData};
+use std::{
+    error::Error as ErrorTrait,
+    fmt::{self,Display},
+    mem,
+    marker::PhantomData,
+};
 
 use crate::{
     sabi_types::{Constructor,VersionStrings},
     std_types::{RBoxError, StaticStr},
+    utils::get_type_name,
 };
 
 use super::TypeInfo;
@@ -15,7 +21,7 @@ use super::TypeInfo;
 #[allow(unused_imports)]
 use crate::type_level::{
     bools::{False, True},
-    impl_enum::{Implemented,Unimplemented},
+    impl_enum::{Implemented,Unimplemented,ImplSubsetOf},
     trait_marker,
 };
 
@@ -68,6 +74,25 @@ macro_rules! declare_InterfaceType {
         }
 
 
+        /**
+        Queries whether the traits enabled in `Self` are a subset of
+        the ones enabled in `Wider`.
+
+        This is used by `DynTrait::{from_value_as,from_ptr_as}` to construct a `DynTrait`
+        with a narrower interface than `ImplType::Interface`.
+        */
+        pub trait InterfaceSubsetOf<Wider:InterfaceType>:InterfaceType{}
+
+        impl<This,Wider> InterfaceSubsetOf<Wider> for This
+        where
+            This:InterfaceType,
+            Wider:InterfaceType,
+            $(
+                This::$trait_:ImplSubsetOf<Wider::$trait_>,
+            )*
+        {}
+
+
     )
 }
 
@@ -158,6 +183,16 @@ impl InterfaceType for FooInterface {
     // type IoBufRead= Unimplemented<trait_marker::IoBufRead>;
     
     // type Error= Unimplemented<trait_marker::Error>;
+
+    // type IteratorSum= Unimplemented<trait_marker::IteratorSum>;
+
+    // type IteratorProduct= Unimplemented<trait_marker::IteratorProduct>;
+
+    // type AsBytes= Unimplemented<trait_marker::AsBytes>;
+
+    // type AsStr= Unimplemented<trait_marker::AsStr>;
+
+    // type Extend= Unimplemented<trait_marker::Extend>;
 }
 */
 
@@ -214,6 +249,24 @@ impl InterfaceType for FooInterface {
         type IoBufRead;
         
         type Error;
+
+        /// Whether the `Item` of the iterator implements `std::iter::Sum`,
+        /// used by `DynTrait::sum_erased`.
+        type IteratorSum;
+
+        /// Whether the `Item` of the iterator implements `std::iter::Product`,
+        /// used by `DynTrait::product_erased`.
+        type IteratorProduct;
+
+        /// Corresponds to `AsRef<[u8]>`,used by `DynTrait::sabi_as_bytes`.
+        type AsBytes;
+
+        /// Corresponds to `AsRef<str>`,used by `DynTrait::sabi_as_str`.
+        type AsStr;
+
+        /// Corresponds to `Extend<Item>`,used by `DynTrait::extend_from_erased`,
+        /// where `Item` is the type in this type's `IteratorItem` impl.
+        type Extend;
     ]
 
 
@@ -299,6 +352,155 @@ pub trait DeserializeDyn<'borr,D> {
 
     /// Converts the proxy type into `D`.
     fn deserialize_dyn(s: Self::Proxy) -> Result<D, RBoxError>;
+
+    /// Gets the text that the proxy contains,
+    /// which is shown in the `DeserializeDynError` returned when 
+    /// `DeserializeDyn::deserialize_dyn` fails.
+    ///
+    /// This returns `None` by default,
+    /// so that the error message doesn't show the input.
+    fn proxy_input(proxy: &Self::Proxy) -> Option<&str> {
+        let _=proxy;
+        None
+    }
+}
+
+
+/**
+The error returned when deserializing a `DynTrait<_>` or a `NonExhaustive<_>` fails,
+which says what interface type was being deserialized,
+and the start of the input it was being deserialized from.
+
+The input is the first 64 characters of the text returned by
+`DeserializeDyn::proxy_input`/`DeserializeEnum::proxy_input`,
+and is omitted from the error message if those return `None`.
+
+This is stored inside an `RBoxError`,
+which can be downcasted back to `DeserializeDynError` to get the 
+error returned by `DeserializeDyn::deserialize_dyn`/`DeserializeEnum::deserialize_enum`.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::{DeserializeDyn,DeserializeDynError,DynTrait},
+    std_types::{RBox,RBoxError,RStr},
+    StableAbi,
+};
+
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Deserialize))]
+struct NumInterface;
+
+impl<'a> DeserializeDyn<'a,DynTrait<'static,RBox<()>,NumInterface>> for NumInterface {
+    type Proxy=RStr<'a>;
+
+    fn deserialize_dyn(s:RStr<'a>)->Result<DynTrait<'static,RBox<()>,NumInterface>,RBoxError>{
+        match s.parse::<u32>() {
+            Ok(x)=>Ok(DynTrait::from_any_value(x,NumInterface)),
+            Err(e)=>Err(RBoxError::new(e)),
+        }
+    }
+
+    fn proxy_input(s:&RStr<'a>)->Option<&str>{
+        Some(s.as_str())
+    }
+}
+
+let err=DynTrait::<'static,RBox<()>,NumInterface>::deserialize_from_proxy("what".into())
+    .unwrap_err();
+
+assert!( err.to_string().starts_with("failed to deserialize ") );
+assert!( err.to_string().contains("NumInterface") );
+assert!( err.to_string().contains(" from `what`: ") );
+
+let err=err.downcast_ref::<DeserializeDynError>().unwrap();
+
+assert!( err.interface_name().contains("NumInterface") );
+assert_eq!( err.input(), Some("what") );
+assert_eq!( err.inner().to_string(), "invalid digit found in string" );
+
+```
+
+*/
+#[derive(Debug)]
+pub struct DeserializeDynError{
+    interface:&'static str,
+    input:Option<String>,
+    error:RBoxError,
+}
+
+/// The maximum amount of characters of the input stored in a `DeserializeDynError`.
+const MAX_INPUT_CHARS:usize=64;
+
+impl DeserializeDynError{
+    /// Wraps the `error` returned by `<I as DeserializeDyn<_>>::deserialize_dyn`,
+    /// or `<I as DeserializeEnum<_>>::deserialize_enum`.
+    ///
+    /// `input` is the text of the proxy that failed to be deserialized,
+    /// as returned by `DeserializeDynError::truncate_input`.
+    pub(crate) fn new<I>(input:Option<String>,error:RBoxError)->Self{
+        Self{
+            interface:get_type_name::<I>().as_str(),
+            input,
+            error,
+        }
+    }
+
+    /// Copies the first `MAX_INPUT_CHARS` characters of the text of a proxy,
+    /// before the proxy is consumed by 
+    /// `DeserializeDyn::deserialize_dyn`/`DeserializeEnum::deserialize_enum`.
+    pub(crate) fn truncate_input(input:Option<&str>)->Option<String>{
+        input.map(|input| truncate_str(input).to_string() )
+    }
+
+    /// The name of the interface type of the `DynTrait`/`NonExhaustive` 
+    /// that failed to be deserialized.
+    ///
+    /// This is `"<unavailable>"` before Rust 1.38.
+    pub fn interface_name(&self)->&'static str{
+        self.interface
+    }
+
+    /// The first 64 characters of the input that failed to be deserialized,
+    /// as returned by `DeserializeDyn::proxy_input`/`DeserializeEnum::proxy_input`.
+    pub fn input(&self)->Option<&str>{
+        self.input.as_ref().map(|x| x.as_str() )
+    }
+
+    /// Gets the error returned by 
+    /// `DeserializeDyn::deserialize_dyn`/`DeserializeEnum::deserialize_enum`.
+    pub fn inner(&self)->&RBoxError{
+        &self.error
+    }
+
+    /// Unwraps this into the error returned by 
+    /// `DeserializeDyn::deserialize_dyn`/`DeserializeEnum::deserialize_enum`.
+    pub fn into_inner(self)->RBoxError{
+        self.error
+    }
+}
+
+impl Display for DeserializeDynError{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        match &self.input {
+            Some(input)=>
+                write!(f,"failed to deserialize {} from `{}`: {}",self.interface,input,self.error),
+            None=>
+                write!(f,"failed to deserialize {}: {}",self.interface,self.error),
+        }
+    }
+}
+
+impl ErrorTrait for DeserializeDynError{}
+
+/// Gets the first `MAX_INPUT_CHARS` characters of `input`.
+fn truncate_str(input:&str)->&str{
+    match input.char_indices().nth(MAX_INPUT_CHARS) {
+        Some((end,_))=>&input[..end],
+        None=>input,
+    }
 }
 
 
@@ -358,10 +560,10 @@ pub trait IteratorItem<'a>:InterfaceType{
 
 
 
-/// Gets the Item type of an Iterator.
+/// Gets the Item type of an Iterator,or of a collection that can be extended.
 ///
 /// Used by `DynTrait`'s vtable to give its iter a default type,
-/// when `I:InterfaceType<Iterator=Implemented<_>>`.
+/// when `I:InterfaceType<Iterator=Implemented<_>>` or `I:InterfaceType<Extend=Implemented<_>>`.
 pub trait IteratorItemOrDefault<'borr>:InterfaceType{
     type Item;
 }
@@ -372,7 +574,7 @@ where
     I:InterfaceType,
     I:IteratorItemOrDefaultHelper<
         'borr,
-        <I as InterfaceType>::Iterator,
+        (<I as InterfaceType>::Iterator,<I as InterfaceType>::Extend),
         Item=Item,
     >
 {
@@ -385,7 +587,21 @@ pub trait IteratorItemOrDefaultHelper<'borr,ImplIsRequired>{
     type Item;
 }
 
-impl<'borr,I,Item> IteratorItemOrDefaultHelper<'borr,Implemented<trait_marker::Iterator>> for I
+impl<'borr,I,Item,Extend> 
+    IteratorItemOrDefaultHelper<'borr,(Implemented<trait_marker::Iterator>,Extend)> 
+for I
+where
+    I:IteratorItem<'borr,Item=Item>,
+{
+    type Item=Item;
+}
+
+impl<'borr,I,Item> 
+    IteratorItemOrDefaultHelper<
+        'borr,
+        (Unimplemented<trait_marker::Iterator>,Implemented<trait_marker::Extend>)
+    > 
+for I
 where
     I:IteratorItem<'borr,Item=Item>,
 {
@@ -393,7 +609,13 @@ where
 }
 
 
-impl<'borr,I> IteratorItemOrDefaultHelper<'borr,Unimplemented<trait_marker::Iterator>> for I{
+impl<'borr,I> 
+    IteratorItemOrDefaultHelper<
+        'borr,
+        (Unimplemented<trait_marker::Iterator>,Unimplemented<trait_marker::Extend>)
+    > 
+for I
+{
     type Item=();
 }
 
@@ -402,7 +624,75 @@ impl<'borr,I> IteratorItemOrDefaultHelper<'borr,Unimplemented<trait_marker::Iter
 //////////////////////////////////////////////////////////////////
 
 
-pub use self::interface_for::InterfaceFor;
+/**
+Queries whether a `DynTrait<_,Wider>` can be viewed as a `DynTrait<_,Self>`.
+
+This requires the traits enabled in `Self` to be a subset of the ones enabled in `Wider`,
+and if `Self` enables `Iterator`/`Extend`/`Serialize`,
+that the `Iterator::Item`/`Extend` item/serialization proxy type is the same in both interfaces.
+
+This is used by `DynTrait::reborrow_mut_as`.
+*/
+pub trait InterfaceNarrowableFrom<'borr,Wider:InterfaceType>:InterfaceSubsetOf<Wider>{}
+
+impl<'borr,This,Wider> InterfaceNarrowableFrom<'borr,Wider> for This
+where
+    This:InterfaceSubsetOf<Wider>,
+    This:SameIteratorItemHelper<'borr,Wider,<This as InterfaceType>::Iterator>,
+    This:SameIteratorItemHelper<'borr,Wider,<This as InterfaceType>::Extend>,
+    This:SameSerializeProxyHelper<Wider,<This as InterfaceType>::Serialize>,
+    Wider:InterfaceType,
+{}
+
+
+#[doc(hidden)]
+pub trait SameIteratorItemHelper<'borr,Wider,ImplIsRequired>{}
+
+impl<'borr,This,Wider,Item> 
+    SameIteratorItemHelper<'borr,Wider,Implemented<trait_marker::Iterator>> 
+for This
+where
+    This:IteratorItem<'borr,Item=Item>,
+    Wider:IteratorItem<'borr,Item=Item>,
+{}
+
+impl<'borr,This,Wider> 
+    SameIteratorItemHelper<'borr,Wider,Unimplemented<trait_marker::Iterator>> 
+for This
+{}
+
+impl<'borr,This,Wider,Item> 
+    SameIteratorItemHelper<'borr,Wider,Implemented<trait_marker::Extend>> 
+for This
+where
+    This:IteratorItem<'borr,Item=Item>,
+    Wider:IteratorItem<'borr,Item=Item>,
+{}
+
+impl<'borr,This,Wider> 
+    SameIteratorItemHelper<'borr,Wider,Unimplemented<trait_marker::Extend>> 
+for This
+{}
+
+
+#[doc(hidden)]
+pub trait SameSerializeProxyHelper<Wider,ImplIsRequired>{}
+
+impl<This,Wider> SameSerializeProxyHelper<Wider,Implemented<trait_marker::Serialize>> for This
+where
+    Wider:for<'s> SerializeProxyType<'s>,
+    This:for<'s> SerializeProxyType<'s,Proxy=<Wider as SerializeProxyType<'s>>::Proxy>,
+{}
+
+impl<This,Wider> SameSerializeProxyHelper<Wider,Unimplemented<trait_marker::Serialize>> for This
+{}
+
+
+
+//////////////////////////////////////////////////////////////////
+
+
+pub use self::interface_for::{InterfaceFor,NarrowedImplType};
 
 #[doc(hidden)]
 pub mod interface_for{
@@ -434,6 +724,25 @@ pub mod interface_for{
             _private_field:(),
         };
     }
+
+    /// Helper struct to get an `ImplType` implementation for `T`,
+    /// with an `Interface` that enables a subset of the traits in `T::Interface`.
+    ///
+    /// This uses the same `INFO` as `T`,so that `DynTrait`s constructed with this
+    /// can be unerased into `T`.
+    pub struct NarrowedImplType<T,Interface>(
+        PhantomData<fn()->(T,Interface)>
+    );
+
+    impl<T,Interface> ImplType for NarrowedImplType<T,Interface>
+    where 
+        T:ImplType,
+        Interface:InterfaceSubsetOf<T::Interface>,
+    {
+        type Interface=Interface;
+        
+        const INFO:&'static TypeInfo=T::INFO;
+    }
 }
 
 
@@ -0,0 +1,289 @@
+/*!
+Contains `TypeErasedRegistry`,a map from types to a value of that type.
+*/
+
+use std::fmt::{self,Debug};
+
+use crate::{
+    erased_types::DynTrait,
+    std_types::{RBox,RHashMap,UTypeId},
+};
+
+
+/// The `InterfaceType` of the values stored in a `TypeErasedRegistry`.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(impl_InterfaceType(Send,Sync))]
+pub struct RegistryInterface;
+
+
+/**
+A map from types to a value of that type,
+in which each type can have at most one value registered.
+
+This is useful to store handlers for some kinds of events,
+where the type of the handler is used to retrieve it.
+
+# Restrictions
+
+This type is only usable within the dynamic library/executable that registered the values,
+since both `UTypeId` and unerasing the `DynTrait`s it stores
+only work for types from the same dynamic library/executable.
+
+Passing this type to another dynamic library is safe,
+but `get`/`get_mut`/`remove` will return `None` for values
+registered in other dynamic libraries.
+
+# Example
+
+```
+use abi_stable::{
+    erased_types::TypeErasedRegistry,
+    std_types::RString,
+};
+
+#[derive(Debug,PartialEq)]
+struct OnConnect{ greeting:RString }
+
+#[derive(Debug,PartialEq)]
+struct OnDisconnect{ count:u32 }
+
+let mut registry=TypeErasedRegistry::new();
+
+assert_eq!( registry.register(OnConnect{greeting:"hello".into()}), None );
+assert_eq!( registry.register(OnDisconnect{count:0}), None );
+
+assert_eq!( registry.get::<OnConnect>().unwrap().greeting.as_str(), "hello" );
+
+registry.get_mut::<OnDisconnect>().unwrap().count+=1;
+assert_eq!( registry.get::<OnDisconnect>(), Some(&OnDisconnect{count:1}) );
+
+assert_eq!(
+    registry.register(OnDisconnect{count:10}),
+    Some(OnDisconnect{count:1}),
+);
+
+assert_eq!( registry.remove::<OnConnect>(), Some(OnConnect{greeting:"hello".into()}) );
+assert_eq!( registry.get::<OnConnect>(), None );
+assert_eq!( registry.len(), 1 );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct TypeErasedRegistry{
+    map:RHashMap<UTypeId,DynTrait<'static,RBox<()>,RegistryInterface>>,
+}
+
+
+impl TypeErasedRegistry{
+    /// Constructs an empty `TypeErasedRegistry`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let registry=TypeErasedRegistry::new();
+    ///
+    /// assert!( registry.is_empty() );
+    /// assert_eq!( registry.get::<u32>(), None );
+    ///
+    /// ```
+    pub fn new()->Self{
+        Self{
+            map:RHashMap::new(),
+        }
+    }
+
+    /// Registers `value` as the value for the `T` type,
+    /// returning the previously registered value for `T` if there was one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let mut registry=TypeErasedRegistry::new();
+    ///
+    /// assert_eq!( registry.register(3_u32), None );
+    /// assert_eq!( registry.register(5_u32), Some(3) );
+    /// assert_eq!( registry.register("hello"), None );
+    ///
+    /// ```
+    pub fn register<T>(&mut self,value:T)->Option<T>
+    where
+        T:Send+Sync+'static,
+    {
+        let value=DynTrait::from_any_value(value,RegistryInterface);
+        self.map.insert(UTypeId::new::<T>(),value)
+            .into_option()
+            .and_then(|prev| prev.into_unerased::<T>().ok() )
+            .map(RBox::into_inner)
+    }
+
+    /// Gets a reference to the value registered for the `T` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let mut registry=TypeErasedRegistry::new();
+    ///
+    /// registry.register(3_u32);
+    ///
+    /// assert_eq!( registry.get::<u32>(), Some(&3) );
+    /// assert_eq!( registry.get::<u64>(), None );
+    ///
+    /// ```
+    pub fn get<T>(&self)->Option<&T>
+    where
+        T:'static,
+    {
+        self.map.get(&UTypeId::new::<T>())
+            .and_then(|value| value.as_unerased::<T>().ok() )
+    }
+
+    /// Gets a mutable reference to the value registered for the `T` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let mut registry=TypeErasedRegistry::new();
+    ///
+    /// registry.register(3_u32);
+    ///
+    /// *registry.get_mut::<u32>().unwrap()+=10;
+    ///
+    /// assert_eq!( registry.get::<u32>(), Some(&13) );
+    /// assert_eq!( registry.get_mut::<u64>(), None );
+    ///
+    /// ```
+    pub fn get_mut<T>(&mut self)->Option<&mut T>
+    where
+        T:'static,
+    {
+        self.map.get_mut(&UTypeId::new::<T>())
+            .and_then(|value| value.as_unerased_mut::<T>().ok() )
+    }
+
+    /// Removes the value registered for the `T` type,returning it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let mut registry=TypeErasedRegistry::new();
+    ///
+    /// registry.register(3_u32);
+    ///
+    /// assert_eq!( registry.remove::<u32>(), Some(3) );
+    /// assert_eq!( registry.remove::<u32>(), None );
+    ///
+    /// ```
+    pub fn remove<T>(&mut self)->Option<T>
+    where
+        T:'static,
+    {
+        self.map.remove(&UTypeId::new::<T>())
+            .into_option()
+            .and_then(|value| value.into_unerased::<T>().ok() )
+            .map(RBox::into_inner)
+    }
+
+    /// Whether there is a value registered for the `T` type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::erased_types::TypeErasedRegistry;
+    ///
+    /// let mut registry=TypeErasedRegistry::new();
+    ///
+    /// registry.register(3_u32);
+    ///
+    /// assert!( registry.contains::<u32>() );
+    /// assert!( !registry.contains::<u64>() );
+    ///
+    /// ```
+    pub fn contains<T>(&self)->bool
+    where
+        T:'static,
+    {
+        self.map.contains_key(&UTypeId::new::<T>())
+    }
+
+    /// The amount of types with a registered value.
+    pub fn len(&self)->usize{
+        self.map.len()
+    }
+
+    /// Whether there are no types with a registered value.
+    pub fn is_empty(&self)->bool{
+        self.map.is_empty()
+    }
+}
+
+
+impl Default for TypeErasedRegistry{
+    fn default()->Self{
+        Self::new()
+    }
+}
+
+
+impl Debug for TypeErasedRegistry{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_struct("TypeErasedRegistry")
+         .field("len",&self.len())
+         .finish()
+    }
+}
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod test {
+    use super::*;
+
+    use std::sync::Arc;
+
+    #[test]
+    fn register_and_remove() {
+        let mut registry = TypeErasedRegistry::new();
+
+        assert_eq!(registry.register(3_u32), None);
+        assert_eq!(registry.register(5_u64), None);
+        assert_eq!(registry.register(8_u32), Some(3));
+        assert_eq!(registry.len(), 2);
+
+        assert_eq!(registry.get::<u32>(), Some(&8));
+        assert_eq!(registry.get::<u64>(), Some(&5));
+        assert_eq!(registry.get::<u16>(), None);
+
+        assert_eq!(registry.remove::<u64>(), Some(5));
+        assert_eq!(registry.remove::<u64>(), None);
+        assert!(!registry.contains::<u64>());
+        assert!(registry.contains::<u32>());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn drops_values() {
+        let arc = Arc::new(0);
+
+        {
+            let mut registry = TypeErasedRegistry::new();
+            registry.register(arc.clone());
+            assert_eq!(Arc::strong_count(&arc), 2);
+
+            registry.register(arc.clone());
+            assert_eq!(Arc::strong_count(&arc), 2);
+        }
+        assert_eq!(Arc::strong_count(&arc), 1);
+    }
+}
@@ -32,6 +32,59 @@ impl TypeInfo {
     pub fn is_compatible(&self, other: &Self) -> bool {
         self._uid==other._uid
     }
+
+    /// Whether this is the `TypeInfo` of a type that `DynTrait` can be unerased into,
+    /// false for `DynTrait`s constructed with the `from_borrowing_*` constructors.
+    pub(crate) fn is_unerasable(&self) -> bool {
+        match self._uid.get() {
+            MaybeCmp::Just(_)=>true,
+            MaybeCmp::Nothing=>false,
+        }
+    }
+
+    /// Whether `self` and `other` describe a type with the same name,
+    /// declared in the same module of the same version of the same package,
+    /// with the same size and alignment.
+    ///
+    /// Unlike `is_compatible`,this can return true for `TypeInfo`s 
+    /// from different dynamic libraries/executables,
+    /// which is only correct if they were compiled from the same source code.
+    ///
+    /// This returns false for `TypeInfo`s of types that can't be unerased,
+    /// and for those that don't contain the module/package/version of the type.
+    ///
+    /// This always returns false when compiled with Rust versions before 1.38,
+    /// because the type name doesn't include the generic arguments of the type
+    /// (`std::any::type_name` isn't available),
+    /// which would make `Foo<u8>` and `Foo<u16>` have the same contents.
+    #[cfg(feature="rust_1_38")]
+    pub fn has_same_contents(&self, other: &Self) -> bool {
+        const UNAVAILABLE:&str="<unavailable>";
+
+        self.is_unerasable()&&
+        other.is_unerasable()&&
+        self.package.as_str()!=UNAVAILABLE&&
+        self.module.as_str()!=UNAVAILABLE&&
+        self.size==other.size&&
+        self.alignment==other.alignment&&
+        self.type_name.get()==other.type_name.get()&&
+        self.module.as_str()==other.module.as_str()&&
+        self.package.as_str()==other.package.as_str()&&
+        self.package_version.version.as_str()==other.package_version.version.as_str()
+    }
+
+    /// Whether `self` and `other` describe a type with the same name,
+    /// declared in the same module of the same version of the same package,
+    /// with the same size and alignment.
+    ///
+    /// This always returns false when compiled with Rust versions before 1.38,
+    /// because the type name doesn't include the generic arguments of the type
+    /// (`std::any::type_name` isn't available),
+    /// which would make `Foo<u8>` and `Foo<u16>` have the same contents.
+    #[cfg(not(feature="rust_1_38"))]
+    pub fn has_same_contents(&self, _other: &Self) -> bool {
+        false
+    }
 }
 
 impl fmt::Display for TypeInfo {
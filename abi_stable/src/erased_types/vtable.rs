@@ -14,6 +14,8 @@ use super::{
     c_functions::*,
     iterator::{
         IteratorFns,MakeIteratorFns,
+        IteratorExtFns,MakeIteratorExtFns,
+        sum as iterator_sum,product as iterator_product,
         DoubleEndedIteratorFns,MakeDoubleEndedIteratorFns,
     },
     traits::{
@@ -62,6 +64,37 @@ pub trait GetVtable<'borr,This,ErasedPtr,OrigPtr,I:InterfaceBound> {
 }
 
 
+/// Gets the vtable of a `DynTrait` whose pointer can be cloned with 
+/// `DynTrait::sabi_try_clone`,even if `I` doesn't require `Clone`.
+pub trait GetTryCloneVtable<'borr,This,ErasedPtr,OrigPtr,I:InterfaceBound>:
+    GetVtable<'borr,This,ErasedPtr,OrigPtr,I>
+{
+    #[doc(hidden)]
+    const _GET_TRY_CLONE_VTABLE:StaticRef<VTable<'borr,ErasedPtr,I>>;
+}
+
+impl<'borr,Anything,This,ErasedPtr,OrigPtr,I> 
+    GetTryCloneVtable<'borr,This,ErasedPtr,OrigPtr,I> 
+for Anything
+where
+    Anything:GetVtable<'borr,This,ErasedPtr,OrigPtr,I>,
+    I:InterfaceBound,
+    OrigPtr:Clone,
+{
+    const _GET_TRY_CLONE_VTABLE:StaticRef<VTable<'borr,ErasedPtr,I>>=unsafe{
+        let x=&WithMetadata::new(
+            PrefixTypeTrait::METADATA,
+            VTableVal{
+                _try_clone_ptr:Some(clone_pointer_impl::<OrigPtr,ErasedPtr>),
+                ..Self::TMP_VTABLE
+            }
+        );
+        let x=StaticRef::from_raw(x);
+        WithMetadata::as_prefix(x)
+    };
+}
+
+
 /// This type allows passing the vtable for DynTrait to `from_const` with `VTableDT::GET`.
 #[repr(transparent)]
 pub struct VTableDT<'borr,T,ErasedPtr,OrigPtr,I,Unerasability>{
@@ -118,6 +151,10 @@ macro_rules! declare_meta_vtable {
             ])*
         ]
 
+        trailing_traits[
+            $($trailing_trait:ident : $trailing_field_ty:ty),* $(,)*
+        ]
+
         $([
             $( #[$field_attr:meta] )*
             $field:ident : $field_ty:ty ;
@@ -157,6 +194,44 @@ macro_rules! declare_meta_vtable {
                 $( #[$field_attr] )*
                 $priv_field:$option_ty<$field_ty>,
             )*
+            #[sabi(
+                unsafe_change_type=
+                "ROption<StaticRef<IteratorExtFns< <I as IteratorItemOrDefault<'borr>>::Item >>>"
+            )]
+            #[sabi(accessible_if="<I as InterfaceBound>::Iterator")]
+            #[sabi(missing_field(default))]
+            _iter_ext:ROption<StaticRef<IteratorExtFns<()>>>,
+            #[sabi(
+                unsafe_change_type=
+                "Option<unsafe extern \"C\" fn(&mut ErasedObject)->
+                    <I as IteratorItemOrDefault<'borr>>::Item>"
+            )]
+            #[sabi(accessible_if="<I as InterfaceBound>::IteratorSum")]
+            _iter_sum:Option<ErasedReductionFn>,
+            #[sabi(
+                unsafe_change_type=
+                "Option<unsafe extern \"C\" fn(&mut ErasedObject)->
+                    <I as IteratorItemOrDefault<'borr>>::Item>"
+            )]
+            #[sabi(accessible_if="<I as InterfaceBound>::IteratorProduct")]
+            _iter_product:Option<ErasedReductionFn>,
+            #[sabi(missing_field(default))]
+            _try_clone_ptr:Option<unsafe extern "C" fn(&$erased_ptr)->$erased_ptr>,
+            #[sabi(missing_field(default))]
+            _enabled_traits:ROption<EnabledTraits>,
+            #[sabi(accessible_if="<I as InterfaceBound>::AsBytes")]
+            _as_bytes:Option<unsafe extern "C" fn(&ErasedObject)->RSlice<'_,u8>>,
+            #[sabi(accessible_if="<I as InterfaceBound>::AsStr")]
+            _as_str:Option<unsafe extern "C" fn(&ErasedObject)->RStr<'_>>,
+            #[sabi(
+                unsafe_change_type=
+                "Option<unsafe extern \"C\" fn(
+                    &mut ErasedObject,
+                    RIterator<'_,<I as IteratorItemOrDefault<'borr>>::Item>,
+                )>"
+            )]
+            #[sabi(accessible_if="<I as InterfaceBound>::Extend")]
+            _extend:Option<ErasedExtendFn>,
         }
 
 
@@ -192,6 +267,135 @@ macro_rules! declare_meta_vtable {
                 }
             }
 
+            /// Gets the iterator methods that were added after `IteratorFns`,
+            /// which is `None` for vtables constructed by an older version of `abi_stable`.
+            pub fn iter_ext(
+                &self
+            )->Option<&IteratorExtFns< <I as IteratorItemOrDefault<'borr>>::Item >>
+            where
+                $interf:InterfaceBound<Iterator=Implemented<trait_marker::Iterator>>,
+                $interf:IteratorItemOrDefault<'borr>,
+            {
+                match self._iter_ext() {
+                    RSome(v)=>unsafe{
+                        Some(v.transmute_ref::<
+                            IteratorExtFns< <I as IteratorItemOrDefault<'borr>>::Item >
+                        >().get())
+                    },
+                    RNone=>None,
+                }
+            }
+
+            pub fn iter_sum(
+                &self
+            )->UnerasedReductionFn<'borr,I>
+            where
+                $interf:InterfaceBound<IteratorSum=Implemented<trait_marker::IteratorSum>>,
+                $interf:IteratorItemOrDefault<'borr>,
+            {
+                match self._iter_sum() {
+                    Some(v)=>unsafe{
+                        std::mem::transmute::<ErasedReductionFn,UnerasedReductionFn<'borr,I>>(v)
+                    },
+                    None=>panic_on_missing_fieldname::<
+                        VTableVal<'borr,$erased_ptr,$interf>,
+                    >(
+                        Self::field_index_for__iter_sum,
+                        self._prefix_type_layout(),
+                    )
+                }
+            }
+
+            pub fn iter_product(
+                &self
+            )->UnerasedReductionFn<'borr,I>
+            where
+                $interf:InterfaceBound<IteratorProduct=Implemented<trait_marker::IteratorProduct>>,
+                $interf:IteratorItemOrDefault<'borr>,
+            {
+                match self._iter_product() {
+                    Some(v)=>unsafe{
+                        std::mem::transmute::<ErasedReductionFn,UnerasedReductionFn<'borr,I>>(v)
+                    },
+                    None=>panic_on_missing_fieldname::<
+                        VTableVal<'borr,$erased_ptr,$interf>,
+                    >(
+                        Self::field_index_for__iter_product,
+                        self._prefix_type_layout(),
+                    )
+                }
+            }
+
+            pub fn as_bytes(&self)->AsBytesFn
+            where
+                $interf:InterfaceBound<AsBytes=Implemented<trait_marker::AsBytes>>,
+            {
+                match self._as_bytes() {
+                    Some(v)=>v,
+                    None=>panic_on_missing_fieldname::<
+                        VTableVal<'borr,$erased_ptr,$interf>,
+                    >(
+                        Self::field_index_for__as_bytes,
+                        self._prefix_type_layout(),
+                    )
+                }
+            }
+
+            pub fn as_str(&self)->AsStrFn
+            where
+                $interf:InterfaceBound<AsStr=Implemented<trait_marker::AsStr>>,
+            {
+                match self._as_str() {
+                    Some(v)=>v,
+                    None=>panic_on_missing_fieldname::<
+                        VTableVal<'borr,$erased_ptr,$interf>,
+                    >(
+                        Self::field_index_for__as_str,
+                        self._prefix_type_layout(),
+                    )
+                }
+            }
+
+            pub fn extend(
+                &self
+            )->UnerasedExtendFn<'borr,I>
+            where
+                $interf:InterfaceBound<Extend=Implemented<trait_marker::Extend>>,
+                $interf:IteratorItemOrDefault<'borr>,
+            {
+                match self._extend() {
+                    Some(v)=>unsafe{
+                        std::mem::transmute::<ErasedExtendFn,UnerasedExtendFn<'borr,I>>(v)
+                    },
+                    None=>panic_on_missing_fieldname::<
+                        VTableVal<'borr,$erased_ptr,$interf>,
+                    >(
+                        Self::field_index_for__extend,
+                        self._prefix_type_layout(),
+                    )
+                }
+            }
+
+            /// Gets the function used to clone the pointer in `DynTrait::sabi_try_clone`,
+            /// which is `None` if the pointer can't be cloned.
+            pub fn try_clone_ptr(&self)->Option<unsafe extern "C" fn(&$erased_ptr)->$erased_ptr>
+            where
+                $interf:InterfaceBound,
+            {
+                self._try_clone_ptr()
+            }
+
+            /// Gets the traits that the interface of the vtable required,
+            /// in the dynamic library/executable that constructed it.
+            ///
+            /// This is `RNone` for vtables constructed by an older version of `abi_stable`.
+            pub fn enabled_traits(&self)->ROption<EnabledTraits>
+            where
+                $interf:InterfaceBound,
+            {
+                self._enabled_traits()
+            }
+
             pub fn back_iter(
                 &self
             )->DoubleEndedIteratorFns< <I as IteratorItemOrDefault<'borr>>::Item >
@@ -224,6 +428,19 @@ macro_rules! declare_meta_vtable {
         }
 
 
+        /// The type of the functions that reduce an iterator into a single item.
+        pub type UnerasedReductionFn<'borr,I>=
+            unsafe extern "C" fn(
+                &mut ErasedObject
+            )-><I as IteratorItemOrDefault<'borr>>::Item;
+
+        /// The type of the function that extends a collection with an iterator.
+        pub type UnerasedExtendFn<'borr,I>=
+            unsafe extern "C" fn(
+                &mut ErasedObject,
+                RIterator<'_,<I as IteratorItemOrDefault<'borr>>::Item>,
+            );
+
         pub type UnerasedSerializeFn<'s,I>=
             unsafe extern "C" fn(
                 &'s ErasedObject
@@ -335,6 +552,10 @@ macro_rules! declare_meta_vtable {
                 /// Marker type representing the trait of the same name.
                 pub struct $selector;
             )*
+            $(
+                /// Marker type representing the trait of the same name.
+                pub struct $trailing_trait;
+            )*
         }
 
 
@@ -363,6 +584,26 @@ macro_rules! declare_meta_vtable {
                     $interf,
                 >,
             )*
+            IteratorExtSelector:VTableFieldValue<
+                'borr,
+                ROption<StaticRef<IteratorExtFns<()>>>,
+                $interf::Iterator,
+                $value,
+                $erased_ptr,
+                $orig_ptr,
+                $interf,
+            >,
+            $(
+                trait_selector::$trailing_trait:VTableFieldValue<
+                    'borr,
+                    $trailing_field_ty,
+                    $interf::$trailing_trait,
+                    $value,
+                    $erased_ptr,
+                    $orig_ptr,
+                    $interf,
+                >,
+            )*
         {
             const TMP_VTABLE:VTableVal<'borr,$erased_ptr,$interf>=VTableVal{
                 type_info:This::INFO,
@@ -387,6 +628,84 @@ macro_rules! declare_meta_vtable {
                             >
                         >::FIELD,
                 )*
+                _iter_ext:
+                    <IteratorExtSelector as
+                        VTableFieldValue<
+                            ROption<StaticRef<IteratorExtFns<()>>>,
+                            $interf::Iterator,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _iter_sum:
+                    <trait_selector::IteratorSum as
+                        VTableFieldValue<
+                            Option<ErasedReductionFn>,
+                            $interf::IteratorSum,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _iter_product:
+                    <trait_selector::IteratorProduct as
+                        VTableFieldValue<
+                            Option<ErasedReductionFn>,
+                            $interf::IteratorProduct,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _try_clone_ptr:
+                    <trait_selector::Clone as
+                        VTableFieldValue<
+                            Option<unsafe extern "C" fn(&$erased_ptr)->$erased_ptr>,
+                            $interf::Clone,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _enabled_traits:RSome(<$interf as InterfaceBound>::EXTRA_CHECKS),
+                _as_bytes:
+                    <trait_selector::AsBytes as
+                        VTableFieldValue<
+                            Option<AsBytesFn>,
+                            $interf::AsBytes,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _as_str:
+                    <trait_selector::AsStr as
+                        VTableFieldValue<
+                            Option<AsStrFn>,
+                            $interf::AsStr,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
+                _extend:
+                    <trait_selector::Extend as
+                        VTableFieldValue<
+                            Option<ErasedExtendFn>,
+                            $interf::Extend,
+                            $value,
+                            $erased_ptr,
+                            $orig_ptr,
+                            $interf,
+                        >
+                    >::FIELD,
                 _marker:PhantomData,
             };
 
@@ -410,6 +729,11 @@ macro_rules! declare_meta_vtable {
                 /// this is associated with is disabled.
                 const $selector:bool; 
             )*
+            $( 
+                /// Used by the `StableAbi` derive macro to determine whether the field 
+                /// this is associated with is disabled.
+                const $trailing_trait:bool; 
+            )*
 
         }
 
@@ -428,6 +752,7 @@ macro_rules! declare_meta_vtable {
             $( I::$auto_trait:IsImplemented, )*
             $( I::$marker_trait:IsImplemented, )*
             $( I::$selector:IsImplemented, )*
+            $( I::$trailing_trait:IsImplemented, )*
         {
             const EXTRA_CHECKS:EnabledTraits=EnabledTraits{
                 
@@ -457,12 +782,21 @@ macro_rules! declare_meta_vtable {
                             enabled_traits::regular_trait_mask::$selector
                         )|
                     )*
+                    $(
+                        if_u64(
+                            <I::$trailing_trait as IsImplemented>::VALUE,
+                            enabled_traits::regular_trait_mask::$trailing_trait
+                        )|
+                    )*
                     0,
             };
 
             $( 
                 const $selector:bool=<I::$selector as IsImplemented>::VALUE;
             )*
+            $( 
+                const $trailing_trait:bool=<I::$trailing_trait as IsImplemented>::VALUE;
+            )*
             
             const __InterfaceBound_BLANKET_IMPL:PrivStruct<Self>=
                 PrivStruct(PhantomData);
@@ -491,6 +825,7 @@ macro_rules! declare_meta_vtable {
                 regular_traits[
                     $($marker_trait,)*
                     $($selector,)*
+                    $($trailing_trait,)*
                 ]
             }
         }
@@ -520,6 +855,17 @@ declare_meta_vtable! {
         ]
     ]
 
+    // These traits have their vtable fields declared after the fields of the other traits,
+    // and their bits in `EnabledTraits` after every other trait,
+    // so that adding them didn't change the layout of older vtables.
+    trailing_traits[
+        IteratorSum:Option<ErasedReductionFn>,
+        IteratorProduct:Option<ErasedReductionFn>,
+        AsBytes:Option<AsBytesFn>,
+        AsStr:Option<AsStrFn>,
+        Extend:Option<ErasedExtendFn>,
+    ]
+
     [
         #[sabi(accessible_if="<I as InterfaceBound>::Clone")]
         clone_ptr:    unsafe extern "C" fn(&ErasedPtr)->ErasedPtr;
@@ -767,6 +1113,279 @@ declare_meta_vtable! {
 //////////////
 
 
+/// Selects the value of the vtable field for the iterator methods in `IteratorExtFns`,
+/// which are only available if the `Iterator` trait is enabled in the interface.
+#[doc(hidden)]
+pub struct IteratorExtSelector;
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        ROption<AnyFieldTy>,
+        Unimplemented<trait_marker::Iterator>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for IteratorExtSelector
+{
+    const FIELD:ROption<AnyFieldTy>=RNone;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        ROption<StaticRef<IteratorExtFns<()>>>,
+        Implemented<trait_marker::Iterator>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for IteratorExtSelector
+where
+    T:Iterator,
+    I:IteratorItemOrDefault<'borr,Item=<T as Iterator>::Item>,
+{
+    const FIELD:ROption<StaticRef<IteratorExtFns<()>>>=RSome(MakeIteratorExtFns::<T>::NEW);
+}
+
+
+//////////////
+
+
+/// The type-erased version of `UnerasedReductionFn`.
+#[doc(hidden)]
+pub type ErasedReductionFn=unsafe extern "C" fn(&mut ErasedObject)->ErasedObject;
+
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AnyFieldTy>,
+        Unimplemented<trait_marker::IteratorSum>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::IteratorSum
+{
+    const FIELD:Option<AnyFieldTy>=None;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<ErasedReductionFn>,
+        Implemented<trait_marker::IteratorSum>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::IteratorSum
+where
+    T:Iterator,
+    <T as Iterator>::Item:std::iter::Sum,
+    I:IteratorItemOrDefault<'borr,Item=<T as Iterator>::Item>,
+{
+    const FIELD:Option<ErasedReductionFn>=Some(unsafe{
+        Transmuter::<
+            unsafe extern "C" fn(&mut ErasedObject)-><T as Iterator>::Item,
+            ErasedReductionFn,
+        >{
+            from:iterator_sum::<T>
+        }.to
+    });
+}
+
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AnyFieldTy>,
+        Unimplemented<trait_marker::IteratorProduct>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::IteratorProduct
+{
+    const FIELD:Option<AnyFieldTy>=None;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<ErasedReductionFn>,
+        Implemented<trait_marker::IteratorProduct>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::IteratorProduct
+where
+    T:Iterator,
+    <T as Iterator>::Item:std::iter::Product,
+    I:IteratorItemOrDefault<'borr,Item=<T as Iterator>::Item>,
+{
+    const FIELD:Option<ErasedReductionFn>=Some(unsafe{
+        Transmuter::<
+            unsafe extern "C" fn(&mut ErasedObject)-><T as Iterator>::Item,
+            ErasedReductionFn,
+        >{
+            from:iterator_product::<T>
+        }.to
+    });
+}
+
+
+//////////////
+
+
+/// The type of the function used by `DynTrait::sabi_as_bytes`.
+pub type AsBytesFn=unsafe extern "C" fn(&ErasedObject)->RSlice<'_,u8>;
+
+/// The type of the function used by `DynTrait::sabi_as_str`.
+pub type AsStrFn=unsafe extern "C" fn(&ErasedObject)->RStr<'_>;
+
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AnyFieldTy>,
+        Unimplemented<trait_marker::AsBytes>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::AsBytes
+{
+    const FIELD:Option<AnyFieldTy>=None;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AsBytesFn>,
+        Implemented<trait_marker::AsBytes>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::AsBytes
+where
+    T:AsRef<[u8]>,
+{
+    const FIELD:Option<AsBytesFn>=Some(unsafe{
+        Transmuter::<
+            for<'a> unsafe extern "C" fn(&'a ErasedObject<T>)->RSlice<'a,u8>,
+            AsBytesFn,
+        >{
+            from:as_bytes_impl::<T>
+        }.to
+    });
+}
+
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AnyFieldTy>,
+        Unimplemented<trait_marker::AsStr>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::AsStr
+{
+    const FIELD:Option<AnyFieldTy>=None;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AsStrFn>,
+        Implemented<trait_marker::AsStr>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::AsStr
+where
+    T:AsRef<str>,
+{
+    const FIELD:Option<AsStrFn>=Some(unsafe{
+        Transmuter::<
+            for<'a> unsafe extern "C" fn(&'a ErasedObject<T>)->RStr<'a>,
+            AsStrFn,
+        >{
+            from:as_str_impl::<T>
+        }.to
+    });
+}
+
+
+//////////////
+
+
+/// The type-erased version of `UnerasedExtendFn`.
+#[doc(hidden)]
+pub type ErasedExtendFn=unsafe extern "C" fn(&mut ErasedObject,RIterator<'_,()>);
+
+
+impl<'borr,AnyFieldTy,T,ErasedPtr,OrigP,I>
+    VTableFieldValue<
+        'borr,
+        Option<AnyFieldTy>,
+        Unimplemented<trait_marker::Extend>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::Extend
+{
+    const FIELD:Option<AnyFieldTy>=None;
+}
+
+impl<'borr,T,ErasedPtr,OrigP,I,Item>
+    VTableFieldValue<
+        'borr,
+        Option<ErasedExtendFn>,
+        Implemented<trait_marker::Extend>,
+        T,
+        ErasedPtr,
+        OrigP,
+        I,
+    >
+for trait_selector::Extend
+where
+    T:Extend<Item>,
+    I:IteratorItemOrDefault<'borr,Item=Item>,
+{
+    const FIELD:Option<ErasedExtendFn>=Some(unsafe{
+        Transmuter::<
+            unsafe extern "C" fn(&mut ErasedObject,RIterator<'_,Item>),
+            ErasedExtendFn,
+        >{
+            from:extend_impl::<T,Item>
+        }.to
+    });
+}
+
+
+//////////////
+
+
 /// Used to prevent InterfaceBound being implemented outside this module,
 /// since it is only constructed in the impl of InterfaceBound in this module.
 #[doc(hidden)]
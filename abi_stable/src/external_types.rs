@@ -22,7 +22,7 @@ pub mod serde_json;
 
 
 pub use self::{
-    parking_lot::{RMutex,RRwLock,ROnce},
+    parking_lot::{RMutex,RRwLock,ROnce,RLazy},
 };
     
 
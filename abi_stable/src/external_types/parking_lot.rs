@@ -4,6 +4,7 @@ Ffi-safe synchronization primitives,most of which are ffi-safe wrappers of
 */
 
 pub mod once;
+pub mod lazy;
 pub mod mutex;
 pub mod rw_lock;
 
@@ -11,6 +12,7 @@ pub use self::{
     mutex::RMutex,
     rw_lock::RRwLock,
     once::ROnce,
+    lazy::RLazy,
 };
 
 
@@ -0,0 +1,228 @@
+//! Contains `RLazy`,a lazily initialized value built on top of `ROnce`.
+use std::{
+    cell::UnsafeCell,
+    fmt::{self,Debug},
+};
+
+use super::ROnce;
+
+use crate::std_types::{ROption,RSome,RNone};
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/**
+A value that is initialized at most once,the first time `get_or_init` is called.
+
+The initialization is synchronized with an `ROnce`,
+so that it is only done once even if multiple threads try to initialize it at the same time.
+
+# Example
+
+```
+use abi_stable::{
+    external_types::RLazy,
+    std_types::RString,
+};
+
+static CONFIG:RLazy<RString>=RLazy::new();
+
+let guards=
+    std::iter::repeat_with(||{
+        std::thread::spawn(||{
+            CONFIG.get_or_init(|| RString::from("plugins=3") ).clone()
+        })
+    })
+    .take(10)
+    .collect::<Vec<_>>();
+
+for guard in guards{
+    assert_eq!( guard.join().unwrap().as_str(), "plugins=3" );
+}
+
+assert_eq!( CONFIG.get().map(|s| s.as_str() ), Some("plugins=3") );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RLazy<T>{
+    once:ROnce,
+    value:UnsafeCell<ROption<T>>,
+}
+
+impl<T> RLazy<T>{
+    /// Constructs an uninitialized RLazy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RLazy;
+    ///
+    /// static LAZY:RLazy<Vec<u32>>=RLazy::new();
+    ///
+    /// let lazy=RLazy::<String>::new();
+    ///
+    /// ```
+    pub const fn new()->Self{
+        Self{
+            once:ROnce::NEW,
+            value:UnsafeCell::new(RNone),
+        }
+    }
+
+    /// Gets a reference to the value,
+    /// initializing it with `f` if it wasn't already initialized.
+    ///
+    /// If multiple threads call this method at the same time,
+    /// only one of the closures runs,
+    /// and the other threads block until it finishes.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics,the panic is propagated and this RLazy becomes poisoned,
+    /// causing every later call to this method to panic.
+    ///
+    /// Calling this method on the same RLazy from inside `f` deadlocks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RLazy;
+    ///
+    /// let lazy=RLazy::new();
+    ///
+    /// assert_eq!( lazy.get_or_init(|| 3 ), &3 );
+    /// assert_eq!( lazy.get_or_init(|| 5 ), &3 );
+    ///
+    /// ```
+    pub fn get_or_init<F>(&self,f:F)->&T
+    where
+        F:FnOnce()->T
+    {
+        self.once.call_once(||unsafe{
+            *self.value.get()=RSome(f());
+        });
+        self.get().expect("bug:RLazy must be initialized after ROnce::call_once returns")
+    }
+
+    /// Gets a reference to the value if it was already initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RLazy;
+    ///
+    /// let lazy=RLazy::new();
+    ///
+    /// assert_eq!( lazy.get(), None );
+    /// lazy.get_or_init(|| "hello" );
+    /// assert_eq!( lazy.get(), Some(&"hello") );
+    ///
+    /// ```
+    pub fn get(&self)->Option<&T>{
+        if self.once.state().done() {
+            unsafe{ (*self.value.get()).as_ref().into_option() }
+        }else{
+            None
+        }
+    }
+
+    /// Unwraps this RLazy into the value,if it was initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::external_types::RLazy;
+    ///
+    /// let lazy=RLazy::new();
+    /// lazy.get_or_init(|| vec![3,5,8] );
+    /// assert_eq!( lazy.into_inner(), Some(vec![3,5,8]) );
+    ///
+    /// assert_eq!( RLazy::<u32>::new().into_inner(), None );
+    ///
+    /// ```
+    pub fn into_inner(self)->Option<T>{
+        self.value.into_inner().into_option()
+    }
+}
+
+impl<T> Default for RLazy<T>{
+    #[inline]
+    fn default()->Self{
+        Self::new()
+    }
+}
+
+impl<T> Debug for RLazy<T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_struct("RLazy")
+         .field("value", &self.get())
+         .finish()
+    }
+}
+
+unsafe impl<T:Send> Send for RLazy<T>{}
+unsafe impl<T:Send+Sync> Sync for RLazy<T>{}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests{
+    use super::*;
+
+    use std::{
+        sync::atomic::{AtomicUsize,Ordering},
+        thread,
+        time::Duration,
+    };
+
+    use crossbeam_utils::thread::scope as scoped_thread;
+
+    use abi_stable_shared::{file_span,test_utils::{must_panic}};
+
+    #[test]
+    fn initializes_once_under_contention(){
+        static LAZY:RLazy<usize>=RLazy::new();
+        static CALLS:AtomicUsize=AtomicUsize::new(0);
+
+        scoped_thread(|scope|{
+            for i in 0..16 {
+                scope.spawn(move|_|{
+                    let value=LAZY.get_or_init(||{
+                        CALLS.fetch_add(1,Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(50));
+                        100+i
+                    });
+                    assert!(100<=*value && *value<116);
+                    *value
+                });
+            }
+        }).unwrap();
+
+        assert_eq!(CALLS.load(Ordering::SeqCst),1);
+
+        let value=*LAZY.get().unwrap();
+        assert_eq!(LAZY.get_or_init(|| panic!("this should not run") ),&value);
+    }
+
+    #[test]
+    fn poisoned(){
+        let lazy=RLazy::<u32>::new();
+        must_panic(file_span!(),||{
+            lazy.get_or_init(|| panic!() );
+        }).unwrap();
+        assert_eq!(lazy.get(),None);
+        must_panic(file_span!(),||{
+            lazy.get_or_init(|| 10 );
+        }).unwrap();
+        assert_eq!(lazy.into_inner(),None);
+    }
+}
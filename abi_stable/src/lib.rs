@@ -133,6 +133,9 @@ These are the kinds of types passed through FFI:
     The method by which *vtables* and *modules* are implemented,
     allowing extending them in minor versions of a library.
 
+- [RFlags derive macro](./docs/rflags/index.html):<br>
+    For declaring ffi-safe bitflag types,wrapping an integer.
+
 
 */
 
@@ -150,6 +153,10 @@ These are the kinds of types passed through FFI:
     all(nightly_rust,feature="nightly_const_params"),
     feature(const_generics)
 )]
+#![cfg_attr(
+    all(feature="nightly_rust",feature="nightly_try"),
+    feature(try_trait_v2,try_trait_v2_residual)
+)]
 
 #[allow(unused_imports)]
 #[cfg(test)]
@@ -168,6 +175,7 @@ extern crate self as abi_stable;
 pub use abi_stable_derive::{
     StableAbi,
     GetStaticEquivalent,
+    RFlags,
 };
 
 #[doc(inline)]
@@ -281,7 +289,7 @@ static EXECUTABLE_IDENTITY: AtomicUsize = AtomicUsize::new(1);
 
 #[doc(inline)]
 pub use crate::{
-    abi_stability::StableAbi,
+    abi_stability::{StableAbi,check_layout_strict},
     erased_types::{DynTrait,ImplType, InterfaceType},
 };
 
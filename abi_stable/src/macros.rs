@@ -986,6 +986,14 @@ macro_rules! delegate_interface_serde {
                     >
                 >::deserialize_enum(s)
             }
+
+            fn proxy_input(s: &Self::Proxy) -> Option<&str> {
+                <$delegates_to as 
+                    $crate::nonexhaustive_enum::DeserializeEnum<
+                        $crate::nonexhaustive_enum::NonExhaustive<$this,S,I>
+                    >
+                >::proxy_input(s)
+            }
         }
     )
 }
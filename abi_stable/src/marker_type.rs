@@ -2,7 +2,13 @@
 Zero-sized types .
 */
 
-use std::{cell::Cell,marker::PhantomData, rc::Rc};
+use std::{
+    cell::Cell,
+    fmt,
+    hash::{Hash,Hasher},
+    marker::PhantomData,
+    rc::Rc,
+};
 
 use crate::{
     derive_macro_reexports::*,
@@ -202,3 +208,188 @@ where
     const S_LAYOUT: &'static TypeLayout = 
         <PhantomData<T> as SharedStableAbi>::S_LAYOUT;
 }
+
+
+//////////////////////////////////////////////////////////////
+
+/**
+An ffi-safe equivalent of `PhantomData`,whose variance is chosen with the `V` type parameter.
+
+`V` can be any of:
+
+- [`Covariant<T>`](./struct.Covariant.html):
+Covariant over `T`,like `PhantomData<fn()->T>`.
+
+- [`Contravariant<T>`](./struct.Contravariant.html):
+Contravariant over `T`,like `PhantomData<fn(T)>`.
+
+- [`Invariant<T>`](./struct.Invariant.html):
+Invariant over `T`,like `PhantomData<fn(T)->T>`.
+
+Unlike `PhantomData<T>`,this type is always `Send+Sync`,
+and does not tell the compiler that the type containing it owns a `T`
+(so it doesn't affect drop checking).
+
+The layout of a `RPhantomData<V>` is that of a `PhantomData<T>`,
+a zero-sized field which is compared like any other type parameter in the layout,
+since variance only has an effect at compile-time.
+
+# Example
+
+```
+use abi_stable::{
+    marker_type::{Covariant,Invariant,RPhantomData},
+    StableAbi,
+};
+
+/// A handle to an element of an arena,
+/// which can't outlive the arena that it was created from.
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct Handle<'a>{
+    index:usize,
+    _marker:RPhantomData<Covariant<&'a ()>>,
+}
+
+/// A reference to a `Cell`-like type,which must be invariant.
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct CellRef<'a,T:'a>{
+    address:usize,
+    _marker:RPhantomData<Invariant<&'a T>>,
+}
+
+fn shorten<'a>(handle:Handle<'static>)->Handle<'a>{
+    handle
+}
+
+let handle=shorten(Handle{ index:0, _marker:RPhantomData::NEW });
+assert_eq!( handle.index, 0 );
+
+assert_eq!( std::mem::size_of::<RPhantomData<Invariant<String>>>(), 0 );
+
+```
+
+*/
+#[repr(C)]
+pub struct RPhantomData<V:?Sized>{
+    _priv: [u8;0],
+    _marker: PhantomData<V>,
+}
+
+impl<V:?Sized> RPhantomData<V>{
+    /// Constructs an `RPhantomData`
+    pub const NEW:Self=Self{
+        _priv:[],
+        _marker:PhantomData,
+    };
+}
+
+impl<V:?Sized> Copy for RPhantomData<V>{}
+
+impl<V:?Sized> Default for RPhantomData<V>{
+    #[inline(always)]
+    fn default()->Self{
+        Self::NEW
+    }
+}
+
+impl<V:?Sized> Clone for RPhantomData<V>{
+    #[inline(always)]
+    fn clone(&self)->Self{
+        *self
+    }
+}
+
+impl<V:?Sized> fmt::Debug for RPhantomData<V>{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.write_str("RPhantomData")
+    }
+}
+
+impl<V:?Sized> PartialEq for RPhantomData<V>{
+    #[inline(always)]
+    fn eq(&self,_:&Self)->bool{
+        true
+    }
+}
+
+impl<V:?Sized> Eq for RPhantomData<V>{}
+
+impl<V:?Sized> Hash for RPhantomData<V>{
+    #[inline(always)]
+    fn hash<H:Hasher>(&self,_:&mut H){}
+}
+
+unsafe impl<V> GetStaticEquivalent_ for RPhantomData<V> 
+where
+    V:PhantomVariance+?Sized,
+    PhantomData<V::Type>:GetStaticEquivalent_
+{
+    type StaticEquivalent=GetStaticEquivalent<PhantomData<V::Type>>;
+}
+
+unsafe impl<V> SharedStableAbi for RPhantomData<V> 
+where
+    V:PhantomVariance+?Sized,
+    PhantomData<V::Type>:SharedStableAbi
+{
+    type IsNonZeroType = False;
+    type Kind=ValueKind;
+
+
+    const S_LAYOUT: &'static TypeLayout = 
+        <PhantomData<V::Type> as SharedStableAbi>::S_LAYOUT;
+}
+
+
+/// The variance markers that can be used as the type parameter of `RPhantomData`.
+///
+/// This trait is sealed,it can't be implemented outside of `abi_stable`.
+pub trait PhantomVariance:variance_sealed::Sealed{
+    /// The type that the variance applies to.
+    type Type:?Sized;
+}
+
+mod variance_sealed{
+    pub trait Sealed{}
+}
+
+
+/// Marks `RPhantomData` as being covariant over `T`.
+///
+/// This means that an `RPhantomData<Covariant<&'static str>>` 
+/// can be coerced to an `RPhantomData<Covariant<&'a str>>`.
+pub struct Covariant<T:?Sized>{
+    _marker:PhantomData<extern "C" fn()->T>,
+}
+
+/// Marks `RPhantomData` as being contravariant over `T`.
+///
+/// This means that an `RPhantomData<Contravariant<&'a str>>` 
+/// can be coerced to an `RPhantomData<Contravariant<&'static str>>`.
+pub struct Contravariant<T:?Sized>{
+    _marker:PhantomData<extern "C" fn(&T)>,
+}
+
+/// Marks `RPhantomData` as being invariant over `T`.
+///
+/// This means that an `RPhantomData<Invariant<&'a str>>` 
+/// can't be coerced to use any lifetime other than `'a`.
+pub struct Invariant<T:?Sized>{
+    _marker:PhantomData<extern "C" fn(&T)->&T>,
+}
+
+macro_rules! impl_phantom_variance {
+    ( $($variance:ident),* ) => (
+        $(
+            impl<T:?Sized> variance_sealed::Sealed for $variance<T>{}
+
+            impl<T:?Sized> PhantomVariance for $variance<T>{
+                type Type=T;
+            }
+        )*
+    )
+}
+
+impl_phantom_variance!{ Covariant, Contravariant, Invariant }
@@ -3,4 +3,6 @@
 
 mod impl_interfacetype_attr;
 
-mod impl_interfacetype_macro;
\ No newline at end of file
+mod impl_interfacetype_macro;
+
+mod rflags;
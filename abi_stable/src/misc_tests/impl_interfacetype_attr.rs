@@ -21,7 +21,10 @@ use crate::{
     Eq,PartialEq,Ord,PartialOrd,Hash,
     Iterator,DoubleEndedIterator,
     FmtWrite,
-    IoWrite,IoSeek,IoRead,IoBufRead,Error
+    IoWrite,IoSeek,IoRead,IoBufRead,Error,
+    IteratorSum,IteratorProduct,
+    AsBytes,AsStr,
+    Extend
 ))]
 pub struct AllTraitsImpld;
 
@@ -48,6 +51,11 @@ fn assert_all_traits_impld(){
     let _:<AllTraitsImpld as InterfaceType>::IoRead             =Implemented::NEW;
     let _:<AllTraitsImpld as InterfaceType>::IoBufRead          =Implemented::NEW;
     let _:<AllTraitsImpld as InterfaceType>::Error              =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::IteratorSum        =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::IteratorProduct    =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::AsBytes            =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::AsStr              =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::Extend             =Implemented::NEW;
 }
 
 
@@ -85,6 +93,11 @@ fn assert_all_traits_unimpld(){
     let _:<NoTraitsImpld<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<NoTraitsImpld<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<NoTraitsImpld<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -121,6 +134,11 @@ fn assert_fmt_traits_impld(){
     let _:<FmtInterface<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<FmtInterface<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<FmtInterface<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -157,6 +175,11 @@ fn assert_hash_ord_impld(){
     let _:<HashOrdInterface<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<HashOrdInterface<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<HashOrdInterface<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<HashOrdInterface<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<HashOrdInterface<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<HashOrdInterface<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<HashOrdInterface<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<HashOrdInterface<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -193,6 +216,11 @@ fn assert_only_eq(){
     let _:<OnlyEq as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<OnlyEq as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<OnlyEq as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<OnlyEq as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<OnlyEq as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<OnlyEq as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<OnlyEq as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<OnlyEq as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -228,6 +256,11 @@ fn assert_only_partial_ord(){
     let _:<OnlyPartialOrd as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<OnlyPartialOrd as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<OnlyPartialOrd as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<OnlyPartialOrd as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<OnlyPartialOrd as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<OnlyPartialOrd as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<OnlyPartialOrd as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<OnlyPartialOrd as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -263,6 +296,11 @@ fn assert_only_error(){
     let _:<OnlyError as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<OnlyError as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<OnlyError as InterfaceType>::Error              =Implemented::NEW;
+    let _:<OnlyError as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<OnlyError as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<OnlyError as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<OnlyError as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<OnlyError as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -299,6 +337,11 @@ fn assert_only_iter(){
     let _:<OnlyIter as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<OnlyIter as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<OnlyIter as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<OnlyIter as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<OnlyIter as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<OnlyIter as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<OnlyIter as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<OnlyIter as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -334,6 +377,11 @@ fn assert_only_de_iter(){
     let _:<OnlyDEIter as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<OnlyDEIter as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<OnlyDEIter as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<OnlyDEIter as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<OnlyDEIter as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<OnlyDEIter as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<OnlyDEIter as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<OnlyDEIter as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
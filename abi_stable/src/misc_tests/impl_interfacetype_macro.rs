@@ -43,6 +43,11 @@ impl_InterfaceType!{
         type IoRead=True;
         type IoBufRead=True;
         type Error=True;
+        type IteratorSum=True;
+        type IteratorProduct=True;
+        type AsBytes=True;
+        type AsStr=True;
+        type Extend=True;
     }
 }
 
@@ -70,6 +75,11 @@ fn assert_all_traits_impld(){
     let _:<AllTraitsImpld as InterfaceType>::IoRead             =Implemented::NEW;
     let _:<AllTraitsImpld as InterfaceType>::IoBufRead          =Implemented::NEW;
     let _:<AllTraitsImpld as InterfaceType>::Error              =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::IteratorSum        =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::IteratorProduct    =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::AsBytes            =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::AsStr              =Implemented::NEW;
+    let _:<AllTraitsImpld as InterfaceType>::Extend             =Implemented::NEW;
 }
 
 
@@ -109,6 +119,11 @@ fn assert_all_traits_unimpld(){
     let _:<NoTraitsImpld<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<NoTraitsImpld<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<NoTraitsImpld<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<NoTraitsImpld<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -153,6 +168,11 @@ fn assert_fmt_traits_impld(){
     let _:<FmtInterface<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<FmtInterface<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<FmtInterface<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<FmtInterface<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
 
 
@@ -197,4 +217,9 @@ fn assert_hash_eq_impld(){
     let _:<HashEqInterface<()> as InterfaceType>::IoRead             =Unimplemented::NEW;
     let _:<HashEqInterface<()> as InterfaceType>::IoBufRead          =Unimplemented::NEW;
     let _:<HashEqInterface<()> as InterfaceType>::Error              =Unimplemented::NEW;
+    let _:<HashEqInterface<()> as InterfaceType>::IteratorSum        =Unimplemented::NEW;
+    let _:<HashEqInterface<()> as InterfaceType>::IteratorProduct    =Unimplemented::NEW;
+    let _:<HashEqInterface<()> as InterfaceType>::AsBytes            =Unimplemented::NEW;
+    let _:<HashEqInterface<()> as InterfaceType>::AsStr              =Unimplemented::NEW;
+    let _:<HashEqInterface<()> as InterfaceType>::Extend             =Unimplemented::NEW;
 }
\ No newline at end of file
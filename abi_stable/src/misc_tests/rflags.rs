@@ -0,0 +1,111 @@
+use crate::{
+    abi_stability::check_layout_compatibility,
+    type_layout::ReprAttr,
+    RFlags,
+    StableAbi,
+};
+
+
+#[repr(transparent)]
+#[derive(StableAbi,RFlags,Debug,Copy,Clone,PartialEq,Eq)]
+pub struct Flags(u32);
+
+impl Flags{
+    const A:Self=Self(1);
+    const B:Self=Self(2);
+    const C:Self=Self(4);
+    const HIGH:Self=Self(1<<31);
+}
+
+
+#[repr(transparent)]
+#[derive(StableAbi,RFlags,Debug,Copy,Clone,PartialEq,Eq)]
+pub struct SmallFlags(u8);
+
+
+#[test]
+fn constructors_accessors(){
+    const EMPTY:Flags=Flags::empty();
+    const FROM_BITS:Flags=Flags::from_bits(5);
+    const BITS:u32=FROM_BITS.bits();
+    const CONTAINS:bool=FROM_BITS.contains(Flags::C);
+
+    assert_eq!(EMPTY.bits(),0);
+    assert!(EMPTY.is_empty());
+    assert_eq!(FROM_BITS,Flags::A|Flags::C);
+    assert!(!FROM_BITS.is_empty());
+    assert_eq!(BITS,5);
+    assert!(CONTAINS);
+    assert_eq!(Flags::HIGH.bits(),0x8000_0000);
+}
+
+#[test]
+fn queries(){
+    let ab=Flags::A|Flags::B;
+
+    assert!(ab.contains(Flags::A));
+    assert!(ab.contains(Flags::B));
+    assert!(ab.contains(ab));
+    assert!(ab.contains(Flags::empty()));
+    assert!(!ab.contains(Flags::C));
+    assert!(!ab.contains(Flags::A|Flags::C));
+
+    assert!(ab.intersects(Flags::A|Flags::C));
+    assert!(!ab.intersects(Flags::C|Flags::HIGH));
+    assert!(!ab.intersects(Flags::empty()));
+}
+
+#[test]
+fn mutators(){
+    let mut flags=Flags::empty();
+
+    flags.insert(Flags::A|Flags::HIGH);
+    assert_eq!(flags,Flags::A|Flags::HIGH);
+    flags.insert(Flags::A);
+    assert_eq!(flags,Flags::A|Flags::HIGH);
+
+    flags.remove(Flags::HIGH|Flags::B);
+    assert_eq!(flags,Flags::A);
+
+    flags.toggle(Flags::A|Flags::C);
+    assert_eq!(flags,Flags::C);
+
+    flags.set(Flags::B,true);
+    assert_eq!(flags,Flags::B|Flags::C);
+    flags.set(Flags::C,false);
+    assert_eq!(flags,Flags::B);
+}
+
+#[test]
+fn operators(){
+    let ab=Flags::A|Flags::B;
+    let bc=Flags::B|Flags::C;
+
+    assert_eq!(ab|bc,Flags::from_bits(0b111));
+    assert_eq!(ab&bc,Flags::B);
+    assert_eq!(ab^bc,Flags::A|Flags::C);
+    assert_eq!(!ab,Flags::from_bits(!0b11));
+    assert_eq!(!SmallFlags::empty(),SmallFlags::from_bits(0xFF));
+
+    let mut flags=ab;
+    flags|=Flags::C;
+    assert_eq!(flags,Flags::from_bits(0b111));
+    flags&=bc;
+    assert_eq!(flags,bc);
+    flags^=Flags::B;
+    assert_eq!(flags,Flags::C);
+}
+
+#[test]
+fn layout_is_the_integer(){
+    let layout=<Flags as StableAbi>::LAYOUT;
+    let u32_layout=<u32 as StableAbi>::LAYOUT;
+    assert_eq!(layout.repr_attr(),ReprAttr::Transparent);
+    assert_eq!(layout.size(),u32_layout.size());
+    assert_eq!(layout.alignment(),u32_layout.alignment());
+
+    assert!(check_layout_compatibility(layout,layout).is_ok());
+    assert!(
+        check_layout_compatibility(layout,<SmallFlags as StableAbi>::LAYOUT).is_err()
+    );
+}
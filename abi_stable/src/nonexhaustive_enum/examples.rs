@@ -395,5 +395,9 @@ pub(crate) mod codecs{
                 .map(NonExhaustive::with_storage_and_interface)
                 .map_err(RBoxError::new)
         }
+
+        fn proxy_input(s: &RString) -> Option<&str> {
+            Some(s.as_str())
+        }
     }
 }
\ No newline at end of file
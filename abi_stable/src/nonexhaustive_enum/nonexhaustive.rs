@@ -18,6 +18,7 @@ use crate::{
         trait_objects::{
             HasherObject,
         },
+        DeserializeDynError,
         InterfaceBound,
     },
     inline_storage::ScratchSpace,
@@ -730,6 +731,10 @@ impl<E,S,I> NonExhaustive<E,S,I>{
     }
 
     /// Deserializes a `NonExhaustive<_>` from a proxy.
+    ///
+    /// Errors returned by `<I as DeserializeEnum<_>>::deserialize_enum` are wrapped in a 
+    /// [`DeserializeDynError`](../erased_types/struct.DeserializeDynError.html),
+    /// which says what interface type was being deserialized.
     pub fn deserialize_from_proxy<'borr>(proxy: I::Proxy) -> Result<Self, RBoxError>
     where
         I: InterfaceBound<Deserialize= Implemented<trait_marker::Deserialize>>,
@@ -737,7 +742,9 @@ impl<E,S,I> NonExhaustive<E,S,I>{
         I::Proxy:'borr,
         E:GetEnumInfo,
     {
+        let input=DeserializeDynError::truncate_input(I::proxy_input(&proxy));
         I::deserialize_enum(proxy)
+            .map_err(|e| RBoxError::new(DeserializeDynError::new::<I>(input,e)) )
     }
 
 }
@@ -788,7 +795,9 @@ where
             Deserialize
         >::deserialize(deserializer)?;
 
-        I::deserialize_enum(s).map_err(de::Error::custom)
+        let input=DeserializeDynError::truncate_input(I::proxy_input(&s));
+        I::deserialize_enum(s)
+            .map_err(|e| de::Error::custom(DeserializeDynError::new::<I>(input,e)) )
     }
 }
 
@@ -425,3 +425,40 @@ fn serde_test() {
 }
 
 
+#[test]
+fn deserialize_error_context() {
+    use self::command_serde::{Foo as FooC, Foo_Interface};
+    use crate::erased_types::DeserializeDynError;
+
+    let inner_err=serde_json::from_str::<FooC>("oinoiasnd").unwrap_err();
+    let interface_name=crate::utils::get_type_name::<Foo_Interface>();
+
+    let err=NonExhaustiveFor::<FooC>::deserialize_from_proxy("oinoiasnd".into()).unwrap_err();
+    {
+        let err=err.downcast_ref::<DeserializeDynError>().unwrap();
+        assert_eq!(err.inner().to_string(), inner_err.to_string());
+        assert_eq!(err.interface_name(), interface_name.as_str());
+        assert_eq!(err.input(), Some("oinoiasnd"));
+        #[cfg(feature="rust_1_38")]
+        assert!(err.interface_name().contains("Foo_Interface"), "{}", err.interface_name());
+    }
+    assert_eq!(
+        err.to_string(),
+        format!("failed to deserialize {} from `oinoiasnd`: {}", interface_name, inner_err),
+    );
+
+    let serde_err=serde_json::from_str::<NonExhaustiveFor<FooC>>(r#" "oinoiasnd" "#)
+        .unwrap_err();
+    assert!(
+        serde_err.to_string()
+            .starts_with(&format!(
+                "failed to deserialize {} from `oinoiasnd`: {}",
+                interface_name,
+                inner_err,
+            )),
+        "{}",
+        serde_err,
+    );
+}
+
+
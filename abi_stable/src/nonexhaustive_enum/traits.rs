@@ -258,6 +258,17 @@ pub trait DeserializeEnum<'borr,NE> {
 
     /// Deserializes an enum from its proxy type.
     fn deserialize_enum(s: Self::Proxy) -> Result<NE, RBoxError>;
+
+    /// Gets the text that the proxy contains,
+    /// which is shown in the `DeserializeDynError` returned when 
+    /// `DeserializeEnum::deserialize_enum` fails.
+    ///
+    /// This returns `None` by default,
+    /// so that the error message doesn't show the input.
+    fn proxy_input(proxy: &Self::Proxy) -> Option<&str> {
+        let _=proxy;
+        None
+    }
 }
 
 #[doc(hidden)]
@@ -109,6 +109,26 @@ impl PointerKindVariant for PK_SmartPointer{
     const VALUE:PointerKind=PointerKind::SmartPointer;
 }
 
+
+/**
+The type-level pointer kinds for which `DynTrait::default` can be called,
+which is only `PK_SmartPointer`.
+
+A reborrowed `DynTrait` (one returned by `reborrow`/`reborrow_mut`)
+uses a reference as its pointer,
+so `default` must be called on the owning `DynTrait` instead.
+*/
+#[cfg_attr(feature="rust_1_78",diagnostic::on_unimplemented(
+    message="cannot call `default` on a reborrowed DynTrait; call it on the owning object",
+    label="`default` requires the DynTrait to own its pointer,this is a `{Self}` pointer",
+    note="`DynTrait::default` can only be called on DynTraits that use smart pointers,\
+          like `RBox<_>` or `RArc<_>`.",
+))]
+pub trait DefaultPointerKind:PointerKindVariant{}
+
+impl DefaultPointerKind for PK_SmartPointer{}
+
+
 unsafe impl<'a,T> GetPointerKind for &'a T{
     type Kind=PK_Reference;
 }
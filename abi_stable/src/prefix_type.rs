@@ -27,13 +27,15 @@ pub use self::{
         BoolArray,
         FieldAccessibility,
         FieldConditionality,
+        FieldOptionality,
         IsAccessible,
         IsConditional,
+        IsOptional,
     },
     layout::PTStructLayout,
 };
 
-pub(crate) use self::pt_metadata::PrefixTypeMetadata;
+pub use self::pt_metadata::PrefixTypeMetadata;
 
 
 /// For types deriving `StableAbi` with `#[sabi(kind(Prefix(..)))]`.
@@ -39,6 +39,13 @@ pub type FieldAccessibility=BoolArray<IsAccessible>;
 /// `#[sabi(accessible_if=" expression ")]` attribute applied to it.
 pub type FieldConditionality=BoolArray<IsConditional>;
 
+/// An array with whether the ith field of a prefix-type is optional,
+/// which means whether it has the `#[sabi(optional)]` attribute applied to it.
+///
+/// This is computed from the fields of the prefix type,
+/// with `TLFields::optional_fields`.
+pub type FieldOptionality=BoolArray<IsOptional>;
+
 
 impl<T> BoolArray<T>{
     /// Creates a BoolArray where the first `count` elements are truthy.
@@ -152,6 +159,31 @@ impl FieldConditionality{
 
 }
 
+impl FieldOptionality{
+    /// Queries whether the field at the `index` position is optional.
+    #[inline]
+    pub const fn is_optional(self,index:usize)->bool{
+        let bits=Self::index_to_bits(index);
+        (self.bits&bits)!=0
+    }
+
+    /// Sets the optionality of a field based on `cond`,
+    /// on IsOptional::Yes the field becomes optional,
+    /// on IsOptional::No the field becomes non-optional.
+    #[inline]
+    pub const fn set_optionality(mut self,index:usize,cond:IsOptional)->Self{
+        let bits=Self::index_to_bits(index);
+        self.bits=[self.bits&!bits,self.bits|bits][cond as usize];
+        self
+    }
+
+    /// Whether all the fields in the `start..end` range are optional.
+    pub fn are_optional(self,start:usize,end:usize)->bool{
+        (start..end).all(|index| self.is_optional(index) )
+    }
+
+}
+
 
 impl<T> Debug for BoolArray<T>
 where
@@ -239,6 +271,38 @@ impl BooleanEnum for IsConditional{
 }
 
 
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether a field is optional,
+/// whether it has a `#[sabi(optional)]` helper attribute or not.
+#[derive(StableAbi)]
+#[derive(Debug,Copy,Clone,PartialEq,Eq)]
+#[repr(u8)]
+pub enum IsOptional{
+    No=0,
+    Yes=1,
+}
+
+impl IsOptional{
+    /// Constructs an IsOptional with a bool saying whether this is optional.
+    pub const fn new(is_optional:bool)->Self{
+        [IsOptional::No,IsOptional::Yes][is_optional as usize]
+    }
+    /// Describes whether this is optional.
+    pub const fn is_optional(self)->bool{
+        self as usize!=0
+    }
+}
+
+impl BooleanEnum for IsOptional{
+    const NAME:&'static str="IsOptional";
+
+    fn from_bool(b:bool)->Self{
+        Self::new(b)
+    }
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 
 
@@ -5,7 +5,9 @@ use std::{
 
 #[allow(unused_imports)]
 use crate::{
+    abi_stability::SharedStableAbi,
     std_types::*,
+    reflection::ModReflMode,
     type_layout::{
         TypeLayout,TLField,TLData,TLPrefixType,TLDataDiscriminant,
         TLFieldsIterator,TLFields,
@@ -13,7 +15,7 @@ use crate::{
 };
 
 use super::{
-    accessible_fields::{FieldAccessibility,FieldConditionality,IsAccessible},
+    accessible_fields::{FieldAccessibility,FieldConditionality,FieldOptionality,IsAccessible},
 };
 
 
@@ -21,8 +23,81 @@ use super::{
 use core_extensions::SelfOps;
 
 
+/**
+Metadata about a prefix type,extracted from its `TypeLayout`.
+
+This can be used to check that a prefix type loaded from a dynamic library 
+has (at least) the fields that the interface requires,
+before calling any of its accessor methods.
+
+For the complete check,use 
+[`check_layout_compatibility`](../abi_stability/fn.check_layout_compatibility.html).
+
+# Example
+
+```
+use abi_stable::{
+    abi_stability::SharedStableAbi,
+    prefix_type::PrefixTypeMetadata,
+    StableAbi,
+};
+
+mod interface{
+    use abi_stable::StableAbi;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Module")))]
+    pub struct ModuleVal{
+        #[sabi(last_prefix_field)]
+        pub first:u32,
+        pub second:u64,
+    }
+}
+
+mod newer_impl{
+    use abi_stable::StableAbi;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Module")))]
+    pub struct ModuleVal{
+        #[sabi(last_prefix_field)]
+        pub first:u32,
+        pub second:u64,
+        pub third:u8,
+    }
+}
+
+mod incompatible_impl{
+    use abi_stable::StableAbi;
+
+    #[repr(C)]
+    #[derive(StableAbi)]
+    #[sabi(kind(Prefix(prefix_struct="Module")))]
+    pub struct ModuleVal{
+        #[sabi(last_prefix_field)]
+        pub first:u32,
+        pub second:u16,
+    }
+}
+
+// `TypeLayout`s of references to prefix types are dereferenced,
+// so the layout of a root module (`LibHeader::layout`) can be passed directly.
+let loaded=PrefixTypeMetadata::from_layout(<&newer_impl::Module>::LAYOUT).unwrap();
+assert!( loaded.is_compatible_with::<interface::Module>() );
+
+let loaded=PrefixTypeMetadata::from_layout(incompatible_impl::Module::S_LAYOUT).unwrap();
+assert!( !loaded.is_compatible_with::<interface::Module>() );
+
+// Not a prefix type
+assert!( PrefixTypeMetadata::from_layout(<u32>::LAYOUT).is_none() );
+
+```
+
+*/
 #[derive(Debug,Clone)]
-pub(crate) struct PrefixTypeMetadata{
+pub struct PrefixTypeMetadata{
     /// This is the ammount of fields on the prefix of the struct,
     /// which is always the same for the same type,regardless of which library it comes from.
     pub prefix_field_count:u8,
@@ -31,7 +106,11 @@ pub(crate) struct PrefixTypeMetadata{
 
     pub conditional_prefix_fields:FieldConditionality,
 
-    pub fields:InitialFieldsOrMut,
+    /// Which fields have the `#[sabi(optional)]` attribute,
+    /// those fields can be absent in the prefix type from another dynlib/binary.
+    pub optional_fields:FieldOptionality,
+
+    pub(crate) fields:InitialFieldsOrMut,
 
     /// The layout of the struct,for error messages.
     pub layout:&'static TypeLayout,
@@ -56,11 +135,90 @@ impl PrefixTypeMetadata{
         }
     }
 
+    /// Gets the metadata of a prefix type from its `TypeLayout`,
+    /// dereferencing it first if it's the layout of a reference to a prefix type.
+    ///
+    /// Returns None if `layout` isn't the layout of a prefix type
+    /// (or of a reference to one).
+    pub fn from_layout(layout:&'static TypeLayout)->Option<Self>{
+        let layout=match (layout.data(),layout.mod_refl_mode()) {
+            (TLData::PrefixType{..},_)=>layout,
+            (TLData::Primitive{..},ModReflMode::DelegateDeref{layout_index})=>{
+                layout.shared_vars().type_layouts()
+                    .get(layout_index as usize)?
+                    .get()
+            }
+            _=>return None,
+        };
+        match layout.data() {
+            TLData::PrefixType(prefix)=>Some(Self::with_prefix_layout(prefix,layout)),
+            _=>None,
+        }
+    }
+
+    /// The amount of fields of the prefix type,including the ones after the prefix.
+    pub fn field_count(&self)->usize{
+        self.fields.len()
+    }
+
+    /// Whether this prefix type (usually loaded from a dynamic library)
+    /// is compatible with the `P` prefix type.
+    ///
+    /// This checks that:
+    ///
+    /// - Both types have the same fields in the prefix,
+    /// and the same conditionality for those fields.
+    ///
+    /// - This type has at least as many fields as the prefix of `P`.
+    /// Fields that either type has and the other doesn't are never an error,
+    /// regardless of whether they are `#[sabi(optional)]`.
+    ///
+    /// - Both types have the same alignment.
+    ///
+    /// - The fields that both types have,and are accessible in both,
+    /// have the same name and layout.
+    ///
+    /// This returns false if `P` is not a prefix type.
+    ///
+    /// This does not check other types for compatibility,
+    /// [`check_layout_compatibility`](../abi_stability/fn.check_layout_compatibility.html) 
+    /// must still be used to check that the type is ABI compatible.
+    pub fn is_compatible_with<P>(&self)->bool
+    where
+        P:SharedStableAbi,
+    {
+        let expected=match Self::from_layout(P::S_LAYOUT) {
+            Some(x)=>x,
+            None=>return false,
+        };
+
+        if self.prefix_field_count != expected.prefix_field_count ||
+            self.conditional_prefix_fields != expected.conditional_prefix_fields ||
+            self.fields.len() < expected.prefix_field_count as usize ||
+            self.layout.alignment() != expected.layout.alignment()
+        {
+            return false;
+        }
+
+        let min_field_count=self.fields.len().min(expected.fields.len());
+
+        self.fields.iter()
+            .zip(expected.fields.iter())
+            .zip(
+                self.accessible_fields.iter_count(min_field_count)
+                    .zip(expected.accessible_fields.iter_count(min_field_count))
+            )
+            .all(|((t_field,e_field),(t_acc,e_acc))|{
+                !(t_acc.is_accessible() && e_acc.is_accessible()) || t_field==e_field
+            })
+    }
+
     pub(crate) fn with_prefix_layout(prefix:TLPrefixType,layout:&'static TypeLayout)->Self{
         Self{
             fields:InitialFieldsOrMut::from(prefix.fields),
             accessible_fields:prefix.accessible_fields,
             conditional_prefix_fields:prefix.conditional_prefix_fields,
+            optional_fields:prefix.fields.optional_fields(),
             prefix_field_count:prefix.first_suffix_field,
             layout,
         }
@@ -101,7 +259,8 @@ impl PrefixTypeMetadata{
 
 
     /// Combines the fields from `other` into `self`,
-    /// replacing any innaccessible field with one from `other`.
+    /// replacing any innaccessible field with one from `other`,
+    /// and considering fields optional if they are optional in either.
     ///
     /// # Preconditions
     ///
@@ -109,6 +268,10 @@ impl PrefixTypeMetadata{
     /// otherwise fields accessible in both `self` and `other` 
     /// won't be checked for compatibility or copied.
     pub(crate) fn combine_fields_from(&mut self,other:&Self){
+        self.optional_fields=FieldOptionality::from_u64(
+            self.optional_fields.bits()|other.optional_fields.bits()
+        );
+
         let mut o_fields=other.fields.iter();
 
         let min_field_count=o_fields.len().min(self.fields.len());
@@ -31,7 +31,7 @@ pub mod reexports{
             pointer_trait::{CanTransmuteElement,TransmuteElement,OwnedPointer},
             prefix_type::{PrefixTypeTrait,WithMetadata},
             traits::IntoInner,
-            sabi_types::{RRef,MovePtr,StaticRef},
+            sabi_types::{RRef,MovePtr,RSmallBox,StaticRef},
             sabi_trait::{
                 robject::{
                     RObject,
@@ -59,9 +59,9 @@ the traits listed bellow.
 
 These are the traits:
 
-- Send
+- Send:if the pointer is also `Send`.
 
-- Sync
+- Sync:if the pointer is also `Sync`.
 
 - Debug
 
@@ -240,13 +240,13 @@ where
 
 unsafe impl<'lt,P,I,V> Send for RObject<'lt,P,I,V> 
 where 
-    P:GetPointerKind,
+    P:Send+GetPointerKind,
     I:InterfaceType<Send = Implemented<trait_marker::Send>>,
 {}
 
 unsafe impl<'lt,P,I,V> Sync for RObject<'lt,P,I,V> 
 where
-    P:GetPointerKind,
+    P:Sync+GetPointerKind,
     I:InterfaceType<Sync = Implemented<trait_marker::Sync>>,
 {}
 
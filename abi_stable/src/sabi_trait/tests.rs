@@ -1,10 +1,13 @@
 use std::{
+    fmt::Debug,
     mem,
+    sync::atomic::{AtomicUsize,Ordering as AtomicOrdering},
+    thread,
 };
 
 use crate::{
     *,
-    std_types::RBox,
+    std_types::{RArc,RBox,RString},
     sabi_trait::prelude::*,
     type_level::bools::*,
 };
@@ -224,4 +227,365 @@ where P:crate::pointer_trait::GetPointerKind,
 #[sabi(no_trait_impl)]
 trait NoTraitImplB{}
 
-impl<This:?Sized> NoTraitImplB for This{}
\ No newline at end of file
+impl<This:?Sized> NoTraitImplB for This{}
+
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that the trait object is Send/Sync when the trait has Send/Sync supertraits.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+trait SharedCounter:Send+Sync{
+    fn add(&self,n:usize)->usize;
+}
+
+struct AtomicCounter(AtomicUsize);
+
+impl SharedCounter for AtomicCounter{
+    fn add(&self,n:usize)->usize{
+        self.0.fetch_add(n,AtomicOrdering::SeqCst)+n
+    }
+}
+
+
+#[test]
+fn send_sync_supertraits(){
+    fn assert_send_sync<T:Send+Sync>(_:&T){}
+
+    let object=SharedCounter_TO::from_value(AtomicCounter(AtomicUsize::new(0)),TU_Opaque);
+    assert_send_sync(&object);
+
+    let object=RArc::new(object);
+    assert_send_sync(&object);
+
+    let handles=(0..4)
+        .map(|_|{
+            let object=object.clone();
+            thread::spawn(move||{
+                for _ in 0..100 {
+                    object.add(1);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(object.add(0),400);
+}
+
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that #[sabi(impl_for_refs)] implements the trait for references.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+#[sabi(impl_for_refs)]
+trait Doubler:Debug{
+    fn double(&self,n:u32)->u32;
+
+    fn name(&self)->RString{
+        RString::from("doubler")
+    }
+}
+
+#[sabi_trait]
+#[sabi(impl_for_refs)]
+trait Accumulator{
+    fn accumulate(&mut self,n:u32);
+
+    fn total(&self)->u32;
+}
+
+// Traits with by-value methods aren't implemented for references.
+#[sabi_trait]
+#[sabi(impl_for_refs)]
+trait Consumer{
+    fn peek(&self)->u32;
+
+    fn consume(self)->u32;
+}
+
+#[derive(Debug)]
+struct Multiplier(u32);
+
+impl Doubler for Multiplier{
+    fn double(&self,n:u32)->u32{
+        n*2*self.0
+    }
+}
+
+impl Consumer for Multiplier{
+    fn peek(&self)->u32{
+        self.0
+    }
+    fn consume(self)->u32{
+        self.0
+    }
+}
+
+impl Accumulator for Multiplier{
+    fn accumulate(&mut self,n:u32){
+        self.0+=n;
+    }
+    fn total(&self)->u32{
+        self.0
+    }
+}
+
+
+#[test]
+fn impl_for_refs(){
+    fn double_twice<D:Doubler>(doubler:D,n:u32)->u32{
+        doubler.double(doubler.double(n))
+    }
+
+    fn accumulate_all<A:Accumulator>(mut acc:A,list:&[u32])->u32{
+        for &n in list {
+            acc.accumulate(n);
+        }
+        acc.total()
+    }
+
+    let mut mult=Multiplier(3);
+
+    assert_eq!(double_twice(&mult,1),36);
+    assert_eq!(double_twice(&mut mult,1),36);
+    assert_eq!((&mult).name().as_str(),"doubler");
+    assert_eq!(format!("{:?}",&mult),"Multiplier(3)");
+
+    assert_eq!(accumulate_all(&mut mult,&[1,2]),6);
+    assert_eq!(accumulate_all(&mut &mut mult,&[4]),10);
+    assert_eq!(mult.0,10);
+
+    {
+        let object=Doubler_TO::from_value(Multiplier(1),TU_Opaque);
+        assert_eq!(double_twice(&object,5),20);
+        assert_eq!(double_twice(&&object,5),20);
+    }
+    {
+        let mut object=Accumulator_TO::from_value(Multiplier(0),TU_Opaque);
+        assert_eq!(accumulate_all(&mut object,&[1,2,3]),6);
+        assert_eq!(object.total(),6);
+    }
+    {
+        let object=Consumer_TO::from_value(Multiplier(4),TU_Opaque);
+        assert_eq!(object.peek(),4);
+        assert_eq!(object.consume(),4);
+        assert_eq!(Multiplier(5).consume(),5);
+    }
+    {
+        // Queries whether `T` implements `Consumer`/`Doubler`,
+        // using inherent methods with where clauses,
+        // which take priority over the `NotImplemented` trait methods.
+        struct Probe<T>(std::marker::PhantomData<T>);
+
+        trait NotImplemented{
+            fn is_consumer(&self)->bool{ false }
+            fn is_doubler(&self)->bool{ false }
+        }
+
+        impl<T> NotImplemented for Probe<T>{}
+
+        impl<T:Consumer> Probe<T>{
+            fn is_consumer(&self)->bool{ true }
+        }
+
+        impl<T:Doubler> Probe<T>{
+            fn is_doubler(&self)->bool{ true }
+        }
+
+        assert!( Probe::<Multiplier>(std::marker::PhantomData).is_consumer() );
+        assert!( !Probe::<&Multiplier>(std::marker::PhantomData).is_consumer() );
+        assert!( !Probe::<&mut Multiplier>(std::marker::PhantomData).is_consumer() );
+        assert!( Probe::<&Multiplier>(std::marker::PhantomData).is_doubler() );
+    }
+}
+
+
+// Traits with `#[sabi(erase_return)]` methods require Rust 1.75,
+// because they have `impl Trait` return types.
+#[cfg(feature="rust_1_75")]
+#[sabi_trait]
+trait Tree{
+    #[sabi(erase_return)]
+    fn children(&self)->impl Iterator<Item=&RString>+Send+Sync;
+
+    #[sabi(erase_return)]
+    fn children_rev<'a>(&'a self)->impl DoubleEndedIterator<Item=u32>+Send+Sync+'a;
+
+    #[sabi(erase_return)]
+    fn into_children(self)->impl Iterator<Item=RString>+Send+Sync+'static;
+
+    #[sabi(erase_return)]
+    fn first_child(&self)->impl Iterator<Item=&RString>+Send+Sync{
+        self.children().take(1)
+    }
+
+    #[sabi(erase_return)]
+    fn shared_lens(&self)->impl DoubleEndedIterator<Item=u32>;
+
+    #[sabi(erase_return)]
+    fn into_shared(self)->impl Iterator<Item=RString>+'static;
+}
+
+#[cfg(feature="rust_1_75")]
+impl Tree for Vec<RString>{
+    fn children(&self)->impl Iterator<Item=&RString>+Send+Sync{
+        self.iter()
+    }
+
+    fn children_rev<'a>(&'a self)->impl DoubleEndedIterator<Item=u32>+Send+Sync+'a{
+        self.iter().map(|x| x.len() as u32 )
+    }
+
+    fn into_children(self)->impl Iterator<Item=RString>+Send+Sync+'static{
+        self.into_iter()
+    }
+
+    fn shared_lens(&self)->impl DoubleEndedIterator<Item=u32>{
+        // `Rc` is neither `Send` nor `Sync`,so neither is this iterator.
+        let shared=std::rc::Rc::new(self.clone());
+        (0..shared.len()).map(move|i| shared[i].len() as u32 )
+    }
+
+    fn into_shared(self)->impl Iterator<Item=RString>+'static{
+        let shared=std::rc::Rc::new(self);
+        (0..shared.len()).map(move|i| shared[i].clone() )
+    }
+}
+
+
+#[cfg(feature="rust_1_75")]
+#[test]
+fn erase_return(){
+    let list=vec![RString::from("hello"),RString::from("world!")];
+
+    let object=Tree_TO::from_value(list.clone(),TU_Opaque);
+
+    assert_eq!(
+        object.children().map(|x| x.as_str() ).collect::<Vec<&str>>(),
+        vec!["hello","world!"],
+    );
+    assert_eq!(object.children_rev().rev().collect::<Vec<u32>>(), vec![6,5]);
+    assert_eq!(
+        object.first_child().map(|x| x.as_str() ).collect::<Vec<&str>>(),
+        vec!["hello"],
+    );
+    {
+        // Calling the methods through the trait,with the erased iterators.
+        fn child_lens<T:Tree>(tree:&T)->Vec<usize>{
+            tree.children().map(|x| x.len() ).collect()
+        }
+        assert_eq!(child_lens(&object), vec![5,6]);
+        assert_eq!(child_lens(&list), vec![5,6]);
+    }
+    assert_eq!(
+        Tree::into_children(list.clone()).collect::<Vec<RString>>(),
+        list,
+    );
+    assert_eq!(
+        object.shared_lens().rev().collect::<Vec<u32>>(),
+        vec![6,5],
+    );
+    {
+        let shared:crate::erased_types::RUnsendDoubleEndedIterator<'_,u32>=
+            object.shared_lens();
+        assert_eq!(shared.collect::<Vec<u32>>(), vec![5,6]);
+    }
+    assert_eq!(
+        Tree_TO::from_value(list.clone(),TU_Opaque)
+            .into_shared()
+            .collect::<Vec<RString>>(),
+        list,
+    );
+    assert_eq!(object.into_children().collect::<Vec<RString>>(), list);
+}
+
+
+/*////////////////////////////////////////////////////////////////////////////////
+Test that #[sabi(inline_size="...")] generates the `from_inline_value` constructor.
+*/////////////////////////////////////////////////////////////////////////////////
+
+#[sabi_trait]
+#[sabi(inline_size="[usize;2]")]
+trait Counter:Clone{
+    fn get(&self)->u64;
+
+    fn increment(&mut self);
+
+    fn into_count(self)->u64;
+}
+
+#[sabi_trait]
+#[sabi(use_dyntrait)]
+#[sabi(inline_size="[usize;2]")]
+trait DynCounter:Debug{
+    fn get(&self)->u64;
+
+    fn increment(&mut self);
+}
+
+#[derive(Debug,Clone)]
+struct SmallCounter(u64);
+
+#[derive(Debug,Clone)]
+struct LargeCounter([u64;8]);
+
+impl Counter for SmallCounter{
+    fn get(&self)->u64{ self.0 }
+    fn increment(&mut self){ self.0+=1; }
+    fn into_count(self)->u64{ self.0 }
+}
+
+impl Counter for LargeCounter{
+    fn get(&self)->u64{ self.0.iter().sum() }
+    fn increment(&mut self){ self.0[7]+=1; }
+    fn into_count(self)->u64{ self.get() }
+}
+
+impl DynCounter for SmallCounter{
+    fn get(&self)->u64{ self.0 }
+    fn increment(&mut self){ self.0+=1; }
+}
+
+impl DynCounter for LargeCounter{
+    fn get(&self)->u64{ self.0.iter().sum() }
+    fn increment(&mut self){ self.0[7]+=1; }
+}
+
+
+#[test]
+fn inline_size(){
+    {
+        let mut object=Counter_TO::from_inline_value(SmallCounter(3),TU_Opaque);
+        object.increment();
+        let clone=object.clone();
+        object.increment();
+        assert_eq!(object.get(),5);
+        assert_eq!(clone.get(),4);
+        assert_eq!(object.into_count(),5);
+    }
+    {
+        let mut object=Counter_TO::from_inline_value(LargeCounter([1;8]),TU_Unerasable);
+        object.increment();
+        assert_eq!(object.get(),9);
+        assert_eq!(
+            object.obj.as_unerased::<LargeCounter>().ok().map(|x| x.0 ),
+            Some([1,1,1,1,1,1,1,2]),
+        );
+        assert_eq!(object.into_count(),9);
+    }
+    {
+        let mut object=DynCounter_TO::from_inline_value(SmallCounter(10),TU_Opaque);
+        object.increment();
+        assert_eq!(object.get(),11);
+        assert_eq!(format!("{:?}",object),"SmallCounter(11)");
+
+        let mut object=DynCounter_TO::from_inline_value(LargeCounter([0;8]),TU_Opaque);
+        object.increment();
+        assert_eq!(object.get(),1);
+    }
+}
@@ -9,6 +9,7 @@ mod late_static_ref;
 mod nul_str;
 mod maybe_cmp;
 pub mod move_ptr;
+mod pinned_ptr;
 mod static_ref;
 mod rref;
 pub mod version;
@@ -22,6 +23,7 @@ pub use self::{
     nul_str::NulStr,
     maybe_cmp::MaybeCmp,
     move_ptr::MovePtr,
+    pinned_ptr::PinnedPtr,
     rref::RRef,
     rsmallbox::RSmallBox,
     late_static_ref::LateStaticRef,
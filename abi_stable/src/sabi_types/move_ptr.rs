@@ -294,6 +294,49 @@ impl<'a,T> MovePtr<'a,T>{
             this.ptr.read()
         }
     }
+
+    /// Moves the value out of the reference,converting it with `f`,
+    /// then passes a `MovePtr` to the converted value to `with`.
+    ///
+    /// The converted value is stored on the stack for the duration of `with`,
+    /// which is why this can't return a `MovePtr<'a,U>`.
+    ///
+    /// The original value is only ever moved out once,
+    /// and the converted value is dropped when the `MovePtr<'_,U>` passed to `with` is,
+    /// unless `with` moves it out of the `MovePtr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     pointer_trait::OwnedPointer,
+    ///     sabi_types::MovePtr,
+    ///     std_types::{RBox,RString,RVec},
+    /// };
+    /// 
+    /// let rbox=RBox::new(vec![String::from("hello"),String::from("world")]);
+    ///
+    /// let joined=rbox.in_move_ptr(|move_ptr|{
+    ///     MovePtr::map_with(
+    ///         move_ptr,
+    ///         |list| list.into_iter().map(RString::from).collect::<RVec<RString>>(),
+    ///         |move_ptr| MovePtr::into_inner(move_ptr).join(" "),
+    ///     )
+    /// });
+    /// 
+    /// assert_eq!( joined.as_str(), "hello world" );
+    /// 
+    /// ```
+    pub fn map_with<U,F,G,R>(this:Self,f:F,with:G)->R
+    where
+        F:FnOnce(T)->U,
+        G:FnOnce(MovePtr<'_,U>)->R,
+    {
+        let mut mapped=ManuallyDrop::new(f(Self::into_inner(this)));
+        // Safety: `mapped` is never read again after this,
+        // the MovePtr is responsible for dropping it.
+        with(unsafe{ MovePtr::new(&mut *mapped) })
+    }
 }
 
 
@@ -350,7 +393,27 @@ impl<'a,T> Drop for MovePtr<'a,T>{
 mod test{
     use super::*;
 
-    use std::sync::Arc;
+    use std::{
+        cell::Cell,
+        panic::{self,AssertUnwindSafe},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    /// Increments the counter it holds when dropped.
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter{
+        fn drop(&mut self){
+            self.0.set(self.0.get()+1);
+        }
+    }
+
+    fn with_counter<R>(f:impl FnOnce(Rc<Cell<usize>>,MovePtr<'_,DropCounter>)->R)->R{
+        let counter=Rc::new(Cell::new(0));
+        let mut value=ManuallyDrop::new(DropCounter(counter.clone()));
+        unsafe{ f(counter,MovePtr::new(&mut *value)) }
+    }
 
     #[test]
     fn with_manuallydrop(){
@@ -373,4 +436,79 @@ mod test{
         }
         assert_eq!(Arc::strong_count(&arc),1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn drops_once(){
+        with_counter(|counter,move_ptr|{
+            drop(move_ptr);
+            assert_eq!(counter.get(),1);
+        });
+        with_counter(|counter,move_ptr|{
+            let value=MovePtr::into_inner(move_ptr);
+            assert_eq!(counter.get(),0);
+            drop(value);
+            assert_eq!(counter.get(),1);
+        });
+        with_counter(|counter,move_ptr|{
+            let value=MovePtr::into_box(move_ptr);
+            assert_eq!(counter.get(),0);
+            drop(value);
+            assert_eq!(counter.get(),1);
+        });
+    }
+
+    #[test]
+    fn map_with_drops_once(){
+        // The converted value is dropped along with the MovePtr passed to `with`.
+        with_counter(|counter,move_ptr|{
+            let counter_2=counter.clone();
+            MovePtr::map_with(
+                move_ptr,
+                |x| (x,10),
+                |pair| {
+                    assert_eq!(counter_2.get(),0);
+                    assert_eq!(pair.1,10);
+                },
+            );
+            assert_eq!(counter.get(),1);
+        });
+
+        // The converted value is moved out of the MovePtr passed to `with`.
+        with_counter(|counter,move_ptr|{
+            let pair=MovePtr::map_with(move_ptr,|x| (x,10),|p| MovePtr::into_inner(p) );
+            assert_eq!(counter.get(),0);
+            drop(pair);
+            assert_eq!(counter.get(),1);
+        });
+
+        // `f` drops the original value.
+        with_counter(|counter,move_ptr|{
+            let counter_2=counter.clone();
+            MovePtr::map_with(
+                move_ptr,
+                drop,
+                |unit| {
+                    assert_eq!(counter_2.get(),1);
+                    MovePtr::into_inner(unit)
+                },
+            );
+            assert_eq!(counter.get(),1);
+        });
+
+        // Panicking inside `f` and `with`.
+        with_counter(|counter,move_ptr|{
+            let res=panic::catch_unwind(AssertUnwindSafe(||{
+                MovePtr::map_with(move_ptr,|_x| -> u32 { panic!() },|_| ());
+            }));
+            assert!(res.is_err());
+            assert_eq!(counter.get(),1);
+        });
+        with_counter(|counter,move_ptr|{
+            let res=panic::catch_unwind(AssertUnwindSafe(||{
+                MovePtr::map_with(move_ptr,|x| (x,10),|_| panic!() );
+            }));
+            assert!(res.is_err());
+            assert_eq!(counter.get(),1);
+        });
+    }
+}
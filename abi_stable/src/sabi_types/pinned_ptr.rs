@@ -0,0 +1,179 @@
+use std::{
+    fmt::{self,Debug,Display},
+    mem::ManuallyDrop,
+    ops::Deref,
+    pin::Pin,
+    ptr,
+};
+
+use crate::{
+    pointer_trait::{CanTransmuteElement,GetPointerKind},
+};
+
+
+/**
+An ffi-safe pointer to a pinned value,
+which only allows getting a shared reference to the value.
+
+This is the pointer type of `DynTrait`s constructed with
+`DynTrait::from_pinned_ptr`/`DynTrait::from_pinned_value`,
+so that the methods that would allow moving the erased value
+(by-value and mutable access) are not available for them.
+
+A `PinnedPtr<P>` can only be constructed from a `Pin<P>`,
+and can be converted back into one with `PinnedPtr::into_pin`.
+
+# Example
+
+```
+use abi_stable::{
+    sabi_types::PinnedPtr,
+    std_types::RBox,
+};
+
+use std::pin::Pin;
+
+let pinned:Pin<RBox<u32>>=unsafe{ Pin::new_unchecked(RBox::new(3)) };
+
+let ptr=PinnedPtr::from_pin(pinned);
+assert_eq!( *ptr, 3 );
+
+let pinned:Pin<RBox<u32>>=ptr.into_pin();
+assert_eq!( *pinned, 3 );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(StableAbi)]
+pub struct PinnedPtr<P>{
+    ptr:P,
+}
+
+
+impl<P> PinnedPtr<P>
+where
+    P:Deref,
+{
+    /// Constructs a `PinnedPtr<P>` from a pinned pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::sabi_types::PinnedPtr;
+    ///
+    /// use std::pin::Pin;
+    ///
+    /// let ptr=PinnedPtr::from_pin(Pin::new(&10_u8));
+    /// assert_eq!( *ptr, 10 );
+    ///
+    /// ```
+    pub fn from_pin(pin:Pin<P>)->Self{
+        // `Pin<P>` is a `#[repr(transparent)]` wrapper around `P`,
+        // and `PinnedPtr<P>` only gives out shared references to the pointee,
+        // so the pointee is never moved.
+        let ptr=unsafe{
+            let pin=ManuallyDrop::new(pin);
+            ptr::read(&*pin as *const Pin<P> as *const P)
+        };
+        Self{ptr}
+    }
+
+    /// Converts this back into a `Pin<P>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::sabi_types::PinnedPtr;
+    ///
+    /// use std::pin::Pin;
+    ///
+    /// let ptr=PinnedPtr::from_pin(Pin::new(&10_u8));
+    /// let pinned:Pin<&u8>=ptr.into_pin();
+    /// assert_eq!( *pinned, 10 );
+    ///
+    /// ```
+    pub fn into_pin(self)->Pin<P>{
+        // The pointee was pinned when this was constructed.
+        unsafe{ Pin::new_unchecked(self.ptr) }
+    }
+
+    /// Gets a pinned shared reference to the pointee.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::sabi_types::PinnedPtr;
+    ///
+    /// use std::pin::Pin;
+    ///
+    /// let ptr=PinnedPtr::from_pin(Pin::new(&10_u8));
+    /// let pinned:Pin<&u8>=ptr.as_pin();
+    /// assert_eq!( *pinned, 10 );
+    ///
+    /// ```
+    pub fn as_pin(&self)->Pin<&P::Target>{
+        unsafe{ Pin::new_unchecked(&*self.ptr) }
+    }
+}
+
+
+impl<P> Deref for PinnedPtr<P>
+where
+    P:Deref,
+{
+    type Target=P::Target;
+
+    fn deref(&self)->&P::Target{
+        &*self.ptr
+    }
+}
+
+
+impl<P> Clone for PinnedPtr<P>
+where
+    P:Clone,
+{
+    fn clone(&self)->Self{
+        Self{ ptr:self.ptr.clone() }
+    }
+}
+
+impl<P> Copy for PinnedPtr<P>
+where
+    P:Copy,
+{}
+
+
+impl<P> Debug for PinnedPtr<P>
+where
+    P:Debug,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Debug::fmt(&self.ptr,f)
+    }
+}
+
+impl<P> Display for PinnedPtr<P>
+where
+    P:Display,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Display::fmt(&self.ptr,f)
+    }
+}
+
+
+unsafe impl<P> GetPointerKind for PinnedPtr<P>
+where
+    P:GetPointerKind,
+{
+    type Kind=P::Kind;
+}
+
+unsafe impl<P,U> CanTransmuteElement<U> for PinnedPtr<P>
+where
+    P:CanTransmuteElement<U>,
+{
+    type TransmutedPtr=PinnedPtr<P::TransmutedPtr>;
+}
@@ -9,8 +9,11 @@ the [external_types module](../external_types/index.html)
 
 pub mod arc;
 pub mod boxed;
+pub mod cell;
 pub mod cmp_ordering;
 pub mod cow;
+#[cfg(feature="rust_1_36")]
+pub mod future;
 //pub mod old_cow;
 pub mod option;
 pub mod map;
@@ -24,10 +27,13 @@ pub mod std_error;
 pub mod std_io;
 pub mod str;
 pub mod string;
+#[cfg(feature="rust_1_36")]
+pub mod task;
 pub mod time;
 pub mod tuple;
 pub mod utypeid;
 pub mod vec;
+pub mod vec_deque;
 
 
 /**
@@ -48,21 +54,30 @@ pub mod sync{}
 pub use self::{
     arc::RArc,
     boxed::RBox,
+    cell::RRefCell,
     cmp_ordering::RCmpOrdering,
-    cow::RCow,
+    cow::{RCow,RCowStr,RCowSlice},
     map::RHashMap,
     option::{RNone, ROption, RSome},
     result::{RErr, ROk, RResult},
-    slice_mut::RSliceMut,
-    slices::RSlice,
+    slice_mut::{RSliceMut,RSliceIterMut},
+    slices::{RSlice,RSliceIter},
     std_error::{RBoxError,SendRBoxError, UnsyncRBoxError},
     std_io::{RIoError,RSeekFrom, RIoErrorKind},
     str::RStr,
     string::RString,
-    time::RDuration,
+    time::{RDuration,RInstant},
     tuple::{Tuple1,Tuple2, Tuple3, Tuple4},
     vec::RVec,
-    utypeid::UTypeId,
+    vec_deque::RVecDeque,
+    utypeid::{RTypeId,UTypeId},
     static_str::StaticStr,
     static_slice::StaticSlice,
-};
\ No newline at end of file
+};
+
+#[cfg(feature="rust_1_36")]
+#[doc(inline)]
+pub use self::{
+    future::RBoxFuture,
+    task::{RContext,RPoll,RWaker},
+};
@@ -125,4 +125,33 @@ impl<'a> Drop for DecrementOnDrop<'a>{
     fn drop(&mut self){
         self.0.set(self.0.get()-1);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn counts(){
+    let rustarc=Arc::new(0);
+    let mut arc=RArc::from(rustarc.clone());
+
+    for &with_testing_vtable in &[false,true] {
+        if with_testing_vtable {
+            arc.set_vtable_for_testing();
+        }
+
+        assert_eq!(RArc::strong_count(&arc),2);
+        assert_eq!(RArc::weak_count(&arc),0);
+        {
+            let clone=arc.clone();
+            assert_eq!(RArc::strong_count(&arc),3);
+            assert_eq!(RArc::strong_count(&clone),3);
+
+            let weak=Arc::downgrade(&rustarc);
+            assert_eq!(RArc::weak_count(&arc),1);
+            drop(weak);
+            assert_eq!(RArc::weak_count(&clone),0);
+        }
+        assert_eq!(RArc::strong_count(&arc),2);
+    }
+
+    drop(rustarc);
+    assert_eq!(RArc::strong_count(&arc),1);
+}
@@ -6,7 +6,7 @@ Contains the ffi-safe equivalent of `std::boxed::Box`.
 use std::{
     borrow::{Borrow,BorrowMut},
     marker::PhantomData, 
-    mem::ManuallyDrop, 
+    mem::{self,ManuallyDrop}, 
     ops::DerefMut,
     ptr,
 };
@@ -217,6 +217,58 @@ impl<T> RBox<T> {
             value
         }
     }
+
+    /// Converts the value in this `RBox<T>` with `f`,returning an `RBox<U>`.
+    ///
+    /// # Allocation
+    ///
+    /// This reuses the heap allocation of this `RBox<T>` when
+    /// `T` and `U` have the same size and alignment,
+    /// and the `RBox<T>` was created in this dynamic library/binary.
+    ///
+    /// Otherwise it's equivalent to `RBox::new(f(RBox::into_inner(this)))`.
+    ///
+    /// If `f` panics,the heap allocation is freed without dropping the value again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RBox;
+    ///
+    /// #[derive(Debug,PartialEq)]
+    /// struct Meters(u64);
+    ///
+    /// let distance=RBox::new(100_u64);
+    /// let address=&*distance as *const u64 as usize;
+    ///
+    /// let distance=RBox::map(distance,Meters);
+    ///
+    /// assert_eq!( *distance, Meters(100) );
+    /// assert_eq!( &*distance as *const Meters as usize, address );
+    ///
+    /// ```
+    pub fn map<U,F>(this: Self, f: F) -> RBox<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        if mem::size_of::<T>()!=mem::size_of::<U>() || mem::align_of::<T>()!=mem::align_of::<U>() {
+            return RBox::new(f(RBox::into_inner(this)));
+        }
+
+        unsafe {
+            // `into_box` only allocates if this RBox was created in another 
+            // dynamic library/binary.
+            //
+            // The ManuallyDrop ensures that if `f` panics,
+            // the Box only deallocates,without dropping the moved-out value.
+            let boxed=Box::from_raw(Box::into_raw(RBox::into_box(this)) as *mut ManuallyDrop<T>);
+            let value=ptr::read(&**boxed);
+            let mapped=f(value);
+            let raw=Box::into_raw(boxed) as *mut U;
+            raw.write(mapped);
+            RBox::from_box(Box::from_raw(raw))
+        }
+    }
 }
 
 impl<T> DerefMut for RBox<T> {
@@ -1,9 +1,15 @@
 use super::*;
 
-use std::sync::Arc;
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash,Hasher},
+    sync::Arc,
+};
 
 use crate::{
     sabi_types::MovePtr,
+    std_types::{RHashMap,RString,RVec},
     test_utils::{must_panic},
 };
 
@@ -125,3 +131,92 @@ fn owned_pointer_trait(){
 
 
 
+
+
+#[test]
+fn map(){
+    #[derive(Debug,Clone,PartialEq)]
+    struct Wrapper(Arc<i32>);
+
+    let arc=Arc::new(10);
+
+    // Same layout,reuses the allocation.
+    {
+        let rbox=RBox::new(arc.clone());
+        let addr=(&*rbox) as *const Arc<i32> as usize;
+
+        let mapped=RBox::map(rbox,Wrapper);
+        assert_eq!(Arc::strong_count(&arc),2);
+        assert_eq!((&*mapped) as *const Wrapper as usize,addr);
+        assert_eq!(*mapped,Wrapper(arc.clone()));
+    }
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    // Created in "another" dynamic library,can't reuse the allocation.
+    {
+        let mut rbox=RBox::new(arc.clone());
+        rbox.set_vtable_for_testing();
+        let mapped=RBox::map(rbox,Wrapper);
+        assert_eq!(Arc::strong_count(&arc),2);
+        assert_eq!(*mapped,Wrapper(arc.clone()));
+    }
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    // Different layout,allocates a new RBox.
+    {
+        let rbox=RBox::new(arc.clone());
+        let mapped=RBox::map(rbox,|x| (x,[0u64;4]) );
+        assert_eq!(Arc::strong_count(&arc),2);
+        assert_eq!(*mapped,(arc.clone(),[0u64;4]));
+
+        let mapped=RBox::map(mapped,|(x,_)| *x );
+        assert_eq!(Arc::strong_count(&arc),1);
+        assert_eq!(*mapped,10);
+    }
+
+    // Panicking inside `f` only drops the value once.
+    must_panic(file_span!(),||{
+        RBox::map(RBox::new(arc.clone()),|_x| -> Wrapper { panic!() });
+    }).unwrap();
+    assert_eq!(Arc::strong_count(&arc),1);
+}
+
+
+#[test]
+fn comparison_and_hashing(){
+    fn hash_value<H:Hash>(v:&H)->u64{
+        let mut hasher=DefaultHasher::new();
+        v.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let hello=RBox::new(RString::from("hello"));
+    let world=RBox::new(RString::from("world"));
+
+    assert_eq!(hello,RBox::new(RString::from("hello")));
+    assert_eq!(hello.cmp(&world),Ordering::Less);
+    assert_eq!(world.partial_cmp(&hello),Some(Ordering::Greater));
+    assert_eq!(hash_value(&hello),hash_value(&RString::from("hello")));
+
+    {
+        let mut map=RHashMap::new();
+        map.insert(hello.clone(),0);
+        map.insert(world.clone(),1);
+        map.insert(RBox::new(RString::from("hello")),2);
+
+        assert_eq!(map.len(),2);
+        assert_eq!(map.get(&hello),Some(&2));
+        assert_eq!(map.get(&world),Some(&1));
+    }
+
+    {
+        let mut list=vec!["c","a","d","b"]
+            .into_iter()
+            .map(|s| RBox::new(RString::from(s)) )
+            .collect::<RVec<_>>();
+        list.sort();
+
+        let sorted=list.iter().map(|s| s.as_str() ).collect::<Vec<_>>();
+        assert_eq!(sorted,["a","b","c","d"]);
+    }
+}
@@ -0,0 +1,438 @@
+/*!
+Contains an ffi-safe equivalent of `std::cell::RefCell`,and its borrow guards.
+
+`std::cell::Cell` and `std::cell::UnsafeCell` already implement StableAbi,
+so they don't need ffi-safe equivalents.
+*/
+
+use std::{
+    cell::{Cell,UnsafeCell},
+    error::Error as ErrorTrait,
+    fmt::{self, Debug, Display},
+    ops::{Deref, DerefMut},
+};
+
+use crate::std_types::{RResult,ROk,RErr};
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+
+/// The borrow state of an `RRefCell` that is not borrowed.
+const UNUSED:isize=0;
+/// The borrow state of an `RRefCell` that is mutably borrowed.
+const WRITING:isize=-1;
+
+
+/**
+Ffi-safe equivalent of `std::cell::RefCell<T>`,
+a mutable memory location with dynamically checked borrow rules.
+
+The borrow state is stored inside the `RRefCell`,
+so that it is shared by every dynamic library/executable that accesses it.
+
+# Guards
+
+The [`RCellRef`](./struct.RCellRef.html) and [`RCellRefMut`](./struct.RCellRefMut.html)
+guards release the borrow when dropped,
+they must not be passed to other dynamic libraries/executables,
+since the borrow would only be released when they're dropped.
+
+They are not named `RRef`/`RRefMut` to avoid confusion with
+[`abi_stable::sabi_types::RRef`](../../sabi_types/struct.RRef.html).
+
+# Example
+
+```
+use abi_stable::{
+    std_types::{RRefCell,RStr,RString},
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+fn append_log(log:&RRefCell<RString>,line:RStr<'_>){
+    let mut log=log.borrow_mut();
+    log.push_str(line.as_str());
+    log.push('\n');
+}
+
+let log=RRefCell::new(RString::new());
+
+append_log(&log,"hello".into());
+append_log(&log,"world".into());
+
+assert_eq!( log.borrow().as_str(), "hello\nworld\n" );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RRefCell<T>{
+    borrow:Cell<isize>,
+    value:UnsafeCell<T>,
+}
+
+impl<T> RRefCell<T>{
+    /// Constructs an `RRefCell` containing `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(100);
+    ///
+    /// assert_eq!( *cell.borrow(), 100 );
+    ///
+    /// ```
+    pub const fn new(value:T)->Self{
+        Self{
+            borrow:Cell::new(UNUSED),
+            value:UnsafeCell::new(value),
+        }
+    }
+
+    /// Unwraps the value in this `RRefCell`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new("hello");
+    ///
+    /// assert_eq!( cell.into_inner(), "hello" );
+    ///
+    /// ```
+    pub fn into_inner(self)->T{
+        self.value.into_inner()
+    }
+
+    /// Replaces the value in this `RRefCell`,returning the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(3);
+    ///
+    /// assert_eq!( cell.replace(5), 3 );
+    /// assert_eq!( *cell.borrow(), 5 );
+    ///
+    /// ```
+    pub fn replace(&self,value:T)->T{
+        std::mem::replace(&mut *self.borrow_mut(),value)
+    }
+
+    /// Immutably borrows the value in this `RRefCell`.
+    ///
+    /// The borrow lasts until the returned `RCellRef` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(vec![3,5,8]);
+    ///
+    /// let first=cell.borrow();
+    /// let second=cell.borrow();
+    ///
+    /// assert_eq!( &*first, &[3,5,8] );
+    /// assert_eq!( &*second, &[3,5,8] );
+    ///
+    /// ```
+    pub fn borrow(&self)->RCellRef<'_,T>{
+        match self.try_borrow() {
+            ROk(x)=>x,
+            RErr(e)=>panic!("{}",e),
+        }
+    }
+
+    /// Mutably borrows the value in this `RRefCell`.
+    ///
+    /// The borrow lasts until the returned `RCellRefMut` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(vec![3,5,8]);
+    ///
+    /// cell.borrow_mut().push(13);
+    ///
+    /// assert_eq!( &*cell.borrow(), &[3,5,8,13] );
+    ///
+    /// ```
+    pub fn borrow_mut(&self)->RCellRefMut<'_,T>{
+        match self.try_borrow_mut() {
+            ROk(x)=>x,
+            RErr(e)=>panic!("{}",e),
+        }
+    }
+
+    /// Attempts to immutably borrow the value in this `RRefCell`,
+    /// returning an error if it's currently mutably borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(0);
+    ///
+    /// {
+    ///     let _guard=cell.borrow_mut();
+    ///     assert!( cell.try_borrow().is_err() );
+    /// }
+    /// assert_eq!( *cell.try_borrow().unwrap(), 0 );
+    ///
+    /// ```
+    pub fn try_borrow(&self)->RResult<RCellRef<'_,T>,BorrowError>{
+        let state=self.borrow.get();
+        if state<UNUSED {
+            return RErr(BorrowError{ _priv:() });
+        }
+        if state==isize::max_value() {
+            panic!("too many immutable borrows of an RRefCell");
+        }
+        self.borrow.set(state+1);
+        ROk(RCellRef{ cell:self })
+    }
+
+    /// Attempts to mutably borrow the value in this `RRefCell`,
+    /// returning an error if it's currently borrowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let cell=RRefCell::new(0);
+    ///
+    /// {
+    ///     let _guard=cell.borrow();
+    ///     assert!( cell.try_borrow_mut().is_err() );
+    /// }
+    /// {
+    ///     let mut guard=cell.try_borrow_mut().unwrap();
+    ///     *guard+=1;
+    /// }
+    /// assert_eq!( *cell.borrow(), 1 );
+    ///
+    /// ```
+    pub fn try_borrow_mut(&self)->RResult<RCellRefMut<'_,T>,BorrowMutError>{
+        if self.borrow.get()!=UNUSED {
+            return RErr(BorrowMutError{ _priv:() });
+        }
+        self.borrow.set(WRITING);
+        ROk(RCellRefMut{ cell:self })
+    }
+
+    /// Gets a mutable reference to the value in this `RRefCell`.
+    ///
+    /// This requires no runtime checks,since it takes the cell by mutable reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RRefCell;
+    ///
+    /// let mut cell=RRefCell::new(10);
+    ///
+    /// *cell.get_mut()*=2;
+    ///
+    /// assert_eq!( cell.into_inner(), 20 );
+    ///
+    /// ```
+    pub fn get_mut(&mut self)->&mut T{
+        unsafe{ &mut *self.value.get() }
+    }
+}
+
+impl<T> Clone for RRefCell<T>
+where
+    T:Clone
+{
+    fn clone(&self)->Self{
+        Self::new((*self.borrow()).clone())
+    }
+}
+
+impl<T> Default for RRefCell<T>
+where
+    T:Default
+{
+    fn default()->Self{
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for RRefCell<T>{
+    fn from(value:T)->Self{
+        Self::new(value)
+    }
+}
+
+impl<T> Debug for RRefCell<T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        match self.try_borrow() {
+            ROk(x)=>f.debug_struct("RRefCell").field("value",&*x).finish(),
+            RErr(_)=>f.debug_struct("RRefCell").field("value",&"<borrowed>").finish(),
+        }
+    }
+}
+
+
+//////////////////////////////////////////////////////////////////////////////
+
+
+/// A guard for an immutable borrow of an [`RRefCell`](./struct.RRefCell.html),
+/// returned by `RRefCell::{borrow,try_borrow}`.
+///
+/// This must not be passed to other dynamic libraries/executables.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(bound="T:'a")]
+pub struct RCellRef<'a,T>{
+    cell:&'a RRefCell<T>,
+}
+
+impl<'a,T> Deref for RCellRef<'a,T>{
+    type Target=T;
+
+    fn deref(&self)->&T{
+        unsafe{ &*self.cell.value.get() }
+    }
+}
+
+impl<'a,T> Drop for RCellRef<'a,T>{
+    fn drop(&mut self){
+        let borrow=&self.cell.borrow;
+        borrow.set(borrow.get()-1);
+    }
+}
+
+impl<'a,T> Debug for RCellRef<'a,T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Debug::fmt(&**self,f)
+    }
+}
+
+impl<'a,T> Display for RCellRef<'a,T>
+where
+    T:Display
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Display::fmt(&**self,f)
+    }
+}
+
+
+/// A guard for a mutable borrow of an [`RRefCell`](./struct.RRefCell.html),
+/// returned by `RRefCell::{borrow_mut,try_borrow_mut}`.
+///
+/// This must not be passed to other dynamic libraries/executables.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(bound="T:'a")]
+pub struct RCellRefMut<'a,T>{
+    cell:&'a RRefCell<T>,
+}
+
+impl<'a,T> Deref for RCellRefMut<'a,T>{
+    type Target=T;
+
+    fn deref(&self)->&T{
+        unsafe{ &*self.cell.value.get() }
+    }
+}
+
+impl<'a,T> DerefMut for RCellRefMut<'a,T>{
+    fn deref_mut(&mut self)->&mut T{
+        unsafe{ &mut *self.cell.value.get() }
+    }
+}
+
+impl<'a,T> Drop for RCellRefMut<'a,T>{
+    fn drop(&mut self){
+        self.cell.borrow.set(UNUSED);
+    }
+}
+
+impl<'a,T> Debug for RCellRefMut<'a,T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Debug::fmt(&**self,f)
+    }
+}
+
+impl<'a,T> Display for RCellRefMut<'a,T>
+where
+    T:Display
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        Display::fmt(&**self,f)
+    }
+}
+
+
+//////////////////////////////////////////////////////////////////////////////
+
+
+/// The error returned by `RRefCell::try_borrow`,
+/// when the `RRefCell` is mutably borrowed.
+#[repr(C)]
+#[derive(Debug,Clone,PartialEq,Eq,StableAbi)]
+pub struct BorrowError{
+    _priv:(),
+}
+
+impl Display for BorrowError{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.write_str("RRefCell is already mutably borrowed")
+    }
+}
+
+impl ErrorTrait for BorrowError{}
+
+
+/// The error returned by `RRefCell::try_borrow_mut`,
+/// when the `RRefCell` is borrowed.
+#[repr(C)]
+#[derive(Debug,Clone,PartialEq,Eq,StableAbi)]
+pub struct BorrowMutError{
+    _priv:(),
+}
+
+impl Display for BorrowMutError{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.write_str("RRefCell is already borrowed")
+    }
+}
+
+impl ErrorTrait for BorrowMutError{}
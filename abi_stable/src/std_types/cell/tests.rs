@@ -0,0 +1,61 @@
+use super::*;
+
+use abi_stable_shared::file_span;
+
+use crate::test_utils::must_panic;
+
+#[test]
+fn borrow_counting() {
+    let cell = RRefCell::new(vec![3, 5, 8]);
+
+    {
+        let first = cell.borrow();
+        let second = cell.try_borrow().unwrap();
+        assert_eq!(&*first, &[3, 5, 8]);
+        assert_eq!(&*second, &[3, 5, 8]);
+        assert_eq!(cell.try_borrow_mut().unwrap_err(), BorrowMutError { _priv: () });
+        drop(first);
+        assert!(cell.try_borrow_mut().is_err());
+    }
+    {
+        let mut guard = cell.try_borrow_mut().unwrap();
+        guard.push(13);
+        assert_eq!(cell.try_borrow().unwrap_err(), BorrowError { _priv: () });
+        assert!(cell.try_borrow_mut().is_err());
+    }
+
+    assert_eq!(&*cell.borrow(), &[3, 5, 8, 13]);
+    cell.borrow_mut().push(21);
+    assert_eq!(cell.into_inner(), vec![3, 5, 8, 13, 21]);
+}
+
+#[test]
+fn borrow_panics() {
+    let cell = RRefCell::new(0);
+
+    {
+        let _guard = cell.borrow();
+        must_panic(file_span!(), || cell.borrow_mut()).unwrap();
+        must_panic(file_span!(), || cell.replace(1)).unwrap();
+    }
+    {
+        let _guard = cell.borrow_mut();
+        must_panic(file_span!(), || cell.borrow()).unwrap();
+        must_panic(file_span!(), || cell.borrow_mut()).unwrap();
+    }
+
+    assert_eq!(cell.replace(1), 0);
+    assert_eq!(*cell.borrow(), 1);
+}
+
+#[test]
+fn debug_formatting() {
+    let cell = RRefCell::new(10);
+
+    assert_eq!(format!("{:?}", cell), "RRefCell { value: 10 }");
+    {
+        let guard = cell.borrow_mut();
+        assert_eq!(format!("{:?}", guard), "10");
+        assert_eq!(format!("{:?}", cell), "RRefCell { value: \"<borrowed>\" }");
+    }
+}
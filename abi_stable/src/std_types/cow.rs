@@ -218,6 +218,12 @@ where
 
 use self::RCow::{Borrowed, Owned};
 
+/// Ffi-safe equivalent of `Cow<'a,str>`,either an `RStr<'a>` or an `RString`.
+pub type RCowStr<'a>=RCow<'a,str>;
+
+/// Ffi-safe equivalent of `Cow<'a,[T]>`,either an `RSlice<'a,T>` or an `RVec<T>`.
+pub type RCowSlice<'a,T>=RCow<'a,[T]>;
+
 
 // ///////////////////////////////////////////////////////////////////////////
 
@@ -165,4 +165,43 @@ fn deserialize(){
     }
 
 
-}
\ No newline at end of file
+}
+
+#[test]
+fn aliases_serde(){
+    {// Borrowed string,with a zero-copy deserializer.
+        let json=r##" "That's a lot of fish." "##;
+        let mut deserializer=serde_json::Deserializer::from_str(json);
+
+        let what:RCowStr<'_>=deserialize_borrowed_str(&mut deserializer).unwrap();
+
+        assert_eq!(
+            what.as_borrowed(),
+            Some("That's a lot of fish.".into_(RStr::T)),
+        );
+        assert_eq!( serde_json::to_string(&what).unwrap(), json.trim() );
+    }
+    {// Escaped strings can't be borrowed.
+        let json=r##" "That's a lot\nof fish." "##;
+        let mut deserializer=serde_json::Deserializer::from_str(json);
+
+        let what:RCowStr<'_>=deserialize_borrowed_str(&mut deserializer).unwrap();
+
+        assert_eq!(
+            what.as_owned(),
+            Some(&"That's a lot\nof fish.".into_(RString::T)),
+        );
+    }
+    {// Slices serialize like the equivalent Cow.
+        let borrowed:RCowSlice<'_,u32>=RCow::Borrowed((&[3,5,8][..]).into_c());
+        let owned:RCowSlice<'_,u32>=RCow::Owned(vec![3,5,8].into_c());
+        let cow:Cow<'_,[u32]>=Cow::Borrowed(&[3,5,8]);
+
+        let expected=serde_json::to_string(&cow).unwrap();
+        assert_eq!( serde_json::to_string(&borrowed).unwrap(), expected );
+        assert_eq!( serde_json::to_string(&owned).unwrap(), expected );
+
+        let what:RCowSlice<'_,u32>=serde_json::from_str(&expected).unwrap();
+        assert_eq!( what.as_owned(), Some(&vec![3,5,8].into_c()) );
+    }
+}
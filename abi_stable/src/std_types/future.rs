@@ -0,0 +1,162 @@
+/*!
+Contains `RBoxFuture`,an ffi-safe equivalent of `Pin<Box<dyn Future<Output=T>+Send+'a>>`.
+*/
+
+use std::{
+    fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    marker_type::{ErasedObject, NonOwningPhantom},
+    pointer_trait::TransmuteElement,
+    prefix_type::{PrefixTypeTrait, WithMetadata},
+    sabi_types::StaticRef,
+    std_types::{
+        task::{RContext, RPoll, RWaker},
+        RBox,
+    },
+    utils::transmute_mut_reference,
+};
+
+/**
+An ffi-safe equivalent of `Pin<Box<dyn Future<Output=T>+Send+'a>>`.
+
+The future is always polled in the dynamic library/executable that created it,
+receiving the waker of the task through an [`RContext`](../task/struct.RContext.html).
+
+# Example
+
+Returning a future from a function,which could be in a dynamic library.
+
+```
+use abi_stable::{
+    std_types::{RBoxFuture,RString},
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+pub fn greet(name:RString)->RBoxFuture<'static,RString>{
+    RBoxFuture::new(async move{
+        format!("hello {}",name).into()
+    })
+}
+
+# fn block_on<F:std::future::Future>(fut:F)->F::Output{
+#     use std::task::{Context,Poll,RawWaker,RawWakerVTable,Waker};
+#     unsafe fn clone(_:*const ())->RawWaker{ RawWaker::new(std::ptr::null(),&VTABLE) }
+#     unsafe fn noop(_:*const ()){}
+#     static VTABLE:RawWakerVTable=RawWakerVTable::new(clone,noop,noop,noop);
+#     let waker=unsafe{ Waker::from_raw(clone(std::ptr::null())) };
+#     let mut cx=Context::from_waker(&waker);
+#     let mut fut=Box::pin(fut);
+#     loop{
+#         if let Poll::Ready(x)=fut.as_mut().poll(&mut cx) { return x; }
+#     }
+# }
+let greeting=block_on(async{ greet("Jane".into()).await });
+assert_eq!( greeting, "hello Jane" );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(bound = "T:'a")]
+pub struct RBoxFuture<'a, T> {
+    future: RBox<ErasedObject>,
+    vtable: StaticRef<FutureVTable<T>>,
+    _marker: NonOwningPhantom<&'a ()>,
+}
+
+impl<'a, T> RBoxFuture<'a, T> {
+    /// Constructs an `RBoxFuture` from a future.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RBoxFuture;
+    ///
+    /// let fut:RBoxFuture<'static,u32>=RBoxFuture::new(async{ 3 });
+    ///
+    /// ```
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = T> + Send + 'a,
+    {
+        unsafe {
+            Self {
+                future: RBox::new(future).transmute_element::<ErasedObject>(),
+                vtable: WithMetadata::as_prefix(VTableGetter::<F>::LIB_VTABLE),
+                _marker: NonOwningPhantom::NEW,
+            }
+        }
+    }
+}
+
+// The future is stored in the heap,and is never moved out of it.
+impl<'a, T> Unpin for RBoxFuture<'a, T> {}
+
+unsafe impl<'a, T> Send for RBoxFuture<'a, T> {}
+
+impl<'a, T> Future for RBoxFuture<'a, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = Pin::get_mut(self);
+        unsafe {
+            let waker = RWaker::borrowing_waker(cx.waker());
+            let poll = this.vtable.get().poll();
+            poll(&mut *this.future, RContext::from_waker(&waker)).into()
+        }
+    }
+}
+
+impl<'a, T> Debug for RBoxFuture<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RBoxFuture").finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(StableAbi)]
+#[repr(C)]
+#[sabi(kind(Prefix(prefix_struct = "FutureVTable")))]
+#[sabi(missing_field(panic))]
+pub(crate) struct FutureVTableVal<T> {
+    #[sabi(last_prefix_field)]
+    poll: unsafe extern "C" fn(&mut ErasedObject, RContext<'_>) -> RPoll<T>,
+}
+
+struct VTableGetter<'a, F>(&'a F);
+
+impl<'a, F: 'a> VTableGetter<'a, F>
+where
+    F: Future,
+{
+    // The VTABLE for this type in this executable/library
+    const LIB_VTABLE: StaticRef<WithMetadata<FutureVTableVal<F::Output>>> = unsafe {
+        StaticRef::from_raw(&WithMetadata::new(
+            PrefixTypeTrait::METADATA,
+            FutureVTableVal {
+                poll: poll_future::<F>,
+            },
+        ))
+    };
+}
+
+unsafe extern "C" fn poll_future<F>(this: &mut ErasedObject, cx: RContext<'_>) -> RPoll<F::Output>
+where
+    F: Future,
+{
+    extern_fn_panic_handling! {
+        let this=transmute_mut_reference::<ErasedObject,F>(this);
+        let waker=cx.waker().borrowed_std_waker();
+        let mut cx=Context::from_waker(&waker);
+        // The future is never moved out of the RBox it was put in.
+        Pin::new_unchecked(this).poll(&mut cx).into()
+    }
+}
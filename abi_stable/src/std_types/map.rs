@@ -32,7 +32,9 @@ use crate::{
 
 
 mod entry;
+mod entry_ref;
 mod extern_fns;
+mod extract_if;
 mod iterator_stuff;
 mod map_query;
 mod map_key;
@@ -44,6 +46,7 @@ use self::{
     map_query::MapQuery,
     map_key::MapKey,
     entry::{BoxedREntry},
+    extract_if::{ExtractIfIter,MapPredicate},
 };
 
 pub use self::{
@@ -52,6 +55,7 @@ pub use self::{
         IntoIter,
     },
     entry::{REntry,ROccupiedEntry,RVacantEntry},
+    entry_ref::{REntryRef,ROccupiedEntryRef,RVacantEntryRef},
 };
 
 
@@ -142,6 +146,11 @@ pub type IterMut<'a,K,V>=
 pub type Drain<'a,K,V>=
     DynTrait<'a,RBox<()>,ValIterInterface<K,V>>;
 
+/// An RHashMap iterator returned by `RHashMap::extract_if`,
+/// implementing `Iterator<Item= Tuple2< K, V > >+!Send+!Sync`
+pub type ExtractIf<'a,K,V>=
+    DynTrait<'a,RBox<()>,ValIterInterface<K,V>>;
+
 
 /// Used as the erased type of the RHashMap type.
 #[repr(C)]
@@ -228,7 +237,7 @@ impl<K,V,S> RHashMap<K,V,S>{
     pub fn with_hasher(hash_builder: S) -> RHashMap<K, V, S> 
     where
         K:Eq+Hash,
-        S:BuildHasher+Default,
+        S:BuildHasher,
     {
         Self::with_capacity_and_hasher(0,hash_builder)
     }
@@ -252,10 +261,9 @@ impl<K,V,S> RHashMap<K,V,S>{
     ) -> RHashMap<K, V, S> 
     where
         K:Eq+Hash,
-        S:BuildHasher+Default,
+        S:BuildHasher,
     {
-        let mut map=VTable::<K,V,S>::erased_map(hash_builder);
-        map.reserve(capacity);
+        let map=VTable::<K,V,S>::erased_map(capacity,hash_builder);
         RHashMap{
             map,
             vtable:WithMetadata::as_prefix(VTable::VTABLE_REF),
@@ -319,6 +327,34 @@ impl<K,V,S> RHashMap<K,V,S>{
         }
     }
 
+    /// Returns references to the key stored in the map and the value associated with it.
+    ///
+    /// This is useful for getting the stored key from a borrowed query,
+    /// eg:to reuse an interned string instead of allocating a new one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString,Tuple2};
+    ///
+    /// let mut map=RHashMap::<RString,u32>::new();
+    /// assert_eq!(map.get_key_value("boo"), None);
+    /// map.insert("boo".into(),0);
+    /// assert_eq!(
+    ///     map.get_key_value("boo"),
+    ///     Some(Tuple2(&RString::from("boo"),&0)),
+    /// );
+    ///
+    /// ```
+    pub fn get_key_value<Q>(&self,query:&Q)->Option<Tuple2<&K,&V>>
+    where
+        K:Borrow<Q>,
+        Q:Hash+Eq+?Sized
+    {
+        let vtable=self.vtable();
+        vtable.get_key_value()(&*self.map,MapQuery::new(&query)).into_option()
+    }
+
     /// Returns a mutable reference to the value associated with the key.
     ///
     /// # Example
@@ -579,6 +615,86 @@ impl<K,V,S> RHashMap<K,V,S>{
         }
     }
 
+    /// Inserts all the key-value pairs in `iter`,
+    /// returning the values that were replaced,in the same order as the pairs.
+    ///
+    /// Unlike `extend`,this inserts all the pairs in a single call through the vtable,
+    /// reserving space for the lower bound of the size_hint of the iterator first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RNone,RSome,Tuple2};
+    ///
+    /// let mut map=RHashMap::<u32,u32>::new();
+    /// map.insert(1,10);
+    ///
+    /// let replaced=map.insert_many(vec![Tuple2(0,1),Tuple2(1,2),Tuple2(0,3)]);
+    ///
+    /// assert_eq!(&replaced[..],&[RNone,RSome(10),RSome(1)][..]);
+    /// assert_eq!(map[&0],3);
+    /// assert_eq!(map[&1],2);
+    ///
+    /// ```
+    pub fn insert_many<I>(&mut self,iter:I)->RVec<ROption<V>>
+    where
+        I:IntoIterator<Item=Tuple2<K,V>>,
+    {
+        let vtable=self.vtable();
+        let iter=DynTrait::from_borrowing_value(iter.into_iter(),ValIterInterface::NEW);
+        vtable.insert_many()(&mut *self.map,iter)
+    }
+
+    /// Inserts all the entries of `other` into this map,
+    /// calling `resolve(key,existing,incoming)` to get the value for keys
+    /// that are in both maps.
+    ///
+    /// The key that is kept for keys in both maps is the one in `self`.
+    ///
+    /// If `resolve` panics,the entry for the key that was being resolved is removed,
+    /// and the remaining entries of `other` are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut host=vec![
+    ///     ("plugins".into(),3),
+    ///     ("threads".into(),4),
+    /// ].into_iter().collect::<RHashMap<RString,u32>>();
+    ///
+    /// let contribution=vec![
+    ///     ("plugins".into(),2),
+    ///     ("sockets".into(),1),
+    /// ].into_iter().collect::<RHashMap<RString,u32>>();
+    ///
+    /// host.merge(contribution,|_,existing,incoming| existing+incoming );
+    ///
+    /// assert_eq!(host.len(),3);
+    /// assert_eq!(host.get("plugins"),Some(&5));
+    /// assert_eq!(host.get("threads"),Some(&4));
+    /// assert_eq!(host.get("sockets"),Some(&1));
+    ///
+    /// ```
+    pub fn merge<F>(&mut self,other:RHashMap<K,V,S>,mut resolve:F)
+    where
+        F:FnMut(&K,V,V)->V,
+    {
+        self.reserve(other.len());
+        for Tuple2(key,incoming) in other {
+            match self.remove_entry_p(&key) {
+                RSome(Tuple2(key,existing))=>{
+                    let value=resolve(&key,existing,incoming);
+                    self.insert(key,value);
+                }
+                RNone=>{
+                    self.insert(key,incoming);
+                }
+            }
+        }
+    }
+
     /// Reserves enough space to insert `reserved` extra elements without reallocating.
     ///
     /// # Example
@@ -681,6 +797,10 @@ impl<K,V,S> RHashMap<K,V,S>{
     ///
     /// This returns an `Iterator<Item= Tuple2< &K, &V > >+!Send+!Sync+Clone`
     ///
+    /// The iteration order is unspecified,
+    /// and can differ between maps with the same entries,
+    /// use `sorted_iter` if you need a deterministic order.
+    ///
     /// # Example
     ///
     /// ```
@@ -701,6 +821,54 @@ impl<K,V,S> RHashMap<K,V,S>{
 
         vtable.iter()(&*self.map)
     }
+
+    /// Returns a wrapper whose `Debug` impl prints the entries of the map sorted by key,
+    /// so that the output doesn't depend on the iteration order of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RHashMap;
+    ///
+    /// let map=vec![(3,"c"),(1,"a"),(2,"b")].into_iter().collect::<RHashMap<u32,&str>>();
+    ///
+    /// assert_eq!( format!("{:?}",map.debug_sorted()), r#"{1: "a", 2: "b", 3: "c"}"# );
+    ///
+    /// ```
+    pub fn debug_sorted(&self)->SortedDebug<'_,K,V,S>{
+        SortedDebug(self)
+    }
+
+    /// Returns the entries of the map sorted by key,with references to the keys and values.
+    ///
+    /// Unlike `iter`,this allocates an `RVec` with all the entries and sorts it,
+    /// taking `O(n log n)` time.
+    /// This is useful to get a deterministic order when serializing or logging the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     std_types::{RHashMap,Tuple2},
+    ///     rvec,
+    /// };
+    ///
+    /// let map=vec![(3,"c"),(1,"a"),(2,"b")].into_iter().collect::<RHashMap<u32,&str>>();
+    ///
+    /// assert_eq!( 
+    ///     map.sorted_iter(), 
+    ///     rvec![Tuple2(&1,&"a"),Tuple2(&2,&"b"),Tuple2(&3,&"c")],
+    /// );
+    ///
+    /// ```
+    pub fn sorted_iter(&self)->RVec<Tuple2<&K,&V>>
+    where
+        K:Ord,
+    {
+        let mut entries=self.iter().collect::<RVec<_>>();
+        entries.sort_by(|l,r| l.0.cmp(r.0) );
+        entries
+    }
     
     /// Iterates over the entries in the map,with mutable references to the values in the map.
     ///
@@ -754,6 +922,47 @@ impl<K,V,S> RHashMap<K,V,S>{
         vtable.drain()(&mut *self.map)
     }
 
+/**
+Returns an iterator that removes and yields the entries for which `pred` returns true,
+leaving the other entries in the map.
+
+`pred` is called with every entry in the map when the iterator is first advanced,
+and can mutate the values of the entries that are kept.
+The entries that `pred` returned true for are then removed one at a time 
+as the iterator is advanced.
+
+If the iterator is dropped before being fully consumed,
+the entries that weren't yielded are kept in the map,
+and if it was never advanced `pred` is not called at all.
+
+The capacity of the map is preserved.
+
+# Example
+
+```
+use abi_stable::std_types::{RHashMap,Tuple2};
+
+let mut map=(0..8).map(|x| Tuple2(x,x*10) ).collect::<RHashMap<u32,u32>>();
+
+let mut removed=map.extract_if(|k,_| k%2==0 ).collect::<Vec<_>>();
+removed.sort();
+assert_eq!( removed, vec![Tuple2(0,0),Tuple2(2,20),Tuple2(4,40),Tuple2(6,60)] );
+
+let mut kept=map.iter().map(|Tuple2(k,v)| (*k,*v) ).collect::<Vec<_>>();
+kept.sort();
+assert_eq!( kept, vec![(1,10),(3,30),(5,50),(7,70)] );
+
+```
+*/
+    pub fn extract_if<'a,F>(&'a mut self,pred:F)->ExtractIf<'a,K,V>
+    where
+        F:FnMut(&K,&mut V)->bool+'a,
+    {
+        let vtable=self.vtable();
+
+        vtable.extract_if()(&mut *self.map,MapPredicate::new(pred))
+    }
+
 /**
 Gets a handle into the entry in the map for the key,
 that allows operating directly on the entry.
@@ -913,6 +1122,16 @@ where
     fn clone(&self)->Self{
         self.iter().map(|Tuple2(k,v)| (k.clone(),v.clone()) ).collect()
     }
+
+    /// Clones the entries of `source` into `self`,
+    /// reusing the allocation of `self` if it has enough capacity.
+    fn clone_from(&mut self,source:&Self){
+        self.clear();
+        self.reserve(source.len());
+        for Tuple2(k,v) in source.iter() {
+            self.insert(k.clone(),v.clone());
+        }
+    }
 }
 
 
@@ -929,6 +1148,34 @@ where
 }
 
 
+/// Wrapper around a `&RHashMap<K,V,S>`,whose `Debug` impl prints the entries sorted by key.
+///
+/// This is useful for getting deterministic output,eg: in snapshot tests.
+///
+/// This is returned by the `RHashMap::debug_sorted` method.
+pub struct SortedDebug<'a,K,V,S=RandomState>(pub &'a RHashMap<K,V,S>);
+
+impl<'a,K,V,S> Copy for SortedDebug<'a,K,V,S>{}
+
+impl<'a,K,V,S> Clone for SortedDebug<'a,K,V,S>{
+    fn clone(&self)->Self{
+        *self
+    }
+}
+
+impl<'a,K,V,S> Debug for SortedDebug<'a,K,V,S>
+where 
+    K:Debug+Ord,
+    V:Debug,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_map()
+         .entries(self.0.sorted_iter().into_iter().map(Tuple2::into_rust))
+         .finish()
+    }
+}
+
+
 impl<K,V,S> Eq for RHashMap<K,V,S>
 where 
     K:Eq,
@@ -1000,12 +1247,14 @@ mod serde{
 
 
     struct RHashMapVisitor<K,V,S> {
+        hash_builder: S,
         marker: PhantomData<fn() -> RHashMap<K,V,S>>
     }
 
     impl<K,V,S> RHashMapVisitor<K,V,S> {
-        fn new() -> Self {
+        fn new(hash_builder:S) -> Self {
             RHashMapVisitor {
+                hash_builder,
                 marker: PhantomData
             }
         }
@@ -1013,9 +1262,9 @@ mod serde{
 
     impl<'de,K,V,S> Visitor<'de> for RHashMapVisitor<K,V,S>
     where
-        K: Deserialize<'de>,
+        K: Deserialize<'de>+Eq+Hash,
         V: Deserialize<'de>,
-        RHashMap<K,V,S>:Default,
+        S: BuildHasher,
     {
         type Value = RHashMap<K,V,S>;
 
@@ -1028,8 +1277,7 @@ mod serde{
             M: MapAccess<'de>,
         {
             let capacity=map_access.size_hint().unwrap_or(0);
-            let mut map = RHashMap::default();
-            map.reserve(capacity);
+            let mut map = RHashMap::with_capacity_and_hasher(capacity,self.hash_builder);
 
             while let Some((k, v)) = map_access.next_entry()? {
                 map.insert(k, v);
@@ -1041,15 +1289,73 @@ mod serde{
 
     impl<'de,K,V,S> Deserialize<'de> for RHashMap<K,V,S>
     where
-        K: Deserialize<'de>,
+        K: Deserialize<'de>+Eq+Hash,
         V: Deserialize<'de>,
-        Self:Default,
+        S: BuildHasher+Default,
     {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_map(RHashMapVisitor::new())
+            deserializer.deserialize_map(RHashMapVisitor::new(S::default()))
+        }
+    }
+
+    impl<K,V,S> RHashMap<K,V,S>{
+        /// Deserializes an `RHashMap` which uses `hash_builder` to hash the keys.
+        ///
+        /// This allows deserializing maps whose hasher doesn't implement `Default`,
+        /// like hashers keyed with a secret that is only known at runtime.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::std_types::{RHashMap,RString};
+        ///
+        /// use std::{
+        ///     collections::hash_map::DefaultHasher,
+        ///     hash::BuildHasher,
+        /// };
+        ///
+        /// // A hasher that can only be constructed with a key.
+        /// #[derive(Clone)]
+        /// struct KeyedState{ key:u64 }
+        ///
+        /// impl BuildHasher for KeyedState{
+        ///     type Hasher=DefaultHasher;
+        ///
+        ///     fn build_hasher(&self)->DefaultHasher{
+        ///         use std::hash::Hasher;
+        ///         let mut hasher=DefaultHasher::new();
+        ///         hasher.write_u64(self.key);
+        ///         hasher
+        ///     }
+        /// }
+        ///
+        /// let json=r#"{"hello":10,"world":20}"#;
+        /// let mut deserializer=serde_json::Deserializer::from_str(json);
+        ///
+        /// let map=RHashMap::<RString,u32,_>::deserialize_with_hasher(
+        ///     &mut deserializer,
+        ///     KeyedState{key:0xDEAD},
+        /// ).unwrap();
+        ///
+        /// assert_eq!( map.len(), 2 );
+        /// assert_eq!( map["hello"], 10 );
+        /// assert_eq!( map["world"], 20 );
+        ///
+        /// ```
+        pub fn deserialize_with_hasher<'de,D>(
+            deserializer: D,
+            hash_builder: S,
+        ) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+            K: Deserialize<'de>+Eq+Hash,
+            V: Deserialize<'de>,
+            S: BuildHasher,
+        {
+            deserializer.deserialize_map(RHashMapVisitor::new(hash_builder))
         }
     }
 
@@ -1110,6 +1416,18 @@ struct VTableVal<K,V,S>{
     iter_val:extern "C" fn(RBox<ErasedMap<K,V,S>>)->IntoIter<K,V>,
     #[sabi(last_prefix_field)]
     entry:extern "C" fn(&mut ErasedMap<K,V,S>,K)->REntry<'_,K,V>,
+    get_key_value:for<'a> extern "C" fn(
+        &'a ErasedMap<K,V,S>,
+        MapQuery<'_,K>
+    )->ROption<Tuple2<&'a K,&'a V>>,
+    insert_many:extern "C" fn(
+        &mut ErasedMap<K,V,S>,
+        DynTrait<'_,RBox<()>,ValIterInterface<K,V>>,
+    )->RVec<ROption<V>>,
+    extract_if:for<'a> extern "C" fn(
+        &'a mut ErasedMap<K,V,S>,
+        MapPredicate<'a,K,V>,
+    )->ExtractIf<'a,K,V>,
 }
 
 
@@ -1126,9 +1444,9 @@ where
         ))
     };
 
-    fn erased_map(hash_builder:S)->RBox<ErasedMap<K,V,S>>{
+    fn erased_map(capacity:usize,hash_builder:S)->RBox<ErasedMap<K,V,S>>{
         unsafe{
-            let map=HashMap::<MapKey<K>,V,S>::with_hasher(hash_builder);
+            let map=HashMap::<MapKey<K>,V,S>::with_capacity_and_hasher(capacity,hash_builder);
             let boxed=BoxedHashMap{
                 map,
                 entry:None,
@@ -1160,6 +1478,9 @@ where
         drain       :ErasedMap::drain,
         iter_val    :ErasedMap::iter_val,
         entry       :ErasedMap::entry,
+        get_key_value:ErasedMap::get_key_value,
+        insert_many :ErasedMap::insert_many,
+        extract_if  :ErasedMap::extract_if,
     };
 
 }
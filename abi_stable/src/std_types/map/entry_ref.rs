@@ -0,0 +1,356 @@
+use super::*;
+
+
+/**
+A handle into an entry in a map,which is either vacant or occupied,
+returned by `RHashMap::entry_ref`.
+
+Unlike `REntry`,this only converts the borrowed key into a `K`
+when a value is inserted into a vacant entry.
+
+This type is not ffi-safe,it's a wrapper around the methods of `RHashMap`.
+*/
+pub enum REntryRef<'a,'q,K,Q:?Sized,V,S=RandomState>{
+    Occupied(ROccupiedEntryRef<'a,'q,Q,V>),
+    Vacant(RVacantEntryRef<'a,'q,K,Q,V,S>),
+}
+
+
+/// A handle into an occupied entry in a map,returned by `RHashMap::entry_ref`.
+pub struct ROccupiedEntryRef<'a,'q,Q:?Sized,V>{
+    key:&'q Q,
+    value:&'a mut V,
+}
+
+
+/// A handle into a vacant entry in a map,returned by `RHashMap::entry_ref`.
+pub struct RVacantEntryRef<'a,'q,K,Q:?Sized,V,S=RandomState>{
+    key:&'q Q,
+    map:&'a mut RHashMap<K,V,S>,
+}
+
+
+/////////////////////////////////////////////////////////////////////////////////////////////
+
+
+impl<K,V,S> RHashMap<K,V,S>{
+/**
+Gets a handle into the entry in the map for the borrowed key,
+that allows operating directly on the entry.
+
+The key is only converted into a `K` (with `ToOwned` and `Into`)
+when a value is inserted into a vacant entry.
+
+# Example
+
+```
+use abi_stable::std_types::{RHashMap,RString};
+
+let mut map=RHashMap::<RString,u32>::new();
+
+// Inserting an entry that wasn't there before,which converts the key to an RString.
+assert_eq!(map.entry_ref("foo").or_insert(3),&mut 3);
+
+// The entry is occupied,so the key isn't converted.
+*map.entry_ref("foo").or_insert(5)+=10;
+
+assert_eq!(map.get("foo"),Some(&13));
+
+```
+*/
+    pub fn entry_ref<'a,'q,Q>(&'a mut self,key:&'q Q)->REntryRef<'a,'q,K,Q,V,S>
+    where
+        K:Borrow<Q>,
+        Q:Hash+Eq+ToOwned+?Sized,
+        Q::Owned:Into<K>,
+    {
+        let this:*mut Self=self;
+        // Going through a raw pointer because the borrow checker
+        // doesn't know that `self` isn't borrowed when `get_mut` returns `None`.
+        unsafe{
+            match (*this).get_mut(key) {
+                Some(value)=>REntryRef::Occupied(ROccupiedEntryRef{key,value}),
+                None=>REntryRef::Vacant(RVacantEntryRef{key,map:&mut *this}),
+            }
+        }
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////////////////////////////////
+
+
+impl<'a,'q,K,Q,V,S> REntryRef<'a,'q,K,Q,V,S>
+where
+    K:Borrow<Q>,
+    Q:Hash+Eq+ToOwned+?Sized,
+    Q::Owned:Into<K>,
+{
+    /// Returns a reference to the value in the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map:RHashMap<RString,u32>=vec![("foo".into(),100)].into_iter().collect();
+    ///
+    /// assert_eq!(map.entry_ref("bar").get(),None);
+    /// assert_eq!(map.entry_ref("foo").get(),Some(&100));
+    ///
+    /// ```
+    pub fn get(&self) -> Option<&V> {
+        match self {
+            REntryRef::Occupied(entry) => Some(entry.get()),
+            REntryRef::Vacant(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value in the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map:RHashMap<RString,u32>=vec![("foo".into(),100)].into_iter().collect();
+    ///
+    /// assert_eq!(map.entry_ref("bar").get_mut(),None);
+    /// assert_eq!(map.entry_ref("foo").get_mut(),Some(&mut 100));
+    ///
+    /// ```
+    pub fn get_mut(&mut self) -> Option<&mut V> {
+        match self {
+            REntryRef::Occupied(entry) => Some(entry.get_mut()),
+            REntryRef::Vacant(_) => None,
+        }
+    }
+
+    /// Gets the borrowed key of the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map=RHashMap::<RString,u32>::new();
+    ///
+    /// assert_eq!(map.entry_ref("foo").key(),"foo");
+    ///
+    /// ```
+    pub fn key(&self) -> &'q Q {
+        match self {
+            REntryRef::Occupied(entry) => entry.key(),
+            REntryRef::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` as the value in the entry if it wasn't occupied,
+    /// returning a mutable reference to the value in the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map=RHashMap::<RString,u32>::new();
+    ///
+    /// assert_eq!(map.entry_ref("foo").or_insert(100),&mut 100);
+    ///
+    /// assert_eq!(map.entry_ref("foo").or_insert(400),&mut 100);
+    ///
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            REntryRef::Occupied(entry) => entry.into_mut(),
+            REntryRef::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts `default()` as the value in the entry if it wasn't occupied,
+    /// returning a mutable reference to the value in the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map=RHashMap::<RString,RString>::new();
+    ///
+    /// assert_eq!(
+    ///     map.entry_ref("foo").or_insert_with(|| "bar".into() ),
+    ///     &mut RString::from("bar")
+    /// );
+    ///
+    /// assert_eq!(
+    ///     map.entry_ref("foo").or_insert_with(|| "baz".into() ),
+    ///     &mut RString::from("bar")
+    /// );
+    ///
+    /// ```
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V
+    {
+        match self {
+            REntryRef::Occupied(entry) => entry.into_mut(),
+            REntryRef::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Allows mutating an occupied entry before doing other operations.
+    ///
+    /// This is a no-op on a vacant entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map=RHashMap::<RString,u32>::new();
+    /// map.insert("foo".into(),100);
+    ///
+    /// assert_eq!(
+    ///     map.entry_ref("foo").and_modify(|x| *x+=1 ).get(),
+    ///     Some(&101)
+    /// );
+    /// assert_eq!(
+    ///     map.entry_ref("bar").and_modify(|x| *x+=1 ).get(),
+    ///     None
+    /// );
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V)
+    {
+        match self {
+            REntryRef::Occupied(mut entry) => {
+                f(entry.get_mut());
+                REntryRef::Occupied(entry)
+            },
+            REntryRef::Vacant(entry) => REntryRef::Vacant(entry),
+        }
+    }
+
+    /// Inserts the `V::default()` value in the entry if it wasn't occupied,
+    /// returning a mutable reference to the value in the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RHashMap,RString};
+    ///
+    /// let mut map=RHashMap::<RString,u32>::new();
+    ///
+    /// assert_eq!(map.entry_ref("foo").or_default(),&mut 0);
+    ///
+    /// *map.entry_ref("foo").or_default()+=3;
+    /// assert_eq!(map.entry_ref("foo").or_default(),&mut 3);
+    ///
+    /// ```
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default
+    {
+        match self {
+            REntryRef::Occupied(entry) => entry.into_mut(),
+            REntryRef::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+
+impl<K,Q,V,S> Debug for REntryRef<'_,'_,K,Q,V,S>
+where
+    Q:Debug+?Sized,
+    V:Debug,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        match self {
+            REntryRef::Occupied(entry)=>Debug::fmt(entry,f),
+            REntryRef::Vacant(entry)=>Debug::fmt(entry,f),
+        }
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////////////////////////////////
+
+
+impl<'a,'q,Q:?Sized,V> ROccupiedEntryRef<'a,'q,Q,V>{
+    /// Gets the borrowed key of the entry.
+    pub fn key(&self)->&'q Q{
+        self.key
+    }
+
+    /// Gets a reference to the value in the entry.
+    pub fn get(&self)->&V{
+        self.value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    pub fn get_mut(&mut self)->&mut V{
+        self.value
+    }
+
+    /// Gets a mutable reference to the value in the entry,
+    /// that borrows with the lifetime of the map instead of
+    /// borrowing from this `ROccupiedEntryRef`.
+    pub fn into_mut(self)->&'a mut V{
+        self.value
+    }
+
+    /// Replaces the current value of the entry with `value`,returning the previous value.
+    pub fn insert(&mut self,value:V)->V{
+        mem::replace(self.value,value)
+    }
+}
+
+
+impl<Q,V> Debug for ROccupiedEntryRef<'_,'_,Q,V>
+where
+    Q:Debug+?Sized,
+    V:Debug,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_struct("ROccupiedEntryRef")
+         .field("key",&self.key)
+         .field("value",&self.value)
+         .finish()
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////////////////////////////////
+
+
+impl<'a,'q,K,Q,V,S> RVacantEntryRef<'a,'q,K,Q,V,S>
+where
+    K:Borrow<Q>,
+    Q:Hash+Eq+ToOwned+?Sized,
+    Q::Owned:Into<K>,
+{
+    /// Gets the borrowed key of the entry.
+    pub fn key(&self)->&'q Q{
+        self.key
+    }
+
+    /// Inserts `value` into the map,converting the borrowed key into a `K`,
+    /// returning a mutable reference to `value`.
+    pub fn insert(self,value:V)->&'a mut V{
+        let key:K=self.key.to_owned().into();
+        self.map.entry(key).or_insert(value)
+    }
+}
+
+
+impl<K,Q,V,S> Debug for RVacantEntryRef<'_,'_,K,Q,V,S>
+where
+    Q:Debug+?Sized,
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_struct("RVacantEntryRef")
+         .field("key",&self.key)
+         .finish()
+    }
+}
@@ -55,12 +55,36 @@ where
         })
     }
 
+    pub(super)extern "C" fn insert_many(
+        &mut self,
+        iter:DynTrait<'_,RBox<()>,ValIterInterface<K,V>>,
+    )->RVec<ROption<V>>{
+        self.run_mut(|this|{
+            this.map.reserve(iter.size_hint().0);
+            iter.map(|Tuple2(key,value)| this.map.insert(MapKey::Value(key),value).into_c() )
+                .collect()
+        })
+    }
+
     pub(super)extern "C" fn get_elem(&self,key:MapQuery<'_,K>)->Option<&V>{
         self.run(|this|unsafe{ 
             this.map.get(&key.as_mapkey()) 
         })
     }    
 
+    pub(super)extern "C" fn get_key_value(&self,key:MapQuery<'_,K>)->ROption<Tuple2<&K,&V>>{
+        self.run(|this|{
+            #[cfg(feature="rust_1_40")]
+            let found=this.map.get_key_value(unsafe{ &key.as_mapkey() });
+
+            // HashMap::get_key_value was stabilized in Rust 1.40
+            #[cfg(not(feature="rust_1_40"))]
+            let found=this.map.iter().find(|(k,_)| key.is_equal(MapKey::as_ref(k)) );
+
+            found.map(map_iter_ref).into_c()
+        })
+    }
+
     pub(super)extern "C" fn get_mut_elem(&mut self,key:MapQuery<'_,K>)->Option<&mut V>{
         self.run_mut(|this|unsafe{ 
             this.map.get_mut(&key.as_mapkey()) 
@@ -131,6 +155,16 @@ where
         })
     }
 
+    pub(super)extern "C" fn extract_if<'a>(
+        &'a mut self,
+        pred:MapPredicate<'a,K,V>,
+    )->ExtractIf<'a,K,V>{
+        self.run_mut(|this|{
+            let iter=ExtractIfIter::new(&mut this.map,pred);
+            DynTrait::from_borrowing_value(iter,ValIterInterface::NEW)
+        })
+    }
+
     pub(super)extern "C" fn iter_val<'a>(this:RBox<ErasedMap<K,V,S>>)->IntoIter<K,V>{
         Self::run_val(this,|this|{
             let iter=this.piped(RBox::into_inner).map.into_iter().map(map_iter_val);
@@ -0,0 +1,129 @@
+use super::*;
+
+use std::{
+    mem::ManuallyDrop,
+    ptr,
+    vec,
+};
+
+use crate::{
+    marker_type::NonOwningPhantom,
+    pointer_trait::TransmuteElement,
+};
+
+
+/// A type-erased `FnMut(&K,&mut V)->bool` closure,
+/// used by `RHashMap::extract_if` to decide which entries to remove.
+#[repr(C)]
+#[derive(StableAbi)]
+pub(super) struct MapPredicate<'a,K,V>{
+    closure:RBox<ErasedObject>,
+    func:unsafe extern "C" fn(&mut ErasedObject,&K,&mut V)->bool,
+    _marker:NonOwningPhantom<&'a ()>,
+}
+
+impl<'a,K,V> MapPredicate<'a,K,V>{
+    pub(super) fn new<F>(closure:F)->Self
+    where
+        F:FnMut(&K,&mut V)->bool+'a,
+    {
+        Self{
+            closure:unsafe{
+                // The `'a` lifetime is kept by the `_marker` field.
+                RBox::new(closure).transmute_element::<ErasedObject>()
+            },
+            func:call_predicate::<F,K,V>,
+            _marker:NonOwningPhantom::NEW,
+        }
+    }
+
+    fn call(&mut self,key:&K,value:&mut V)->bool{
+        unsafe{ (self.func)(&mut *self.closure,key,value) }
+    }
+}
+
+unsafe extern "C" fn call_predicate<F,K,V>(closure:&mut ErasedObject,key:&K,value:&mut V)->bool
+where
+    F:FnMut(&K,&mut V)->bool,
+{
+    extern_fn_panic_handling! {
+        let closure=transmute_mut_reference::<ErasedObject,F>(closure);
+        closure(key,value)
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/// The iterator that `RHashMap::extract_if` erases into an `ExtractIf`.
+///
+/// Nothing is done until the iterator is first advanced,
+/// at which point `pred` is called on every entry,
+/// storing (bitwise) copies of the keys of the entries that it returned true for.
+/// Those entries are then removed from the map one at a time as the iterator is advanced,
+/// so the entries that weren't yielded stay in the map when this is dropped.
+pub(super) struct ExtractIfIter<'a,K,V,S>
+where
+    K:Eq+Hash,
+    S:BuildHasher,
+{
+    map:&'a mut HashMap<MapKey<K>,V,S>,
+    /// The keys of the entries to remove,`None` before the iterator is first advanced.
+    ///
+    /// These are bitwise copies of keys that are still in the map,
+    /// which are only used to look up those entries,and are never dropped.
+    matched:Option<vec::IntoIter<ManuallyDrop<MapKey<K>>>>,
+    pred:MapPredicate<'a,K,V>,
+}
+
+impl<'a,K,V,S> ExtractIfIter<'a,K,V,S>
+where
+    K:Eq+Hash,
+    S:BuildHasher,
+{
+    pub(super) fn new(map:&'a mut HashMap<MapKey<K>,V,S>,pred:MapPredicate<'a,K,V>)->Self{
+        Self{map,matched:None,pred}
+    }
+
+    fn matched(&mut self)->&mut vec::IntoIter<ManuallyDrop<MapKey<K>>>{
+        let map=&mut *self.map;
+        let pred=&mut self.pred;
+        self.matched.get_or_insert_with(||{
+            map.iter_mut()
+                .filter_map(|(key,value)|{
+                    if pred.call(key.as_ref(),value) {
+                        // Safety: the copy is never dropped,
+                        // and is only used to look up the entry while it's in the map.
+                        Some(ManuallyDrop::new(unsafe{ ptr::read(key) }))
+                    }else{
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+        })
+    }
+}
+
+impl<'a,K,V,S> Iterator for ExtractIfIter<'a,K,V,S>
+where
+    K:Eq+Hash,
+    S:BuildHasher,
+{
+    type Item=Tuple2<K,V>;
+
+    fn next(&mut self)->Option<Tuple2<K,V>>{
+        let key=self.matched().next()?;
+        let (key,value)=self.map.remove_entry(&*key)
+            .expect("the entries yielded by extract_if must be in the map");
+        Some(Tuple2(key.into_inner(),value))
+    }
+
+    fn size_hint(&self)->(usize,Option<usize>){
+        match &self.matched {
+            Some(matched)=>(matched.len(),Some(matched.len())),
+            None=>(0,Some(self.map.len())),
+        }
+    }
+}
@@ -71,6 +71,19 @@ fn reserve(){
 }
 
 
+#[test]
+fn with_capacity_and_hasher(){
+    for &capacity in &[0,1,10,100,1000] {
+        let map=RHashMap::<u32,u32,_>::with_capacity_and_hasher(capacity,RandomState::new());
+        assert!(capacity <= map.capacity(),"capacity:{}",map.capacity());
+        assert_eq!(map.len(),0);
+    }
+
+    let map=RHashMap::<u32,u32,_>::with_hasher(RandomState::new());
+    assert_eq!(map.capacity(),0);
+}
+
+
 #[test]
 fn test_eq(){
     let map0=new_map::<String,String,DefaultBH>();
@@ -251,6 +264,80 @@ fn get(){
 }
 
 
+#[test]
+fn get_key_value(){
+    let mut map=RHashMap::<RString,u32>::new();
+    assert_eq!(map.get_key_value("what"),None);
+
+    map.insert("what".into(),10);
+    map.insert("the".into(),5);
+
+    let Tuple2(key,value)=map.get_key_value("what").unwrap();
+    assert_eq!(key,"what");
+    assert_eq!(*value,10);
+
+    // The key is the one stored in the map,not a copy of the query.
+    let stored_ptr=key.as_ptr();
+    let Tuple2(key,_)=map.get_key_value(&RString::from("what")).unwrap();
+    assert_eq!(key.as_ptr(),stored_ptr);
+
+    assert_eq!(map.get_key_value("oof"),None);
+}
+
+
+#[test]
+fn insert_many(){
+    let mut map=RHashMap::<RString,u32>::new();
+    map.insert("what".into(),10);
+    map.insert("the".into(),5);
+
+    let replaced=map.insert_many(
+        vec![("oof",1),("what",2),("the",3),("oof",4),("you",5)]
+            .into_iter()
+            .map(|(k,v)| Tuple2(RString::from(k),v) )
+    );
+    assert_eq!(&replaced[..],&[RNone,RSome(10),RSome(5),RSome(1),RNone][..]);
+
+    assert_eq!(map.len(),4);
+    assert_eq!(map["what"],2);
+    assert_eq!(map["the"],3);
+    assert_eq!(map["oof"],4);
+    assert_eq!(map["you"],5);
+
+    assert_eq!(map.insert_many(Vec::new()).len(),0);
+    assert_eq!(map.len(),4);
+}
+
+
+#[test]
+fn merge(){
+    let mut map=RHashMap::<RString,RString>::new();
+    map.insert("what".into(),"a".into());
+    map.insert("the".into(),"b".into());
+
+    let other=vec![("the","c"),("oof","d"),("what","e")]
+        .into_iter()
+        .map(|(k,v)| (RString::from(k),RString::from(v)) )
+        .collect::<RHashMap<RString,RString>>();
+
+    let mut resolved=Vec::new();
+    map.merge(other,|key,existing,incoming|{
+        resolved.push(key.clone());
+        format!("{}{}",existing,incoming).into()
+    });
+    resolved.sort();
+
+    assert_eq!(resolved,vec![RString::from("the"),RString::from("what")]);
+    assert_eq!(map.len(),3);
+    assert_eq!(map["what"].as_str(),"ae");
+    assert_eq!(map["the"].as_str(),"bc");
+    assert_eq!(map["oof"].as_str(),"d");
+
+    map.merge(RHashMap::new(),|_,_,_| panic!("there are no overlapping keys") );
+    assert_eq!(map.len(),3);
+}
+
+
 
 
 #[test]
@@ -403,6 +490,26 @@ fn iter_mut(){
 }
 
 
+#[test]
+fn clone_from(){
+    let source=new_map::<String,String,DefaultBH>();
+
+    let mut map=RHashMap::<String,String>::with_capacity(64);
+    map.insert("hello".into(),"world".into());
+    let capacity=map.capacity();
+
+    map.clone_from(&source);
+    assert_eq!(map, source);
+    assert_eq!(map.capacity(), capacity);
+
+    // Cloning from a map with more entries than the capacity
+    let mut map=RHashMap::<String,String>::new();
+    map.insert("hello".into(),"world".into());
+
+    map.clone_from(&source);
+    assert_eq!(map, source);
+}
+
 #[test]
 fn extend(){
     let expected=new_map::<String,String,DefaultBH>();
@@ -597,3 +704,240 @@ fn entry_or_default(){
         "hello".into_(RString::T)
     );
 }
+
+
+#[test]
+fn entry_ref_only_converts_on_insert(){
+    use std::cell::Cell;
+
+    thread_local!{
+        static CONVERSIONS:Cell<usize>=Cell::new(0);
+    }
+
+    #[derive(Debug,PartialEq,Eq)]
+    struct CountedKey(RString);
+
+    impl Hash for CountedKey{
+        fn hash<H:Hasher>(&self,state:&mut H){
+            self.0.as_str().hash(state)
+        }
+    }
+
+    impl Borrow<str> for CountedKey{
+        fn borrow(&self)->&str{
+            self.0.as_str()
+        }
+    }
+
+    impl From<String> for CountedKey{
+        fn from(s:String)->Self{
+            CONVERSIONS.with(|c| c.set(c.get()+1) );
+            CountedKey(s.into())
+        }
+    }
+
+    let conversions=||CONVERSIONS.with(|c| c.get() );
+
+    let mut map=RHashMap::<CountedKey,u32>::new();
+
+    assert_eq!(map.entry_ref("foo").get(),None);
+    assert_eq!(map.entry_ref("foo").key(),"foo");
+    assert_eq!(conversions(),0);
+
+    assert_eq!(*map.entry_ref("foo").or_insert(3),3);
+    assert_eq!(conversions(),1);
+
+    *map.entry_ref("foo").and_modify(|v| *v+=10 ).or_insert(100)+=1;
+    assert_eq!(*map.entry_ref("foo").or_default(),14);
+    assert_eq!(conversions(),1);
+
+    match map.entry_ref("foo") {
+        REntryRef::Occupied(mut entry)=>{
+            assert_eq!(entry.key(),"foo");
+            assert_eq!(entry.insert(20),14);
+        }
+        REntryRef::Vacant(_)=>unreachable!(),
+    }
+
+    match map.entry_ref("bar") {
+        REntryRef::Occupied(_)=>unreachable!(),
+        REntryRef::Vacant(entry)=>{
+            assert_eq!(entry.key(),"bar");
+            assert_eq!(*entry.insert(30),30);
+        }
+    }
+    assert_eq!(conversions(),2);
+
+    assert_eq!(*map.entry_ref("bar").or_insert_with(||unreachable!()),30);
+    assert_eq!(conversions(),2);
+
+    assert_eq!(map.len(),2);
+    assert_eq!(map.get("foo"),Some(&20));
+    assert_eq!(map.get("bar"),Some(&30));
+}
+
+
+#[test]
+fn extract_if(){
+    let new_map=||(0..100u32).map(|x| Tuple2(x,x) ).collect::<RHashMap<u32,u32>>();
+
+    {
+        let mut map=new_map();
+        let capacity=map.capacity();
+
+        let mut removed=map.extract_if(|k,v|{ *v+=1000; k%3==0 }).collect::<Vec<_>>();
+        removed.sort();
+        assert_eq!(
+            removed,
+            (0..100).filter(|x| x%3==0 ).map(|x| Tuple2(x,x+1000) ).collect::<Vec<_>>()
+        );
+
+        assert_eq!(map.len(),100-removed.len());
+        assert_eq!(map.capacity(),capacity);
+        for k in 0..100 {
+            if k%3==0 {
+                assert_eq!(map.get(&k),None);
+            }else{
+                assert_eq!(map.get(&k),Some(&(k+1000)));
+            }
+        }
+    }
+
+    // Dropping the iterator early keeps the entries that weren't yielded.
+    {
+        let mut map=new_map();
+        let mut visited=0;
+        {
+            let mut iter=map.extract_if(|k,_|{ visited+=1; k%2==0 });
+            assert_eq!(iter.size_hint(),(0,Some(100)));
+            assert!(iter.next().is_some());
+            assert_eq!(iter.size_hint(),(49,Some(49)));
+            assert!(iter.next().is_some());
+            assert_eq!(iter.size_hint(),(48,Some(48)));
+        }
+        assert_eq!(visited,100);
+        assert_eq!(map.len(),98);
+        assert_eq!((0..100u32).filter(|k| map.contains_key(k) ).count(),98);
+        assert_eq!((0..100u32).filter(|k| *k%2==1 && map.contains_key(k) ).count(),50);
+    }
+
+    // Not consuming the iterator at all doesn't call the predicate,nor remove anything.
+    {
+        let mut map=new_map();
+        drop(map.extract_if(|_,_| unreachable!() ));
+        assert_eq!(map,new_map());
+    }
+
+    // Entries with owned keys and values,dropped exactly once.
+    {
+        use std::sync::Arc;
+
+        let arc=Arc::new(0);
+        let mut map=(0..10)
+            .map(|x| Tuple2(RString::from(x.to_string()),arc.clone()) )
+            .collect::<RHashMap<RString,Arc<i32>>>();
+
+        {
+            let mut iter=map.extract_if(|k,_| k.as_str()<"5" );
+            drop(iter.next());
+            assert_eq!(Arc::strong_count(&arc),10);
+        }
+        assert_eq!(Arc::strong_count(&arc),10);
+        assert_eq!(map.len(),9);
+
+        let removed=map.extract_if(|k,_| k.as_str()<"5" ).count();
+        assert_eq!(removed,4);
+        assert_eq!(Arc::strong_count(&arc),6);
+    }
+}
+
+
+#[test]
+fn debug_sorted(){
+    let mut map=RHashMap::<u32,RString>::new();
+    assert_eq!(format!("{:?}",map.debug_sorted()),"{}");
+
+    for k in (0..20).rev() {
+        map.insert(k*7%20,k.to_string().into());
+    }
+    let expected=(0..20)
+        .map(|k| (k*7%20,RString::from(k.to_string())) )
+        .collect::<std::collections::BTreeMap<_,_>>();
+
+    assert_eq!(format!("{:?}",map.debug_sorted()),format!("{:?}",expected));
+    assert_eq!(format!("{:#?}",SortedDebug(&map)),format!("{:#?}",expected));
+}
+
+
+#[test]
+fn sorted_iter(){
+    let mut map=RHashMap::<u32,RString>::new();
+    assert!(map.sorted_iter().is_empty());
+
+    for k in (0..20).rev() {
+        map.insert(k*7%20,k.to_string().into());
+    }
+    let expected=(0..20)
+        .map(|k| (k*7%20,RString::from(k.to_string())) )
+        .collect::<std::collections::BTreeMap<_,_>>();
+
+    let sorted=map.sorted_iter();
+    assert_eq!(sorted.len(),expected.len());
+    for (Tuple2(k,v),(e_k,e_v)) in sorted.into_iter().zip(&expected) {
+        assert_eq!(k,e_k);
+        assert_eq!(v,e_v);
+    }
+}
+
+
+#[test]
+fn deserialize_with_hasher(){
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::Hasher,
+    };
+
+    /// A hasher that doesn't implement Default.
+    #[derive(Clone)]
+    struct KeyedState{
+        key:u64,
+    }
+
+    impl BuildHasher for KeyedState{
+        type Hasher=DefaultHasher;
+
+        fn build_hasher(&self)->DefaultHasher{
+            let mut hasher=DefaultHasher::new();
+            hasher.write_u64(self.key);
+            hasher
+        }
+    }
+
+    let json=r##"{"90":"40","10":"20","88":"30","77":"22"}"##;
+
+    let mut deserializer=serde_json::Deserializer::from_str(json);
+    let map=RHashMap::<String,RString,_>::deserialize_with_hasher(
+        &mut deserializer,
+        KeyedState{key:1234},
+    ).unwrap();
+
+    assert_eq!(map.len(),4);
+    assert_eq!(map.get("90").map(|x| x.as_str() ),Some("40"));
+    assert_eq!(map.get("10").map(|x| x.as_str() ),Some("20"));
+    assert_eq!(map.get("88").map(|x| x.as_str() ),Some("30"));
+    assert_eq!(map.get("77").map(|x| x.as_str() ),Some("22"));
+    assert_eq!(map.get("11"),None);
+
+    let default_map=serde_json::from_str::<RHashMap<String,RString>>(json).unwrap();
+    for Tuple2(k,v) in default_map.iter() {
+        assert_eq!(map.get(k),Some(v));
+    }
+
+    let mut deserializer=serde_json::Deserializer::from_str("[1,2]");
+    assert!(
+        RHashMap::<String,RString,_>::deserialize_with_hasher(
+            &mut deserializer,
+            KeyedState{key:1234},
+        ).is_err()
+    );
+}
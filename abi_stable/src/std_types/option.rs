@@ -8,6 +8,8 @@ use core_extensions::matches;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::std_types::{RResult,ROk,RErr};
+
 
 /// Ffi-safe equivalent of the `Option<_>` type.
 ///
@@ -356,6 +358,32 @@ impl<T> ROption<T> {
         RNone
     }
 
+    /// Calls `f` with a reference to the value in `RSome`,returning `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abi_stable::std_types::*; 
+    ///
+    /// let mut seen=Vec::new();
+    ///
+    /// assert_eq!(RSome(10).inspect(|x| seen.push(*x) ),RSome(10));
+    /// assert_eq!(RNone::<u32>.inspect(|x| seen.push(*x) ),RNone);
+    ///
+    /// assert_eq!(seen,vec![10]);
+    ///
+    /// ```
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let RSome(x) = &self {
+            f(x);
+        }
+        self
+    }
+
     /// Returns `self` if it is RNone,otherwise returns `optb`.
     ///
     /// # Example
@@ -576,6 +604,34 @@ impl<T> ROption<T> {
         mem::replace(self, RSome(value))
     }
 }
+
+
+impl<T, E> ROption<RResult<T, E>> {
+    /// Transposes an `ROption` of a `RResult` into a `RResult` of an `ROption`.
+    ///
+    /// `RNone` maps to `ROk(RNone)`,
+    /// `RSome(ROk(x))` maps to `ROk(RSome(x))`,
+    /// and `RSome(RErr(e))` maps to `RErr(e)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abi_stable::std_types::*; 
+    ///
+    /// assert_eq!(RSome(ROk::<u32,u32>(10)).transpose(),ROk(RSome(10)));
+    /// assert_eq!(RNone::<RResult<u32,u32>>.transpose(),ROk(RNone));
+    /// assert_eq!(RSome(RErr::<u32,u32>(5)).transpose(),RErr(5));
+    ///
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> RResult<ROption<T>, E> {
+        match self {
+            RSome(ROk(v)) => ROk(RSome(v)),
+            RSome(RErr(e)) => RErr(e),
+            RNone => ROk(RNone),
+        }
+    }
+}
     
 
 impl<T> ROption<&T>{
@@ -733,6 +789,8 @@ where
 mod test{
     use super::*;
 
+    use crate::std_types::RString;
+
     #[test]
     fn from_into(){
         assert_eq!(ROption::from(Some(10)),RSome(10));
@@ -742,4 +800,21 @@ mod test{
         assert_eq!(RNone::<u32>.into_option(),None    );
     }
 
+    #[test]
+    fn inspect(){
+        let mut calls=Vec::new();
+
+        assert_eq!(RSome(10).inspect(|x| calls.push(*x) ),RSome(10));
+        assert_eq!(calls,vec![10]);
+
+        assert_eq!(RNone::<u32>.inspect(|x| calls.push(*x) ),RNone);
+        assert_eq!(calls,vec![10]);
+
+        // The value is returned unchanged,not a copy.
+        let string=RString::from("hello");
+        let address=string.as_ptr();
+        let returned=RSome(string).inspect(|s| assert_eq!(s.as_str(),"hello") );
+        assert_eq!(returned.unwrap().as_ptr(),address);
+    }
+
 }
@@ -2,11 +2,15 @@
 Contains the ffi-safe equivalent of `std::result::Result`.
 */
 
-use std::fmt::Debug;
+use std::{
+    error::Error as ErrorTrait,
+    fmt::Debug,
+    iter::FromIterator,
+};
 
 use core_extensions::matches;
 
-use crate::std_types::{ROption,RSome,RNone};
+use crate::std_types::{RBoxError,ROption,RSome,RNone};
 
 
 
@@ -189,6 +193,99 @@ impl<T, E> RResult<T, E> {
         }
     }
 
+    /// Converts the `RResult<T,E>` to a `RResult<T,RBoxError>`,
+    /// wrapping the error in an `RBoxError`.
+    ///
+    /// This is equivalent to `.map_err(RBoxError::new)`,
+    /// and is meant to be used to return errors that don't implement `StableAbi`
+    /// across the ffi boundary.
+    ///
+    /// The `RBoxError` formats itself with the `Display` and `Debug` impls of the error,
+    /// and can be converted back into the original error with `RBoxError::downcast`.
+    ///
+    /// `RBoxError` doesn't forward `Error::source`,
+    /// so the source of the error is only accessible after downcasting it back to `E`,
+    /// which can only be done in the dynamic library/executable that created it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RBox,RBoxError,RResult};
+    ///
+    /// use std::num::ParseIntError;
+    ///
+    /// fn parse(s:&str)->RResult<u32,RBoxError>{
+    ///     RResult::from(s.parse::<u32>()).erase_err()
+    /// }
+    ///
+    /// assert_eq!(parse("100").unwrap(),100);
+    ///
+    /// let err=parse("hello").unwrap_err();
+    /// let parse_err="hello".parse::<u32>().unwrap_err();
+    /// assert_eq!(err.to_string(),parse_err.to_string());
+    /// assert_eq!(err.downcast::<ParseIntError>().unwrap(),RBox::new(parse_err));
+    ///
+    /// ```
+    #[inline]
+    pub fn erase_err(self) -> RResult<T, RBoxError>
+    where
+        E: ErrorTrait + Send + Sync + 'static,
+    {
+        self.map_err(RBoxError::new)
+    }
+
+    /// Calls `f` with a reference to the value in `ROk`,returning `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abi_stable::std_types::*; 
+    ///
+    /// let mut seen=Vec::new();
+    ///
+    /// assert_eq!(ROk::<u32,u32>(10).inspect(|x| seen.push(*x) ),ROk(10));
+    /// assert_eq!(RErr::<u32,u32>(5).inspect(|x| seen.push(*x) ),RErr(5));
+    ///
+    /// assert_eq!(seen,vec![10]);
+    ///
+    /// ```
+    #[inline]
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let ROk(x) = &self {
+            f(x);
+        }
+        self
+    }
+
+    /// Calls `f` with a reference to the value in `RErr`,returning `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abi_stable::std_types::*; 
+    ///
+    /// let mut seen=Vec::new();
+    ///
+    /// assert_eq!(ROk::<u32,u32>(10).inspect_err(|e| seen.push(*e) ),ROk(10));
+    /// assert_eq!(RErr::<u32,u32>(5).inspect_err(|e| seen.push(*e) ),RErr(5));
+    ///
+    /// assert_eq!(seen,vec![5]);
+    ///
+    /// ```
+    #[inline]
+    pub fn inspect_err<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E),
+    {
+        if let RErr(e) = &self {
+            f(e);
+        }
+        self
+    }
+
     /// Converts the `RResult<T,E>` to a `U` by 
     /// transforming the value in ROk using the `with_ok` closure,
     /// otherwise transforming the value in RErr using the `with_err` closure,
@@ -496,6 +593,129 @@ impl<T, E> RResult<T, E> {
     }
 }
 
+impl<T, E> RResult<ROption<T>, E> {
+    /// Transposes a `RResult` of an `ROption` into an `ROption` of a `RResult`.
+    ///
+    /// `ROk(RNone)` maps to `RNone`,
+    /// `ROk(RSome(x))` maps to `RSome(ROk(x))`,
+    /// and `RErr(e)` maps to `RSome(RErr(e))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abi_stable::std_types::*; 
+    ///
+    /// assert_eq!(ROk::<ROption<u32>,u32>(RSome(10)).transpose(),RSome(ROk(10)));
+    /// assert_eq!(ROk::<ROption<u32>,u32>(RNone).transpose(),RNone);
+    /// assert_eq!(RErr::<ROption<u32>,u32>(5).transpose(),RSome(RErr(5)));
+    ///
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> ROption<RResult<T, E>> {
+        match self {
+            ROk(RSome(v)) => RSome(ROk(v)),
+            ROk(RNone) => RNone,
+            RErr(e) => RSome(RErr(e)),
+        }
+    }
+}
+
+/// Collects an iterator of `RResult<T,E>` into an `RResult<C,E>`,
+/// stopping at the first `RErr`.
+///
+/// This mirrors the `FromIterator` impl for `Result`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::{rvec,std_types::*};
+///
+/// let oks=vec![ROk::<u32,u32>(3),ROk(5),ROk(8)];
+/// assert_eq!(
+///     oks.into_iter().collect::<RResult<RVec<u32>,u32>>(),
+///     ROk(rvec![3,5,8]),
+/// );
+///
+/// let with_errs=vec![ROk::<u32,u32>(3),RErr(5),RErr(8)];
+/// assert_eq!(
+///     with_errs.into_iter().collect::<RResult<RVec<u32>,u32>>(),
+///     RErr(5),
+/// );
+///
+/// ```
+impl<T, E, C> FromIterator<RResult<T, E>> for RResult<C, E>
+where
+    C: FromIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RResult<T, E>>,
+    {
+        iter.into_iter()
+            .map(RResult::into_result)
+            .collect::<Result<C, E>>()
+            .into()
+    }
+}
+
+/// Allows using the `?` operator on `RResult`,
+/// requires enabling the `nightly_try` feature in a nightly compiler.
+#[cfg(all(feature="nightly_rust",feature="nightly_try"))]
+impl<T, E> std::ops::Try for RResult<T, E> {
+    type Output = T;
+    type Residual = RResult<std::convert::Infallible, E>;
+
+    #[inline]
+    fn from_output(v: T) -> Self {
+        ROk(v)
+    }
+
+    #[inline]
+    fn branch(self) -> std::ops::ControlFlow<Self::Residual, T> {
+        match self {
+            ROk(v) => std::ops::ControlFlow::Continue(v),
+            RErr(e) => std::ops::ControlFlow::Break(RErr(e)),
+        }
+    }
+}
+
+#[cfg(all(feature="nightly_rust",feature="nightly_try"))]
+impl<T, E> std::ops::Residual<T> for RResult<std::convert::Infallible, E> {
+    type TryType = RResult<T, E>;
+}
+
+/// Allows using the `?` operator on an `RResult` inside a function returning `RResult`,
+/// converting the error with `From`.
+#[cfg(all(feature="nightly_rust",feature="nightly_try"))]
+impl<T, E, F> std::ops::FromResidual<RResult<std::convert::Infallible, E>> for RResult<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: RResult<std::convert::Infallible, E>) -> Self {
+        match residual {
+            ROk(never) => match never {},
+            RErr(e) => RErr(From::from(e)),
+        }
+    }
+}
+
+/// Allows using the `?` operator on a `Result` inside a function returning `RResult`,
+/// converting the error with `From`.
+#[cfg(all(feature="nightly_rust",feature="nightly_try"))]
+impl<T, E, F> std::ops::FromResidual<Result<std::convert::Infallible, E>> for RResult<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Result<std::convert::Infallible, E>) -> Self {
+        match residual {
+            Ok(never) => match never {},
+            Err(e) => RErr(From::from(e)),
+        }
+    }
+}
+
 impl_from_rust_repr! {
     impl[T, E] From<Result<T, E>> for RResult<T, E> {
         fn(this){
@@ -528,6 +748,8 @@ impl_into_rust_repr! {
 mod test{
     use super::*;
 
+    use crate::std_types::RVec;
+
 
     #[test]
     fn from_into(){
@@ -538,4 +760,123 @@ mod test{
         assert_eq!(RErr::<u32,u32>(4).into_result(),Err(4));
     }
 
+    #[test]
+    fn inspect(){
+        let mut oks=Vec::new();
+        let mut errs=Vec::new();
+
+        let res=ROk::<u32,u32>(10)
+            .inspect(|x| oks.push(*x) )
+            .inspect_err(|e| errs.push(*e) );
+        assert_eq!(res,ROk(10));
+        assert_eq!((&oks[..],&errs[..]),(&[10][..],&[][..]));
+
+        let res=RErr::<u32,u32>(4)
+            .inspect(|x| oks.push(*x) )
+            .inspect_err(|e| errs.push(*e) );
+        assert_eq!(res,RErr(4));
+        assert_eq!((&oks[..],&errs[..]),(&[10][..],&[4][..]));
+    }
+
+    #[test]
+    fn transpose(){
+        let cases=vec![
+            ROk::<ROption<u32>,u32>(RSome(10)),
+            ROk(RNone),
+            RErr(4),
+        ];
+        for case in cases {
+            let transposed=case.transpose();
+            assert_eq!(transposed.transpose(),case);
+            assert_eq!(
+                transposed.into_option().map(RResult::into_result),
+                case.into_result().map(ROption::into_option).transpose(),
+            );
+        }
+    }
+
+    #[test]
+    fn collect_short_circuits(){
+        let mut yielded=0;
+        let collected=vec![ROk::<u32,u32>(3),ROk(5),RErr(8),ROk(13),RErr(21)]
+            .into_iter()
+            .inspect(|_| yielded+=1 )
+            .collect::<RResult<RVec<u32>,u32>>();
+        assert_eq!(collected,RErr(8));
+        assert_eq!(yielded,3);
+
+        let collected=(0..4).map(ROk::<u32,u32>).collect::<RResult<RVec<u32>,u32>>();
+        assert_eq!(collected,ROk(rvec![0,1,2,3]));
+
+        let collected=Vec::<RResult<u32,u32>>::new()
+            .into_iter()
+            .collect::<RResult<RVec<u32>,u32>>();
+        assert_eq!(collected,ROk(RVec::new()));
+    }
+
+    #[test]
+    fn erase_err(){
+        use std::{
+            error::Error as ErrorTrait,
+            fmt::{self,Display},
+            num::ParseIntError,
+        };
+
+        #[derive(Debug)]
+        struct ConfigError{
+            source:ParseIntError,
+        }
+
+        impl Display for ConfigError{
+            fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+                write!(f,"invalid plugin count: {}",self.source)
+            }
+        }
+
+        impl ErrorTrait for ConfigError{
+            fn source(&self)->Option<&(dyn ErrorTrait+'static)>{
+                Some(&self.source)
+            }
+        }
+
+        fn parse(s:&str)->RResult<u32,ConfigError>{
+            RResult::from(s.parse::<u32>()).map_err(|source| ConfigError{source} )
+        }
+
+        assert_eq!(parse("3").erase_err().unwrap(),3);
+
+        let original=parse("a").unwrap_err();
+        let erased=parse("a").erase_err().unwrap_err();
+        assert_eq!(erased.to_string(),original.to_string());
+        assert_eq!(format!("{:?}",erased),format!("{:?}",original));
+
+        let unerased=erased.downcast::<ConfigError>().unwrap();
+        assert_eq!(
+            unerased.source().map(|e| e.to_string() ),
+            original.source().map(|e| e.to_string() ),
+        );
+    }
+
+    #[cfg(all(feature="nightly_rust",feature="nightly_try"))]
+    #[test]
+    fn try_operator(){
+        fn parse_sum(l:&str,r:&str)->RResult<u32,std::num::ParseIntError>{
+            let l=RResult::from(l.parse::<u32>())?;
+            let r=RResult::from(r.parse::<u32>())?;
+            ROk(l+r)
+        }
+
+        assert_eq!(parse_sum("3","5"),ROk(8));
+        assert!(parse_sum("3","a").is_rerr());
+        assert!(parse_sum("a","5").is_rerr());
+
+        fn parse_std(l:&str)->RResult<u32,std::num::ParseIntError>{
+            let l=l.parse::<u32>()?;
+            ROk(l*2)
+        }
+
+        assert_eq!(parse_std("4"),ROk(8));
+        assert!(parse_std("b").is_rerr());
+    }
+
 }
@@ -4,7 +4,9 @@ Contains the ffi-safe equivalent of `&'a mut [T]`.
 
 use std::{
     borrow::{Borrow,BorrowMut},
+    fmt::{self, Debug},
     io::{self, Write},
+    iter::FusedIterator,
     marker::PhantomData,
     mem,
     ops::{Deref, DerefMut, Index, IndexMut},
@@ -15,7 +17,7 @@ use serde::{Serialize, Serializer};
 #[allow(unused_imports)]
 use core_extensions::prelude::*;
 
-use crate::std_types::{RSlice, RVec};
+use crate::std_types::{RSlice, RVec, Tuple2};
 
 mod privacy {
     use super::*;
@@ -306,6 +308,42 @@ impl<'a, T> RSliceMut<'a, T> {
         self.as_mut_slice().index_mut(i).into()
     }
 
+    /// Divides the slice into two mutable slices at the `mid` index,
+    /// the first slice contains the `[0,mid)` range,
+    /// and the second slice contains the `[mid,len)` range.
+    ///
+    /// It's not named `split_at_mut` so that it doesn't hide `<[T]>::split_at_mut`,
+    /// which returns a tuple of slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSliceMut,Tuple2};
+    ///
+    /// let slic=&mut[0,1,2,3];
+    /// let mut slic=RSliceMut::from_mut_slice(slic);
+    ///
+    /// {
+    ///     let Tuple2(mut left,mut right)=slic.split_at_mut_r(1);
+    ///     assert_eq!(left,RSliceMut::from_mut_slice(&mut[0]));
+    ///     assert_eq!(right,RSliceMut::from_mut_slice(&mut[1,2,3]));
+    ///
+    ///     left[0]=10;
+    ///     right[2]=30;
+    /// }
+    ///
+    /// assert_eq!(slic.as_slice(),&[10,1,2,30]);
+    ///
+    /// ```
+    pub fn split_at_mut_r(&mut self, mid: usize) -> Tuple2<RSliceMut<'_, T>, RSliceMut<'_, T>> {
+        let (left, right) = self.as_mut_slice().split_at_mut(mid);
+        Tuple2(left.into(), right.into())
+    }
+
     /// Creates a new `RVec<T>` and clones all the elements of this slice into it.
     ///
     /// # Example
@@ -475,6 +513,111 @@ impl<'a, T> IntoIterator for RSliceMut<'a, T> {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+
+
+/**
+An ffi-safe iterator over the elements of a mutable slice,yielding `&'a mut T`.
+
+This is the ffi-safe equivalent of `std::slice::IterMut<'a,T>`,
+and it can be returned from functions exported by dynamic libraries.
+
+# Example
+
+```
+use abi_stable::{
+    std_types::{RSliceIterMut,RVec},
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+fn iter_scores(scores:&mut RVec<u32>)->RSliceIterMut<'_,u32>{
+    scores.riter_mut()
+}
+
+let mut list=RVec::from(vec![3,5,8,13]);
+
+for score in iter_scores(&mut list).rev().take(2) {
+    *score*=10;
+}
+
+assert_eq!( list.as_slice(), &[3,5,80,130] );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(StableAbi)]
+#[sabi(bound = "T:'a")]
+pub struct RSliceIterMut<'a, T> {
+    slice: RSliceMut<'a, T>,
+}
+
+impl<'a, T> RSliceIterMut<'a, T> {
+    /// Constructs an iterator over the elements of `slice`.
+    pub fn new(slice: RSliceMut<'a, T>) -> Self {
+        Self { slice }
+    }
+
+    /// Gets the remaining elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.slice.as_slice()
+    }
+
+    /// Converts this iterator into a mutable slice of the remaining elements.
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.slice.into_mut_slice()
+    }
+
+    fn take_slice(&mut self) -> &'a mut [T] {
+        mem::replace(&mut self.slice, RSliceMut::default()).into_mut_slice()
+    }
+}
+
+impl<'a, T> Iterator for RSliceIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let (first, rest) = self.take_slice().split_first_mut()?;
+        self.slice = rest.into();
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.slice.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RSliceIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        let (last, rest) = self.take_slice().split_last_mut()?;
+        self.slice = rest.into();
+        Some(last)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RSliceIterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for RSliceIterMut<'a, T> {}
+
+impl<'a, T> Debug for RSliceIterMut<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RSliceIterMut").field(&self.as_slice()).finish()
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
 impl<'a, T> Deref for RSliceMut<'a, T> {
     type Target = [T];
 
@@ -590,6 +733,8 @@ shared_impls! {
 mod test {
     use super::*;
 
+    use abi_stable_shared::{file_span,test_utils::{must_panic}};
+
     #[test]
     fn from_to_slice() {
         let a = b"what the hell".to_vec();
@@ -602,4 +747,72 @@ mod test {
         assert_eq!(a_addr, b.data());
         assert_eq!(a.len(), b.len());
     }
+
+    #[test]
+    fn rslice_iter_mut() {
+        let mut list = [3, 5, 8, 13, 21];
+
+        {
+            let mut iter = RSliceIterMut::new(RSliceMut::from(&mut list[..]));
+            assert_eq!(iter.len(), 5);
+            *iter.next().unwrap() += 100;
+            *iter.next_back().unwrap() += 200;
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.as_slice(), &[5, 8, 13]);
+
+            for (i, elem) in iter.enumerate() {
+                *elem = i;
+            }
+        }
+        assert_eq!(list, [103, 0, 1, 2, 221]);
+
+        {
+            let mut iter = RSliceIterMut::new(RSliceMut::from(&mut list[1..3]));
+            assert_eq!(iter.next_back(), Some(&mut 1));
+            assert_eq!(iter.next(), Some(&mut 0));
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        let iter = RSliceIterMut::new(RSliceMut::from(&mut list[..]));
+        assert_eq!(iter.into_slice(), &mut [103, 0, 1, 2, 221]);
+
+        // Zero-sized types
+        let mut units = [(); 3];
+        let iter = RSliceIterMut::new(RSliceMut::from(&mut units[..]));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[test]
+    fn split_at_mut_r() {
+        let mut list = [3, 5, 8, 13, 21];
+        let mut rslice = RSliceMut::from(&mut list[..]);
+
+        for mid in 0..=5 {
+            let Tuple2(left, right) = rslice.split_at_mut_r(mid);
+            assert_eq!(left.len(), mid);
+            assert_eq!(right.len(), 5 - mid);
+        }
+
+        {
+            let Tuple2(mut left, mut right) = rslice.split_at_mut_r(2);
+            left[1] += 100;
+            right[0] += 200;
+        }
+        assert_eq!(list, [3, 105, 208, 13, 21]);
+
+        let mut rslice = RSliceMut::from(&mut list[..]);
+
+        // The slice method is still reachable by dereferencing.
+        {
+            let (left, right) = rslice.split_at_mut(2);
+            left[0] += 300;
+            right[2] += 400;
+        }
+        assert_eq!(rslice.as_slice(), &[303, 105, 208, 13, 421]);
+
+        must_panic(file_span!(), || { rslice.split_at_mut_r(6); }).unwrap();
+    }
 }
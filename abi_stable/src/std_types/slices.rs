@@ -4,9 +4,12 @@ Contains the ffi-safe equivalent of `&'a [T]`.
 
 use std::{
     borrow::Borrow,
+    fmt::{self, Debug},
     io::{self, BufRead, Read},
+    iter::FusedIterator,
     marker::PhantomData,
     ops::{Deref, Index},
+    slice::SliceIndex,
 };
 
 #[allow(unused_imports)]
@@ -14,7 +17,10 @@ use core_extensions::prelude::*;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::std_types::{RVec};
+use crate::{
+    std_types::{ROption, RVec, Tuple2},
+    traits::IntoReprC,
+};
 
 mod private {
     use super::*;
@@ -324,6 +330,121 @@ assert_eq!(RSlice::from_slice(&[0,1]).as_slice()   , &[0,1][..]);
         self.as_slice().index(i).into()
     }
 
+    /// Creates an `RSlice<'a,T>` with access to the `range` range of elements,
+    /// returning `RNone` if the range is out of bounds.
+    ///
+    /// This is the non-panicking version of `slice`.
+    /// It's not named `get` so that it doesn't hide `<[T]>::get`,
+    /// which can also take a `usize` index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSlice,RSome};
+    ///
+    /// let slic=RSlice::from_slice(&[0,1,2,3]);
+    ///
+    /// assert_eq!(slic.get_range(..2),RSome(RSlice::from_slice(&[0,1])));
+    /// assert_eq!(slic.get_range(1..=3),RSome(RSlice::from_slice(&[1,2,3])));
+    /// assert_eq!(slic.get_range(4..),RSome(RSlice::from_slice(&[])));
+    /// assert_eq!(slic.get_range(2..5),RNone);
+    /// assert_eq!(slic.get_range(3..2),RNone);
+    ///
+    /// ```
+    pub fn get_range<I>(&self, i: I) -> ROption<RSlice<'a, T>>
+    where
+        I: SliceIndex<[T], Output = [T]>,
+    {
+        self.as_slice().get(i).map(RSlice::from).into_c()
+    }
+
+    /// Divides the slice into two at the `mid` index,
+    /// the first slice contains the `[0,mid)` range,
+    /// and the second slice contains the `[mid,len)` range.
+    ///
+    /// It's not named `split_at` so that it doesn't hide `<[T]>::split_at`,
+    /// which returns a tuple of slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSlice,Tuple2};
+    ///
+    /// let slic=RSlice::from_slice(&[0,1,2,3]);
+    ///
+    /// assert_eq!(
+    ///     slic.split_at_r(0),
+    ///     Tuple2(RSlice::from_slice(&[]),RSlice::from_slice(&[0,1,2,3])),
+    /// );
+    /// assert_eq!(
+    ///     slic.split_at_r(1),
+    ///     Tuple2(RSlice::from_slice(&[0]),RSlice::from_slice(&[1,2,3])),
+    /// );
+    /// assert_eq!(
+    ///     slic.split_at_r(4),
+    ///     Tuple2(RSlice::from_slice(&[0,1,2,3]),RSlice::from_slice(&[])),
+    /// );
+    ///
+    /// ```
+    pub fn split_at_r(&self, mid: usize) -> Tuple2<RSlice<'a, T>, RSlice<'a, T>> {
+        let (left, right) = self.as_slice().split_at(mid);
+        Tuple2(left.into(), right.into())
+    }
+
+    /// Returns the first element and the rest of the slice,
+    /// returning `RNone` if the slice is empty.
+    ///
+    /// It's not named `split_first` so that it doesn't hide `<[T]>::split_first`,
+    /// which returns an `Option` of a tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSlice,RSome,Tuple2};
+    ///
+    /// let slic=RSlice::from_slice(&[0,1,2]);
+    ///
+    /// assert_eq!(slic.split_first_r(),RSome(Tuple2(&0,RSlice::from_slice(&[1,2]))));
+    /// assert_eq!(RSlice::from_slice(&[5]).split_first_r(),RSome(Tuple2(&5,RSlice::empty())));
+    /// assert_eq!(RSlice::<u8>::empty().split_first_r(),RNone);
+    ///
+    /// ```
+    pub fn split_first_r(&self) -> ROption<Tuple2<&'a T, RSlice<'a, T>>> {
+        self.as_slice()
+            .split_first()
+            .map(|(first, rest)| Tuple2(first, rest.into()))
+            .into_c()
+    }
+
+    /// Returns the last element and the rest of the slice,
+    /// returning `RNone` if the slice is empty.
+    ///
+    /// It's not named `split_last` so that it doesn't hide `<[T]>::split_last`,
+    /// which returns an `Option` of a tuple.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSlice,RSome,Tuple2};
+    ///
+    /// let slic=RSlice::from_slice(&[0,1,2]);
+    ///
+    /// assert_eq!(slic.split_last_r(),RSome(Tuple2(&2,RSlice::from_slice(&[0,1]))));
+    /// assert_eq!(RSlice::from_slice(&[5]).split_last_r(),RSome(Tuple2(&5,RSlice::empty())));
+    /// assert_eq!(RSlice::<u8>::empty().split_last_r(),RNone);
+    ///
+    /// ```
+    pub fn split_last_r(&self) -> ROption<Tuple2<&'a T, RSlice<'a, T>>> {
+        self.as_slice()
+            .split_last()
+            .map(|(last, rest)| Tuple2(last, rest.into()))
+            .into_c()
+    }
+
     /// Creates a new `RVec<T>` and clones all the elements of this slice into it.
     ///
     /// # Example
@@ -346,6 +467,112 @@ assert_eq!(RSlice::from_slice(&[0,1]).as_slice()   , &[0,1][..]);
         self.to_vec().into()
     }
 
+    /// Creates an `RVec<T>` by cloning the elements of this slice `n` times.
+    ///
+    /// It's not named `repeat` so that it doesn't hide `<[T]>::repeat`,
+    /// which returns a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the returned `RVec<T>` would overflow a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSlice,RVec};
+    ///
+    /// let slic=RSlice::from_slice(&[0,1]);
+    ///
+    /// assert_eq!( slic.repeat_r(0), RVec::new() );
+    /// assert_eq!( slic.repeat_r(1), RVec::from_slice(&[0,1]) );
+    /// assert_eq!( slic.repeat_r(3), RVec::from_slice(&[0,1,0,1,0,1]) );
+    ///
+    /// ```
+    pub fn repeat_r(&self, n: usize) -> RVec<T>
+    where
+        T: Clone,
+    {
+        let capacity=self.len().checked_mul(n).expect("capacity overflow");
+        let mut ret=RVec::with_capacity(capacity);
+        for _ in 0..n {
+            ret.extend_from_slice(self.as_slice());
+        }
+        ret
+    }
+
+    /// Returns whether the slice contains an element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic=RSlice::from_slice(&[3,5,8,13]);
+    ///
+    /// assert!( slic.contains(&5) );
+    /// assert!( slic.contains(&13) );
+    /// assert!( !slic.contains(&4) );
+    ///
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Binary searches this sorted slice for `x`.
+    ///
+    /// Returns `Ok(index)` of a matching element if one is found,
+    /// otherwise returns `Err(index)` with the index where `x` could be inserted
+    /// to keep the slice sorted.
+    ///
+    /// This has the same semantics as `<[T]>::binary_search`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic=RSlice::from_slice(&[3,5,8,13]);
+    ///
+    /// assert_eq!( slic.binary_search(&3), Ok(0) );
+    /// assert_eq!( slic.binary_search(&8), Ok(2) );
+    /// assert_eq!( slic.binary_search(&0), Err(0) );
+    /// assert_eq!( slic.binary_search(&6), Err(2) );
+    /// assert_eq!( slic.binary_search(&21), Err(4) );
+    ///
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary searches this slice,sorted by the key returned by `f`,for `key`.
+    ///
+    /// This has the same semantics as `<[T]>::binary_search_by_key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RSlice;
+    ///
+    /// let slic=RSlice::from_slice(&[(1,"foo"),(4,"bar"),(9,"baz")]);
+    ///
+    /// assert_eq!( slic.binary_search_by_key(&4,|&(k,_)| k ), Ok(1) );
+    /// assert_eq!( slic.binary_search_by_key(&5,|&(k,_)| k ), Err(2) );
+    ///
+    /// ```
+    pub fn binary_search_by_key<B, F>(&self, key: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(key, f)
+    }
+
     /// Transmutes n `RSlice<'a,T>` to a `RSlice<'a,U>`
     pub const unsafe fn transmute_ref<U>(self)->RSlice<'a,U>
     where
@@ -386,6 +613,113 @@ impl<'a, T> IntoIterator for RSlice<'a, T> {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+
+
+/**
+An ffi-safe iterator over the elements of a slice,yielding `&'a T`.
+
+This is the ffi-safe equivalent of `std::slice::Iter<'a,T>`,
+and it can be returned from functions exported by dynamic libraries.
+
+# Example
+
+```
+use abi_stable::{
+    std_types::{RSliceIter,RVec},
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+fn iter_names(names:&RVec<u32>)->RSliceIter<'_,u32>{
+    names.riter()
+}
+
+let list=RVec::from(vec![3,5,8,13]);
+
+let mut iter=iter_names(&list);
+assert_eq!( iter.len(), 4 );
+assert_eq!( iter.next(), Some(&3) );
+assert_eq!( iter.next_back(), Some(&13) );
+assert_eq!( iter.as_slice(), &[5,8] );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(StableAbi)]
+#[sabi(bound = "T:'a")]
+pub struct RSliceIter<'a, T> {
+    slice: RSlice<'a, T>,
+}
+
+impl<'a, T> RSliceIter<'a, T> {
+    /// Constructs an iterator over the elements of `slice`.
+    pub fn new(slice: RSlice<'a, T>) -> Self {
+        Self { slice }
+    }
+
+    /// Gets the remaining elements as a slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice.as_slice()
+    }
+
+    /// Gets the remaining elements as an `RSlice`.
+    pub fn as_rslice(&self) -> RSlice<'a, T> {
+        self.slice
+    }
+}
+
+impl<'a, T> Clone for RSliceIter<'a, T> {
+    fn clone(&self) -> Self {
+        Self { slice: self.slice }
+    }
+}
+
+impl<'a, T> Iterator for RSliceIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (first, rest) = self.slice.as_slice().split_first()?;
+        self.slice = rest.into();
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.slice.len()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RSliceIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        let (last, rest) = self.slice.as_slice().split_last()?;
+        self.slice = rest.into();
+        Some(last)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RSliceIter<'a, T> {}
+
+impl<'a, T> FusedIterator for RSliceIter<'a, T> {}
+
+impl<'a, T> Debug for RSliceIter<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RSliceIter").field(&self.as_slice()).finish()
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
 impl<'a, T: 'a> Deref for RSlice<'a, T> {
     type Target = [T];
 
@@ -503,6 +837,89 @@ shared_impls! {
 mod test {
     use super::*;
 
+    use crate::std_types::{RNone,RSome};
+
+    use abi_stable_shared::{file_span,test_utils::{must_panic}};
+
+    #[test]
+    fn get_range() {
+        let list = [3, 5, 8, 13, 21];
+        let rslice = RSlice::from(&list[..]);
+
+        assert_eq!(rslice.get_range(..), RSome(rslice));
+        assert_eq!(rslice.get_range(1..3), RSome(RSlice::from(&list[1..3])));
+        assert_eq!(rslice.get_range(..=4), RSome(rslice));
+        assert_eq!(rslice.get_range(5..), RSome(RSlice::from(&list[5..])));
+        assert_eq!(rslice.get_range(4..6), RNone);
+        assert_eq!(rslice.get_range(..=5), RNone);
+        assert_eq!(rslice.get_range(6..), RNone);
+        assert_eq!(rslice.get_range(3..1), RNone);
+
+        // The returned slice borrows from the list,not from `rslice`.
+        let sub = {
+            let rslice = RSlice::from(&list[..]);
+            rslice.get_range(2..).unwrap()
+        };
+        assert_eq!(sub.as_slice(), &[8, 13, 21]);
+    }
+
+    #[test]
+    fn get_index() {
+        let list = [3, 5, 8];
+        let rslice = RSlice::from(&list[..]);
+
+        // `<[T]>::get` is still reachable through Deref.
+        assert_eq!(rslice.get(0), Some(&3));
+        assert_eq!(rslice.get(2), Some(&8));
+        assert_eq!(rslice.get(3), None);
+        assert_eq!(rslice.get(1..), Some(&list[1..]));
+
+        match rslice.get(1) {
+            Some(x) => assert_eq!(*x, 5),
+            None => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn split_methods() {
+        let list = [3, 5, 8, 13, 21];
+        let rslice = RSlice::from(&list[..]);
+
+        for mid in 0..=5 {
+            let Tuple2(left, right) = rslice.split_at_r(mid);
+            assert_eq!(left.as_slice(), &list[..mid]);
+            assert_eq!(right.as_slice(), &list[mid..]);
+        }
+        must_panic(file_span!(), || rslice.split_at_r(6)).unwrap();
+
+        assert_eq!(rslice.split_first_r(), RSome(Tuple2(&3, RSlice::from(&list[1..]))));
+        assert_eq!(rslice.split_last_r(), RSome(Tuple2(&21, RSlice::from(&list[..4]))));
+        assert_eq!(RSlice::<u8>::empty().split_first_r(), RNone);
+        assert_eq!(RSlice::<u8>::empty().split_last_r(), RNone);
+
+        // Peeling off elements one at a time.
+        let mut rest = rslice;
+        let mut peeled = Vec::new();
+        while let RSome(Tuple2(first, tail)) = rest.split_first_r() {
+            peeled.push(*first);
+            rest = tail;
+        }
+        assert_eq!(peeled, list);
+
+        // The returned slices borrow from the list,not from `rslice`.
+        let tail = {
+            let rslice = RSlice::from(&list[..]);
+            rslice.split_at_r(3).1
+        };
+        assert_eq!(tail.as_slice(), &[13, 21]);
+
+        // The slice methods are still reachable by dereferencing.
+        let (left, right) = rslice.split_at(2);
+        assert_eq!((left, right), (&list[..2], &list[2..]));
+        assert_eq!(rslice.split_first(), Some((&3, &list[1..])));
+        assert_eq!(rslice.split_last(), Some((&21, &list[..4])));
+    }
+
     #[test]
     fn from_to_slice() {
         let a = "what the hell".as_bytes();
@@ -511,4 +928,50 @@ mod test {
         assert_eq!(a, &*b);
         assert_eq!(a.len(), b.len());
     }
+
+    #[test]
+    fn rslice_iter() {
+        let list = [3, 5, 8, 13, 21];
+        let rslice = RSlice::from(&list[..]);
+
+        assert!(RSliceIter::new(rslice).eq(list.iter()));
+        assert!(RSliceIter::new(rslice).rev().eq(list.iter().rev()));
+
+        let mut iter = RSliceIter::new(rslice);
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&21));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.clone().count(), 3);
+        assert_eq!(iter.as_slice(), &[5, 8, 13]);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&8));
+        assert_eq!(iter.next_back(), Some(&13));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // Zero-sized types
+        let units = [(); 3];
+        let iter = RSliceIter::new(RSlice::from(&units[..]));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[test]
+    fn repeat() {
+        let rslice = RSlice::from(&[3, 5, 8][..]);
+
+        assert_eq!(rslice.repeat_r(0), RVec::<i32>::new());
+        assert_eq!(RSlice::<i32>::EMPTY.repeat_r(100), RVec::<i32>::new());
+        assert_eq!(&*rslice.repeat_r(2), &[3, 5, 8, 3, 5, 8][..]);
+
+        let repeated = rslice.repeat_r(10_000);
+        assert_eq!(repeated.len(), 30_000);
+        assert_eq!(repeated.capacity(), 30_000);
+        assert!(repeated.chunks(3).all(|chunk| chunk == &[3, 5, 8][..]));
+
+        let res = std::panic::catch_unwind(|| rslice.repeat_r(usize::max_value()));
+        assert!(res.is_err());
+    }
 }
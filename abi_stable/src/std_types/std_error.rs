@@ -12,6 +12,8 @@ use std::{
 #[allow(unused_imports)]
 use core_extensions::prelude::*;
 
+use serde::ser;
+
 use crate::{
     erased_types::{
         c_functions::{adapt_std_fmt, debug_impl, display_impl},
@@ -469,6 +471,21 @@ impl RBoxError_<UnsyncUnsend> {
 
 impl<M> ErrorTrait for RBoxError_<M> {}
 
+/// Allows `RBoxError_<_>` to be used as the error type of a `serde::Serializer`.
+impl<M> ser::Error for RBoxError_<M> {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        let msg = msg.to_string();
+        DebugDisplay {
+            debug: msg.clone(),
+            display: msg,
+        }
+        .piped(Self::new_inner)
+    }
+}
+
 impl<M> Display for RBoxError_<M> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         unsafe{
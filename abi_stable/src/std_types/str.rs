@@ -4,8 +4,11 @@ Contains an ffi-safe equivalent of `&'a str`.
 
 use std::{
     borrow::{Cow,Borrow},
+    error::Error as ErrorTrait,
     fmt::{self, Display},
+    iter::FusedIterator,
     ops::{Deref, Index},
+    slice::SliceIndex,
     str,
 };
 
@@ -14,7 +17,18 @@ use core_extensions::prelude::*;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::std_types::{RSlice, RString};
+use crate::{
+    std_types::{RBoxError, ROption, RResult, RSlice, RString, Tuple2},
+    traits::IntoReprC,
+};
+
+
+mod iters;
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+pub use self::iters::{RBytes, RChars, RMatchIndices, RMatches};
 
 /**
 Ffi-safe equivalent of `&'a str`
@@ -178,6 +192,35 @@ impl<'a> RStr<'a> {
         self.as_str().index(i).into()
     }
 
+    /// For slicing `RStr`s,
+    /// returning `RNone` if the range is out of bounds,
+    /// or either end of the range is not on a `char` boundary.
+    ///
+    /// This is the non-panicking version of `slice`.
+    /// It's not named `get` so that it doesn't hide `str::get`,
+    /// which returns an `Option<&str>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSome,RStr};
+    ///
+    /// let str=RStr::from("ñandú");
+    ///
+    /// assert_eq!(str.get_range(..),RSome(str));
+    /// assert_eq!(str.get_range(2..),RSome(RStr::from("andú")));
+    /// assert_eq!(str.get_range(2..4),RSome(RStr::from("an")));
+    /// assert_eq!(str.get_range(1..),RNone);
+    /// assert_eq!(str.get_range(..100),RNone);
+    ///
+    /// ```
+    pub fn get_range<I>(&self, i: I) -> ROption<RStr<'a>>
+    where
+        I: SliceIndex<str, Output = str>,
+    {
+        self.as_str().get(i).map(RStr::from).into_c()
+    }
+
     /// Accesses the underlying byte slice.
     ///
     /// # Example
@@ -196,6 +239,29 @@ impl<'a> RStr<'a> {
         self.inner
     }
 
+    /// Gets an `RSlice<'a,u8>` with the bytes of this string slice.
+    ///
+    /// This is equivalent to `as_rslice`.
+    /// It's not named `as_bytes` so that it doesn't hide `str::as_bytes`,
+    /// which returns a `&[u8]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSlice,RStr};
+    ///
+    /// let str=RStr::from("What is that.");
+    ///
+    /// assert_eq!(str.as_rbytes(),RSlice::from("What is that.".as_bytes()));
+    /// assert_eq!(str.slice(5..7).as_rbytes(),RSlice::from_slice(b"is"));
+    /// assert_eq!(str.as_bytes(),b"What is that.");
+    ///
+    /// ```
+    #[inline]
+    pub const fn as_rbytes(&self) -> RSlice<'a, u8> {
+        self.inner
+    }
+
     /// Casts this `RStr<'a>` to a `&'a str`.
     ///
     /// # Example
@@ -249,6 +315,337 @@ impl<'a> RStr<'a> {
     pub const fn is_empty(&self)->bool{
         self.inner.is_empty()
     }
+
+    /// Returns this `RStr<'a>` without leading and trailing whitespace.
+    ///
+    /// Whitespace is defined like in `str::trim`.
+    ///
+    /// It's not named `trim` so that it doesn't hide `str::trim`,
+    /// which returns a `&str`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!(RStr::from("  What is that.\n").trim_r(),RStr::from("What is that."));
+    /// assert_eq!(RStr::from("What").trim_r(),RStr::from("What"));
+    /// assert_eq!(RStr::from(" \t ").trim_r(),RStr::from(""));
+    ///
+    /// ```
+    pub fn trim_r(&self) -> RStr<'a> {
+        self.as_str().trim().into()
+    }
+
+    /// Returns this `RStr<'a>` without leading whitespace.
+    ///
+    /// Whitespace is defined like in `str::trim_start`.
+    ///
+    /// It's not named `trim_start` so that it doesn't hide `str::trim_start`,
+    /// which returns a `&str`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!(RStr::from("  What is that. ").trim_start_r(),RStr::from("What is that. "));
+    /// assert_eq!(RStr::from("What").trim_start_r(),RStr::from("What"));
+    ///
+    /// ```
+    pub fn trim_start_r(&self) -> RStr<'a> {
+        self.as_str().trim_start().into()
+    }
+
+    /// Returns this `RStr<'a>` without trailing whitespace.
+    ///
+    /// Whitespace is defined like in `str::trim_end`.
+    ///
+    /// It's not named `trim_end` so that it doesn't hide `str::trim_end`,
+    /// which returns a `&str`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!(RStr::from("  What is that. ").trim_end_r(),RStr::from("  What is that."));
+    /// assert_eq!(RStr::from("What").trim_end_r(),RStr::from("What"));
+    ///
+    /// ```
+    pub fn trim_end_r(&self) -> RStr<'a> {
+        self.as_str().trim_end().into()
+    }
+
+    /// Queries whether `prefix` is a prefix of this `RStr<'a>`.
+    ///
+    /// `prefix` can be any of the pattern types listed in [`RStrPattern`].
+    ///
+    /// [`RStrPattern`]: ./trait.RStrPattern.html
+    ///
+    /// It's not named `starts_with` so that it doesn't hide `str::starts_with`,
+    /// which accepts any pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("Content-Length: 10");
+    ///
+    /// assert!( str.starts_with_r("Content-Length:") );
+    /// assert!( str.starts_with_r("") );
+    /// assert!( str.starts_with_r('C') );
+    /// assert!( str.starts_with_r(|c:char| c.is_uppercase() ) );
+    /// assert!( !str.starts_with_r("Content-Type:") );
+    ///
+    /// ```
+    pub fn starts_with_r<P>(&self, prefix: P) -> bool
+    where
+        P: RStrPattern,
+    {
+        prefix.is_prefix_of(self.as_str())
+    }
+
+    /// Queries whether `suffix` is a suffix of this `RStr<'a>`.
+    ///
+    /// `suffix` can be any of the pattern types listed in [`RStrPattern`].
+    ///
+    /// [`RStrPattern`]: ./trait.RStrPattern.html
+    ///
+    /// It's not named `ends_with` so that it doesn't hide `str::ends_with`,
+    /// which accepts any pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("Content-Length: 10\r\n");
+    ///
+    /// assert!( str.ends_with_r("\r\n") );
+    /// assert!( str.ends_with_r("") );
+    /// assert!( str.ends_with_r(&['\r','\n'][..]) );
+    /// assert!( !str.ends_with_r("10") );
+    ///
+    /// ```
+    pub fn ends_with_r<P>(&self, suffix: P) -> bool
+    where
+        P: RStrPattern,
+    {
+        suffix.is_suffix_of(self.as_str())
+    }
+
+    /// Returns the byte index of the first occurrence of `needle` in this `RStr<'a>`,
+    /// returning `RNone` if there is none.
+    ///
+    /// `needle` can be any of the pattern types listed in [`RStrPattern`].
+    ///
+    /// [`RStrPattern`]: ./trait.RStrPattern.html
+    ///
+    /// It's not named `find` so that it doesn't hide `str::find`,
+    /// which returns an `Option<usize>` and accepts any pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSome,RStr};
+    ///
+    /// let str=RStr::from("Content-Length: 10");
+    ///
+    /// assert_eq!( str.find_r(":"), RSome(14) );
+    /// assert_eq!( str.find_r("Length"), RSome(8) );
+    /// assert_eq!( str.find_r(""), RSome(0) );
+    /// assert_eq!( str.find_r(';'), RNone );
+    /// assert_eq!( str.find_r(|c:char| c.is_ascii_digit() ), RSome(16) );
+    ///
+    /// ```
+    pub fn find_r<P>(&self, needle: P) -> ROption<usize>
+    where
+        P: RStrPattern,
+    {
+        needle.find_in(self.as_str()).into_c()
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat` in this `RStr<'a>`.
+    ///
+    /// This is the `RStr<'a>` equivalent of `str::matches`,
+    /// only supporting `&str` patterns.
+    ///
+    /// It's not named `matches` so that it doesn't hide `str::matches`,
+    /// which yields `&str`s and accepts any pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("one, two, three");
+    ///
+    /// assert_eq!( str.matches_r(", ").count(), 2 );
+    /// assert_eq!( str.matches_r("o").map(|s| s.as_str() ).collect::<Vec<_>>(), vec!["o","o"] );
+    /// assert_eq!( str.matches_r(";").next(), None );
+    ///
+    /// ```
+    pub fn matches_r<'p>(&self, pat: &'p str) -> RMatches<'a, 'p> {
+        RMatches::new(*self, pat.into())
+    }
+
+    /// Returns an iterator over the non-overlapping matches of `pat` in this `RStr<'a>`,
+    /// alongside the index at which each match starts.
+    ///
+    /// This is the `RStr<'a>` equivalent of `str::match_indices`,
+    /// only supporting `&str` patterns.
+    ///
+    /// It's not named `match_indices` so that it doesn't hide `str::match_indices`,
+    /// which yields `(usize,&str)` tuples and accepts any pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RStr,Tuple2};
+    ///
+    /// let str=RStr::from("one, two, three");
+    ///
+    /// assert_eq!(
+    ///     str.match_indices_r(", ").collect::<Vec<_>>(),
+    ///     vec![ Tuple2(3,RStr::from(", ")), Tuple2(8,RStr::from(", ")) ],
+    /// );
+    ///
+    /// ```
+    pub fn match_indices_r<'p>(&self, pat: &'p str) -> RMatchIndices<'a, 'p> {
+        RMatchIndices::new(*self, pat.into())
+    }
+
+    /// Returns an iterator over the `char`s of this `RStr<'a>`.
+    ///
+    /// This is the `RStr<'a>` equivalent of `str::chars`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("añ🍕");
+    ///
+    /// let mut chars=str.chars();
+    /// assert_eq!( chars.next(), Some('a') );
+    /// assert_eq!( chars.next_back(), Some('🍕') );
+    /// assert_eq!( chars.as_rstr(), RStr::from("ñ") );
+    /// assert_eq!( chars.next(), Some('ñ') );
+    /// assert_eq!( chars.next(), None );
+    ///
+    /// ```
+    pub fn chars(&self) -> RChars<'a> {
+        RChars::new(*self)
+    }
+
+    /// Returns an iterator over the bytes of this `RStr<'a>`.
+    ///
+    /// This is the `RStr<'a>` equivalent of `str::bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("añ");
+    ///
+    /// assert_eq!( str.bytes().collect::<Vec<u8>>(), vec![b'a',0xC3,0xB1] );
+    /// assert_eq!( str.bytes().len(), 3 );
+    ///
+    /// ```
+    pub fn bytes(&self) -> RBytes<'a> {
+        RBytes::new(*self)
+    }
+
+    /// Returns the amount of `char`s in this `RStr<'a>`,
+    /// which can be smaller than its length in bytes.
+    ///
+    /// This is equivalent to `self.chars().count()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!( RStr::from("").char_count(), 0 );
+    /// assert_eq!( RStr::from("hello").char_count(), 5 );
+    /// assert_eq!( RStr::from("ñandú 🍕").char_count(), 7 );
+    ///
+    /// ```
+    pub fn char_count(&self) -> usize {
+        self.as_str().chars().count()
+    }
+
+    /// Creates an `RString` by repeating this `RStr<'a>` `n` times.
+    ///
+    /// It's not named `repeat` so that it doesn't hide `str::repeat`,
+    /// which returns a `String`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the returned `RString` would overflow a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let str=RStr::from("ab");
+    ///
+    /// assert_eq!( str.repeat_r(0).as_str(), "" );
+    /// assert_eq!( str.repeat_r(3).as_str(), "ababab" );
+    ///
+    /// ```
+    pub fn repeat_r(&self, n: usize) -> RString {
+        self.as_str().repeat(n).into()
+    }
+
+    /// Parses this `RStr<'a>` into another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!( RStr::from("100").parse::<u32>(), Ok(100) );
+    /// assert_eq!( RStr::from("-1.5").parse::<f64>(), Ok(-1.5) );
+    /// assert!( RStr::from("hello").parse::<u32>().is_err() );
+    ///
+    /// ```
+    pub fn parse<T>(&self) -> Result<T, T::Err>
+    where
+        T: str::FromStr,
+    {
+        self.as_str().parse()
+    }
+
+    /// Parses this `RStr<'a>` into another type,
+    /// returning an ffi-safe error if parsing fails.
+    ///
+    /// This is useful when the error has to be passed to another dynamic library,
+    /// since the error types of `FromStr` impls generally aren't ffi-safe.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// assert_eq!( RStr::from("100").parse_r::<u32>().unwrap(), 100 );
+    /// assert_eq!( RStr::from("true").parse_r::<bool>().unwrap(), true );
+    ///
+    /// let err=RStr::from("hello").parse_r::<u32>().unwrap_err();
+    /// assert_eq!( err.to_string(), "hello".parse::<u32>().unwrap_err().to_string() );
+    ///
+    /// ```
+    pub fn parse_r<T>(&self) -> RResult<T, RBoxError>
+    where
+        T: str::FromStr,
+        T::Err: ErrorTrait + Send + Sync + 'static,
+    {
+        self.parse::<T>().map_err(RBoxError::new).into_c()
+    }
 }
 
 unsafe impl<'a> Send for RStr<'a> {}
@@ -353,6 +750,130 @@ impl<'a> Serialize for RStr<'a> {
     }
 }
 
+////////////////////////////////////////////////////
+
+/**
+The pattern types accepted by the `RStr::{find_r,starts_with_r,ends_with_r}` methods.
+
+This is a subset of the types that implement the (unstable) `std::str::pattern::Pattern` trait,
+it's implemented for:
+
+- `&str`
+
+- `&String`
+
+- `RStr<'_>`/`&RStr<'_>`
+
+- `&RString`
+
+- `char`
+
+- `&[char]`
+
+- `F: FnMut(char)->bool`
+
+This trait is sealed,it can't be implemented outside of `abi_stable`.
+
+# Example
+
+```
+use abi_stable::std_types::{RSome,RStr};
+
+let str=RStr::from("key=value;");
+
+assert_eq!( str.find_r("="), RSome(3) );
+assert_eq!( str.find_r('='), RSome(3) );
+assert_eq!( str.find_r(&['=',';'][..]), RSome(3) );
+assert_eq!( str.find_r(|c:char| !c.is_alphabetic() ), RSome(3) );
+
+```
+
+*/
+pub trait RStrPattern: Sized + pattern_sealed::Sealed {
+    #[doc(hidden)]
+    fn find_in(self, haystack: &str) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn is_prefix_of(self, haystack: &str) -> bool;
+
+    #[doc(hidden)]
+    fn is_suffix_of(self, haystack: &str) -> bool;
+}
+
+mod pattern_sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_rstr_pattern {
+    ( $( impl[$($impl_params:tt)*] for $ty:ty $(where[$($where_preds:tt)*])? ;)* ) => {
+        $(
+            impl<$($impl_params)*> pattern_sealed::Sealed for $ty 
+            $(where $($where_preds)*)?
+            {}
+
+            impl<$($impl_params)*> RStrPattern for $ty 
+            $(where $($where_preds)*)?
+            {
+                #[inline]
+                fn find_in(self, haystack: &str) -> Option<usize> {
+                    haystack.find(self)
+                }
+
+                #[inline]
+                fn is_prefix_of(self, haystack: &str) -> bool {
+                    haystack.starts_with(self)
+                }
+
+                #[inline]
+                fn is_suffix_of(self, haystack: &str) -> bool {
+                    haystack.ends_with(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_rstr_pattern! {
+    impl['p] for &'p str;
+    impl['p] for &'p String;
+    impl[] for char;
+    impl['p] for &'p [char];
+    impl[F] for F where[ F: FnMut(char) -> bool ];
+}
+
+macro_rules! impl_rstr_pattern_as_str {
+    ( $( impl[$($impl_params:tt)*] for $ty:ty ;)* ) => {
+        $(
+            impl<$($impl_params)*> pattern_sealed::Sealed for $ty {}
+
+            impl<$($impl_params)*> RStrPattern for $ty {
+                #[inline]
+                fn find_in(self, haystack: &str) -> Option<usize> {
+                    haystack.find(self.as_str())
+                }
+
+                #[inline]
+                fn is_prefix_of(self, haystack: &str) -> bool {
+                    haystack.starts_with(self.as_str())
+                }
+
+                #[inline]
+                fn is_suffix_of(self, haystack: &str) -> bool {
+                    haystack.ends_with(self.as_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_rstr_pattern_as_str! {
+    impl['p] for RStr<'p>;
+    impl['p,'q] for &'p RStr<'q>;
+    impl['p] for &'p RString;
+}
+
+////////////////////////////////////////////////////
+
 #[allow(dead_code)]
 type Str<'a> = &'a str;
 
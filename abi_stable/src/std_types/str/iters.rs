@@ -0,0 +1,277 @@
+use super::*;
+
+/////////////////////////////////////////////////////////////////////////////
+
+/**
+An Iterator returned by `RStr::match_indices_r`,
+which yields the starting index of every non-overlapping match of a pattern,
+alongside the matched `RStr`.
+
+# Example
+
+```
+use abi_stable::std_types::{RStr,Tuple2};
+
+let str=RStr::from("a,b,,c");
+
+let mut iter=str.match_indices_r(",");
+assert_eq!( iter.next(), Some(Tuple2(1,RStr::from(","))) );
+assert_eq!( iter.next(), Some(Tuple2(3,RStr::from(","))) );
+assert_eq!( iter.next(), Some(Tuple2(4,RStr::from(","))) );
+assert_eq!( iter.next(), None );
+
+```
+
+*/
+#[repr(C)]
+#[derive(Debug, Clone, StableAbi)]
+pub struct RMatchIndices<'a, 'p> {
+    haystack: RStr<'a>,
+    pattern: RStr<'p>,
+    position: usize,
+    finished: bool,
+}
+
+impl<'a, 'p> RMatchIndices<'a, 'p> {
+    pub(super) fn new(haystack: RStr<'a>, pattern: RStr<'p>) -> Self {
+        Self {
+            haystack,
+            pattern,
+            position: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, 'p> Iterator for RMatchIndices<'a, 'p> {
+    type Item = Tuple2<usize, RStr<'a>>;
+
+    fn next(&mut self) -> Option<Tuple2<usize, RStr<'a>>> {
+        if self.finished {
+            return None;
+        }
+
+        let haystack = self.haystack.as_str();
+        let pattern = self.pattern.as_str();
+
+        let start = match haystack[self.position..].find(pattern) {
+            Some(offset) => self.position + offset,
+            None => {
+                self.finished = true;
+                return None;
+            }
+        };
+        let end = start + pattern.len();
+
+        if pattern.is_empty() {
+            // Empty patterns match at every char boundary,
+            // so this skips the next char to not match at the same position again.
+            match haystack[start..].chars().next() {
+                Some(c) => self.position = start + c.len_utf8(),
+                None => self.finished = true,
+            }
+        } else {
+            self.position = end;
+        }
+
+        Some(Tuple2(start, self.haystack.slice(start..end)))
+    }
+}
+
+impl<'a, 'p> FusedIterator for RMatchIndices<'a, 'p> {}
+
+/////////////////////////////////////////////////////////////////////////////
+
+/**
+An Iterator returned by `RStr::matches_r`,
+which yields every non-overlapping match of a pattern as an `RStr`.
+
+# Example
+
+```
+use abi_stable::std_types::RStr;
+
+let str=RStr::from("abababa");
+
+assert_eq!( str.matches_r("aba").count(), 2 );
+assert_eq!( str.matches_r("b").map(|s| s.as_str() ).collect::<Vec<_>>(), vec!["b","b","b"] );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, StableAbi)]
+pub struct RMatches<'a, 'p> {
+    indices: RMatchIndices<'a, 'p>,
+}
+
+impl<'a, 'p> RMatches<'a, 'p> {
+    pub(super) fn new(haystack: RStr<'a>, pattern: RStr<'p>) -> Self {
+        Self {
+            indices: RMatchIndices::new(haystack, pattern),
+        }
+    }
+}
+
+impl<'a, 'p> Iterator for RMatches<'a, 'p> {
+    type Item = RStr<'a>;
+
+    fn next(&mut self) -> Option<RStr<'a>> {
+        self.indices.next().map(|Tuple2(_, matched)| matched)
+    }
+}
+
+impl<'a, 'p> FusedIterator for RMatches<'a, 'p> {}
+
+/////////////////////////////////////////////////////////////////////////////
+
+/**
+An Iterator returned by `RStr::chars`,
+which yields the `char`s of an `RStr`.
+
+This stores the part of the `RStr` that wasn't iterated over yet,
+decoding a `char` from it on every call to `next`/`next_back`.
+
+# Example
+
+```
+use abi_stable::std_types::RStr;
+
+let str=RStr::from("ñandú");
+
+assert_eq!( str.chars().collect::<String>(), "ñandú" );
+assert_eq!( str.chars().rev().collect::<String>(), "údnañ" );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, StableAbi)]
+pub struct RChars<'a> {
+    remaining: RStr<'a>,
+}
+
+impl<'a> RChars<'a> {
+    pub(super) fn new(remaining: RStr<'a>) -> Self {
+        Self { remaining }
+    }
+
+    /// Gets the part of the `RStr` that wasn't iterated over yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RStr;
+    ///
+    /// let mut chars=RStr::from("über").chars();
+    /// chars.next();
+    /// assert_eq!( chars.as_rstr().as_str(), "ber" );
+    ///
+    /// ```
+    pub fn as_rstr(&self) -> RStr<'a> {
+        self.remaining
+    }
+}
+
+impl<'a> Iterator for RChars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let mut chars = self.remaining.as_str().chars();
+        let ret = chars.next()?;
+        self.remaining = chars.as_str().into();
+        Some(ret)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        ((len + 3) / 4, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.remaining.as_str().chars().count()
+    }
+
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<'a> DoubleEndedIterator for RChars<'a> {
+    fn next_back(&mut self) -> Option<char> {
+        let mut chars = self.remaining.as_str().chars();
+        let ret = chars.next_back()?;
+        self.remaining = chars.as_str().into();
+        Some(ret)
+    }
+}
+
+impl<'a> FusedIterator for RChars<'a> {}
+
+/////////////////////////////////////////////////////////////////////////////
+
+/**
+An Iterator returned by `RStr::bytes`,
+which yields the bytes of an `RStr`.
+
+# Example
+
+```
+use abi_stable::std_types::RStr;
+
+let str=RStr::from("hello");
+
+assert_eq!( str.bytes().filter(|b| *b==b'l' ).count(), 2 );
+assert_eq!( str.bytes().rev().next(), Some(b'o') );
+
+```
+
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, StableAbi)]
+pub struct RBytes<'a> {
+    remaining: RSlice<'a, u8>,
+}
+
+impl<'a> RBytes<'a> {
+    pub(super) fn new(str: RStr<'a>) -> Self {
+        Self {
+            remaining: str.as_str().as_bytes().into(),
+        }
+    }
+}
+
+impl<'a> Iterator for RBytes<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let (&first, rest) = self.remaining.as_slice().split_first()?;
+        self.remaining = rest.into();
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.remaining.len()
+    }
+
+    fn last(mut self) -> Option<u8> {
+        self.next_back()
+    }
+}
+
+impl<'a> DoubleEndedIterator for RBytes<'a> {
+    fn next_back(&mut self) -> Option<u8> {
+        let (&last, rest) = self.remaining.as_slice().split_last()?;
+        self.remaining = rest.into();
+        Some(last)
+    }
+}
+
+impl<'a> ExactSizeIterator for RBytes<'a> {}
+
+impl<'a> FusedIterator for RBytes<'a> {}
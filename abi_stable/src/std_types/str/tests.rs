@@ -0,0 +1,307 @@
+use super::*;
+
+use crate::std_types::{RNone,RSome};
+
+
+#[test]
+fn trim(){
+    let cases=[
+        "",
+        " ",
+        "hello",
+        "  hello  ",
+        "\t\r\nhello world\u{3000}",
+        "\u{2003}ñandú 🍕\u{a0}\n",
+    ];
+
+    for case in cases.iter().cloned() {
+        let rstr=RStr::from(case);
+        assert_eq!(rstr.trim_r().as_str(), case.trim());
+        assert_eq!(rstr.trim_start_r().as_str(), case.trim_start());
+        assert_eq!(rstr.trim_end_r().as_str(), case.trim_end());
+    }
+}
+
+#[test]
+fn trim_outlives_borrow(){
+    // The returned RStr borrows from the string,not from the RStr.
+    let string=String::from("  key: value  ");
+    let trimmed={
+        let rstr=RStr::from(string.as_str());
+        (rstr.trim_r(),rstr.trim_start_r(),rstr.trim_end_r())
+    };
+    assert_eq!(trimmed.0.as_str(), "key: value");
+    assert_eq!(trimmed.1.as_str(), "key: value  ");
+    assert_eq!(trimmed.2.as_str(), "  key: value");
+}
+
+#[test]
+fn prefix_suffix_find(){
+    let rstr=RStr::from("cáscara.ñ.🎊🍕");
+
+    assert!(rstr.starts_with_r("cás"));
+    assert!(!rstr.starts_with_r("cas"));
+    assert!(rstr.ends_with_r("🍕"));
+    assert!(rstr.ends_with_r(".🎊🍕"));
+    assert!(!rstr.ends_with_r("🎊"));
+
+    assert_eq!(rstr.find_r("á"), RSome(1));
+    assert_eq!(rstr.find_r("ñ"), RSome(9));
+    assert_eq!(rstr.find_r("🍕"), RSome(16));
+    assert_eq!(rstr.find_r("ñ.🎊🍕."), RNone);
+
+    let empty=RStr::from("");
+    assert!(empty.starts_with_r(""));
+    assert!(empty.ends_with_r(""));
+    assert_eq!(empty.find_r(""), RSome(0));
+    assert_eq!(empty.find_r("a"), RNone);
+}
+
+#[test]
+fn prefix_suffix_find_patterns(){
+    let rstr=RStr::from("cáscara.ñ.🎊🍕");
+
+    // char patterns
+    assert!(rstr.starts_with_r('c'));
+    assert!(!rstr.starts_with_r('á'));
+    assert!(rstr.ends_with_r('🍕'));
+    assert!(!rstr.ends_with_r('🎊'));
+    assert_eq!(rstr.find_r('á'), RSome(1));
+    assert_eq!(rstr.find_r('.'), RSome(8));
+    assert_eq!(rstr.find_r('z'), RNone);
+
+    // closure patterns
+    assert!(rstr.starts_with_r(|c:char| c.is_ascii_lowercase() ));
+    assert!(!rstr.ends_with_r(|c:char| c.is_ascii() ));
+    assert_eq!(rstr.find_r(|c:char| !c.is_ascii() ), RSome(1));
+    assert_eq!(rstr.find_r(|c:char| c=='ñ' || c=='🎊' ), RSome(9));
+    assert_eq!(rstr.find_r(|c:char| c.is_whitespace() ), RNone);
+    {
+        let mut calls=0;
+        assert_eq!(rstr.find_r(|c:char|{ calls+=1; c=='s' }), RSome(3));
+        assert_eq!(calls, 3);
+    }
+
+    // char slice patterns
+    assert!(rstr.starts_with_r(&['a','c'][..]));
+    assert!(rstr.ends_with_r(&['🎊','🍕'][..]));
+    assert_eq!(rstr.find_r(&['ñ','.'][..]), RSome(8));
+    assert_eq!(rstr.find_r(&[] as &[char]), RNone);
+
+    // string patterns other than `&str`
+    assert!(rstr.starts_with_r(&String::from("cás")));
+    assert!(rstr.ends_with_r(RStr::from("🎊🍕")));
+    assert!(rstr.ends_with_r(&RStr::from("🍕")));
+    assert_eq!(rstr.find_r(&RString::from("ñ")), RSome(9));
+
+    let empty=RStr::from("");
+    assert!(!empty.starts_with_r('a'));
+    assert!(!empty.ends_with_r(|_:char| true ));
+    assert_eq!(empty.find_r(|_:char| true ), RNone);
+}
+
+#[test]
+fn str_methods_are_reachable(){
+    let rstr=RStr::from("  key=value; ");
+
+    let trimmed:&str=rstr.trim();
+    assert_eq!(trimmed, "key=value;");
+    assert_eq!(rstr.trim_start(), "key=value; ");
+    assert_eq!(rstr.trim_end(), "  key=value;");
+
+    if let Some(pos)=rstr.find('=') {
+        assert_eq!(pos, 5);
+    }else{
+        panic!("expected to find '='");
+    }
+    assert_eq!(rstr.find(char::is_alphabetic), Some(2));
+    assert!(rstr.starts_with(char::is_whitespace));
+    assert!(rstr.ends_with(&[' ',';'] as &[char]));
+}
+
+#[test]
+fn repeat(){
+    let rstr=RStr::from("ñ-");
+
+    assert_eq!(rstr.repeat_r(0).as_str(), "");
+    assert_eq!(RStr::from("").repeat_r(100).as_str(), "");
+    assert_eq!(rstr.repeat_r(3).as_str(), "ñ-ñ-ñ-");
+
+    let repeated=rstr.repeat_r(10_000);
+    assert_eq!(repeated.len(), 30_000);
+    assert_eq!(repeated.as_str(), "ñ-".repeat(10_000));
+
+    let res=std::panic::catch_unwind(|| rstr.repeat_r(usize::max_value()) );
+    assert!(res.is_err());
+
+    // The str method is still reachable by dereferencing.
+    let repeated:String=rstr.repeat(2);
+    assert_eq!(repeated, "ñ-ñ-");
+}
+
+
+#[test]
+fn get_range(){
+    let rstr=RStr::from("ñandú 🍕");
+
+    assert_eq!(rstr.get_range(..), RSome(rstr));
+    assert_eq!(rstr.get_range(2..5), RSome(RStr::from("and")));
+    assert_eq!(rstr.get_range(..=1), RSome(RStr::from("ñ")));
+    assert_eq!(rstr.get_range(rstr.len()..), RSome(RStr::from("")));
+
+    // Out of bounds
+    assert_eq!(rstr.get_range(..rstr.len()+1), RNone);
+    assert_eq!(rstr.get_range(rstr.len()+1..), RNone);
+    assert_eq!(rstr.get_range(5..2), RNone);
+
+    // Not on a char boundary
+    assert_eq!(rstr.get_range(1..), RNone);
+    assert_eq!(rstr.get_range(..6), RNone);
+    assert_eq!(rstr.get_range(9..), RNone);
+    assert_eq!(rstr.get_range(..rstr.len()-1), RNone);
+
+    // `str::get` is still reachable through Deref.
+    assert_eq!(rstr.get(2..5), Some("and"));
+    assert_eq!(rstr.get(1..), None);
+}
+
+
+#[test]
+fn parse(){
+    let cases=["0","100","-5","2.5","true","hello",""];
+
+    for case in cases.iter().cloned() {
+        let rstr=RStr::from(case);
+        let rstring=RString::from(case);
+
+        assert_eq!(rstr.parse::<u32>(), case.parse::<u32>());
+        assert_eq!(rstr.parse::<i64>(), case.parse::<i64>());
+        assert_eq!(rstr.parse::<f64>(), case.parse::<f64>());
+        assert_eq!(rstr.parse::<bool>(), case.parse::<bool>());
+        assert_eq!(rstring.parse::<i64>(), case.parse::<i64>());
+
+        match (rstr.parse_r::<i64>().into_result(), case.parse::<i64>()) {
+            (Ok(l),Ok(r))=>assert_eq!(l,r),
+            (Err(l),Err(r))=>assert_eq!(l.to_string(),r.to_string()),
+            (l,r)=>panic!("\nleft:{:?}\nright:{:?}\n",l,r),
+        }
+        assert_eq!(
+            rstring.parse_r::<bool>().ok(),
+            case.parse::<bool>().ok().into_c(),
+        );
+    }
+}
+
+
+#[test]
+fn matches(){
+    let cases=[
+        ("", ""),
+        ("", "a"),
+        ("abc", ""),
+        ("ñandú", ""),
+        ("a,b,,c,", ","),
+        ("aaaa", "aa"),
+        ("aaaaa", "aa"),
+        ("abababa", "aba"),
+        ("the cat sat on the mat", "at"),
+        ("ñandú ñandú", "ñan"),
+        ("hello", "world"),
+    ];
+
+    for &(haystack, pattern) in cases.iter() {
+        let rstr=RStr::from(haystack);
+
+        let matches=rstr.matches_r(pattern).map(|s| s.as_str() ).collect::<Vec<_>>();
+        assert_eq!(matches, haystack.matches(pattern).collect::<Vec<_>>());
+        assert_eq!(rstr.matches_r(pattern).count(), haystack.matches(pattern).count());
+
+        let indices=rstr.match_indices_r(pattern)
+            .map(|Tuple2(i,s)| (i,s.as_str()) )
+            .collect::<Vec<_>>();
+        assert_eq!(indices, haystack.match_indices(pattern).collect::<Vec<_>>());
+
+        // The str methods are still reachable by dereferencing.
+        assert_eq!(
+            rstr.matches(pattern).collect::<Vec<&str>>(),
+            haystack.matches(pattern).collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            rstr.match_indices(pattern).collect::<Vec<(usize,&str)>>(),
+            haystack.match_indices(pattern).collect::<Vec<_>>(),
+        );
+    }
+}
+
+#[test]
+fn matches_are_not_overlapping(){
+    let rstr=RStr::from("aaaaa");
+
+    // Overlapping matches would start at every index from 0 to 3.
+    assert_eq!(rstr.matches_r("aa").count(), 2);
+    assert_eq!(
+        rstr.match_indices_r("aa").collect::<Vec<_>>(),
+        vec![Tuple2(0,RStr::from("aa")), Tuple2(2,RStr::from("aa"))],
+    );
+
+    let mut iter=rstr.matches_r("aaa");
+    assert_eq!(iter.next(), Some(RStr::from("aaa")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn matches_outlive_pattern(){
+    let haystack=RStr::from("key=value;key=other");
+    let found={
+        let pattern=String::from("key");
+        haystack.match_indices_r(&pattern).collect::<Vec<_>>()
+    };
+    assert_eq!(found, vec![Tuple2(0,RStr::from("key")), Tuple2(10,RStr::from("key"))]);
+}
+
+#[test]
+fn chars_bytes_char_count(){
+    let cases=[
+        "",
+        "hello",
+        "cáscara",
+        "ñandú 🍕",
+        "\u{3000}日本語\u{10FFFF}",
+    ];
+
+    for case in cases.iter().cloned() {
+        let rstr=RStr::from(case);
+
+        assert_eq!(rstr.chars().collect::<Vec<char>>(), case.chars().collect::<Vec<char>>());
+        assert_eq!(
+            rstr.chars().rev().collect::<Vec<char>>(),
+            case.chars().rev().collect::<Vec<char>>(),
+        );
+        assert_eq!(rstr.chars().count(), case.chars().count());
+        assert_eq!(rstr.chars().last(), case.chars().last());
+        assert_eq!(rstr.char_count(), case.chars().count());
+
+        assert_eq!(rstr.bytes().collect::<Vec<u8>>(), case.as_bytes());
+        assert_eq!(rstr.bytes().rev().collect::<Vec<u8>>(), case.bytes().rev().collect::<Vec<u8>>());
+        assert_eq!(rstr.bytes().len(), case.len());
+    }
+
+    let rstr=RStr::from("ñ🍕a");
+    assert_eq!(rstr.len(), 7);
+    assert_eq!(rstr.char_count(), 3);
+
+    let mut chars=rstr.chars();
+    assert_eq!(chars.next(), Some('ñ'));
+    assert_eq!(chars.as_rstr().as_str(), "🍕a");
+    assert_eq!(chars.next(), Some('\u{1F355}'));
+    assert_eq!(chars.as_rstr().as_str(), "a");
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.next(), None);
+    assert_eq!(chars.next_back(), None);
+
+    let mut bytes=rstr.bytes();
+    assert_eq!(bytes.next(), Some(0xC3));
+    assert_eq!(bytes.next_back(), Some(b'a'));
+    assert_eq!(bytes.len(), 5);
+}
@@ -4,6 +4,7 @@ Contains an ffi-safe equivalent of `std::string::String`.
 
 use std::{
     borrow::{Cow,Borrow},
+    error::Error as ErrorTrait,
     fmt::{self, Display, Formatter},
     iter::{FromIterator, FusedIterator},
     mem,
@@ -19,7 +20,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[allow(unused_imports)]
 use core_extensions::{prelude::*, SliceExt};
 
-use crate::std_types::{RStr, RVec};
+use crate::std_types::{RBoxError, RResult, RSlice, RStr, RVec};
 
 mod iters;
 
@@ -160,6 +161,70 @@ impl RString {
         }
     }
 
+    /// Gets an `RSlice<'_,u8>` with the bytes of this `RString`.
+    ///
+    /// It's not named `as_bytes` so that it doesn't hide `str::as_bytes`,
+    /// which returns a `&[u8]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RSlice,RString};
+    ///
+    /// let str=RString::from("What is that.");
+    ///
+    /// assert_eq!(str.as_rbytes(),RSlice::from("What is that.".as_bytes()));
+    /// assert_eq!(RString::new().as_rbytes(),RSlice::empty());
+    /// assert_eq!(str.as_bytes(),b"What is that.");
+    ///
+    /// ```
+    #[inline]
+    pub const fn as_rbytes(&self) -> RSlice<'_, u8> {
+        unsafe{
+            RSlice::from_raw_parts(self.as_ptr(),self.len())
+        }
+    }
+
+    /// Parses this `RString` into another type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// assert_eq!( RString::from("100").parse::<u32>(), Ok(100) );
+    /// assert!( RString::from("hello").parse::<u32>().is_err() );
+    ///
+    /// ```
+    pub fn parse<T>(&self) -> Result<T, T::Err>
+    where
+        T: FromStr,
+    {
+        self.as_str().parse()
+    }
+
+    /// Parses this `RString` into another type,
+    /// returning an ffi-safe error if parsing fails.
+    ///
+    /// For more details,look at [`RStr::parse_r`](./struct.RStr.html#method.parse_r).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// assert_eq!( RString::from("100").parse_r::<u32>().unwrap(), 100 );
+    /// assert!( RString::from("hello").parse_r::<u32>().is_err() );
+    ///
+    /// ```
+    pub fn parse_r<T>(&self) -> RResult<T, RBoxError>
+    where
+        T: FromStr,
+        T::Err: ErrorTrait + Send + Sync + 'static,
+    {
+        self.as_rstr().parse_r()
+    }
+
     /// Returns the current length (in bytes) of the RString.
     ///
     /// # Example
@@ -282,7 +347,8 @@ impl RString {
         String::from_utf16(s).map(From::from)
     }
 
-    /// Cheap conversion of this `RString` to a `RVec<u8>` 
+    /// Cheap conversion of this `RString` to a `RVec<u8>`,
+    /// reusing the allocation of the `RString`.
     ///
     /// # Example
     ///
@@ -369,6 +435,28 @@ impl RString {
         self.inner.shrink_to_fit()
     }
 
+    /// Shrinks the capacity of the RString,
+    /// never going below `min_capacity` or the length of the RString.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let mut str=RString::with_capacity(100);
+    /// str.push_str("nope");
+    ///
+    /// str.shrink_to(10);
+    /// assert_eq!(str.capacity(),10);
+    ///
+    /// str.shrink_to(0);
+    /// assert_eq!(str.capacity(),4);
+    ///
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity)
+    }
+
     /// Reserves `àdditional` additional capacity for any extra string data.
     /// 
     /// Prefer using `reserve` for most situations.
@@ -555,7 +643,12 @@ impl RString {
     pub fn insert_str(&mut self, idx: usize, string: &str) {
         // literal copy-paste of std,so if this is wrong std is wrong.
         
-        assert!(self.is_char_boundary(idx));
+        assert!(
+            self.is_char_boundary(idx),
+            "byte index {} is not a char boundary (the string is {} bytes long)",
+            idx,
+            self.len(),
+        );
 
         unsafe {
             self.insert_bytes(idx, string.as_bytes());
@@ -645,6 +738,81 @@ impl RString {
         }
     }
 
+    /// Shortens the string to `new_len` bytes,keeping the same allocated buffer.
+    ///
+    /// If `new_len` is greater than or equal to the length of the string this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let mut str=RString::from("Hello, world");
+    ///
+    /// str.truncate(100);
+    /// assert_eq!(str.as_str(),"Hello, world");
+    ///
+    /// str.truncate(5);
+    /// assert_eq!(str.as_str(),"Hello");
+    ///
+    /// str.truncate(0);
+    /// assert_eq!(str.as_str(),"");
+    ///
+    /// ```
+    pub fn truncate(&mut self, new_len: usize){
+        if new_len < self.len() {
+            assert!(
+                self.is_char_boundary(new_len),
+                "byte index {} is not a char boundary (the string is {} bytes long)",
+                new_len,
+                self.len(),
+            );
+            self.inner.truncate(new_len);
+        }
+    }
+
+    /// Splits the string in two at the `at` byte position,
+    /// returning the `[at,len)` range in a newly allocated RString,
+    /// and leaving the `[0,at)` range in `self`.
+    ///
+    /// `self` keeps the same allocated buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is out of bounds or if it is not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RString;
+    ///
+    /// let mut str=RString::from("GET /index.html");
+    ///
+    /// let path=str.split_off(4);
+    /// assert_eq!(str.as_str(),"GET ");
+    /// assert_eq!(path.as_str(),"/index.html");
+    ///
+    /// let empty=str.split_off(4);
+    /// assert_eq!(str.as_str(),"GET ");
+    /// assert_eq!(empty.as_str(),"");
+    ///
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> RString {
+        assert!(
+            self.is_char_boundary(at),
+            "byte index {} is not a char boundary (the string is {} bytes long)",
+            at,
+            self.len(),
+        );
+        let tail=RString::from(&self[at..]);
+        self.inner.truncate(at);
+        tail
+    }
+
     /// Turns this into an empty RString,keeping the same allocated buffer.
     ///
     /// # Example
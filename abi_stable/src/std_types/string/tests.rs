@@ -72,21 +72,63 @@ fn insert_str(){
     }
     {// insert at the end
         let mut rstr=rstr.clone();
-        rstr.insert_str(18,"💔love💔is💔foo");
+        rstr.insert_str(18,"foo");
+        assert_eq!(&*rstr,"💔love💔is💔foo");
     }
 
     {// insert at the start
         let mut rstr=rstr.clone();
-        rstr.insert_str(0,"foo💔love💔is💔");
+        rstr.insert_str(0,"foo");
+        assert_eq!(&*rstr,"foo💔love💔is💔");
     }
 
     {// insert in the middle
         let mut rstr=rstr.clone();
-        rstr.insert_str(12,"💔love💔foois💔");
+        rstr.insert_str(12,"foo");
+        assert_eq!(&*rstr,"💔love💔foois💔");
     }
     {// insert in the middle 2
         let mut rstr=rstr.clone();
-        rstr.insert_str(14,"💔love💔isfoo💔");
+        rstr.insert_str(14,"foo");
+        assert_eq!(&*rstr,"💔love💔isfoo💔");
+    }
+}
+
+#[test]
+fn insert(){
+    // '💔' is 4 bytes long
+    let test_str="💔love💔is💔";
+    let rstr=test_str.into_(RString::T);
+    
+    {
+        let mut rstr=rstr.clone();
+        must_panic(file_span!(),|| rstr.insert(1,'x') ).unwrap();
+        must_panic(file_span!(),|| rstr.insert(3,'x') ).unwrap();
+        must_panic(file_span!(),|| rstr.insert(9,'x') ).unwrap();
+        must_panic(file_span!(),|| rstr.insert(17,'x') ).unwrap();
+        must_panic(file_span!(),|| rstr.insert(19,'x') ).unwrap();
+        assert_eq!(&*rstr,test_str);
+    }
+    {// insert at the start
+        let mut rstr=rstr.clone();
+        rstr.insert(0,'x');
+        assert_eq!(&*rstr,"x💔love💔is💔");
+        rstr.insert(0,'💔');
+        assert_eq!(&*rstr,"💔x💔love💔is💔");
+    }
+    {// insert in the middle
+        let mut rstr=rstr.clone();
+        rstr.insert(6,'x');
+        assert_eq!(&*rstr,"💔loxve💔is💔");
+        rstr.insert(13,'💔');
+        assert_eq!(&*rstr,"💔loxve💔💔is💔");
+    }
+    {// insert at the end
+        let mut rstr=rstr.clone();
+        rstr.insert(18,'x');
+        assert_eq!(&*rstr,"💔love💔is💔x");
+        rstr.insert(19,'💔');
+        assert_eq!(&*rstr,"💔love💔is💔x💔");
     }
 }
 
@@ -141,6 +183,77 @@ fn remove(){
     }
 }
 
+#[test]
+fn truncate(){
+    // '💔' is 4 bytes long
+    let test_str="💔love💔is";
+    let mut rstr=test_str.into_(RString::T);
+
+    for i in 1..4 {
+        must_panic(file_span!(),|| rstr.clone().truncate(i) ).unwrap();
+        must_panic(file_span!(),|| rstr.clone().truncate(8+i) ).unwrap();
+    }
+
+    rstr.truncate(test_str.len()+1);
+    assert_eq!(&*rstr,test_str);
+
+    rstr.truncate(test_str.len());
+    assert_eq!(&*rstr,test_str);
+
+    rstr.truncate(12);
+    assert_eq!(&*rstr,"💔love💔");
+
+    rstr.truncate(8);
+    assert_eq!(&*rstr,"💔love");
+
+    rstr.truncate(0);
+    assert_eq!(&*rstr,"");
+    assert!(rstr.capacity()>=test_str.len());
+}
+
+#[test]
+fn split_off(){
+    // '💔' is 4 bytes long
+    let test_str="💔love💔is";
+    let rstr=test_str.into_(RString::T);
+
+    for i in 1..4 {
+        must_panic(file_span!(),|| rstr.clone().split_off(i) ).unwrap();
+        must_panic(file_span!(),|| rstr.clone().split_off(8+i) ).unwrap();
+    }
+    must_panic(file_span!(),|| rstr.clone().split_off(test_str.len()+1) ).unwrap();
+
+    {// Splitting at 0 moves the whole string to the returned RString
+        let mut rstr=rstr.clone();
+        let tail=rstr.split_off(0);
+        assert_eq!(&*rstr,"");
+        assert_eq!(&*tail,test_str);
+    }
+    {// Splitting at the length returns an empty RString
+        let mut rstr=rstr.clone();
+        let tail=rstr.split_off(test_str.len());
+        assert_eq!(&*rstr,test_str);
+        assert_eq!(&*tail,"");
+    }
+    {
+        let mut rstr=rstr.clone();
+        let capacity=rstr.capacity();
+        let tail=rstr.split_off(8);
+        assert_eq!(&*rstr,"💔love");
+        assert_eq!(&*tail,"💔is");
+        assert_eq!(rstr.capacity(),capacity);
+    }
+}
+
+#[test]
+fn clear(){
+    let mut rstr=RString::from("hello");
+    let capacity=rstr.capacity();
+    rstr.clear();
+    assert_eq!(&*rstr,"");
+    assert_eq!(rstr.capacity(),capacity);
+}
+
 #[test]
 fn push_str() {
     let mut rstr = RString::new();
@@ -289,3 +402,69 @@ fn drain() {
 
 
 
+
+
+#[test]
+fn as_rbytes(){
+    let rstring=RString::from(TEST_STR);
+
+    assert_eq!(rstring.as_rbytes().as_slice(), TEST_STR.as_bytes());
+    assert_eq!(rstring.as_rbytes().as_ptr(), rstring.as_ptr());
+    assert_eq!(rstring.as_rstr().as_rbytes(), rstring.as_rbytes());
+    assert_eq!(rstring.as_rstr().slice(..5).as_rbytes().as_slice(), b"hello");
+    assert_eq!(RString::new().as_rbytes().len(), 0);
+
+    // `str::as_bytes` is still reachable through Deref.
+    let bytes:&[u8]=rstring.as_bytes();
+    assert_eq!(bytes, TEST_STR.as_bytes());
+    let bytes:&[u8]=rstring.as_rstr().as_bytes();
+    assert_eq!(bytes, TEST_STR.as_bytes());
+}
+
+
+#[test]
+fn into_bytes_reuses_allocation(){
+    let rstring=RString::from(TEST_STR);
+    let ptr=rstring.as_ptr();
+    let capacity=rstring.capacity();
+
+    let bytes=rstring.into_bytes();
+    assert_eq!(bytes.as_ptr(), ptr);
+    assert_eq!(bytes.capacity(), capacity);
+    assert_eq!(&bytes[..], TEST_STR.as_bytes());
+
+    let rstring=RString::from_utf8(bytes).unwrap();
+    assert_eq!(rstring.as_ptr(), ptr);
+    assert_eq!(rstring.capacity(), capacity);
+    assert_eq!(rstring.as_str(), TEST_STR);
+}
+
+
+#[test]
+fn capacity_management(){
+    let mut rstr=RString::with_capacity(TEST_STR.len());
+    assert!(rstr.capacity()>=TEST_STR.len());
+    assert_eq!(rstr.len(),0);
+
+    rstr.push_str(TEST_STR);
+    rstr.reserve(100);
+    assert!(rstr.capacity()>=TEST_STR.len()+100);
+
+    rstr.shrink_to_fit();
+    assert_eq!(rstr.capacity(),TEST_STR.len());
+
+    rstr.reserve_exact(7);
+    assert_eq!(rstr.capacity(),TEST_STR.len()+7);
+
+    // Reserving less than the spare capacity doesn't reallocate.
+    let capacity=rstr.capacity();
+    rstr.reserve(3);
+    rstr.reserve_exact(7);
+    assert_eq!(rstr.capacity(),capacity);
+
+    rstr.shrink_to(TEST_STR.len()+2);
+    assert_eq!(rstr.capacity(),TEST_STR.len()+2);
+    rstr.shrink_to(0);
+    assert_eq!(rstr.capacity(),TEST_STR.len());
+    assert_eq!(rstr.as_str(),TEST_STR);
+}
@@ -0,0 +1,392 @@
+/*!
+Contains ffi-safe equivalents of items from `std::task`.
+
+These are the building blocks for passing futures through the ffi boundary,
+`RWaker` can be converted to and from a `std::task::Waker`.
+*/
+
+use std::{
+    fmt::{self, Debug},
+    mem::ManuallyDrop,
+    task::{Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+use crate::{
+    marker_type::ErasedObject,
+    prefix_type::{PrefixTypeTrait, WithMetadata},
+    sabi_types::StaticRef,
+};
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Ffi-safe equivalent of `std::task::Poll`.
+///
+/// # Example
+///
+/// ```
+/// use abi_stable::std_types::task::{RPoll,RPending,RReady};
+///
+/// use std::task::Poll;
+///
+/// assert_eq!( RPoll::from(Poll::Ready(10)), RReady(10) );
+/// assert_eq!( RPoll::<u32>::from(Poll::Pending), RPending );
+///
+/// assert_eq!( RReady(10).into_poll(), Poll::Ready(10) );
+/// assert_eq!( RPoll::<u32>::RPending.into_poll(), Poll::Pending );
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(u8)]
+#[derive(StableAbi)]
+pub enum RPoll<T> {
+    RReady(T),
+    RPending,
+}
+
+pub use self::RPoll::*;
+
+impl<T> RPoll<T> {
+    /// Converts this `RPoll<T>` into a `Poll<T>`.
+    pub fn into_poll(self) -> Poll<T> {
+        self.into()
+    }
+
+    /// Whether this is an `RReady`.
+    pub fn is_ready(&self) -> bool {
+        match self {
+            RReady(_) => true,
+            RPending => false,
+        }
+    }
+
+    /// Whether this is an `RPending`.
+    pub fn is_pending(&self) -> bool {
+        !self.is_ready()
+    }
+}
+
+impl_from_rust_repr! {
+    impl[T] From<Poll<T>> for RPoll<T> {
+        fn(this){
+            match this {
+                Poll::Ready(x) => RReady(x),
+                Poll::Pending => RPending,
+            }
+        }
+    }
+}
+
+impl_into_rust_repr! {
+    impl[T] Into<Poll<T>> for RPoll<T> {
+        fn(this){
+            match this {
+                RReady(x) => Poll::Ready(x),
+                RPending => Poll::Pending,
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/**
+Ffi-safe equivalent of `std::task::Waker`.
+
+This is a data pointer with a vtable of `clone`/`wake`/`wake_by_ref`/`drop` functions,
+which are always called in the dynamic library/executable that created the `RWaker`.
+
+# Example
+
+Converting a `Waker` to an `RWaker` and back,
+waking the original task with the converted `Waker`.
+
+```
+use abi_stable::std_types::task::RWaker;
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize,Ordering},
+        Arc,
+    },
+    task::{RawWaker,RawWakerVTable,Waker},
+};
+
+static VTABLE:RawWakerVTable=RawWakerVTable::new(clone,wake,wake_by_ref,drop_waker);
+
+unsafe fn clone(data:*const ())->RawWaker{
+    let arc=Arc::from_raw(data as *const AtomicUsize);
+    let cloned=arc.clone();
+    std::mem::forget(arc);
+    RawWaker::new(Arc::into_raw(cloned) as *const (),&VTABLE)
+}
+unsafe fn wake(data:*const ()){
+    Arc::from_raw(data as *const AtomicUsize).fetch_add(1,Ordering::SeqCst);
+}
+unsafe fn wake_by_ref(data:*const ()){
+    (*(data as *const AtomicUsize)).fetch_add(1,Ordering::SeqCst);
+}
+unsafe fn drop_waker(data:*const ()){
+    drop(Arc::from_raw(data as *const AtomicUsize));
+}
+
+let counter=Arc::new(AtomicUsize::new(0));
+let waker=unsafe{
+    Waker::from_raw(RawWaker::new(Arc::into_raw(counter.clone()) as *const (),&VTABLE))
+};
+
+let rwaker=RWaker::from(waker);
+rwaker.wake_by_ref();
+assert_eq!( counter.load(Ordering::SeqCst), 1 );
+
+let waker:Waker=rwaker.into();
+waker.wake();
+assert_eq!( counter.load(Ordering::SeqCst), 2 );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RWaker {
+    data: *const ErasedObject,
+    vtable: StaticRef<RWakerVTable>,
+}
+
+unsafe impl Send for RWaker {}
+unsafe impl Sync for RWaker {}
+
+impl RWaker {
+    /// Constructs an `RWaker` which wakes the task `waker` wakes.
+    ///
+    /// To avoid cloning the `Waker` you can use `RWaker::from(waker)`.
+    pub fn from_waker(waker: &Waker) -> Self {
+        Self::from(waker.clone())
+    }
+
+    /// Converts this `RWaker` into a `std::task::Waker`,
+    /// which wakes the same task as this.
+    pub fn into_waker(self) -> Waker {
+        let raw = RawWaker::new(
+            Box::into_raw(Box::new(self)) as *const (),
+            &OWNED_RWAKER_VTABLE,
+        );
+        unsafe { Waker::from_raw(raw) }
+    }
+
+    /// Wakes up the task associated with this `RWaker`.
+    pub fn wake(self) {
+        let this = ManuallyDrop::new(self);
+        unsafe { (this.vtable.get().wake())(this.data) }
+    }
+
+    /// Wakes up the task associated with this `RWaker`,without consuming it.
+    pub fn wake_by_ref(&self) {
+        unsafe { (self.vtable.get().wake_by_ref())(self.data) }
+    }
+
+    /// Constructs an `RWaker` which borrows `waker`,
+    /// this is used to avoid allocating every time that a future is polled.
+    ///
+    /// # Safety
+    ///
+    /// The returned `RWaker` must not outlive `waker`,
+    /// and must only be passed by reference (cloning it is fine).
+    pub(crate) unsafe fn borrowing_waker(waker: &Waker) -> ManuallyDrop<RWaker> {
+        ManuallyDrop::new(RWaker {
+            data: waker as *const Waker as *const ErasedObject,
+            vtable: WithMetadata::as_prefix(BORROWED_STD_WAKER_VTABLE),
+        })
+    }
+
+    /// Constructs a `std::task::Waker` which borrows this `RWaker`,
+    /// this is used to avoid allocating every time that a future is polled.
+    ///
+    /// # Safety
+    ///
+    /// The returned `Waker` must not outlive `self`,
+    /// and must only be passed by reference (cloning it is fine).
+    pub(crate) unsafe fn borrowed_std_waker(&self) -> ManuallyDrop<Waker> {
+        let raw = RawWaker::new(
+            self as *const RWaker as *const (),
+            &BORROWED_RWAKER_VTABLE,
+        );
+        ManuallyDrop::new(Waker::from_raw(raw))
+    }
+}
+
+impl_from_rust_repr! {
+    impl From<Waker> for RWaker {
+        fn(this){
+            RWaker {
+                data: Box::into_raw(Box::new(this)) as *const ErasedObject,
+                vtable: WithMetadata::as_prefix(STD_WAKER_VTABLE),
+            }
+        }
+    }
+}
+
+impl_into_rust_repr! {
+    impl Into<Waker> for RWaker {
+        fn(this){
+            this.into_waker()
+        }
+    }
+}
+
+impl Debug for RWaker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RWaker").finish()
+    }
+}
+
+impl Clone for RWaker {
+    fn clone(&self) -> Self {
+        unsafe { (self.vtable.get().clone_waker())(self.data) }
+    }
+}
+
+impl Drop for RWaker {
+    fn drop(&mut self) {
+        unsafe { (self.vtable.get().drop_waker())(self.data) }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Ffi-safe equivalent of `std::task::Context`.
+///
+/// This only provides access to the `RWaker` of the current task.
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RContext<'a> {
+    waker: &'a RWaker,
+}
+
+impl<'a> RContext<'a> {
+    /// Constructs an `RContext` from a reference to the `RWaker` of the current task.
+    pub fn from_waker(waker: &'a RWaker) -> Self {
+        Self { waker }
+    }
+
+    /// Gets the `RWaker` of the current task.
+    pub fn waker(&self) -> &'a RWaker {
+        self.waker
+    }
+}
+
+impl<'a> Debug for RContext<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RContext")
+            .field("waker", &self.waker)
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(StableAbi)]
+#[repr(C)]
+#[sabi(kind(Prefix(prefix_struct = "RWakerVTable")))]
+#[sabi(missing_field(panic))]
+pub(crate) struct RWakerVTableVal {
+    clone_waker: unsafe extern "C" fn(*const ErasedObject) -> RWaker,
+    wake: unsafe extern "C" fn(*const ErasedObject),
+    wake_by_ref: unsafe extern "C" fn(*const ErasedObject),
+    #[sabi(last_prefix_field)]
+    drop_waker: unsafe extern "C" fn(*const ErasedObject),
+}
+
+// The vtable for `RWaker`s that own a `Box<Waker>`.
+const STD_WAKER_VTABLE: StaticRef<WithMetadata<RWakerVTableVal>> = unsafe {
+    StaticRef::from_raw(&WithMetadata::new(
+        PrefixTypeTrait::METADATA,
+        RWakerVTableVal {
+            clone_waker: clone_std_waker,
+            wake: wake_std_waker,
+            wake_by_ref: wake_by_ref_std_waker,
+            drop_waker: drop_std_waker,
+        },
+    ))
+};
+
+// The vtable for `RWaker`s that borrow a `Waker`.
+const BORROWED_STD_WAKER_VTABLE: StaticRef<WithMetadata<RWakerVTableVal>> = unsafe {
+    StaticRef::from_raw(&WithMetadata::new(
+        PrefixTypeTrait::METADATA,
+        RWakerVTableVal {
+            clone_waker: clone_std_waker,
+            wake: wake_by_ref_std_waker,
+            wake_by_ref: wake_by_ref_std_waker,
+            drop_waker: noop_drop_waker,
+        },
+    ))
+};
+
+unsafe extern "C" fn clone_std_waker(data: *const ErasedObject) -> RWaker {
+    extern_fn_panic_handling! {
+        RWaker::from_waker(&*(data as *const Waker))
+    }
+}
+
+unsafe extern "C" fn wake_std_waker(data: *const ErasedObject) {
+    extern_fn_panic_handling! {no_early_return;
+        Box::from_raw(data as *mut Waker).wake();
+    }
+}
+
+unsafe extern "C" fn wake_by_ref_std_waker(data: *const ErasedObject) {
+    extern_fn_panic_handling! {no_early_return;
+        (*(data as *const Waker)).wake_by_ref();
+    }
+}
+
+unsafe extern "C" fn drop_std_waker(data: *const ErasedObject) {
+    extern_fn_panic_handling! {no_early_return;
+        drop(Box::from_raw(data as *mut Waker));
+    }
+}
+
+unsafe extern "C" fn noop_drop_waker(_: *const ErasedObject) {}
+
+///////////////////////////////////////////////////////////////////////////////
+
+// The vtable for `Waker`s that own a `Box<RWaker>`.
+static OWNED_RWAKER_VTABLE: RawWakerVTable = RawWakerVTable::new(
+    clone_rwaker,
+    wake_rwaker,
+    wake_by_ref_rwaker,
+    drop_rwaker,
+);
+
+// The vtable for `Waker`s that borrow an `RWaker`.
+static BORROWED_RWAKER_VTABLE: RawWakerVTable = RawWakerVTable::new(
+    clone_rwaker,
+    wake_by_ref_rwaker,
+    wake_by_ref_rwaker,
+    noop_drop_rwaker,
+);
+
+unsafe fn clone_rwaker(data: *const ()) -> RawWaker {
+    let cloned = (*(data as *const RWaker)).clone();
+    RawWaker::new(
+        Box::into_raw(Box::new(cloned)) as *const (),
+        &OWNED_RWAKER_VTABLE,
+    )
+}
+
+unsafe fn wake_rwaker(data: *const ()) {
+    Box::from_raw(data as *mut RWaker).wake();
+}
+
+unsafe fn wake_by_ref_rwaker(data: *const ()) {
+    (*(data as *const RWaker)).wake_by_ref();
+}
+
+unsafe fn drop_rwaker(data: *const ()) {
+    drop(Box::from_raw(data as *mut RWaker));
+}
+
+unsafe fn noop_drop_rwaker(_: *const ()) {}
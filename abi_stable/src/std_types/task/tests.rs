@@ -0,0 +1,104 @@
+use super::*;
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Context,
+};
+
+/// A `Waker` that counts how many times it was woken up.
+fn counting_waker() -> (Arc<AtomicUsize>, Waker) {
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake_by_ref, drop_waker);
+
+    unsafe fn clone(data: *const ()) -> RawWaker {
+        let arc = ManuallyDrop::new(Arc::from_raw(data as *const AtomicUsize));
+        let cloned: Arc<AtomicUsize> = (*arc).clone();
+        RawWaker::new(Arc::into_raw(cloned) as *const (), &VTABLE)
+    }
+    unsafe fn wake(data: *const ()) {
+        Arc::from_raw(data as *const AtomicUsize).fetch_add(1, Ordering::SeqCst);
+    }
+    unsafe fn wake_by_ref(data: *const ()) {
+        (*(data as *const AtomicUsize)).fetch_add(1, Ordering::SeqCst);
+    }
+    unsafe fn drop_waker(data: *const ()) {
+        drop(Arc::from_raw(data as *const AtomicUsize));
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let raw = RawWaker::new(Arc::into_raw(counter.clone()) as *const (), &VTABLE);
+    (counter, unsafe { Waker::from_raw(raw) })
+}
+
+#[test]
+fn round_trip_wakes_original() {
+    let (counter, waker) = counting_waker();
+
+    let rwaker = RWaker::from(waker);
+    let waker: Waker = rwaker.into();
+
+    waker.wake_by_ref();
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    let cloned = waker.clone();
+    assert_eq!(Arc::strong_count(&counter), 3);
+    cloned.wake();
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert_eq!(Arc::strong_count(&counter), 2);
+
+    // Converting the converted Waker again
+    let rwaker = RWaker::from(waker);
+    rwaker.wake_by_ref();
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+    rwaker.clone().wake();
+    assert_eq!(counter.load(Ordering::SeqCst), 4);
+    rwaker.wake();
+    assert_eq!(counter.load(Ordering::SeqCst), 5);
+
+    assert_eq!(Arc::strong_count(&counter), 1);
+}
+
+#[test]
+fn dropping_does_not_leak() {
+    let (counter, waker) = counting_waker();
+
+    let rwaker = RWaker::from_waker(&waker);
+    assert_eq!(Arc::strong_count(&counter), 3);
+
+    let rwaker_clone = rwaker.clone();
+    let std_waker = rwaker_clone.into_waker();
+    assert_eq!(Arc::strong_count(&counter), 4);
+
+    drop(std_waker);
+    drop(rwaker);
+    drop(waker);
+    assert_eq!(Arc::strong_count(&counter), 1);
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn borrowed_wakers() {
+    let (counter, waker) = counting_waker();
+
+    unsafe {
+        let rwaker = RWaker::borrowing_waker(&waker);
+        let rcx = RContext::from_waker(&rwaker);
+        rcx.waker().wake_by_ref();
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        let std_waker = rcx.waker().borrowed_std_waker();
+        let cx = Context::from_waker(&std_waker);
+        cx.waker().wake_by_ref();
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        // Clones of borrowed wakers own their waker.
+        let cloned = cx.waker().clone();
+        assert_eq!(Arc::strong_count(&counter), 3);
+        cloned.wake();
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    assert_eq!(Arc::strong_count(&counter), 2);
+}
@@ -1,11 +1,34 @@
 /*!
-Contains ffi-safe equivalent of `std::time::Duration`.
+Contains ffi-safe equivalents of `std::time::Duration` and `std::time::Instant`.
 */
 
-use std::time::Duration;
+use std::{
+    cmp::Ordering,
+    time::{Duration,Instant},
+};
+
+use crate::{
+    sabi_types::LateStaticRef,
+    std_types::{ROption,RNone,RSome},
+    utils::leak_value,
+};
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
 
 /// Ffi-safe equivalent of ::std::time::Duration .
 ///
+/// # Serialization
+///
+/// This is serialized as a struct with a `seconds:u64` field and
+/// a `subsec_nanos:u32` field (the nanoseconds inside the last second),
+/// eg:`{"seconds":5,"subsec_nanos":250000000}` in json.
+///
+/// This format is independent of the serialization format of `std::time::Duration`,
+/// and will not change in semver compatible versions.
+///
 /// # Example
 ///
 /// ```
@@ -230,3 +253,189 @@ impl_into_rust_repr! {
         }
     }
 }
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/**
+Ffi-safe equivalent of ::std::time::Instant ,a measurement of a monotonically nondecreasing clock.
+
+# Representation
+
+This is stored as the time elapsed since an anchor `Instant` chosen
+the first time `RInstant::now` is called in a dynamic library/executable,
+along with an identifier for that anchor.
+
+Every dynamic library/executable has its own anchor,
+all of them measured with the same monotonic clock as `std::time::Instant`.
+
+# Limitations
+
+`RInstant`s are only comparable with other `RInstant`s created in the same
+dynamic library/executable.
+
+Comparing `RInstant`s created on different sides of the ffi boundary
+(eg:calling `checked_duration_since` with an `RInstant` that a plugin created) 
+is detected by comparing their anchors,
+in which case `partial_cmp` returns `None`,
+`checked_duration_since` returns `RNone`,
+and `duration_since`/`elapsed` panic.
+This is why `RInstant` does not implement `Ord`.
+
+To measure time across the ffi boundary,
+pass `RDuration`s (eg:the return value of `RInstant::elapsed`) instead.
+
+`RInstant`s are also only meaningful within the process that created them,
+they must not be sent to (or persisted for) other processes.
+
+The anchor is not shared through `abi_stable::globals`
+because that struct isn't extensible without breaking compatibility with 
+dynamic libraries built with other `0.8.*` versions of abi_stable.
+
+# Serialization
+
+This is serialized as a struct with a `since_anchor` field,
+which is the `RDuration` elapsed since the anchor,
+and an `anchor` field,which identifies the anchor,
+eg:`{"since_anchor":{"seconds":5,"subsec_nanos":250000000},"anchor":1234}` in json.
+
+Because the anchor is different in each process,
+deserialized `RInstant`s can only be compared with `RInstant`s
+from the process that serialized them.
+
+# Example
+
+```
+use abi_stable::std_types::{RDuration,RInstant};
+
+let start=RInstant::now();
+let later=RInstant::now();
+
+assert!( start <= later );
+assert!( later.duration_since(start) <= start.elapsed() );
+assert_eq!( start.duration_since(start), RDuration::new(0,0) );
+
+```
+
+*/
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize, StableAbi)]
+#[repr(C)]
+pub struct RInstant {
+    since_anchor: RDuration,
+    anchor: usize,
+}
+
+impl RInstant {
+    /// Returns an `RInstant` corresponding to "now".
+    ///
+    /// The returned value can only be compared with `RInstant`s created in
+    /// the same dynamic library/executable,
+    /// [more details here](#limitations).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RInstant;
+    /// 
+    /// let first=RInstant::now();
+    /// let second=RInstant::now();
+    /// assert!( first <= second );
+    /// 
+    /// ```
+    pub fn now()->Self{
+        static ANCHOR:LateStaticRef<Instant>=LateStaticRef::new();
+
+        let anchor:&'static Instant=ANCHOR.init(|| leak_value(Instant::now()) );
+        RInstant{
+            since_anchor:Instant::now().duration_since(*anchor).into(),
+            anchor:anchor as *const Instant as usize,
+        }
+    }
+
+    /// Returns the amount of time elapsed since this `RInstant` was created.
+    ///
+    /// # Panics
+    ///
+    /// This panics if this `RInstant` was created in a different dynamic library/executable,
+    /// [more details here](#limitations).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RDuration,RInstant};
+    /// 
+    /// let instant=RInstant::now();
+    /// assert!( instant.elapsed() >= RDuration::new(0,0) );
+    /// 
+    /// ```
+    pub fn elapsed(&self)->RDuration{
+        RInstant::now().duration_since(*self)
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `earlier` is later than `self`,
+    /// or if they were created in different dynamic libraries/executables,
+    /// [more details here](#limitations).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RDuration,RInstant};
+    /// 
+    /// let earlier=RInstant::now();
+    /// let later=RInstant::now();
+    /// assert!( later.duration_since(earlier) <= earlier.elapsed() );
+    /// assert_eq!( earlier.duration_since(earlier), RDuration::new(0,0) );
+    /// 
+    /// ```
+    pub fn duration_since(&self,earlier:RInstant)->RDuration{
+        match self.checked_duration_since(earlier) {
+            RSome(x)=>x,
+            RNone if self.anchor!=earlier.anchor=>
+                panic!("supplied instant was created in a different dynamic library/executable"),
+            RNone=>panic!("supplied instant is later than self"),
+        }
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to `self`,
+    /// returning `RNone` if `earlier` is later than `self`,
+    /// or if they were created in different dynamic libraries/executables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RDuration,RInstant,RNone,RSome};
+    /// 
+    /// let earlier=RInstant::now();
+    /// let later=RInstant::now();
+    /// 
+    /// assert!( later.checked_duration_since(earlier).is_some() );
+    /// assert_eq!( earlier.checked_duration_since(earlier), RSome(RDuration::new(0,0)) );
+    /// 
+    /// ```
+    pub fn checked_duration_since(&self,earlier:RInstant)->ROption<RDuration>{
+        if self.anchor!=earlier.anchor {
+            return RNone;
+        }
+        let this:Duration=self.since_anchor.into();
+        let earlier:Duration=earlier.since_anchor.into();
+        this.checked_sub(earlier).map(RDuration::from).into()
+    }
+}
+
+
+impl PartialOrd for RInstant {
+    /// Compares the instants,
+    /// returning `None` if they were created in different dynamic libraries/executables.
+    fn partial_cmp(&self,other:&Self)->Option<Ordering>{
+        if self.anchor==other.anchor {
+            self.since_anchor.partial_cmp(&other.since_anchor)
+        }else{
+            None
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+
+#[test]
+fn duration_serde_roundtrip(){
+    let list=vec![
+        RDuration::new(0,0),
+        RDuration::new(0,999_999_999),
+        RDuration::from_millis(31416),
+        RDuration::new(u64::max_value(),999_999_999),
+    ];
+    for dur in list {
+        let json=serde_json::to_string(&dur).unwrap();
+        assert_eq!(serde_json::from_str::<RDuration>(&json).unwrap(),dur);
+
+        let bin=bincode::serialize(&dur).unwrap();
+        assert_eq!(bincode::deserialize::<RDuration>(&bin).unwrap(),dur);
+    }
+}
+
+#[test]
+fn duration_serde_format(){
+    let dur=RDuration::new(5,250_000_000);
+
+    assert_eq!(
+        serde_json::to_string(&dur).unwrap(),
+        r#"{"seconds":5,"subsec_nanos":250000000}"#,
+    );
+    assert_eq!(
+        serde_json::from_str::<RDuration>(r#"{"subsec_nanos":7,"seconds":3}"#).unwrap(),
+        RDuration::new(3,7),
+    );
+    assert_eq!(
+        bincode::serialize(&dur).unwrap(),
+        vec![5,0,0,0,0,0,0,0, 0x80,0xB2,0xE6,0x0E],
+    );
+}
+
+#[test]
+fn instant_serde(){
+    let instant=RInstant::now();
+    let json=serde_json::to_string(&instant).unwrap();
+    assert_eq!(serde_json::from_str::<RInstant>(&json).unwrap(),instant);
+
+    let instant=RInstant{ since_anchor:RDuration::new(5,250_000_000), anchor:1234 };
+    assert_eq!(
+        serde_json::to_string(&instant).unwrap(),
+        r#"{"since_anchor":{"seconds":5,"subsec_nanos":250000000},"anchor":1234}"#,
+    );
+}
+
+#[test]
+fn instant_different_anchors(){
+    let instant=RInstant::now();
+    let other=RInstant{ anchor:instant.anchor.wrapping_add(1), ..instant };
+
+    assert_eq!( instant.partial_cmp(&instant), Some(Ordering::Equal) );
+    assert_eq!( instant.partial_cmp(&other), None );
+    assert_ne!( instant, other );
+
+    assert_eq!( instant.checked_duration_since(instant), RSome(RDuration::new(0,0)) );
+    assert_eq!( instant.checked_duration_since(other), RNone );
+    assert_eq!( other.checked_duration_since(instant), RNone );
+
+    assert!( std::panic::catch_unwind(|| instant.duration_since(other) ).is_err() );
+    assert!( std::panic::catch_unwind(|| other.elapsed() ).is_err() );
+}
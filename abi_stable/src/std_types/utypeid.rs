@@ -118,6 +118,96 @@ impl UTypeId {
 
 /////////////////////////////////////////////////////////////////////////////
 
+
+/**
+An ffi-safe identifier for a `'static` type,
+meant to be used to tag values passed through ffi with the type they contain.
+
+This is a wrapper around `UTypeId`,
+so `RTypeId`s of the same type constructed in different dynamic libraries/executables
+compare unequal,meaning that it identifies types within a dynamic library/executable,
+not across them.
+
+# Example
+
+This uses `RTypeId` as the key of a dispatch table.
+
+```
+use abi_stable::std_types::{RHashMap,RStr,RTypeId};
+
+struct Ping;
+struct Pong;
+
+let mut handlers=RHashMap::<RTypeId,RStr<'static>>::new();
+handlers.insert(RTypeId::of::<Ping>(),"ping handler".into());
+handlers.insert(RTypeId::of::<Pong>(),"pong handler".into());
+
+assert_eq!( handlers.get(&RTypeId::of::<Ping>()).map(|s| s.as_str() ), Some("ping handler") );
+assert_eq!( handlers.get(&RTypeId::of::<Pong>()).map(|s| s.as_str() ), Some("pong handler") );
+assert_eq!( handlers.get(&RTypeId::of::<()>()), None );
+
+```
+*/
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Copy, Clone, Hash, StableAbi)]
+pub struct RTypeId {
+    id: UTypeId,
+}
+
+impl RTypeId {
+    /// Constructs the `RTypeId` of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RTypeId;
+    ///
+    /// assert_eq!( RTypeId::of::<String>(), RTypeId::of::<String>() );
+    /// assert_ne!( RTypeId::of::<String>(), RTypeId::of::<&str>() );
+    ///
+    /// ```
+    #[inline]
+    pub fn of<T>() -> Self
+    where
+        T: 'static,
+    {
+        Self {
+            id: UTypeId::new::<T>(),
+        }
+    }
+
+    /// Gets the `UTypeId` that this wraps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RTypeId,UTypeId};
+    ///
+    /// assert_eq!( RTypeId::of::<u8>().to_utypeid(), UTypeId::new::<u8>() );
+    ///
+    /// ```
+    #[inline]
+    pub fn to_utypeid(self) -> UTypeId {
+        self.id
+    }
+}
+
+impl From<UTypeId> for RTypeId {
+    #[inline]
+    fn from(id: UTypeId) -> Self {
+        Self { id }
+    }
+}
+
+impl From<RTypeId> for UTypeId {
+    #[inline]
+    fn from(id: RTypeId) -> Self {
+        id.id
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+
 type TypeIdArray = [u8; mem::size_of::<TypeId>()];
 
 const MAX_TYPE_ID_SIZE: usize = 16;
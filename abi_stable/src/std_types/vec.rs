@@ -9,7 +9,7 @@ use std::{
     iter::FromIterator,
     marker::PhantomData,
     mem::{self, ManuallyDrop},
-    ops::{Deref, DerefMut, Index, IndexMut},
+    ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds},
     ptr,
 };
 
@@ -19,8 +19,12 @@ use core_extensions::prelude::*;
 
 use crate::{
     sabi_types::{Constructor,StaticRef},
-    std_types::{RSlice, RSliceMut,utypeid::{UTypeId,new_utypeid}},
+    std_types::{
+        ROption, RResult, ROk, RErr, RSlice, RSliceMut, RSliceIter, RSliceIterMut, RString,
+        utypeid::{UTypeId,new_utypeid},
+    },
     prefix_type::{PrefixTypeTrait,WithMetadata},
+    traits::IntoReprC,
 };
 
 #[cfg(test)]
@@ -105,6 +109,9 @@ pub fn partition_evenness(numbers:RSlice<'_,u32>)->Partitioned{
 
         /// This returns the ammount of elements this RVec can store without reallocating.
         ///
+        /// Along with `as_mut_ptr` and `set_len`,
+        /// this allows passing the RVec's buffer to C functions that write into it.
+        ///
         /// # Example
         ///
         /// ```
@@ -146,11 +153,66 @@ pub fn partition_evenness(numbers:RSlice<'_,u32>)->Partitioned{
         }
 
         /// Gets a raw pointer to the start of this RVec's buffer.
+        ///
+        /// The pointer is invalidated when the RVec reallocates its buffer,
+        /// eg:by calling `push`/`reserve`/`shrink_to_fit`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use abi_stable::std_types::RVec;
+        ///
+        /// let list=RVec::from(vec![3,5,8]);
+        ///
+        /// let ptr=list.as_ptr();
+        ///
+        /// unsafe{
+        ///     assert_eq!( *ptr, 3 );
+        ///     assert_eq!( *ptr.add(2), 8 );
+        /// }
+        ///
+        /// ```
         #[inline(always)]
         pub const fn as_ptr(&self) -> *const T{
             self.buffer
         }
 
+        /// Gets a mutable raw pointer to the start of this RVec's buffer.
+        ///
+        /// The pointer is invalidated when the RVec reallocates its buffer,
+        /// eg:by calling `push`/`reserve`/`shrink_to_fit`.
+        ///
+        /// # Example
+        ///
+        /// This example passes the buffer of the RVec to a (simulated) C function,
+        /// which writes up to `capacity` elements and returns how many it wrote.
+        ///
+        /// ```
+        /// use abi_stable::std_types::RVec;
+        ///
+        /// extern "C" fn fill_buffer(buffer:*mut u8,capacity:usize)->usize{
+        ///     let written=capacity.min(4);
+        ///     for i in 0..written {
+        ///         unsafe{ buffer.add(i).write(i as u8 * 10) }
+        ///     }
+        ///     written
+        /// }
+        ///
+        /// let mut list=RVec::<u8>::with_capacity(16);
+        ///
+        /// unsafe{
+        ///     let written=fill_buffer(list.as_mut_ptr(),list.capacity());
+        ///     list.set_len(written);
+        /// }
+        ///
+        /// assert_eq!( &list[..], &[0,10,20,30] );
+        ///
+        /// ```
+        #[inline(always)]
+        pub fn as_mut_ptr(&mut self) -> *mut T{
+            self.buffer
+        }
+
         /// Creates a new,empty `RVec<T>`.
         ///
         /// This function does not allocate.
@@ -227,6 +289,46 @@ impl<T> RVec<T> {
         Vec::with_capacity(cap).into()
     }
 
+    /// Creates an `RVec<T>` from an iterator of `RResult<T,E>`s,
+    /// returning the first error if there is any.
+    ///
+    /// This reserves space for the lower bound of the size_hint of the iterator first,
+    /// and stops iterating at the first `RErr`,
+    /// dropping the elements that were collected before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{
+    ///     std_types::{RErr,ROk,RResult,RVec},
+    ///     rvec,
+    /// };
+    ///
+    /// fn parse_all(records:&[&str])->RResult<RVec<u32>,std::num::ParseIntError>{
+    ///     RVec::try_from_iter( records.iter().map(|x| RResult::from(x.parse::<u32>()) ) )
+    /// }
+    ///
+    /// assert_eq!( parse_all(&["3","5","8"]), ROk(rvec![3,5,8]) );
+    /// assert!( parse_all(&["3","hello","8"]).is_rerr() );
+    /// assert_eq!( parse_all(&[]), ROk(RVec::new()) );
+    ///
+    /// ```
+    pub fn try_from_iter<I,E>(iter: I) -> RResult<RVec<T>,E>
+    where
+        I: IntoIterator<Item = RResult<T,E>>,
+    {
+        let iter = iter.into_iter();
+        let mut list = RVec::with_capacity(iter.size_hint().0);
+        for elem in iter {
+            match elem {
+                ROk(elem) => list.push(elem),
+                // `list` is dropped here,dropping the elements collected so far.
+                RErr(e) => return RErr(e),
+            }
+        }
+        ROk(list)
+    }
+
     /// Creates an `RSlice<'a,T>` with access to the `range` range of
     /// elements of the `RVec<T>`.
     ///
@@ -335,6 +437,48 @@ impl<T> RVec<T> {
         self.as_mut_slice().into()
     }
 
+    /// Creates an ffi-safe iterator over references to the elements of the `RVec<T>`.
+    ///
+    /// Unlike `iter` (which comes from the slice methods),
+    /// the returned iterator can be returned from functions exported by dynamic libraries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let list=RVec::from(vec![0,1,2,3]);
+    ///
+    /// assert_eq!( list.riter().rev().collect::<Vec<&u32>>(), vec![&3,&2,&1,&0] );
+    ///
+    /// ```
+    pub fn riter(&self) -> RSliceIter<'_, T> {
+        RSliceIter::new(self.as_rslice())
+    }
+
+    /// Creates an ffi-safe iterator over mutable references to the elements of the `RVec<T>`.
+    ///
+    /// Unlike `iter_mut` (which comes from the slice methods),
+    /// the returned iterator can be returned from functions exported by dynamic libraries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::from(vec![0,1,2,3]);
+    ///
+    /// for elem in list.riter_mut() {
+    ///     *elem+=10;
+    /// }
+    ///
+    /// assert_eq!( list.as_slice(), &[10,11,12,13] );
+    ///
+    /// ```
+    pub fn riter_mut(&mut self) -> RSliceIterMut<'_, T> {
+        RSliceIterMut::new(self.as_mut_rslice())
+    }
+
     /// Returns the ammount of elements of the `RVec<T>`.
     ///
     /// # Example
@@ -412,6 +556,45 @@ impl<T> RVec<T> {
         vtable.shrink_to_fit()(self);
     }
 
+    /// Shrinks the capacity of the RVec,
+    /// never going below `min_capacity` or the length of the RVec.
+    ///
+    /// This does nothing if the capacity is already `min_capacity` or lower.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::<u32>::with_capacity(16);
+    ///
+    /// list.extend( std::iter::repeat(11).take(4) );
+    /// assert_eq!(list.capacity(),16);
+    ///
+    /// list.shrink_to(10);
+    /// assert_eq!(list.len(),4);
+    /// assert_eq!(list.capacity(),10);
+    ///
+    /// list.shrink_to(0);
+    /// assert_eq!(list.len(),4);
+    /// assert_eq!(list.capacity(),4);
+    ///
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() <= min_capacity {
+            return;
+        }
+        let vtable = self.vtable();
+        match vtable.shrink_to() {
+            Some(shrink_to) => shrink_to(self, min_capacity),
+            // RVecs created by a library compiled against an older version of abi_stable
+            None => {
+                vtable.shrink_to_fit()(self);
+                self.resize_capacity(min_capacity, Exactness::Exact);
+            }
+        }
+    }
+
     /// Whether the length of the `RVec<T>` is 0.
     ///
     /// # Example
@@ -435,12 +618,48 @@ impl<T> RVec<T> {
         self.length == 0
     }
 
+    /// Converts a `Vec<T>` into an `RVec<T>`,reusing its allocation.
+    ///
+    /// # Allocation
+    ///
+    /// This never allocates,
+    /// the `RVec<T>` stores a vtable with the functions to (re/de)allocate the buffer
+    /// using the allocator of the dynamic library/binary that called this,
+    /// so it's safe to pass the returned `RVec<T>` to other dynamic libraries.
+    ///
+    /// This is equivalent to `RVec::from(vec)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let vec=vec![3,5,8];
+    /// let buffer=vec.as_ptr();
+    ///
+    /// let list=RVec::from_vec(vec);
+    /// assert_eq!( list.as_ptr(), buffer );
+    /// assert_eq!( list.as_slice(), &[3,5,8] );
+    ///
+    /// ```
+    #[inline]
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        vec.into()
+    }
+
     /// Returns a `Vec<T>`,consuming `self`.
     ///
     /// # Allocation
     ///
+    /// The allocation of the `RVec<T>` is only reused if
+    /// the `RVec<T>` was created in the same dynamic library/binary that calls this,
+    /// which is checked by comparing the vtable of `self` with the one of
+    /// this dynamic library/binary.
+    ///
     /// If this is invoked outside of the dynamic library/binary that created it,
-    /// it will allocate a new `Vec<T>` and move the data into it.
+    /// it will allocate a new `Vec<T>` and move the data into it,
+    /// because the allocator of the `RVec<T>` might not be the global allocator
+    /// of the caller.
     ///
     /// # Example
     ///
@@ -567,6 +786,111 @@ impl<T> RVec<T> {
         }
     }
 
+    /// Inserts `value` into this sorted `RVec<T>`,keeping it sorted,
+    /// returning the index where it was inserted.
+    ///
+    /// If there are elements equal to `value`,it is inserted after all of them.
+    ///
+    /// If the `RVec<T>` is not sorted the position `value` is inserted at is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::from(vec![1,3,3,5]);
+    ///
+    /// assert_eq!(list.insert_sorted(4),3);
+    /// assert_eq!(list.as_slice(),&[1,3,3,4,5]);
+    ///
+    /// assert_eq!(list.insert_sorted(3),3);
+    /// assert_eq!(list.as_slice(),&[1,3,3,3,4,5]);
+    ///
+    /// assert_eq!(list.insert_sorted(0),0);
+    /// assert_eq!(list.insert_sorted(8),7);
+    /// assert_eq!(list.as_slice(),&[0,1,3,3,3,4,5,8]);
+    ///
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = match self.as_slice().binary_search_by(|elem| {
+            if *elem <= value { Ordering::Less } else { Ordering::Greater }
+        }) {
+            Ok(index)|Err(index) => index,
+        };
+        self.insert(index, value);
+        index
+    }
+
+    /// Binary searches this sorted `RVec<T>`,sorted by the key returned by `f`,for `key`.
+    ///
+    /// This has the same semantics as `<[T]>::binary_search_by_key`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let list=RVec::from(vec![(1,"foo"),(4,"bar"),(9,"baz")]);
+    ///
+    /// assert_eq!( list.binary_search_by_key(&4,|&(k,_)| k ), Ok(1) );
+    /// assert_eq!( list.binary_search_by_key(&0,|&(k,_)| k ), Err(0) );
+    /// assert_eq!( list.binary_search_by_key(&5,|&(k,_)| k ), Err(2) );
+    /// assert_eq!( list.binary_search_by_key(&10,|&(k,_)| k ), Err(3) );
+    ///
+    /// ```
+    pub fn binary_search_by_key<'a, B, F>(&'a self, key: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(key, f)
+    }
+
+    /// Whether this `RVec<T>` contains an element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let list=RVec::from(vec![3,5,8]);
+    ///
+    /// assert!( list.contains(&5) );
+    /// assert!( !list.contains(&4) );
+    ///
+    /// ```
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns the index of the first element for which `pred` returns true,
+    /// returning RNone if there is no such element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::{RNone,RSome,RVec};
+    ///
+    /// let list=RVec::from(vec![3,5,8,10]);
+    ///
+    /// assert_eq!( list.position(|x| x%2==0 ), RSome(2) );
+    /// assert_eq!( list.position(|&x| x==3 ), RSome(0) );
+    /// assert_eq!( list.position(|&x| x>10 ), RNone );
+    ///
+    /// ```
+    pub fn position<F>(&self, pred: F) -> ROption<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred).into_c()
+    }
+
     /// Attemps to remove the element at `index` position,
     /// returns None if self.len() <= index.
     ///
@@ -775,6 +1099,55 @@ impl<T> RVec<T> {
         self.truncate_inner(0);
     }
 
+    /// Splits the `RVec<T>` at the `at` index,
+    /// returning a new `RVec<T>` with the elements in `[at..]`,
+    /// and leaving the elements in `[..at]` in `self`.
+    ///
+    /// Note:this has no effect on the capacity of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::<u32>::from_slice(&[11,22,55,66,77]);
+    ///
+    /// let tail=list.split_off(2);
+    /// assert_eq!( list.as_slice(), &[11,22] );
+    /// assert_eq!( tail.as_slice(), &[55,66,77] );
+    ///
+    /// let empty=list.split_off(2);
+    /// assert_eq!( list.as_slice(), &[11,22] );
+    /// assert!( empty.is_empty() );
+    ///
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> RVec<T> {
+        assert!(
+            at <= self.length,
+            "index out of bounds,index={} len={} ",
+            at,
+            self.length
+        );
+        let tail_len = self.length - at;
+        let mut tail = RVec::with_capacity(tail_len);
+        unsafe {
+            // The elements are moved into `tail`,
+            // so `self` must stop owning them before they're copied.
+            self.length = at;
+            ptr::copy_nonoverlapping(
+                self.buffer().offset(at as isize),
+                tail.buffer_mut(),
+                tail_len,
+            );
+            tail.set_len(tail_len);
+        }
+        tail
+    }
+
     
 
     /// Retains only the elements that satisfy the `pred` predicate
@@ -815,6 +1188,83 @@ impl<T> RVec<T> {
         };
     }
 
+    /// Removes consecutive elements for which `same_bucket` returns true,
+    /// keeping the first element of each run.
+    ///
+    /// `same_bucket` is passed the element being checked as the first argument,
+    /// and the last element that was kept as the second argument.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::from_slice(&["foo","FOO","bar","Bar","baz","foo"]);
+    ///
+    /// list.dedup_by(|a,b| a.eq_ignore_ascii_case(b) );
+    ///
+    /// assert_eq!( list.as_slice(), &["foo","bar","baz","foo"] );
+    ///
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where F: FnMut(&mut T, &mut T) -> bool
+    {
+        let old_len = self.len();
+        if old_len <= 1 {
+            return;
+        }
+
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            // Leaks the elements if `same_bucket` panics,
+            // instead of leaving the `RVec` with moved-from or dropped elements.
+            self.set_len(0);
+
+            // The length of the prefix of deduplicated elements.
+            let mut kept = 1;
+            for i in 1..old_len {
+                let current = ptr.add(i);
+                let last_kept = ptr.add(kept - 1);
+                if same_bucket(&mut *current, &mut *last_kept) {
+                    ptr::drop_in_place(current);
+                } else {
+                    if i != kept {
+                        ptr::copy_nonoverlapping(current, ptr.add(kept), 1);
+                    }
+                    kept += 1;
+                }
+            }
+
+            self.set_len(kept);
+        }
+    }
+
+    /// Overwrites every element of the `RVec<T>` with the return value of `f`,
+    /// calling `f` once for each element.
+    ///
+    /// This does not change the length of the `RVec<T>`,
+    /// nor does it write into the spare capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::from_slice(&[3,5,8]);
+    ///
+    /// let mut next=10;
+    /// list.fill_with(||{ next+=1; next });
+    /// assert_eq!( list.as_slice(), &[11,12,13] );
+    ///
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where F: FnMut() -> T
+    {
+        for elem in self.as_mut_slice() {
+            *elem = f();
+        }
+    }
+
     fn truncate_inner(&mut self, to: usize) {
         let old_length = self.length;
         self.length = to;
@@ -934,6 +1384,31 @@ where
         }
     }
 
+    /// Overwrites every element of the `RVec<T>` with clones of `value`.
+    ///
+    /// This does not change the length of the `RVec<T>`,
+    /// nor does it write into the spare capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut buffer=RVec::<u8>::from_slice(&[13,21,34,55]);
+    ///
+    /// buffer.fill(0);
+    /// assert_eq!( buffer.as_slice(), &[0,0,0,0] );
+    ///
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        if let Some((last, elems)) = self.as_mut_slice().split_last_mut() {
+            for elem in elems {
+                elem.clone_from(&value);
+            }
+            *last = value;
+        }
+    }
+
     /// Extends this `RVec<_>` with clones of the elements of the slice.
     ///
     /// # Example
@@ -954,6 +1429,68 @@ where
             self.push(elem.clone());
         }
     }
+
+    /// Extends this `RVec<_>` with clones of the elements in the `range` range
+    /// of the `RVec<_>` itself.
+    ///
+    /// The bounds of the range are computed before any element is appended,
+    /// so the appended elements are never part of the cloned range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end,
+    /// or if the end of the range is greater than the length of the `RVec<_>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVec;
+    ///
+    /// let mut list=RVec::from(vec![0,1,2,3]);
+    ///
+    /// list.extend_from_within(1..3);
+    /// assert_eq!( list.as_slice(), &[0,1,2,3,1,2] );
+    ///
+    /// list.extend_from_within(..);
+    /// assert_eq!( list.as_slice(), &[0,1,2,3,1,2,0,1,2,3,1,2] );
+    ///
+    /// ```
+    pub fn extend_from_within<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)
+                .expect("attempted to index RVec from after usize::MAX"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)
+                .expect("attempted to index RVec up to usize::MAX"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end,
+            "range start index {} is greater than range end index {}",
+            start,
+            end,
+        );
+        assert!(
+            end <= len,
+            "range end index {} is out of range for RVec of length {}",
+            end,
+            len,
+        );
+
+        self.reserve(end - start);
+        for i in start..end {
+            let elem = self[i].clone();
+            self.push(elem);
+        }
+    }
 }
 
 impl<T> RVec<T>
@@ -991,6 +1528,107 @@ where
     }
 }
 
+impl<T> RVec<RVec<T>>
+where
+    T: Clone,
+{
+    /// Flattens this `RVec<RVec<T>>` into a single `RVec<T>`,
+    /// cloning the elements.
+    ///
+    /// This allocates the returned `RVec<T>` once.
+    ///
+    /// It's not named `concat` so that it doesn't hide the `concat` slice method,
+    /// which returns a `Vec<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec,std_types::RVec};
+    ///
+    /// let list=rvec![rvec![3,5],rvec![],rvec![8,13,21]];
+    ///
+    /// assert_eq!( list.concat_r(), rvec![3,5,8,13,21] );
+    /// assert_eq!( RVec::<RVec<u8>>::new().concat_r(), RVec::new() );
+    ///
+    /// ```
+    pub fn concat_r(&self) -> RVec<T> {
+        let len = self.iter().map(|x| x.len()).sum();
+        let mut out = RVec::with_capacity(len);
+        for elem in self {
+            out.extend_from_slice(elem);
+        }
+        out
+    }
+
+    /// Flattens this `RVec<RVec<T>>` into a single `RVec<T>`,
+    /// cloning the elements,with a clone of `sep` between each `RVec<T>`.
+    ///
+    /// This allocates the returned `RVec<T>` once.
+    ///
+    /// It's not named `join` so that it doesn't hide the `join` slice method,
+    /// which returns a `Vec<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec,std_types::RVec};
+    ///
+    /// let list=rvec![rvec![3,5],rvec![],rvec![8,13,21]];
+    ///
+    /// assert_eq!( list.join_r(&[0,0]), rvec![3,5,0,0,0,0,8,13,21] );
+    /// assert_eq!( list.join_r(&[]), rvec![3,5,8,13,21] );
+    /// assert_eq!( RVec::<RVec<u8>>::new().join_r(&[0]), RVec::new() );
+    ///
+    /// ```
+    pub fn join_r(&self, sep: &[T]) -> RVec<T> {
+        let len = self.iter().map(|x| x.len()).sum::<usize>()
+            + sep.len() * self.len().saturating_sub(1);
+        let mut out = RVec::with_capacity(len);
+        for (i, elem) in self.iter().enumerate() {
+            if i != 0 {
+                out.extend_from_slice(sep);
+            }
+            out.extend_from_slice(elem);
+        }
+        out
+    }
+}
+
+impl RVec<RString> {
+    /// Joins the strings in this `RVec<RString>` into a single `RString`,
+    /// with `sep` between each string.
+    ///
+    /// This allocates the returned `RString` once.
+    ///
+    /// It's not named `join` so that it doesn't hide the `join` slice method,
+    /// which returns a `String`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::{rvec,std_types::{RString,RVec}};
+    ///
+    /// let list:RVec<RString>=rvec!["foo".into(),"bar".into(),"baz".into()];
+    ///
+    /// assert_eq!( list.join_r(", ").as_str(), "foo, bar, baz" );
+    /// assert_eq!( list.join_r("").as_str(), "foobarbaz" );
+    /// assert_eq!( RVec::<RString>::new().join_r(", ").as_str(), "" );
+    ///
+    /// ```
+    pub fn join_r(&self, sep: &str) -> RString {
+        let len = self.iter().map(|x| x.len()).sum::<usize>()
+            + sep.len() * self.len().saturating_sub(1);
+        let mut out = RString::with_capacity(len);
+        for (i, elem) in self.iter().enumerate() {
+            if i != 0 {
+                out.push_str(sep);
+            }
+            out.push_str(elem);
+        }
+        out
+    }
+}
+
 impl<T> Clone for RVec<T>
 where
     T: Clone,
@@ -1225,7 +1863,13 @@ impl<T> FromIterator<T> for RVec<T> {
     where
         I: IntoIterator<Item = T>,
     {
-        iter.piped(Vec::from_iter).piped(Self::from)
+        // `ExactSizeIterator`s return their exact length as the lower bound,
+        // so those only allocate once.
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut this = Self::with_capacity(lower);
+        this.extend(iter);
+        this
     }
 }
 
@@ -1289,6 +1933,7 @@ impl<'a, T: 'a> VTableGetter<'a, T> {
         destructor: destructor_vec,
         grow_capacity_to: grow_capacity_to_vec,
         shrink_to_fit: shrink_to_fit_vec,
+        shrink_to: shrink_to_vec,
     };
 
     // The VTABLE for this type in this executable/library
@@ -1323,6 +1968,8 @@ struct VecVTableVal<T> {
     grow_capacity_to: extern "C" fn(&mut RVec<T>, usize, Exactness),
     #[sabi(last_prefix_field)]
     shrink_to_fit: extern "C" fn(&mut RVec<T>),
+    #[sabi(missing_field(option))]
+    shrink_to: extern "C" fn(&mut RVec<T>, usize),
 }
 
 
@@ -1361,3 +2008,20 @@ extern "C" fn shrink_to_fit_vec<T>(this: &mut RVec<T>) {
         }
     }
 }
+
+extern "C" fn shrink_to_vec<T>(this: &mut RVec<T>, min_capacity: usize) {
+    extern_fn_panic_handling! {
+        unsafe{
+            this.with_vec(|list| {
+                let to = list.len().max(min_capacity);
+                if to < list.capacity() {
+                    // `Vec::shrink_to` isn't available in every supported Rust version,
+                    // so this moves the elements into a smaller allocation instead.
+                    let mut shrunk = Vec::with_capacity(to);
+                    shrunk.extend(list.drain(..));
+                    *list = shrunk;
+                }
+            })
+        }
+    }
+}
@@ -8,6 +8,7 @@ use std::{iter,sync::Arc};
 use core_extensions::prelude::*;
 
 use crate::{
+    std_types::{RNone,RSome,RStr},
     test_utils::{must_panic, ShouldHavePanickedAt},
     traits::IntoReprC,
 };
@@ -163,6 +164,131 @@ fn truncate() {
     }
 }
 
+#[test]
+fn split_off() {
+    let orig = vec![0, 1, 2, 3, 4];
+    for at in 0..=orig.len() {
+        let mut list = orig.clone().into_c();
+        let capacity = list.capacity();
+
+        let tail = list.split_off(at);
+        assert_eq!(&*list, &orig[..at]);
+        assert_eq!(&*tail, &orig[at..]);
+        assert_eq!(list.capacity(), capacity);
+    }
+    {
+        // Everything moves to the tail.
+        let mut list = orig.clone().into_c();
+        let tail = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(&*tail, &*orig);
+    }
+    {
+        // The tail is empty.
+        let mut list = orig.clone().into_c();
+        let tail = list.split_off(orig.len());
+        assert_eq!(&*list, &*orig);
+        assert!(tail.is_empty());
+    }
+    {
+        let pointer = Arc::new(());
+        let length = 10;
+
+        for &at in &[0, 4, length] {
+            let mut list = iter::repeat(pointer.clone())
+                .take(length)
+                .collect::<RVec<_>>();
+            assert_eq!(Arc::strong_count(&pointer), 1 + length);
+
+            let tail = list.split_off(at);
+            assert_eq!(list.len(), at);
+            assert_eq!(tail.len(), length - at);
+            assert_eq!(Arc::strong_count(&pointer), 1 + length);
+
+            drop(list);
+            assert_eq!(Arc::strong_count(&pointer), 1 + length - at);
+            drop(tail);
+            assert_eq!(Arc::strong_count(&pointer), 1);
+        }
+    }
+}
+
+#[test]
+fn split_off_panics() -> Result<(), ShouldHavePanickedAt> {
+    let mut list = vec![0, 1, 2].into_c();
+    must_panic(file_span!(), || list.split_off(4))?;
+    must_panic(file_span!(), || list.split_off(usize::max_value()))?;
+    assert_eq!(&*list, &[0, 1, 2]);
+    Ok(())
+}
+
+#[test]
+fn dedup_by() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Event {
+        timestamp: u32,
+        name: &'static str,
+    }
+
+    let orig = vec![
+        Event { timestamp: 0, name: "a" },
+        Event { timestamp: 3, name: "b" },
+        Event { timestamp: 9, name: "c" },
+        Event { timestamp: 10, name: "d" },
+        Event { timestamp: 25, name: "e" },
+        Event { timestamp: 21, name: "f" },
+        Event { timestamp: 3, name: "g" },
+        Event { timestamp: 5, name: "h" },
+    ];
+
+    let same_bucket = |a: &mut Event, b: &mut Event| a.timestamp / 10 == b.timestamp / 10;
+
+    let mut expected = orig.clone();
+    expected.dedup_by(same_bucket);
+
+    let mut list = orig.clone().into_c();
+    list.dedup_by(same_bucket);
+    assert_eq!(&*list, &*expected);
+    assert_eq!(
+        list.iter().map(|e| e.name).collect::<Vec<_>>(),
+        vec!["a", "d", "e", "g"],
+    );
+
+    {
+        // The later element is the first argument.
+        let mut list = RVec::from_slice(&[1, 2, 3, 10, 11, 20]);
+        let mut args = Vec::new();
+        list.dedup_by(|a, b| {
+            args.push((*a, *b));
+            *a - *b < 5
+        });
+        assert_eq!(&*list, &[1, 10, 20]);
+        assert_eq!(args, vec![(2, 1), (3, 1), (10, 1), (11, 10), (20, 10)]);
+    }
+    {
+        let mut list = RVec::<u32>::new();
+        list.dedup_by(|_, _| true);
+        assert!(list.is_empty());
+
+        let mut list = RVec::from_slice(&[3]);
+        list.dedup_by(|_, _| true);
+        assert_eq!(&*list, &[3]);
+    }
+    {
+        // Removed elements are dropped.
+        let pointer = Arc::new(());
+        let mut list = iter::repeat(pointer.clone()).take(10).collect::<RVec<_>>();
+        assert_eq!(Arc::strong_count(&pointer), 11);
+
+        list.dedup_by(|a, b| Arc::ptr_eq(a, b));
+        assert_eq!(list.len(), 1);
+        assert_eq!(Arc::strong_count(&pointer), 2);
+
+        drop(list);
+        assert_eq!(Arc::strong_count(&pointer), 1);
+    }
+}
+
 #[test]
 fn retain(){
     let orig = vec![2, 3, 4 , 5, 6,7,8];
@@ -256,6 +382,40 @@ fn resize() {
     }
 }
 
+#[test]
+fn fill() {
+    let mut list = RVec::<u8>::with_capacity(16);
+    list.fill(7);
+    assert_eq!(&*list, <&[u8]>::default());
+
+    list.extend_from_slice(&[1, 2, 3, 4, 5]);
+    let cap = list.capacity();
+
+    list.fill(0);
+    assert_eq!(&*list, &[0, 0, 0, 0, 0][..]);
+    assert_eq!(list.capacity(), cap);
+
+    let mut strings = RVec::from(vec!["a".to_string(), "b".to_string()]);
+    strings.fill("c".into());
+    assert_eq!(&*strings, &["c".to_string(), "c".to_string()][..]);
+}
+
+#[test]
+fn fill_with() {
+    let mut list = RVec::<u32>::with_capacity(16);
+    let mut calls = 0;
+    list.fill_with(|| { calls += 1; 0 });
+    assert_eq!(calls, 0);
+
+    list.extend_from_slice(&[1, 2, 3, 4, 5]);
+    let cap = list.capacity();
+
+    list.fill_with(|| { calls += 1; calls * 10 });
+    assert_eq!(calls, 5);
+    assert_eq!(&*list, &[10, 20, 30, 40, 50][..]);
+    assert_eq!(list.capacity(), cap);
+}
+
 #[test]
 fn extend_from_slice() {
     let mut list = RVec::new();
@@ -298,6 +458,48 @@ fn extend() {
     assert_eq!(&*list, &*from_upto2);
 }
 
+#[test]
+fn from_iter_capacity() {
+    // An iterator with an inexact size_hint.
+    struct AtLeast<I>{
+        iter: I,
+        lower: usize,
+    }
+
+    impl<I: Iterator> Iterator for AtLeast<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {
+            self.iter.next()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.lower, None)
+        }
+    }
+
+    for len in 0..64 {
+        let list = (0..len).collect::<RVec<u32>>();
+        assert_eq!(list.len(), len as usize);
+        assert_eq!(list.capacity(), len as usize);
+        assert_eq!(&*list, &*(0..len).collect::<Vec<u32>>());
+    }
+    {
+        let list = AtLeast{ iter: 0..10u32, lower: 10 }.collect::<RVec<u32>>();
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.capacity(), 10);
+    }
+    {
+        // A size_hint with a lower bound larger than the amount of elements.
+        let list = AtLeast{ iter: 0..10u32, lower: 20 }.collect::<RVec<u32>>();
+        assert_eq!(&*list, &[0,1,2,3,4,5,6,7,8,9][..]);
+        assert!(list.capacity() >= list.len());
+    }
+    {
+        let list = AtLeast{ iter: 0..10u32, lower: 5 }.collect::<RVec<u32>>();
+        assert_eq!(&*list, &[0,1,2,3,4,5,6,7,8,9][..]);
+        assert!(list.capacity() >= 10);
+    }
+}
+
 #[test]
 fn into_iter() {
     assert_eq!(RVec::<()>::new().into_iter().next(), None);
@@ -356,6 +558,22 @@ fn from_vec() {
     assert_eq!(buffer_ptr, list.as_ptr());
 }
 
+#[test]
+fn from_vec_into_vec_roundtrip() {
+    let mut orig = Vec::with_capacity(16);
+    orig.extend(vec![10, 11, 12, 13]);
+    let buffer_ptr = orig.as_ptr();
+
+    let list = RVec::from_vec(orig);
+    assert_eq!(list.as_ptr(), buffer_ptr);
+    assert_eq!(list.capacity(), 16);
+
+    let vec = list.into_vec();
+    assert_eq!(vec.as_ptr(), buffer_ptr);
+    assert_eq!(vec.capacity(), 16);
+    assert_eq!(vec, vec![10, 11, 12, 13]);
+}
+
 #[test]
 fn test_drop() {
     let pointer = Arc::new(());
@@ -397,4 +615,241 @@ fn rvec_macro(){
     assert_eq!(RVec::from(vec![0,3]), rvec![0,3]);
     assert_eq!(RVec::from(vec![0,3,6]), rvec![0,3,6]);
     assert_eq!(RVec::from(vec![1;10]), rvec![1;10]);
-}
\ No newline at end of file
+}
+#[test]
+fn raw_parts_accessors(){
+    let mut list=RVec::<u8>::with_capacity(8);
+    let capacity=list.capacity();
+    assert!(capacity>=8);
+    assert_eq!(list.as_ptr(),list.as_mut_ptr() as *const u8);
+
+    unsafe{
+        let ptr=list.as_mut_ptr();
+        for i in 0..5 {
+            ptr.add(i).write(i as u8+b'a');
+        }
+        list.set_len(5);
+    }
+    assert_eq!(&list[..],b"abcde");
+    assert_eq!(list.capacity(),capacity);
+    assert_eq!(list.as_ptr(),list.as_slice().as_ptr());
+
+    let mut list=list.set_vtable_for_testing();
+    assert_eq!(list.as_ptr(),list.as_mut_ptr() as *const u8);
+    assert_eq!(&list[..],b"abcde");
+}
+
+#[test]
+fn concat_join(){
+    let list:RVec<RVec<u8>>=rvec![rvec![b'a'],rvec![],rvec![b'b',b'c']];
+
+    let concat=list.concat_r();
+    assert_eq!(&concat[..],b"abc");
+    assert_eq!(concat.capacity(),3);
+
+    let joined=list.join_r(b"--");
+    assert_eq!(&joined[..],b"a----bc");
+    assert_eq!(joined.capacity(),7);
+
+    assert_eq!(&rvec![rvec![b'a']].join_r(b"--")[..],b"a");
+    assert_eq!(RVec::<RVec<u8>>::new().join_r(b"--"),RVec::new());
+
+    let strings:RVec<RString>=rvec!["hello".into(),"".into(),"world".into()];
+    let joined=strings.join_r(", ");
+    assert_eq!(joined.as_str(),"hello, , world");
+    assert_eq!(joined.capacity(),14);
+    assert_eq!(rvec![RString::from("hello")].join_r(", ").as_str(),"hello");
+
+    // The slice methods are still reachable by dereferencing.
+    let concat:Vec<u8>=list.concat();
+    assert_eq!(concat,b"abc".to_vec());
+    let joined:Vec<u8>=list.join(&b'-');
+    assert_eq!(joined,b"a--bc".to_vec());
+    let joined:String=strings.join(", ");
+    assert_eq!(joined,"hello, , world");
+}
+
+#[test]
+fn join_borrowed_separators(){
+    use std::borrow::Borrow;
+
+    fn join_slices<S>(list:&RVec<RVec<u8>>,sep:S)->RVec<u8>
+    where S:Borrow<[u8]>
+    {
+        list.join_r(sep.borrow())
+    }
+
+    fn join_strings<S>(list:&RVec<RString>,sep:S)->RString
+    where S:Borrow<str>
+    {
+        list.join_r(sep.borrow())
+    }
+
+    let list:RVec<RVec<u8>>=rvec![rvec![b'a'],rvec![],rvec![b'b',b'c']];
+
+    assert_eq!(&join_slices(&list,&b"--"[..])[..],b"a----bc");
+    assert_eq!(&join_slices(&list,vec![b'-'])[..],b"a--bc");
+    assert_eq!(&join_slices(&list,rvec![b'-',b'-'])[..],b"a----bc");
+    assert_eq!(&join_slices(&list,RSlice::from_slice(b"-"))[..],b"a--bc");
+    assert_eq!(&join_slices(&list,RVec::new())[..],b"abc");
+
+    let strings:RVec<RString>=rvec!["hello".into(),"".into(),"world".into()];
+
+    assert_eq!(join_strings(&strings,", ").as_str(),"hello, , world");
+    assert_eq!(join_strings(&strings,String::from("-")).as_str(),"hello--world");
+    assert_eq!(join_strings(&strings,RString::from("|")).as_str(),"hello||world");
+    assert_eq!(join_strings(&strings,RStr::from("")).as_str(),"helloworld");
+}
+
+#[test]
+fn binary_search_by_key() {
+    let list: RVec<(u32, char)> = rvec![(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+
+    assert_eq!(list.binary_search_by_key(&1, |&(k, _)| k), Ok(0));
+    assert_eq!(list.binary_search_by_key(&8, |&(k, _)| k), Ok(3));
+    let found = list.binary_search_by_key(&3, |&(k, _)| k);
+    assert!(found == Ok(1) || found == Ok(2), "{:?}", found);
+    assert_eq!(list.binary_search_by_key(&0, |&(k, _)| k), Err(0));
+    assert_eq!(list.binary_search_by_key(&5, |&(k, _)| k), Err(3));
+    assert_eq!(list.binary_search_by_key(&9, |&(k, _)| k), Err(4));
+    assert_eq!(RVec::<(u32, char)>::new().binary_search_by_key(&9, |&(k, _)| k), Err(0));
+}
+
+#[test]
+fn insert_sorted() {
+    let mut list = RVec::<u32>::new();
+
+    assert_eq!(list.insert_sorted(5), 0);
+    assert_eq!(list.insert_sorted(3), 0);
+    assert_eq!(list.insert_sorted(8), 2);
+    assert_eq!(&list[..], &[3, 5, 8]);
+
+    // At the ends
+    assert_eq!(list.insert_sorted(1), 0);
+    assert_eq!(list.insert_sorted(13), 4);
+    assert_eq!(&list[..], &[1, 3, 5, 8, 13]);
+
+    // Duplicates are inserted after the equal elements.
+    assert_eq!(list.insert_sorted(5), 3);
+    assert_eq!(list.insert_sorted(5), 4);
+    assert_eq!(list.insert_sorted(1), 1);
+    assert_eq!(list.insert_sorted(13), 8);
+    assert_eq!(&list[..], &[1, 1, 3, 5, 5, 5, 8, 13, 13]);
+}
+
+#[test]
+fn contains_and_position() {
+    let list = RVec::from(vec![3_u32, 5, 8, 5]);
+
+    assert!(list.contains(&3));
+    assert!(list.contains(&5));
+    assert!(list.contains(&8));
+    assert!(!list.contains(&0));
+    assert!(!list.contains(&4));
+    assert!(!RVec::<u32>::new().contains(&0));
+
+    assert_eq!(list.position(|&x| x == 5), RSome(1));
+    assert_eq!(list.position(|&x| x % 2 == 0), RSome(2));
+    assert_eq!(list.position(|&x| x > 8), RNone);
+    assert_eq!(RVec::<u32>::new().position(|_| true), RNone);
+}
+
+#[test]
+fn extend_from_within() -> Result<(), ShouldHavePanickedAt> {
+    {
+        // Doubling a prefix.
+        let mut list = RVec::from_slice(&[3, 5, 8, 13]);
+        list.extend_from_within(..2);
+        assert_eq!(&*list, &[3, 5, 8, 13, 3, 5]);
+        list.extend_from_within(..6);
+        assert_eq!(&*list, &[3, 5, 8, 13, 3, 5, 3, 5, 8, 13, 3, 5]);
+    }
+    {
+        let mut list = RVec::from_slice(&[0, 1, 2, 3, 4]);
+        list.extend_from_within(1..=2);
+        assert_eq!(&*list, &[0, 1, 2, 3, 4, 1, 2]);
+        list.extend_from_within(5..);
+        assert_eq!(&*list, &[0, 1, 2, 3, 4, 1, 2, 1, 2]);
+        list.extend_from_within(3..3);
+        assert_eq!(&*list, &[0, 1, 2, 3, 4, 1, 2, 1, 2]);
+    }
+    {
+        // Reallocating while cloning doesn't invalidate the source range.
+        let mut list = RVec::with_capacity(2);
+        list.extend(vec![String::from("foo"), String::from("bar")]);
+        list.extend_from_within(..);
+        assert!(list.capacity() >= 4);
+        assert_eq!(&*list, &["foo", "bar", "foo", "bar"]);
+    }
+    {
+        let pointer = Arc::new(());
+        let mut list = iter::repeat(pointer.clone()).take(3).collect::<RVec<_>>();
+        list.extend_from_within(1..);
+        assert_eq!(list.len(), 5);
+        assert_eq!(Arc::strong_count(&pointer), 6);
+        drop(list);
+        assert_eq!(Arc::strong_count(&pointer), 1);
+    }
+    {
+        let mut list = RVec::from_slice(&[0, 1, 2]);
+        must_panic(file_span!(), || list.extend_from_within(..4))?;
+        must_panic(file_span!(), || list.extend_from_within(2..1))?;
+        must_panic(file_span!(), || list.extend_from_within(1..=usize::max_value()))?;
+        assert_eq!(&*list, &[0, 1, 2]);
+    }
+    Ok(())
+}
+
+#[test]
+fn shrink_to() {
+    let mut list = RVec::<u32>::with_capacity(64);
+    list.extend_from_slice(&[1, 2, 3, 4, 5]);
+
+    list.shrink_to(100);
+    assert_eq!(list.capacity(), 64);
+
+    list.shrink_to(20);
+    assert!(20 <= list.capacity() && list.capacity() < 64);
+    assert_eq!(&*list, &[1, 2, 3, 4, 5][..]);
+
+    list.shrink_to(2);
+    assert_eq!(list.capacity(), 5);
+    assert_eq!(&*list, &[1, 2, 3, 4, 5][..]);
+
+    let mut strings = RVec::from(vec!["a".to_string(), "b".to_string()]);
+    strings.reserve_exact(30);
+    strings.shrink_to(0);
+    assert_eq!(strings.capacity(), 2);
+    assert_eq!(&*strings, &["a".to_string(), "b".to_string()][..]);
+}
+
+#[test]
+fn try_from_iter() {
+    use crate::std_types::{RErr, ROk, RResult};
+
+    let list = RVec::try_from_iter((0..5).map(ROk::<u32, ()>));
+    assert_eq!(list, ROk(RVec::from(vec![0, 1, 2, 3, 4])));
+    assert_eq!(list.unwrap().capacity(), 5);
+
+    let pointer = Arc::new(());
+    let mut yielded = 0;
+    let elems = vec![
+        ROk(pointer.clone()),
+        ROk(pointer.clone()),
+        RErr("failed"),
+        ROk(pointer.clone()),
+        RErr("not reached"),
+    ];
+    assert_eq!(Arc::strong_count(&pointer), 4);
+
+    let res: RResult<RVec<Arc<()>>, &str> = RVec::try_from_iter(
+        elems.into_iter().inspect(|_| yielded += 1),
+    );
+    assert_eq!(res.unwrap_err(), "failed");
+    assert_eq!(yielded, 3);
+    // The partially collected RVec and the unconsumed elements were dropped.
+    assert_eq!(Arc::strong_count(&pointer), 1);
+
+    let empty = RVec::<u32>::try_from_iter(Vec::<RResult<u32, ()>>::new());
+    assert_eq!(empty, ROk(RVec::new()));
+}
@@ -0,0 +1,734 @@
+/*!
+Contains an ffi-safe equivalent of `std::collections::VecDeque`.
+*/
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    ptr,
+    slice,
+};
+
+use crate::std_types::{RSliceIter, RVec};
+
+
+#[cfg(all(test,not(feature="only_new_tests")))]
+mod tests;
+
+
+/**
+Ffi-safe equivalent of `std::collections::VecDeque<T>`,
+a double-ended queue implemented with a growable ring buffer.
+
+The ring buffer is stored in an `RVec<T>` with a length of 0,
+which is only used to allocate/deallocate the buffer,
+so that the buffer is always freed by the dynamic library/executable that allocated it.
+
+# Example
+
+```
+use abi_stable::{
+    std_types::{RVecDeque,RString},
+    sabi_extern_fn,
+};
+
+#[sabi_extern_fn]
+fn next_job(queue:&mut RVecDeque<RString>)->RString{
+    queue.pop_front().unwrap_or_default()
+}
+
+let mut queue=RVecDeque::new();
+queue.push_back(RString::from("compile"));
+queue.push_back(RString::from("test"));
+queue.push_front(RString::from("fetch"));
+
+assert_eq!( next_job(&mut queue).as_str(), "fetch" );
+assert_eq!( next_job(&mut queue).as_str(), "compile" );
+assert_eq!( queue.len(), 1 );
+
+```
+
+*/
+#[repr(C)]
+#[derive(StableAbi)]
+pub struct RVecDeque<T>{
+    buffer:RVec<T>,
+    head:usize,
+    len:usize,
+}
+
+
+impl<T> RVecDeque<T>{
+    /// Creates a new,empty `RVecDeque<T>`.
+    ///
+    /// This function does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let queue=RVecDeque::<u32>::new();
+    /// assert!( queue.is_empty() );
+    ///
+    /// ```
+    pub const fn new()->Self{
+        Self{
+            buffer:RVec::new(),
+            head:0,
+            len:0,
+        }
+    }
+
+    /// Creates a new,empty `RVecDeque<T>`,with space for at least `cap` elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let queue=RVecDeque::<u32>::with_capacity(7);
+    /// assert!( queue.capacity() >= 7 );
+    ///
+    /// ```
+    pub fn with_capacity(cap:usize)->Self{
+        Self{
+            buffer:RVec::with_capacity(cap),
+            head:0,
+            len:0,
+        }
+    }
+
+    /// Returns the amount of elements in the `RVecDeque<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// assert_eq!( queue.len(), 0 );
+    ///
+    /// queue.push_back(3);
+    /// queue.push_front(5);
+    /// assert_eq!( queue.len(), 2 );
+    ///
+    /// ```
+    #[inline]
+    pub const fn len(&self)->usize{
+        self.len
+    }
+
+    /// Returns whether the `RVecDeque<T>` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// assert!( queue.is_empty() );
+    ///
+    /// queue.push_back(3);
+    /// assert!( !queue.is_empty() );
+    ///
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self)->bool{
+        self.len==0
+    }
+
+    /// Returns the amount of elements the `RVecDeque<T>` can store without reallocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let queue=RVecDeque::<u32>::new();
+    /// assert_eq!( queue.capacity(), 0 );
+    ///
+    /// ```
+    #[inline]
+    pub const fn capacity(&self)->usize{
+        self.buffer.capacity()
+    }
+
+    /// Appends `value` to the back of the `RVecDeque<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// queue.push_back(3);
+    /// queue.push_back(5);
+    ///
+    /// assert_eq!( queue.back(), Some(&5) );
+    ///
+    /// ```
+    pub fn push_back(&mut self,value:T){
+        self.grow_if_full();
+        unsafe{
+            let index=self.physical_index(self.len);
+            self.buffer_ptr().add(index).write(value);
+        }
+        self.len+=1;
+    }
+
+    /// Prepends `value` to the front of the `RVecDeque<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// queue.push_front(3);
+    /// queue.push_front(5);
+    ///
+    /// assert_eq!( queue.front(), Some(&5) );
+    ///
+    /// ```
+    pub fn push_front(&mut self,value:T){
+        self.grow_if_full();
+        self.head=if self.head==0 { self.capacity()-1 }else{ self.head-1 };
+        unsafe{
+            self.buffer_ptr().add(self.head).write(value);
+        }
+        self.len+=1;
+    }
+
+    /// Removes the first element of the `RVecDeque<T>`,returning it.
+    ///
+    /// Returns `None` if the `RVecDeque<T>` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=vec![3,5].into_iter().collect::<RVecDeque<u32>>();
+    ///
+    /// assert_eq!( queue.pop_front(), Some(3) );
+    /// assert_eq!( queue.pop_front(), Some(5) );
+    /// assert_eq!( queue.pop_front(), None );
+    ///
+    /// ```
+    pub fn pop_front(&mut self)->Option<T>{
+        if self.len==0 {
+            return None;
+        }
+        let value=unsafe{ self.buffer_ptr().add(self.head).read() };
+        self.head=self.physical_index(1);
+        self.len-=1;
+        Some(value)
+    }
+
+    /// Removes the last element of the `RVecDeque<T>`,returning it.
+    ///
+    /// Returns `None` if the `RVecDeque<T>` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=vec![3,5].into_iter().collect::<RVecDeque<u32>>();
+    ///
+    /// assert_eq!( queue.pop_back(), Some(5) );
+    /// assert_eq!( queue.pop_back(), Some(3) );
+    /// assert_eq!( queue.pop_back(), None );
+    ///
+    /// ```
+    pub fn pop_back(&mut self)->Option<T>{
+        if self.len==0 {
+            return None;
+        }
+        self.len-=1;
+        unsafe{
+            let index=self.physical_index(self.len);
+            Some(self.buffer_ptr().add(index).read())
+        }
+    }
+
+    /// Gets a reference to the element at the `index` position from the front.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// queue.push_back(5);
+    /// queue.push_front(3);
+    ///
+    /// assert_eq!( queue.get(0), Some(&3) );
+    /// assert_eq!( queue.get(1), Some(&5) );
+    /// assert_eq!( queue.get(2), None );
+    ///
+    /// ```
+    pub fn get(&self,index:usize)->Option<&T>{
+        if index<self.len {
+            unsafe{ Some(&*self.buffer.as_ptr().add(self.physical_index(index))) }
+        }else{
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the element at the `index` position from the front.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=vec![3,5].into_iter().collect::<RVecDeque<u32>>();
+    ///
+    /// *queue.get_mut(1).unwrap()+=10;
+    ///
+    /// assert_eq!( queue.get(1), Some(&15) );
+    ///
+    /// ```
+    pub fn get_mut(&mut self,index:usize)->Option<&mut T>{
+        if index<self.len {
+            unsafe{ Some(&mut *self.buffer_ptr().add(self.physical_index(index))) }
+        }else{
+            None
+        }
+    }
+
+    /// Gets a reference to the first element,if there is one.
+    pub fn front(&self)->Option<&T>{
+        self.get(0)
+    }
+
+    /// Gets a mutable reference to the first element,if there is one.
+    pub fn front_mut(&mut self)->Option<&mut T>{
+        self.get_mut(0)
+    }
+
+    /// Gets a reference to the last element,if there is one.
+    pub fn back(&self)->Option<&T>{
+        self.len.checked_sub(1).and_then(|i| self.get(i) )
+    }
+
+    /// Gets a mutable reference to the last element,if there is one.
+    pub fn back_mut(&mut self)->Option<&mut T>{
+        match self.len.checked_sub(1) {
+            Some(i)=>self.get_mut(i),
+            None=>None,
+        }
+    }
+
+    /// Returns the elements of the `RVecDeque<T>` as two slices,
+    /// the first one containing the front,and the second one the back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::with_capacity(4);
+    /// queue.push_back(5);
+    /// queue.push_back(8);
+    /// queue.push_front(3);
+    ///
+    /// let (front,back)=queue.as_slices();
+    /// assert_eq!( [front,back].concat(), vec![3,5,8] );
+    ///
+    /// ```
+    pub fn as_slices(&self)->(&[T],&[T]){
+        let ptr=self.buffer.as_ptr();
+        let (front,back)=self.slice_ranges();
+        unsafe{
+            (
+                slice::from_raw_parts(ptr.add(self.head),front),
+                slice::from_raw_parts(ptr,back),
+            )
+        }
+    }
+
+    /// Returns the elements of the `RVecDeque<T>` as two mutable slices,
+    /// the first one containing the front,and the second one the back.
+    pub fn as_mut_slices(&mut self)->(&mut [T],&mut [T]){
+        let ptr=self.buffer_ptr();
+        let (front,back)=self.slice_ranges();
+        unsafe{
+            (
+                slice::from_raw_parts_mut(ptr.add(self.head),front),
+                slice::from_raw_parts_mut(ptr,back),
+            )
+        }
+    }
+
+    /// Creates an ffi-safe iterator over references to the elements,
+    /// from the front to the back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=RVecDeque::new();
+    /// queue.push_back(5);
+    /// queue.push_back(8);
+    /// queue.push_front(3);
+    ///
+    /// assert_eq!( queue.iter().cloned().collect::<Vec<_>>(), vec![3,5,8] );
+    /// assert_eq!( queue.iter().rev().cloned().collect::<Vec<_>>(), vec![8,5,3] );
+    ///
+    /// ```
+    pub fn iter(&self)->Iter<'_,T>{
+        let (front,back)=self.as_slices();
+        Iter{
+            front:RSliceIter::new(front.into()),
+            back:RSliceIter::new(back.into()),
+        }
+    }
+
+    /// Removes all the elements,keeping the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use abi_stable::std_types::RVecDeque;
+    ///
+    /// let mut queue=vec![3,5].into_iter().collect::<RVecDeque<u32>>();
+    /// queue.clear();
+    ///
+    /// assert!( queue.is_empty() );
+    ///
+    /// ```
+    pub fn clear(&mut self){
+        unsafe{
+            let (front,back)=self.as_mut_slices();
+            let front:*mut [T]=front;
+            let back:*mut [T]=back;
+            // Resetting the length first,in case a destructor panics.
+            self.len=0;
+            self.head=0;
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+
+    #[inline]
+    fn buffer_ptr(&mut self)->*mut T{
+        self.buffer.as_mut_ptr()
+    }
+
+    /// Converts a position from the front into an index into the buffer,
+    /// without overflowing when the capacity is `usize::max_value()`.
+    #[inline]
+    fn physical_index(&self,index:usize)->usize{
+        let until_end=self.capacity()-self.head;
+        if index<until_end {
+            self.head+index
+        }else{
+            index-until_end
+        }
+    }
+
+    /// The lengths of the front and back slices.
+    fn slice_ranges(&self)->(usize,usize){
+        let until_end=self.capacity()-self.head;
+        if self.len<=until_end {
+            (self.len,0)
+        }else{
+            (until_end,self.len-until_end)
+        }
+    }
+
+    fn grow_if_full(&mut self){
+        if self.len==self.capacity() {
+            self.grow();
+        }
+    }
+
+    /// Moves the elements into a larger buffer,putting the front at the start of it.
+    #[cold]
+    fn grow(&mut self){
+        let new_cap=self.capacity()
+            .checked_mul(2)
+            .expect("capacity overflow")
+            .max(4);
+
+        let mut new_buffer=RVec::<T>::with_capacity(new_cap);
+        unsafe{
+            let (front,back)=self.as_slices();
+            let dst=new_buffer.as_mut_ptr();
+            ptr::copy_nonoverlapping(front.as_ptr(),dst,front.len());
+            ptr::copy_nonoverlapping(back.as_ptr(),dst.add(front.len()),back.len());
+        }
+        // The old buffer always has a length of 0,so this only deallocates it.
+        self.buffer=new_buffer;
+        self.head=0;
+    }
+}
+
+
+impl<T> Default for RVecDeque<T>{
+    fn default()->Self{
+        Self::new()
+    }
+}
+
+
+impl<T> Drop for RVecDeque<T>{
+    fn drop(&mut self){
+        // `buffer` deallocates the buffer afterwards.
+        self.clear();
+    }
+}
+
+
+impl<T> Clone for RVecDeque<T>
+where
+    T:Clone
+{
+    fn clone(&self)->Self{
+        self.iter().cloned().collect()
+    }
+}
+
+
+impl<T> Debug for RVecDeque<T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+
+impl<T> PartialEq for RVecDeque<T>
+where
+    T:PartialEq
+{
+    fn eq(&self,other:&Self)->bool{
+        self.len==other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Eq for RVecDeque<T>
+where
+    T:Eq
+{}
+
+impl<T> PartialOrd for RVecDeque<T>
+where
+    T:PartialOrd
+{
+    fn partial_cmp(&self,other:&Self)->Option<Ordering>{
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T> Ord for RVecDeque<T>
+where
+    T:Ord
+{
+    fn cmp(&self,other:&Self)->Ordering{
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T> Hash for RVecDeque<T>
+where
+    T:Hash
+{
+    fn hash<H:Hasher>(&self,state:&mut H){
+        state.write_usize(self.len);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+
+/// Reuses the buffer of the `RVec<T>`,without moving the elements.
+impl<T> From<RVec<T>> for RVecDeque<T>{
+    fn from(mut vec:RVec<T>)->Self{
+        let len=vec.len();
+        unsafe{ vec.set_len(0); }
+        Self{
+            buffer:vec,
+            head:0,
+            len,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for RVecDeque<T>{
+    fn from(vec:Vec<T>)->Self{
+        RVec::from(vec).into()
+    }
+}
+
+
+impl<T> Extend<T> for RVecDeque<T>{
+    fn extend<I>(&mut self,iter:I)
+    where
+        I:IntoIterator<Item=T>
+    {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for RVecDeque<T>{
+    fn from_iter<I>(iter:I)->Self
+    where
+        I:IntoIterator<Item=T>
+    {
+        let iter=iter.into_iter();
+        let mut this=Self::with_capacity(iter.size_hint().0);
+        this.extend(iter);
+        this
+    }
+}
+
+
+impl<T> IntoIterator for RVecDeque<T>{
+    type Item=T;
+    type IntoIter=IntoIter<T>;
+
+    fn into_iter(self)->IntoIter<T>{
+        IntoIter{deque:self}
+    }
+}
+
+impl<'a,T> IntoIterator for &'a RVecDeque<T>{
+    type Item=&'a T;
+    type IntoIter=Iter<'a,T>;
+
+    fn into_iter(self)->Iter<'a,T>{
+        self.iter()
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/// An ffi-safe iterator over references to the elements of an `RVecDeque<T>`,
+/// returned by `RVecDeque::iter`.
+#[repr(C)]
+#[derive(StableAbi)]
+#[sabi(bound = "T:'a")]
+pub struct Iter<'a,T>{
+    front:RSliceIter<'a,T>,
+    back:RSliceIter<'a,T>,
+}
+
+impl<'a,T> Clone for Iter<'a,T>{
+    fn clone(&self)->Self{
+        Self{
+            front:self.front.clone(),
+            back:self.back.clone(),
+        }
+    }
+}
+
+impl<'a,T> Iterator for Iter<'a,T>{
+    type Item=&'a T;
+
+    fn next(&mut self)->Option<&'a T>{
+        match self.front.next() {
+            Some(x)=>Some(x),
+            None=>self.back.next(),
+        }
+    }
+
+    fn size_hint(&self)->(usize,Option<usize>){
+        let len=self.len();
+        (len,Some(len))
+    }
+}
+
+impl<'a,T> DoubleEndedIterator for Iter<'a,T>{
+    fn next_back(&mut self)->Option<&'a T>{
+        match self.back.next_back() {
+            Some(x)=>Some(x),
+            None=>self.front.next_back(),
+        }
+    }
+}
+
+impl<'a,T> ExactSizeIterator for Iter<'a,T>{
+    fn len(&self)->usize{
+        self.front.len()+self.back.len()
+    }
+}
+
+impl<'a,T> FusedIterator for Iter<'a,T>{}
+
+impl<'a,T> Debug for Iter<'a,T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+
+///////////////////////////////////////////////////////////////////////////////
+
+
+/// An ffi-safe iterator over the elements of an `RVecDeque<T>`,
+/// returned by `<RVecDeque<T> as IntoIterator>::into_iter`.
+#[repr(transparent)]
+#[derive(StableAbi)]
+pub struct IntoIter<T>{
+    deque:RVecDeque<T>,
+}
+
+impl<T> IntoIter<T>{
+    /// Converts the remaining elements back into an `RVecDeque<T>`.
+    pub fn into_deque(self)->RVecDeque<T>{
+        self.deque
+    }
+}
+
+impl<T> Iterator for IntoIter<T>{
+    type Item=T;
+
+    fn next(&mut self)->Option<T>{
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self)->(usize,Option<usize>){
+        let len=self.deque.len();
+        (len,Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T>{
+    fn next_back(&mut self)->Option<T>{
+        self.deque.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T>{}
+
+impl<T> FusedIterator for IntoIter<T>{}
+
+impl<T> Debug for IntoIter<T>
+where
+    T:Debug
+{
+    fn fmt(&self,f:&mut fmt::Formatter<'_>)->fmt::Result{
+        f.debug_tuple("IntoIter").field(&self.deque).finish()
+    }
+}
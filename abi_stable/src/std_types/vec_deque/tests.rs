@@ -0,0 +1,198 @@
+use super::*;
+
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+};
+
+
+/// Checks that `deque` has the same elements as `expected`,through all the accessors.
+fn assert_same<T>(deque:&RVecDeque<T>,expected:&VecDeque<T>)
+where
+    T:PartialEq+Debug,
+{
+    assert_eq!(deque.len(),expected.len());
+    assert_eq!(deque.is_empty(),expected.is_empty());
+    assert!(deque.iter().eq(expected.iter()));
+    assert!(deque.iter().rev().eq(expected.iter().rev()));
+    assert_eq!(deque.iter().len(),expected.len());
+    assert_eq!(deque.front(),expected.front());
+    assert_eq!(deque.back(),expected.back());
+    for i in 0..=expected.len() {
+        assert_eq!(deque.get(i),expected.get(i));
+    }
+
+    let (front,back)=deque.as_slices();
+    assert!(front.iter().chain(back).eq(expected.iter()));
+}
+
+
+#[test]
+fn push_pop_wraparound(){
+    let mut deque=RVecDeque::new();
+    let mut expected=VecDeque::new();
+
+    assert_eq!(deque.capacity(),0);
+    assert_eq!(deque.pop_front(),None);
+    assert_eq!(deque.pop_back(),None);
+
+    // Mixes operations so that the head moves around the whole buffer,
+    // and the buffer grows while wrapped around.
+    for i in 0..200u32 {
+        match i%7 {
+            0|3=>{
+                deque.push_front(i);
+                expected.push_front(i);
+            }
+            1|4|5=>{
+                deque.push_back(i);
+                expected.push_back(i);
+            }
+            2=>{
+                assert_eq!(deque.pop_front(),expected.pop_front());
+            }
+            _=>{
+                assert_eq!(deque.pop_back(),expected.pop_back());
+            }
+        }
+        assert_same(&deque,&expected);
+    }
+
+    while let Some(x)=expected.pop_back() {
+        assert_eq!(deque.pop_back(),Some(x));
+    }
+    assert_eq!(deque.pop_back(),None);
+    assert_same(&deque,&expected);
+}
+
+
+#[test]
+fn wrapped_slices(){
+    let mut deque=RVecDeque::with_capacity(4);
+    let capacity=deque.capacity();
+
+    for i in 0..capacity {
+        deque.push_back(i);
+    }
+    deque.pop_front();
+    deque.pop_front();
+    deque.push_back(100);
+
+    // No reallocation happened,the back wrapped around to the start of the buffer.
+    assert_eq!(deque.capacity(),capacity);
+    let (front,back)=deque.as_slices();
+    assert_eq!(front,&(2..capacity).collect::<Vec<_>>()[..]);
+    assert_eq!(back,&[100][..]);
+
+    for (i,x) in deque.as_mut_slices().0.iter_mut().enumerate() {
+        *x=i;
+    }
+    *deque.get_mut(capacity-2).unwrap()=200;
+    *deque.back_mut().unwrap()+=1;
+    *deque.front_mut().unwrap()+=10;
+
+    let mut expected=(0..capacity-2).collect::<Vec<_>>();
+    expected[0]+=10;
+    expected.push(201);
+    assert!(deque.iter().eq(expected.iter()));
+}
+
+
+#[test]
+fn drops_elements_once(){
+    let arc=Arc::new(0);
+
+    {
+        let mut deque=RVecDeque::new();
+        for _ in 0..10 {
+            deque.push_back(arc.clone());
+            deque.push_front(arc.clone());
+        }
+        assert_eq!(Arc::strong_count(&arc),21);
+
+        drop(deque.pop_front());
+        drop(deque.pop_back());
+        assert_eq!(Arc::strong_count(&arc),19);
+
+        let cloned=deque.clone();
+        assert_eq!(Arc::strong_count(&arc),37);
+        drop(cloned);
+        assert_eq!(Arc::strong_count(&arc),19);
+
+        let mut iter=deque.into_iter();
+        drop(iter.next());
+        drop(iter.next_back());
+        assert_eq!(iter.len(),16);
+        assert_eq!(Arc::strong_count(&arc),17);
+    }
+    assert_eq!(Arc::strong_count(&arc),1);
+
+    let mut deque=(0..10).map(|_| arc.clone() ).collect::<RVecDeque<_>>();
+    deque.clear();
+    assert!(deque.is_empty());
+    assert_eq!(Arc::strong_count(&arc),1);
+}
+
+
+#[test]
+fn zero_sized(){
+    let mut deque=RVecDeque::new();
+    for _ in 0..100 {
+        deque.push_back(());
+        deque.push_front(());
+    }
+    assert_eq!(deque.len(),200);
+    assert_eq!(deque.iter().count(),200);
+    assert_eq!(deque.pop_front(),Some(()));
+    assert_eq!(deque.pop_back(),Some(()));
+    assert_eq!(deque.into_iter().count(),198);
+}
+
+
+#[test]
+fn conversions(){
+    let mut vec=RVec::with_capacity(16);
+    vec.extend(vec![3,5,8,13]);
+    let buffer=vec.as_ptr();
+
+    let mut deque=RVecDeque::from(vec);
+    assert_eq!(deque.capacity(),16);
+    assert!(deque.iter().eq([3,5,8,13].iter()));
+    assert_eq!(deque.get(0).unwrap() as *const i32,buffer);
+
+    deque.extend(vec![21,34]);
+    deque.push_front(2);
+    assert_eq!(
+        deque.clone().into_iter().collect::<Vec<_>>(),
+        vec![2,3,5,8,13,21,34],
+    );
+    assert_eq!(
+        deque.into_iter().rev().collect::<Vec<_>>(),
+        vec![34,21,13,8,5,3,2],
+    );
+
+    let deque=RVecDeque::from(vec![0,1,2]);
+    assert_eq!(deque,(0..3).collect::<RVecDeque<_>>());
+    assert_eq!(format!("{:?}",deque),"[0, 1, 2]");
+    assert_eq!((&deque).into_iter().sum::<i32>(),3);
+
+    let mut iter=deque.into_iter();
+    iter.next();
+    assert_eq!(iter.into_deque(),RVecDeque::from(vec![1,2]));
+}
+
+
+#[test]
+fn comparison(){
+    let mut wrapped=RVecDeque::with_capacity(4);
+    wrapped.push_back(2);
+    wrapped.push_back(3);
+    wrapped.push_front(1);
+
+    let contiguous=RVecDeque::from(vec![1,2,3]);
+
+    assert_eq!(wrapped,contiguous);
+    assert_eq!(wrapped.cmp(&contiguous),Ordering::Equal);
+    assert!(wrapped<RVecDeque::from(vec![1,2,4]));
+    assert_ne!(wrapped,RVecDeque::from(vec![1,2]));
+}
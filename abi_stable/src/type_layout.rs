@@ -21,7 +21,7 @@ use crate::{
     sabi_types::VersionStrings, 
     sabi_types::{CmpIgnored,Constructor,NulStr},
     std_types::{RStr,StaticStr,RSlice,UTypeId},
-    prefix_type::{FieldAccessibility,FieldConditionality},
+    prefix_type::{FieldAccessibility,FieldConditionality,FieldOptionality,IsOptional},
     reflection::ModReflMode,
 };
 
@@ -166,6 +166,20 @@ impl TLFields{
     pub fn to_vec(&self)->Vec<TLField>{
         self.iter().collect()
     }
+
+    /// Gets which fields are optional fields of a prefix type,
+    /// declared with the `#[sabi(optional)]` attribute.
+    ///
+    /// This is stored in the compressed fields
+    /// (in a bit that older versions of abi_stable ignore),
+    /// so that the layout of prefix types is the same as in older `0.8.*` versions.
+    pub fn optional_fields(&self)->FieldOptionality{
+        self.comp_fields.iter()
+            .enumerate()
+            .fold(FieldOptionality::empty(),|acc,(i,field)|{
+                acc.set_optionality(i,IsOptional::new(field.is_optional()))
+            })
+    }
 }
 
 
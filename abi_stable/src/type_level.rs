@@ -84,6 +84,11 @@ pub mod trait_marker{
     pub struct IoRead;
     pub struct IoBufRead;
     pub struct Error;
+    pub struct IteratorSum;
+    pub struct IteratorProduct;
+    pub struct AsBytes;
+    pub struct AsStr;
+    pub struct Extend;
     
     #[doc(hidden)]
     #[allow(non_camel_case_types)]
@@ -167,4 +172,21 @@ pub mod impl_enum{
     impl<T> IsImplemented for Unimplemented<T>{
         const VALUE:bool=false;
     }
+
+
+
+    /// Queries whether the trait described by `Self` is only enabled
+    /// if it's also enabled in `Other`.
+    ///
+    /// This is implemented for `Unimplemented<T>` with any `Other`,
+    /// and for `Implemented<T>` when `Other` is `Implemented<T>`.
+    #[cfg_attr(feature="rust_1_78",diagnostic::on_unimplemented(
+        message="`{Self}` is not enabled in the wider interface",
+        label="the wider interface has `{Other}` instead",
+    ))]
+    pub trait ImplSubsetOf<Other:?Sized>:Sealed{}
+
+    impl<T,Other:?Sized> ImplSubsetOf<Other> for Unimplemented<T>{}
+
+    impl<T> ImplSubsetOf<Implemented<T>> for Implemented<T>{}
 }
\ No newline at end of file
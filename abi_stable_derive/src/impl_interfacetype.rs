@@ -223,6 +223,11 @@ usable_traits!{
     io_read=(IoRead,"::std::io::Read",false,UB::DYN_TRAIT),
     io_buf_read=(IoBufRead,"::std::io::BufRead",false,UB::DYN_TRAIT),
     error=(Error,"::std::error::Error",false,UB::ROBJECT_AND_DYN_TRAIT),
+    iterator_sum=(IteratorSum,"::std::iter::Sum",false,UB::DYN_TRAIT),
+    iterator_product=(IteratorProduct,"::std::iter::Product",false,UB::DYN_TRAIT),
+    as_bytes=(AsBytes,"::std::convert::AsRef<[u8]>",false,UB::DYN_TRAIT),
+    as_str=(AsStr,"::std::convert::AsRef<str>",false,UB::DYN_TRAIT),
+    extend=(Extend,"::std::iter::Extend",false,UB::DYN_TRAIT),
 }
 
 pub(crate) fn private_associated_type()->syn::Ident{
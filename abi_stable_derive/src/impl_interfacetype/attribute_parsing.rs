@@ -65,7 +65,8 @@ pub(crate) fn parse_impl_interfacetype<'a>(
                 impld_struct[which_trait]=true;
 
                 match which_trait {
-                    WhichTrait::Iterator|WhichTrait::DoubleEndedIterator=>{
+                    WhichTrait::Iterator|WhichTrait::DoubleEndedIterator|
+                    WhichTrait::IteratorSum|WhichTrait::IteratorProduct=>{
                         impld_struct.iterator=true;
                     }
                     WhichTrait::Eq|WhichTrait::PartialOrd=>{
@@ -159,6 +159,16 @@ pub fn derive_get_static_equivalent(input: TokenStream1) -> TokenStream1 {
 
 
 
+/**
+This macro is documented in `abi_stable::docs::rflags`
+*/
+#[proc_macro_derive(RFlags)]
+pub fn derive_rflags(input: TokenStream1) -> TokenStream1 {
+    parse_or_compile_err( input, rflags::derive ).into()
+}
+
+
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn get_string_length(input: TokenStream1) -> TokenStream1 {
@@ -219,6 +229,7 @@ mod literals_constructors;
 mod export_root_module_impl;
 mod my_visibility;
 mod parse_utils;
+mod rflags;
 mod sabi_extern_fn_impl;
 mod set_span_visitor;
 mod workaround;
@@ -0,0 +1,193 @@
+/*!
+Stuff related to the `RFlags` derive macro.
+*/
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::{
+    Data, DeriveInput, Fields, Meta, NestedMeta,
+};
+
+use as_derive_utils::{
+    return_spanned_err,
+};
+
+use crate::utils::SynPathExt;
+
+
+/// The implementation of the `RFlags` derive macro.
+pub(crate) fn derive(data: DeriveInput) -> Result<TokenStream2,syn::Error> {
+    let name=&data.ident;
+    let (impl_generics, ty_generics, where_clause) = data.generics.split_for_impl();
+
+    if !is_repr_transparent(&data)? {
+        return_spanned_err!(
+            name,
+            "RFlags can only be derived for #[repr(transparent)] structs."
+        );
+    }
+
+    let bits_ty=match &data.data {
+        Data::Struct(struct_)=>match &struct_.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len()==1 =>{
+                &fields.unnamed[0].ty
+            }
+            fields=>return_spanned_err!(
+                fields,
+                "RFlags can only be derived for structs with a single unnamed field,\
+                 eg:`struct Flags(u32);`"
+            ),
+        },
+        _=>return_spanned_err!(name,"RFlags can only be derived for structs."),
+    };
+
+    Ok(quote!(
+        #[allow(dead_code)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Constructs a flag set with no flags enabled.
+            #[inline]
+            pub const fn empty()->Self{
+                Self(0)
+            }
+
+            /// Constructs a flag set from the underlying integer.
+            #[inline]
+            pub const fn from_bits(bits:#bits_ty)->Self{
+                Self(bits)
+            }
+
+            /// Gets the underlying integer.
+            #[inline]
+            pub const fn bits(&self)->#bits_ty{
+                self.0
+            }
+
+            /// Whether no flags are enabled.
+            #[inline]
+            pub const fn is_empty(&self)->bool{
+                self.0==0
+            }
+
+            /// Whether all the flags enabled in `other` are enabled in `self`.
+            #[inline]
+            pub const fn contains(&self,other:Self)->bool{
+                (self.0 & other.0)==other.0
+            }
+
+            /// Whether any of the flags enabled in `other` are enabled in `self`.
+            #[inline]
+            pub const fn intersects(&self,other:Self)->bool{
+                (self.0 & other.0)!=0
+            }
+
+            /// Enables the flags that are enabled in `other`.
+            #[inline]
+            pub fn insert(&mut self,other:Self){
+                self.0|=other.0;
+            }
+
+            /// Disables the flags that are enabled in `other`.
+            #[inline]
+            pub fn remove(&mut self,other:Self){
+                self.0&=!other.0;
+            }
+
+            /// Toggles the flags that are enabled in `other`.
+            #[inline]
+            pub fn toggle(&mut self,other:Self){
+                self.0^=other.0;
+            }
+
+            /// Enables the flags in `other` if `value` is true,otherwise disables them.
+            #[inline]
+            pub fn set(&mut self,other:Self,value:bool){
+                if value {
+                    self.insert(other);
+                }else{
+                    self.remove(other);
+                }
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitOr for #name #ty_generics #where_clause {
+            type Output=Self;
+
+            #[inline]
+            fn bitor(self,other:Self)->Self{
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitAnd for #name #ty_generics #where_clause {
+            type Output=Self;
+
+            #[inline]
+            fn bitand(self,other:Self)->Self{
+                Self(self.0 & other.0)
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitXor for #name #ty_generics #where_clause {
+            type Output=Self;
+
+            #[inline]
+            fn bitxor(self,other:Self)->Self{
+                Self(self.0 ^ other.0)
+            }
+        }
+
+        impl #impl_generics ::std::ops::Not for #name #ty_generics #where_clause {
+            type Output=Self;
+
+            #[inline]
+            fn not(self)->Self{
+                Self(!self.0)
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitOrAssign for #name #ty_generics #where_clause {
+            #[inline]
+            fn bitor_assign(&mut self,other:Self){
+                self.0|=other.0;
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitAndAssign for #name #ty_generics #where_clause {
+            #[inline]
+            fn bitand_assign(&mut self,other:Self){
+                self.0&=other.0;
+            }
+        }
+
+        impl #impl_generics ::std::ops::BitXorAssign for #name #ty_generics #where_clause {
+            #[inline]
+            fn bitxor_assign(&mut self,other:Self){
+                self.0^=other.0;
+            }
+        }
+    ))
+}
+
+
+/// Whether the type has a `#[repr(transparent)]` attribute.
+fn is_repr_transparent(data:&DeriveInput)->Result<bool,syn::Error>{
+    for attr in &data.attrs {
+        if !attr.path.equals_str("repr") {
+            continue;
+        }
+        if let Meta::List(list)=attr.parse_meta()? {
+            let is_transparent=list.nested.iter().any(|nested|{
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path))=>path.equals_str("transparent"),
+                    _=>false,
+                }
+            });
+            if is_transparent {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
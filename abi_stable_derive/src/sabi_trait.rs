@@ -127,6 +127,8 @@ pub fn derive_sabi_trait(item: ItemTrait) -> Result<TokenStream2,syn::Error> {
     
     trait_and_impl(tokenizer_params,&mut mod_contents);
 
+    ref_impls(tokenizer_params,&mut mod_contents);
+
     methods_impls(tokenizer_params,&mut mod_contents)?;
 
     declare_vtable(tokenizer_params,&mut mod_contents);
@@ -470,22 +472,31 @@ fn constructor_items<'a>(
     };
 
 
-    let extra_constraints_value=match totrait_def.which_object {
-        WhichObject::DynTrait=>quote!(
-            #trait_interface<#trait_interface_use>:
-                ::abi_stable::erased_types::InterfaceBound,
-            __sabi_re::InterfaceFor<_Self,#trait_interface<#trait_interface_use>,Unerasability>: 
-                __sabi_re::GetVtable<
-                    #one_lt
-                    _Self,
-                    __sabi_re::RBox<()>,
-                    __sabi_re::RBox<_Self>,
-                    #trait_interface<#trait_interface_use>,
-                >,
-        ),
-        WhichObject::RObject=>quote!(),
+    // The constraints for constructing the trait object from a value,
+    // which is stored in the `erased_ptr`/`orig_ptr` smart pointer.
+    let extra_constraints_value=|erased_ptr:&TokenStream2,orig_ptr:&TokenStream2|{
+        match totrait_def.which_object {
+            WhichObject::DynTrait=>quote!(
+                #trait_interface<#trait_interface_use>:
+                    ::abi_stable::erased_types::InterfaceBound,
+                __sabi_re::InterfaceFor<_Self,#trait_interface<#trait_interface_use>,Unerasability>: 
+                    __sabi_re::GetVtable<
+                        #one_lt
+                        _Self,
+                        #erased_ptr,
+                        #orig_ptr,
+                        #trait_interface<#trait_interface_use>,
+                    >,
+            ),
+            WhichObject::RObject=>quote!(),
+        }
     };
 
+    let extra_constraints_rbox=extra_constraints_value(
+        &quote!(__sabi_re::RBox<()>),
+        &quote!(__sabi_re::RBox<_Self>),
+    );
+
     let gen_params_header=
         totrait_def.generics_tokenizer(
             InWhat::ImplHeader,
@@ -545,6 +556,7 @@ fn constructor_items<'a>(
     let mut shared_docs=String::new();
     let mut from_ptr_docs=String::new();
     let mut from_value_docs=String::new();
+    let mut from_inline_value_docs=String::new();
     let mut from_const_docs=String::new();
 
     if doc_hidden_attr.is_none() {
@@ -566,6 +578,16 @@ fn constructor_items<'a>(
             trait_=trait_ident
         );
 
+        if let Some(inline_size)=totrait_def.inline_size {
+            from_inline_value_docs=format!(
+                "Constructs this trait object from a type that implements `{trait_}`,\n\
+                 storing it inline if it fits in a `{inline_size}`,\
+                 otherwise storing it in the heap.",
+                trait_=trait_ident,
+                inline_size=token_stream_to_string(inline_size.into_token_stream()),
+            );
+        }
+
         from_const_docs=format!(
             "Constructs this trait from a constant of a type that implements `{trait_}`.\n\
              \n\
@@ -626,6 +648,57 @@ fn constructor_items<'a>(
         }),
     };
 
+    let from_inline_value=ToTokenFnMut::new(|ts|{
+        let inline_size=match totrait_def.inline_size {
+            Some(x)=>x,
+            None=>return,
+        };
+
+        let lt=&lt_tokens.lt;
+        let lt_rsmallbox=params.arenas.alloc(quote!(
+            #lt __sabi_re::RSmallBox<(),#inline_size>,
+        ));
+        let gen_params_use_to_rsmallbox=
+            totrait_def.generics_tokenizer(
+                InWhat::ItemUse,
+                WithAssocTys::Yes(WhichSelf::NoSelf),
+                lt_rsmallbox,
+            );
+
+        let erased_ptr=quote!(__sabi_re::RSmallBox<(),#inline_size>);
+        let orig_ptr=quote!(__sabi_re::RSmallBox<_Self,#inline_size>);
+        let extra_constraints_rsmallbox=extra_constraints_value(&erased_ptr,&orig_ptr);
+
+        let assoc_tys_a=totrait_def.assoc_tys.keys();
+        let assoc_tys_b=assoc_tys_a.clone();
+
+        quote!(
+            impl<#gen_params_header_rbox> #trait_to<#gen_params_use_to_rsmallbox> {
+                #[doc=#from_inline_value_docs]
+                #[doc=#shared_docs]
+                #submod_vis fn from_inline_value<_Self,Unerasability>(
+                    ptr:_Self,
+                    unerasability:Unerasability,
+                )->Self
+                where
+                    _Self:
+                        #trait_bounds<#trait_params #( #assoc_tys_a= #assoc_tys_b, )* >
+                        #plus_lt,
+                    #trait_interface<#trait_interface_use>:
+                        __sabi_re::GetRObjectVTable<
+                            Unerasability,_Self,#erased_ptr,#orig_ptr
+                        >,
+                    #extra_constraints_rsmallbox
+                {
+                    Self::from_ptr::<
+                        #orig_ptr,
+                        Unerasability
+                    >(__sabi_re::RSmallBox::new(ptr),unerasability)
+                }
+            }
+        ).to_tokens(ts);
+    });
+
     quote!(
         impl<#gen_params_header> #trait_to<#gen_params_use_to> 
         where
@@ -688,7 +761,7 @@ fn constructor_items<'a>(
                     __sabi_re::GetRObjectVTable<
                         Unerasability,_Self,__sabi_re::RBox<()>,__sabi_re::RBox<_Self>
                     >,
-                #extra_constraints_value
+                #extra_constraints_rbox
             {
                 Self::from_ptr::<
                     __sabi_re::RBox<_Self>,
@@ -697,6 +770,8 @@ fn constructor_items<'a>(
             }
         }
 
+        #from_inline_value
+
         impl<#gen_params_header_rref> #trait_to<#gen_params_use_to_rref>{
             #[doc=#from_const_docs]
             #[doc=#shared_docs]
@@ -886,6 +961,68 @@ fn trait_and_impl<'a>(
 
 }
 
+/**
+Outputs the implementations of the trait for references to implementors of the trait,
+if the `#[sabi(impl_for_refs)]` attribute was used.
+
+`impl Trait for &mut _Self` is output if the trait has no by-value methods.
+
+`impl Trait for &_Self` is output if the trait only has `&self` methods.
+
+Neither is output if the trait has by-value methods,
+since those can't be forwarded through a reference.
+
+*/
+fn ref_impls<'a>(
+    TokenizerParams{ctokens,trait_def,trait_ident,..}:TokenizerParams,
+    mod_:&mut TokenStream2,
+){
+    if !trait_def.impl_for_refs || trait_def.has_val_methods {
+        return;
+    }
+
+    let gen_params_header=
+        trait_def.generics_tokenizer(
+            InWhat::ImplHeader,
+            WithAssocTys::No,
+            &ctokens.ts_lt_ref_uself_unsized,
+        );
+    let gen_params_use_trait=
+        trait_def.generics_tokenizer(
+            InWhat::ItemUse,
+            WithAssocTys::No,
+            &ctokens.empty_ts,
+        );
+    let methods_tokenizer=trait_def.methods_tokenizer(WhichItem::TraitRefImpl);
+
+    let shared_ref=Some(quote!( &'_ref _Self )).filter(|_| !trait_def.has_mut_methods );
+    let mut_ref=quote!( &'_ref mut _Self );
+
+    for ref_ty in shared_ref.into_iter().chain(Some(mut_ref)) {
+        let where_preds=(&trait_def.where_preds).into_iter();
+        let super_traits=trait_def.impld_traits.iter().map(|t| &t.bound );
+        let assoc_tys_a=trait_def.assoc_tys.values().map(|x| &x.assoc_ty.ident );
+        let assoc_tys_b=assoc_tys_a.clone();
+
+        quote!(
+            impl<#gen_params_header> #trait_ident<#gen_params_use_trait> for #ref_ty
+            where
+                _Self: #trait_ident<#gen_params_use_trait>,
+                Self: #( #super_traits + )* ,
+                #(#where_preds,)*
+            {
+                #( 
+                    type #assoc_tys_a=
+                        <_Self as #trait_ident<#gen_params_use_trait>>::#assoc_tys_b; 
+                )*
+
+                #methods_tokenizer
+            }
+        ).to_tokens(mod_);
+    }
+
+}
+
 /// An inherent implementation of the generated trait object,
 /// which mirrors the trait definition.
 fn methods_impls<'a>(
@@ -1271,6 +1408,8 @@ pub(crate) enum WhichItem{
     Trait,
     /// the method in the trait implemetation for the generated trait object.
     TraitImpl,
+    /// the method in the trait implemetation for references to implementors of the trait.
+    TraitRefImpl,
     /// the methods in the inherent implemetation of the generated trait object.
     TraitObjectImpl,
     /// the fields of the trait object vtable.
@@ -6,17 +6,19 @@ use super::{
 use std::{iter,mem};
 
 use syn::{
-    Attribute, Ident, Meta, MetaList, NestedMeta,
+    Attribute, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta,
     ItemTrait,TraitItem,TraitItemMethod,
 };
 
 #[allow(unused_imports)]
 use core_extensions::prelude::*;
 
+use as_derive_utils::return_spanned_err;
+
 use crate::{
     attribute_parsing::with_nested_meta,
     arenas::Arenas,
-    parse_utils::parse_str_as_path,
+    parse_utils::{parse_str_as_path,parse_lit_as_type},
     utils::{LinearResult,SynPathExt,SynResultExt},
 };
 
@@ -73,6 +75,9 @@ pub(crate) struct MethodWithAttrs<'a>{
     /// The attributes used in the vtable,and the trait.
     pub(crate) attrs:OwnedDeriveAndOtherAttrs,
     pub(crate) item:&'a TraitItemMethod,
+    /// Whether the `impl Trait` return type of the method is erased,
+    /// enabled with the `#[sabi(erase_return)]` attribute.
+    pub(crate) erase_return:bool,
 }
 
 
@@ -85,6 +90,7 @@ impl<'a> MethodWithAttrs<'a>{
                 other_attrs:Vec::new(),
             },
             item,
+            erase_return:false,
         }
     }
 }
@@ -107,6 +113,11 @@ pub(super) struct SabiTraitAttrs<'a> {
     pub(super) which_object:WhichObject,
     /// If true,removes the `impl Trait for Trait_TO`
     pub(super) disable_trait_impl:bool,
+    /// If true,implements the trait for references to types that implement the trait.
+    pub(super) impl_for_refs:bool,
+    /// The inline storage of the `RSmallBox` that the trait object can be constructed with,
+    /// enabled with the `#[sabi(inline_size="Type")]` attribute.
+    pub(super) inline_size:Option<&'a syn::Type>,
     /// If true,doesn't use the default implementation of methods when 
     /// the vtable entry is absent.
     pub(super) disable_inherent_default:Vec<bool>,
@@ -259,7 +270,7 @@ fn parse_sabi_trait_attr<'a>(
     this: &mut SabiTraitAttrs<'a>,
     pctx: ParseContext<'a>, 
     attr: Meta, 
-    _arenas: &'a Arenas
+    arenas: &'a Arenas
 )-> Result<(),syn::Error> {
     fn push_attr<'a>(this:&mut SabiTraitAttrs<'a>, pctx: ParseContext<'a>, attr:Meta){
         match pctx{
@@ -299,11 +310,26 @@ fn parse_sabi_trait_attr<'a>(
                 }
             }else if ident=="debug_print_trait" {
                 this.debug_print_trait=true;
+            }else if ident=="erase_return" {
+                match pctx {
+                    ParseContext::TraitAttr{..}=>{
+                        return_spanned_err!(
+                            ident,
+                            "Cannot use the `erase_return` attribute on the trait,\
+                             it can only be used on methods."
+                        );
+                    }
+                    ParseContext::Method{..}=>{
+                        this.methods_with_attrs.last_mut().unwrap().erase_return=true;
+                    }
+                }
             }else if let ParseContext::TraitAttr{..}=pctx {
                 if ident=="use_dyntrait"||ident=="use_dyn_trait" {
                     this.which_object=WhichObject::DynTrait;
                 }else if ident=="no_trait_impl" {
                     this.disable_trait_impl=true;
+                }else if ident=="impl_for_refs" {
+                    this.impl_for_refs=true;
                 }else{
                     push_attr(this,pctx,Meta::Path(ident.into()));
                 }
@@ -311,6 +337,30 @@ fn parse_sabi_trait_attr<'a>(
                 push_attr(this,pctx,Meta::Path(ident.into()))
             }
         }
+        (pctx, Meta::NameValue(MetaNameValue{ref path,ref lit,..})) 
+        if path.equals_str("inline_size") =>
+        {
+            match (pctx,lit) {
+                (ParseContext::Method{..},_)=>{
+                    return_spanned_err!(
+                        path,
+                        "Cannot use the `inline_size` attribute on methods,\
+                         it can only be used on the trait."
+                    );
+                }
+                (ParseContext::TraitAttr{..},Lit::Str(unparsed_lit))=>{
+                    let ty=arenas.alloc(parse_lit_as_type(unparsed_lit)?);
+                    this.inline_size=Some(ty);
+                }
+                (ParseContext::TraitAttr{..},_)=>{
+                    return_spanned_err!(
+                        lit,
+                        "Expected a string literal with a type,\
+                         eg:`#[sabi(inline_size=\"[usize;2]\")]`."
+                    );
+                }
+            }
+        }
         (pctx,attr)=>{
             push_attr(this,pctx,attr);
         }
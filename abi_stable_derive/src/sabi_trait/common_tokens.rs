@@ -113,6 +113,7 @@ declare_common_tokens! {
 
         ts_self ="Self",
         ts_uself="_Self,",
+        ts_lt_ref_uself_unsized="'_ref,_Self:?Sized,",
 
         ts_self_colon2 ="Self::",
         ts_uself_colon2="_Self::",
@@ -164,7 +164,7 @@ pub(super) fn delegated_impls<'a>(
             unsafe impl<#gen_params_header> std::marker::Send
             for #trait_to<#gen_params_use_to>
             where
-                _ErasedPtr:__GetPointerKind,
+                _ErasedPtr:__GetPointerKind+std::marker::Send,
                 #(#where_preds,)*
             {}
         ).to_tokens(mod_);
@@ -176,7 +176,7 @@ pub(super) fn delegated_impls<'a>(
             unsafe impl<#gen_params_header> std::marker::Sync
             for #trait_to<#gen_params_use_to>
             where
-                _ErasedPtr:__GetPointerKind,
+                _ErasedPtr:__GetPointerKind+std::marker::Sync,
                 #(#where_preds,)*
             {}
         ).to_tokens(mod_);
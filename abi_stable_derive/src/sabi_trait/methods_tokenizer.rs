@@ -10,6 +10,9 @@ Where this is used is determined by WhichItem:
 - `WhichItem::TraitImpl`: 
     outputs the method in the trait implemetation for the generated trait object.
 
+- `WhichItem::TraitRefImpl`: 
+    outputs the method in the trait implemetation for references to implementors.
+
 - `WhichItem::TraitObjectImpl`:
     outputs the methods in the inherent implemetation of the generated trait object.
 
@@ -66,7 +69,7 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
         // vis: the visibility of the generated method,
         //      None if it's implicit,Some(_) if it's explicit.
         let (is_method,vis)=match which_item {
-            WhichItem::Trait|WhichItem::TraitImpl=>{
+            WhichItem::Trait|WhichItem::TraitImpl|WhichItem::TraitRefImpl=>{
                 (true,None)
             }
             WhichItem::TraitObjectImpl=>{
@@ -124,7 +127,14 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
         let param_names_c=param_names_a.clone();
         let param_names_d=param_names_a.clone();
         let param_names_e=method.params.iter().map(|x| x.pattern );
-        let return_ty=(&method.output).into_iter();
+        // The vtable and the trait object use the erased return type of 
+        // `#[sabi(erase_return)]` methods,while the trait uses the `impl Trait` type.
+        let return_ty=match which_item {
+            WhichItem::TraitObjectImpl|WhichItem::VtableDecl|WhichItem::VtableImpl=>
+                method.erased_output.as_ref().or(method.output.as_ref()),
+            WhichItem::Trait|WhichItem::TraitImpl|WhichItem::TraitRefImpl=>
+                method.output.as_ref(),
+        }.into_iter();
         
         let self_is_sized_bound=Some(&ctokens.self_sized)
             .filter(|_| is_method&&method.self_param==SelfParam::ByVal );
@@ -192,6 +202,19 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
                     self.#method_name(#(#param_names_c,)*)
                 }).to_tokens(ts);
             }
+            (WhichItem::TraitRefImpl,SelfParam::ByRef{is_mutable:false,..})=>{
+                quote_spanned!(method_span=>{
+                    __Trait::#method_name(&**self,#(#param_names_c,)*)
+                }).to_tokens(ts);
+            }
+            (WhichItem::TraitRefImpl,SelfParam::ByRef{is_mutable:true,..})=>{
+                quote_spanned!(method_span=>{
+                    __Trait::#method_name(&mut **self,#(#param_names_c,)*)
+                }).to_tokens(ts);
+            }
+            (WhichItem::TraitRefImpl,SelfParam::ByVal)=>{
+                unreachable!("#[sabi(impl_for_refs)] doesn't implement traits with by-value methods")
+            }
             (WhichItem::TraitObjectImpl,_)=>{
                 let method_call=match &method.self_param {
                     SelfParam::ByRef{is_mutable:false,..}=>{
@@ -216,6 +239,7 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
                 match default_ {
                     Some(default_)=>{
                         let block=&default_.block;
+                        let block=erase_return(method,quote_spanned!(method_span=> #block ));
                         quote_spanned!(method_span=>
                                 #ptr_constraint
                             {
@@ -253,32 +277,50 @@ impl<'a> ToTokens for MethodTokenizer<'a> {
             
             }
             (WhichItem::VtableImpl,SelfParam::ByRef{is_mutable:false,..})=>{
+                let ret=erase_return(method,quote_spanned!(method_span=>
+                    __Trait::#method_name(_self,#(#param_names_c,)*)
+                ));
                 quote_spanned!(method_span=>{
                     __sabi_re::sabi_from_ref(
                         _self,
-                        move|_self| 
-                            __Trait::#method_name(_self,#(#param_names_c,)*)
+                        move|_self| #ret
                     )
                 }).to_tokens(ts);
             }
             (WhichItem::VtableImpl,SelfParam::ByRef{is_mutable:true,..})=>{
+                let ret=erase_return(method,quote_spanned!(method_span=>
+                    __Trait::#method_name(_self,#(#param_names_c,)*)
+                ));
                 quote_spanned!(method_span=>{
                     __sabi_re::sabi_from_mut(
                         _self,
-                        move|_self| 
-                            __Trait::#method_name(_self,#(#param_names_c,)*)
+                        move|_self| #ret
                     )
                 }).to_tokens(ts);
             }
             (WhichItem::VtableImpl,SelfParam::ByVal)=>{
+                let ret=erase_return(method,quote_spanned!(method_span=>
+                    __Trait::#method_name(
+                        __sabi_re::MovePtr::into_inner(_self),#(#param_names_c,)*
+                    )
+                ));
                 quote_spanned!(method_span=>{
                     ::abi_stable::extern_fn_panic_handling!{no_early_return;
-                        __Trait::#method_name(
-                            __sabi_re::MovePtr::into_inner(_self),#(#param_names_c,)*
-                        )
+                        #ret
                     }
                 }).to_tokens(ts);
             }
         }
     }
-}
\ No newline at end of file
+}
+
+
+/// Wraps the value returned by the implementor of the trait 
+/// in the erased iterator type for `#[sabi(erase_return)]` methods,
+/// returning `ret` unchanged for other methods.
+fn erase_return(method:&TraitMethod<'_>,ret:TokenStream2)->TokenStream2{
+    match &method.erased_output {
+        Some(erased_ty)=>quote!( <#erased_ty>::from_iter(#ret) ),
+        None=>ret,
+    }
+}
@@ -34,7 +34,58 @@ fn must_not_pass(){
             trait Bar {
                 const X: usize;
             }
+        ",
+        // erase_return requires an `impl Iterator`/`impl DoubleEndedIterator` return type.
         "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->u32;
+            }
+        ",
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl Iterator;
+            }
+        ",
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl Iterator<Item=u32>+Send+Sync+Clone;
+            }
+        ",
+        // erase_return requires either both the Send and Sync bounds,or neither.
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl Iterator<Item=u32>+Send;
+            }
+        ",
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl DoubleEndedIterator<Item=u32>+Sync;
+            }
+        ",
+        // erase_return requires a lifetime bound in by-value methods.
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn into_nodes(self)->impl Iterator<Item=u32>+Send+Sync;
+            }
+        ",
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self);
+            }
+        ",
+        "
+            #[sabi(erase_return)]
+            trait Nodes {
+                fn nodes(&self)->impl Iterator<Item=u32>;
+            }
+        ",
     ];
     for elem in list {
         if derive_sabi_trait(elem).is_ok() {
@@ -56,6 +107,39 @@ fn must_pass(){
                 fn baz(self);
             }
         ",
+        // impl_for_refs doesn't implement traits with by-value methods for references.
+        "
+            #[sabi(impl_for_refs)]
+            trait Qux {
+                fn qux(&self);
+                fn into_qux(self);
+            }
+        ",
+        "
+            trait Nodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl Iterator<Item=u32>+Send+Sync;
+                #[sabi(erase_return)]
+                fn nodes_rev<'a>(&'a self)->impl DoubleEndedIterator<Item=u32>+Send+Sync+'a;
+                #[sabi(erase_return)]
+                fn into_nodes(self)->impl Iterator<Item=u32>+Send+Sync+'static;
+                #[sabi(erase_return)]
+                fn first_nodes(&self)->impl Iterator<Item=u32>+Send+Sync {
+                    self.nodes().take(2)
+                }
+            }
+        ",
+        // erase_return without the Send and Sync bounds.
+        "
+            trait UnsendNodes {
+                #[sabi(erase_return)]
+                fn nodes(&self)->impl Iterator<Item=u32>;
+                #[sabi(erase_return)]
+                fn nodes_rev<'a>(&'a self)->impl DoubleEndedIterator<Item=u32>+'a;
+                #[sabi(erase_return)]
+                fn into_nodes(self)->impl Iterator<Item=u32>+'static;
+            }
+        ",
     ];
 
     for elem in list {
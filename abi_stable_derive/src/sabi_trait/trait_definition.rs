@@ -99,6 +99,11 @@ pub(crate) struct TraitDefinition<'a>{
     pub(crate) has_val_methods:bool,
     /// Disables `ìmpl Trait for Trait_TO`
     pub(crate) disable_trait_impl:bool,
+    /// Enables `impl Trait for &_Self` and `impl Trait for &mut _Self`
+    pub(crate) impl_for_refs:bool,
+    /// The inline storage of the `RSmallBox` that the trait object can be constructed with,
+    /// None if the `#[sabi(inline_size="Type")]` attribute wasn't used.
+    pub(crate) inline_size:Option<&'a syn::Type>,
     /// Whether this has `'static` as a supertrait syntactically.
     pub(crate) is_static:IsStaticTrait,
     /// A TokenStream with the equivalent of `<Pointer::Target as Trait>::`
@@ -117,6 +122,8 @@ impl<'a> TraitDefinition<'a>{
             methods_with_attrs,
             which_object,
             disable_trait_impl,
+            impl_for_refs,
+            inline_size,
             disable_inherent_default,
             ..
         }:SabiTraitAttrs<'a>,
@@ -249,6 +256,8 @@ impl<'a> TraitDefinition<'a>{
             has_mut_methods,
             has_val_methods,
             disable_trait_impl,
+            impl_for_refs,
+            inline_size,
             ts_fq_self:arenas.alloc(ts_fq_self),
             is_static,
             ctokens,
@@ -266,7 +275,7 @@ impl<'a> TraitDefinition<'a>{
         let mut errors=LinearResult::ok(());
 
         let replace_with=match which_item {
-            WhichItem::Trait|WhichItem::TraitImpl=>{
+            WhichItem::Trait|WhichItem::TraitImpl|WhichItem::TraitRefImpl=>{
                 return Ok(this);
             }
             WhichItem::TraitObjectImpl=>{
@@ -409,6 +418,11 @@ pub(crate) struct TraitMethod<'a>{
     pub(crate) params: Vec<MethodParam<'a>>,
     /// The return type of this method,if None this returns `()`.
     pub(crate) output: Option<syn::Type>,
+    /// The `RIterator`/`RDoubleEndedIterator` type 
+    /// (or `RUnsendIterator`/`RUnsendDoubleEndedIterator`)
+    /// that the return type is erased into
+    /// in the vtable and the trait object,for `#[sabi(erase_return)]` methods.
+    pub(crate) erased_output: Option<syn::Type>,
     pub(crate) where_clause:MethodWhereClause<'a>,
     /// The default implementation of the method.
     pub(crate) default:Option<DefaultMethod<'a>>,
@@ -488,20 +502,45 @@ impl<'a> TraitMethod<'a>{
 
         let mut lifetimes:Vec<&'a syn::LifetimeDef>=decl.generics.lifetimes().collect();
 
+        let mut erased_output=None::<syn::Type>;
+
         let output=match &decl.output {
             syn::ReturnType::Default=>None,
             syn::ReturnType::Type(_,ty)=>{
                 let mut ty:syn::Type=(**ty).clone();
+                if mwa.erase_return {
+                    let is_by_val=self_param==SelfParam::ByVal;
+                    match erase_impl_trait_return(&ty,is_by_val) {
+                        Ok((user_ty,erased_ty))=>{
+                            ty=user_ty;
+                            erased_output=Some(erased_ty);
+                        }
+                        Err(e)=>errors.push_err(e),
+                    }
+                }
                 if let SelfParam::ByRef{lifetime,..}=&mut self_param {
                     LifetimeUnelider::new(ctokens,lifetime)
                         .visit_type(&mut ty)
                         .into_iter()
                         .extending(&mut lifetimes);
+                    if let Some(erased_ty)=&mut erased_output {
+                        LifetimeUnelider::new(ctokens,lifetime)
+                            .visit_type(erased_ty)
+                            .into_iter()
+                            .extending(&mut lifetimes);
+                    }
                 }
                 Some(ty)
             },
         };
 
+        if mwa.erase_return && output.is_none() {
+            errors.push_err(spanned_err!(
+                method_signature.ident,
+                "Cannot use `#[sabi(erase_return)]` on methods without a return type."
+            ));
+        }
+
         let default=mwa.item.default.as_ref().map(|block| DefaultMethod{block} );
 
         let where_clause=decl.generics.where_clause.as_ref()
@@ -550,6 +589,7 @@ impl<'a> TraitMethod<'a>{
             self_param,
             params,
             output,
+            erased_output,
             where_clause,
             default,
             semicolon:mwa.item.semi_token.as_ref(),
@@ -575,6 +615,7 @@ impl<'a> TraitMethod<'a>{
         for param in self.params.iter_mut()
             .map(|x| &mut x.ty )
             .chain(self.output.as_mut())
+            .chain(self.erased_output.as_mut())
         {
             replace_self_path::replace_self_path(
                 param,
@@ -824,6 +865,28 @@ where
                                     "Serialize is not currently supported."
                                 ));
                             }
+                            WhichTrait::Extend=>{
+                                errors.push_err(spanned_err!(
+                                    trait_bound.path,
+                                    "Extend is not currently supported."
+                                ));
+                            }
+                            WhichTrait::IteratorSum|WhichTrait::IteratorProduct=>{
+                                errors.push_err(spanned_err!(
+                                    trait_bound.path,
+                                    "IteratorSum and IteratorProduct are not traits,\
+                                     they can only be used in \
+                                     #[sabi(impl_InterfaceType(...))]."
+                                ));
+                            }
+                            WhichTrait::AsBytes|WhichTrait::AsStr=>{
+                                errors.push_err(spanned_err!(
+                                    trait_bound.path,
+                                    "AsBytes and AsStr are not traits,\
+                                     they can only be used in \
+                                     #[sabi(impl_InterfaceType(...))]."
+                                ));
+                            }
                             WhichTrait::Eq|WhichTrait::PartialOrd=>{
                                 set_impld(&mut trait_struct.partial_eq,span);
                             }
@@ -994,4 +1057,153 @@ fn extract_deserialize_lifetime<'a>(
         }
     }
     Err(spanned_err!(last_path_component,"Expected a lifetime parameter inside"))
-}
\ No newline at end of file
+}
+
+/// Parses the `impl Iterator<Item=T>+Send+Sync+'lt`/
+/// `impl DoubleEndedIterator<Item=T>+Send+Sync+'lt` 
+/// return type of a `#[sabi(erase_return)]` method,
+/// returning the type used in the trait,
+/// and the `RIterator<'lt,T>`/`RDoubleEndedIterator<'lt,T>` type it's erased into
+/// (in the vtable and the trait object).
+///
+/// If the return type doesn't have the `Send` and `Sync` bounds,
+/// it's erased into `RUnsendIterator<'lt,T>`/`RUnsendDoubleEndedIterator<'lt,T>` instead.
+///
+/// If no lifetime is specified,this uses `'_`,
+/// which gets unelided into the lifetime of the `&self`/`&mut self` parameter.
+fn erase_impl_trait_return(
+    ty:&syn::Type,
+    is_by_val:bool,
+)-> Result<(syn::Type,syn::Type),syn::Error> {
+    let impl_trait=match ty {
+        syn::Type::ImplTrait(x)=>x,
+        _=>return_spanned_err!(
+            ty,
+            "Expected an `impl Iterator<Item=T>` or \
+             `impl DoubleEndedIterator<Item=T>` return type \
+             for `#[sabi(erase_return)]`."
+        ),
+    };
+
+    let mut erased_into=None::<(&str,syn::Type)>;
+    let mut lifetime=None::<&syn::Lifetime>;
+    let mut has_send=false;
+    let mut has_sync=false;
+
+    for bound in &impl_trait.bounds {
+        match bound {
+            TypeParamBound::Trait(trait_bound)=>{
+                let last_path_component=match trait_bound.path.segments.last() {
+                    Some(x)=>x,
+                    None=>continue,
+                };
+                let trait_ident=&last_path_component.ident;
+                let erased_name=if trait_ident=="Iterator" {
+                    "RIterator"
+                }else if trait_ident=="DoubleEndedIterator" {
+                    "RDoubleEndedIterator"
+                }else if trait_ident=="Send" {
+                    has_send=true;
+                    continue;
+                }else if trait_ident=="Sync" {
+                    has_sync=true;
+                    continue;
+                }else{
+                    return_spanned_err!(
+                        trait_bound,
+                        "Unsupported trait for `#[sabi(erase_return)]`,\
+                         only Iterator,DoubleEndedIterator,Send,and Sync are supported."
+                    )
+                };
+                if erased_into.is_some() {
+                    return_spanned_err!(
+                        trait_bound,
+                        "Cannot have both Iterator and DoubleEndedIterator bounds \
+                         in an `#[sabi(erase_return)]` return type,\
+                         use only DoubleEndedIterator."
+                    );
+                }
+                let item=match &last_path_component.arguments {
+                    syn::PathArguments::AngleBracketed(args)=>{
+                        args.args.iter()
+                            .filter_map(|gen_arg| match gen_arg {
+                                syn::GenericArgument::Binding(bind) if bind.ident=="Item" =>
+                                    Some(bind.ty.clone()),
+                                _=>None,
+                            })
+                            .next()
+                    }
+                    _=>None,
+                };
+                match item {
+                    Some(item)=>erased_into=Some((erased_name,item)),
+                    None=>return_spanned_err!(
+                        trait_bound,
+                        "Expected the Item associated type to be specified,eg:`{}<Item=u32>`",
+                        trait_ident,
+                    ),
+                }
+            }
+            TypeParamBound::Lifetime(lt)=>{
+                if lifetime.is_some() {
+                    return_spanned_err!(
+                        lt,
+                        "Cannot have more than one lifetime bound \
+                         in an `#[sabi(erase_return)]` return type."
+                    );
+                }
+                lifetime=Some(lt);
+            }
+        }
+    }
+
+    let (erased_name,item)=match erased_into {
+        Some(x)=>x,
+        None=>return_spanned_err!(
+            impl_trait,
+            "Expected an Iterator or DoubleEndedIterator bound \
+             in the `#[sabi(erase_return)]` return type."
+        ),
+    };
+    if has_send!=has_sync {
+        return_spanned_err!(
+            impl_trait,
+            "The return type of `#[sabi(erase_return)]` methods must have \
+             either both the `Send` and `Sync` bounds,or neither of them,\
+             because the iterator is erased into a `Send+Sync` `{}`,\
+             or a `RUnsend{}` that is neither `Send` nor `Sync`.",
+            erased_name,
+            &erased_name[1..],
+        );
+    }
+    let erased_name=if has_send {
+        erased_name.to_string()
+    }else{
+        format!("RUnsend{}",&erased_name[1..])
+    };
+    if is_by_val && lifetime.is_none() {
+        return_spanned_err!(
+            impl_trait,
+            "`#[sabi(erase_return)]` methods that take `self` by value \
+             must specify the lifetime bound of the returned iterator,eg:`+'static`."
+        );
+    }
+
+    let erased_name=parse_str_as_ident(&erased_name);
+
+    let mut user_ty=impl_trait.clone();
+    let lifetime=match lifetime {
+        Some(lt)=>lt.clone(),
+        None=>{
+            let lt=syn::Lifetime::new("'_",Span::call_site());
+            user_ty.bounds.push(TypeParamBound::Lifetime(lt.clone()));
+            lt
+        }
+    };
+
+    let erased_ty=syn::parse_quote!(
+        abi_stable::erased_types::#erased_name<#lifetime,#item>
+    );
+
+    Ok((syn::Type::ImplTrait(user_ty),erased_ty))
+}
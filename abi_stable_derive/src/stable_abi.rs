@@ -411,16 +411,18 @@ pub(crate) fn derive(mut data: DeriveInput) -> Result<TokenStream2,syn::Error> {
         }
     };
 
-    let phantom_field_tys  =config.phantom_fields.iter().map(|x| x.1 );
+    let phantom_field_tys  =config.phantom_fields.iter()
+        .filter(|x| !x.2.is_opaque() )
+        .map(|x| x.1 );
 
     // This has to be collected into a Vec ahead of time,
     // so that the names and types are stored in SharedVars.
     let phantom_fields=config.phantom_fields.iter()
-        .map(|(name,ty)|{
+        .map(|&(name,ty,layout_ctor)|{
             CompTLField::from_expanded_std_field(
                 name,
                 std::iter::empty(),
-                shared_vars.push_type(LayoutConstructor::SharedStableAbi,*ty),
+                shared_vars.push_type(layout_ctor,ty),
                 shared_vars,
             )
         })
@@ -20,7 +20,7 @@ use std::{
 
 use core_extensions::{matches,IteratorExt};
 
-use proc_macro2::Span;
+use proc_macro2::{Span,TokenTree};
 
 use quote::ToTokens;
 
@@ -76,8 +76,9 @@ pub(crate) struct StableAbiOptions<'a> {
 
     pub(crate) impl_interfacetype:Option<ImplInterfaceType>,
 
-    pub(crate) phantom_fields:Vec<(&'a Ident,&'a Type)>,
+    pub(crate) phantom_fields:Vec<(&'a Ident,&'a Type,LayoutConstructor)>,
     pub(crate) phantom_type_params:Vec<&'a Type>,
+    pub(crate) phantom_only_type_params:Vec<&'a Type>,
     pub(crate) phantom_const_params:Vec<&'a syn::Expr>,
 
     pub(crate) const_idents:ConstIdents,
@@ -187,7 +188,7 @@ impl<'a> StableAbiOptions<'a> {
         mut this: StableAbiAttrs<'a>,
         arenas: &'a Arenas,
     ) -> Result<Self,syn::Error> {
-        let mut phantom_fields=Vec::<(&'a Ident,&'a Type)>::new();
+        let mut phantom_fields=Vec::<(&'a Ident,&'a Type,LayoutConstructor)>::new();
 
         let repr = ReprAttr::new(this.repr)?;
 
@@ -206,6 +207,27 @@ impl<'a> StableAbiOptions<'a> {
             }
             UncheckedStabilityKind::Value => StabilityKind::Value,
             UncheckedStabilityKind::Prefix(prefix)=>{
+                for (fi,pk_field) in this.prefix_kind_fields.iter() {
+                    if !pk_field.is_optional { continue }
+
+                    let field_name=ds.variants[fi.variant].fields[fi.pos].ident();
+
+                    if fi.pos < this.first_suffix_field.field_pos {
+                        errors.push_err(spanned_err!(
+                            field_name,
+                            "`#[sabi(optional)]` can only be used on fields after \
+                             the one with the `#[sabi(last_prefix_field)]` attribute."
+                        ));
+                    }
+                    if pk_field.on_missing.is_some() {
+                        errors.push_err(spanned_err!(
+                            field_name,
+                            "`#[sabi(optional)]` cannot be combined with `#[sabi(missing_field(..))]`,\
+                             optional fields always return `RNone` when they are missing."
+                        ));
+                    }
+                }
+
                 StabilityKind::Prefix(PrefixKind::new(
                     this.first_suffix_field,
                     prefix.prefix_struct,
@@ -258,7 +280,7 @@ impl<'a> StableAbiOptions<'a> {
                     .piped(|x| arenas.alloc(x) );
 
                 let dt=arenas.alloc(parse_str_as_ident("deref_target"));
-                phantom_fields.push((dt,field_ty));
+                phantom_fields.push((dt,field_ty,LayoutConstructor::SharedStableAbi));
 
                 &[
                     "Self: ::std::ops::Deref",
@@ -275,14 +297,53 @@ impl<'a> StableAbiOptions<'a> {
                 ModReflMode::Opaque,
         };
 
-        phantom_fields.extend(this.extra_phantom_fields);
+        // The type parameters of the deriving type that were passed to
+        // `#[sabi(phantom_only_type_param="..")]`,
+        // these are only used inside of `PhantomData`s.
+        let mut phantom_generic_params=Vec::<&'a Ident>::new();
+        for ty in &this.phantom_only_type_params {
+            match own_type_param(ds,ty) {
+                Some(param)=>phantom_generic_params.push(param),
+                None=>errors.push_err(spanned_err!(
+                    ty,
+                    "`#[sabi(phantom_only_type_param=\"..\")]` \
+                     only accepts type parameters of the deriving type."
+                )),
+            }
+        }
+
+        for param in &phantom_generic_params {
+            let bound=&mut this.type_param_bounds[*param];
+            if *bound==ASTypeParamBound::StableAbi {
+                *bound=ASTypeParamBound::GetStaticEquivalent;
+            }
+        }
+
+        for field in ds.variants.iter().flat_map(|v| &v.fields ) {
+            if this.changed_types[field].is_none() &&
+                this.layout_ctor[field]==LayoutConstructor::Regular &&
+                is_phantomdata_of(ds,field.ty,&phantom_generic_params)
+            {
+                this.layout_ctor[field]=LayoutConstructor::Opaque;
+            }
+        }
+
+        phantom_fields.extend(
+            this.extra_phantom_fields.iter()
+                .map(|&(name,ty)| (name,ty,LayoutConstructor::SharedStableAbi) )
+        );
         phantom_fields.extend(
             this.phantom_type_params.iter().cloned()
+                .map(|ty| (ty,LayoutConstructor::SharedStableAbi) )
+                .chain(
+                    this.phantom_only_type_params.iter().cloned()
+                        .map(|ty| (ty,LayoutConstructor::Opaque) )
+                )
                 .enumerate()
-                .map(|(i,ty)|{
+                .map(|(i,(ty,layout_ctor))|{
                     let x=format!("_phantom_ty_param_{}",i);
                     let name=arenas.alloc(parse_str_as_ident(&x));
-                    (name,ty)
+                    (name,ty,layout_ctor)
                 })
         );
 
@@ -312,6 +373,7 @@ impl<'a> StableAbiOptions<'a> {
             impl_interfacetype: this.impl_interfacetype,
             phantom_fields,
             phantom_type_params: this.phantom_type_params,
+            phantom_only_type_params: this.phantom_only_type_params,
             phantom_const_params: this.phantom_const_params,
             allow_type_macros: this.allow_type_macros,
             with_field_indices: this.with_field_indices,
@@ -358,6 +420,7 @@ struct StableAbiAttrs<'a> {
 
     extra_phantom_fields:Vec<(&'a Ident,&'a Type)>,
     phantom_type_params:Vec<&'a Type>,
+    phantom_only_type_params:Vec<&'a Type>,
     phantom_const_params:Vec<&'a syn::Expr>,
 
     impl_interfacetype:Option<ImplInterfaceType>,
@@ -549,6 +612,8 @@ fn parse_sabi_attr<'a>(
             }else if word == "last_prefix_field" {
                 let field_pos=field_index+1;
                 this.first_suffix_field=FirstSuffixField{field_pos};
+            }else if word == "optional" {
+                this.prefix_kind_fields[field].is_optional=true;
             }else{
                 return Err(make_err(&path))?;
             }
@@ -643,6 +708,9 @@ fn parse_sabi_attr<'a>(
             }else if ident=="phantom_type_param"{
                 let ty=arenas.alloc(parse_lit_as_type(unparsed_lit)?);
                 this.phantom_type_params.push(ty);
+            }else if ident=="phantom_only_type_param"{
+                let ty=arenas.alloc(parse_lit_as_type(unparsed_lit)?);
+                this.phantom_only_type_params.push(ty);
             }else if ident=="phantom_const_param"{
                 let constant=arenas.alloc(parse_lit_as_expr(unparsed_lit)?);
                 this.phantom_const_params.push(constant);
@@ -1203,6 +1271,52 @@ fn where_predicate_from(
 }
 
 
+/// Gets the identifier of `ty` if it's one of the type parameters of the deriving type.
+fn own_type_param<'a>(ds:&'a DataStructure<'a>,ty:&Type)->Option<&'a Ident>{
+    let ident=match ty {
+        Type::Path(x) if x.qself.is_none() => x.path.get_ident()?,
+        _=>return None,
+    };
+    ds.generics.type_params()
+        .map(|x| &x.ident )
+        .find(|x| *x==ident )
+}
+
+/// Whether `ty` is a `PhantomData` that mentions any of the `params` type parameters,
+/// and no other type parameter of the deriving type.
+fn is_phantomdata_of(ds:&DataStructure<'_>,ty:&Type,params:&[&Ident])->bool{
+    fn visit_idents(ts:TokenStream2,f:&mut dyn FnMut(&Ident)){
+        for tt in ts {
+            match tt {
+                TokenTree::Ident(ident)=>f(&ident),
+                TokenTree::Group(group)=>visit_idents(group.stream(),f),
+                _=>{}
+            }
+        }
+    }
+
+    let is_phantomdata=match ty {
+        Type::Path(x)=>x.path.segments.last().map_or(false,|x| x.ident=="PhantomData" ),
+        _=>false,
+    };
+    if params.is_empty() || !is_phantomdata {
+        return false;
+    }
+
+    let mut mentions_params=false;
+    let mut mentions_others=false;
+    visit_idents(ty.into_token_stream(),&mut |ident|{
+        if params.iter().any(|p| *p==ident ) {
+            mentions_params=true;
+        }else if ds.generics.type_params().any(|x| x.ident==*ident ) {
+            mentions_others=true;
+        }
+    });
+
+    mentions_params && !mentions_others
+}
+
+
 
 
 
@@ -26,10 +26,15 @@ impl<'a> GenericParams<'a>{
             let shared_vars=std::cell::RefCell::new(&mut *shared_vars);
             let phantom_type_params=config.phantom_type_params
                 .iter()
-                .map(|ty|{
+                .map(|ty| (ty,LayoutConstructor::SharedStableAbi) )
+                .chain(
+                    config.phantom_only_type_params.iter()
+                        .map(|ty| (ty,LayoutConstructor::Opaque) )
+                )
+                .map(|(ty,layout_ctor)|{
                     let ty:&'a syn::Type=*ty;
                     shared_vars.borrow_mut()
-                        .push_type(LayoutConstructor::SharedStableAbi,ty) 
+                        .push_type(layout_ctor,ty) 
                         .to_u10()
                 });
 
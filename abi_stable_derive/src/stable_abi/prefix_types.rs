@@ -107,6 +107,7 @@ impl<'a> PrefixKind<'a>{
 pub(crate) struct PrefixKindField<'a>{
     pub(crate) accessible_if:Option<&'a syn::Expr>,
     pub(crate) on_missing:Option<OnMissingField<'a>>,
+    pub(crate) is_optional:bool,
 }
 
 
@@ -142,6 +143,10 @@ pub(crate) struct FirstSuffixField{
 pub(crate) enum OnMissingField<'a>{
     /// Returns an `Option<FieldType>`,where it returns None if the field is absent.
     ReturnOption,
+    /// Returns an `ROption<FieldType>`,where it returns RNone if the field is absent.
+    /// 
+    /// This is only used for `#[sabi(optional)]` fields.
+    ReturnROption,
     /// Panics with a default message.
     Panic,
     /// Evaluates `function()`,and returns the return value of the function.
@@ -170,7 +175,12 @@ impl<'a> AccessorOrMaybe<'a>{
         pkf:PrefixKindField<'a>,
         default_omf:OnMissingField<'a>,
     )->Self{
-        if field_i.pos < first_suffix_field.field_pos && 
+        if pkf.is_optional {
+            AccessorOrMaybe::Maybe(MaybeAccessor{
+                accessible_if:pkf.accessible_if,
+                on_missing:OnMissingField::ReturnROption,
+            })
+        }else if field_i.pos < first_suffix_field.field_pos && 
             pkf.accessible_if.is_none() &&
             pkf.on_missing!=Some(OnMissingField::ReturnOption)
         {
@@ -188,6 +198,12 @@ impl<'a> AccessorOrMaybe<'a>{
         self.to_maybe_accessor().map_or(false,|x| x.accessible_if.is_some() )
     }
 
+    /// Whether the field has the `#[sabi(optional)]` attribute.
+    pub(crate) fn is_optional(&self)->bool{
+        self.to_maybe_accessor()
+            .map_or(false,|x| x.on_missing==OnMissingField::ReturnROption )
+    }
+
     /// Converts this to a MaybeAccessor,returning None if it is not the `Maybe` variant.
     pub(crate) fn to_maybe_accessor(&self)->Option<MaybeAccessor<'a>>{
         match *self {
@@ -213,7 +229,7 @@ impl<'a> PrefixKind<'a>{
                 FieldAccessor::Method{name:None},
             AccessorOrMaybe::Maybe(MaybeAccessor{on_missing,..})=>
                 match on_missing {
-                    OMF::ReturnOption=>
+                    OMF::ReturnOption|OMF::ReturnROption=>
                         FieldAccessor::MethodOption,
                     OMF::Panic{..}|OMF::With{..}|OMF::Value{..}|OMF::Default_=>
                         FieldAccessor::Method{name:None},
@@ -373,6 +389,13 @@ then use the `as_prefix` method at runtime to cast it to `&{name}{generics}`.
                              `None` if it does not.\
                             "
                         ),
+                    AOM::Maybe(MaybeAccessor{on_missing:OnMissingField::ReturnROption,..})=>
+                        acc_doc_buffer.push_str(
+                            "This is for an optional field,\
+                             returns `RSome(field_value)` if the field exists,\
+                             `RNone` if it does not.\
+                            "
+                        ),
                     AOM::Maybe(MaybeAccessor{on_missing:OnMissingField::Panic,..})=>
                         acc_doc_buffer.push_str(
                             "\n\n# Panic\n\nPanics if the field does not exist."
@@ -450,18 +473,23 @@ then use the `as_prefix` method at runtime to cast it to `&{name}{generics}`.
                 },
                 AccessorOrMaybe::Maybe(maybe_accessor)=>{
                     let on_missing_field=maybe_accessor.on_missing;
-                    let is_optional=on_missing_field==OnMissingField::ReturnOption;
 
-                    let return_ty=if is_optional {
-                        quote!( Option< #ty > )
-                    }else{
-                        quote!( #ty)
+                    let return_ty=match on_missing_field {
+                        OnMissingField::ReturnOption=>
+                            quote!( Option< #ty > ),
+                        OnMissingField::ReturnROption=>
+                            quote!( #module::_sabi_reexports::ROption< #ty > ),
+                        _=>
+                            quote!( #ty),
                     };
 
                     let else_=match on_missing_field {
                         OnMissingField::ReturnOption=>quote_spanned!{field_span=>
                             return None 
                         },
+                        OnMissingField::ReturnROption=>quote_spanned!{field_span=>
+                            return #module::_sabi_reexports::RNone
+                        },
                         OnMissingField::Panic=>quote_spanned!(field_span=>
                             #module::_sabi_reexports::panic_on_missing_field_ty::<
                                 #deriving_name #ty_generics
@@ -481,10 +509,13 @@ then use the `as_prefix` method at runtime to cast it to `&{name}{generics}`.
                         },
                     };
 
-                    let with_val=if is_optional {
-                        quote_spanned!(field_span=> Some(val) )
-                    }else{
-                        quote_spanned!(field_span=> val )
+                    let with_val=match on_missing_field {
+                        OnMissingField::ReturnOption=>
+                            quote_spanned!(field_span=> Some(val) ),
+                        OnMissingField::ReturnROption=>
+                            quote_spanned!(field_span=> #module::_sabi_reexports::RSome(val) ),
+                        _=>
+                            quote_spanned!(field_span=> val ),
                     };
 
                     conditional_accessors.push(quote_spanned!{field_span=>
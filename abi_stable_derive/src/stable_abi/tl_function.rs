@@ -73,6 +73,12 @@ impl<'a> VisitedFieldMap<'a>{
                 shared_vars,
             );
 
+            let comp_field=match &config.kind {
+                StabilityKind::Prefix(prefix)=>
+                    comp_field.set_optional(prefix.fields[field].is_optional()),
+                _=>comp_field,
+            };
+
             let iterated_functions=if is_opaque { Vec::new() }else{ visit_info.functions };
 
             let functions=iterated_functions.iter().enumerate()
@@ -28,8 +28,13 @@ macro_rules! declare_comp_tl_field {(
         const IS_FUNCTION_OFFSET:u32=Self::TYPE_LAYOUT_OFFSET+TypeLayoutIndex::BIT_SIZE;
         const IS_FUNCTION_BIT_SIZE:u32=1;
 
+        // This bit was added after the other ones,
+        // versions of abi_stable that don't know about it ignore it.
+        const IS_OPTIONAL_OFFSET:u32=Self::IS_FUNCTION_OFFSET+Self::IS_FUNCTION_BIT_SIZE;
+        const IS_OPTIONAL_BIT_SIZE:u32=1;
+
         /// The ammount of bits necessary to represent a CompTLField.
-        pub const BIT_SIZE:u32=Self::IS_FUNCTION_OFFSET+Self::IS_FUNCTION_BIT_SIZE;
+        pub const BIT_SIZE:u32=Self::IS_OPTIONAL_OFFSET+Self::IS_OPTIONAL_BIT_SIZE;
 
         /// Constructs a CompTLField.
         #[inline]
@@ -82,6 +87,23 @@ macro_rules! declare_comp_tl_field {(
             (self.bits0 & (1<<Self::IS_FUNCTION_OFFSET))!=0
         }
 
+        /// Whether this is an optional field of a prefix type,
+        /// declared with the `#[sabi(optional)]` attribute.
+        #[inline]
+        pub fn is_optional(&self)->bool{
+            (self.bits0 & (1<<Self::IS_OPTIONAL_OFFSET))!=0
+        }
+
+        /// Sets whether this is an optional field of a prefix type.
+        #[inline]
+        pub const fn set_optional(self,is_optional:bool)->Self{
+            CompTLField{
+                bits0:
+                    (self.bits0 & !(1<<Self::IS_OPTIONAL_OFFSET))|
+                    ((is_optional as u64)<<Self::IS_OPTIONAL_OFFSET),
+            }
+        }
+
         #[inline]
         pub(crate) const fn std_field(
             name:StartLen,
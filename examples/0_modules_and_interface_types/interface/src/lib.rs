@@ -158,6 +158,10 @@ impl<'borr> DeserializeDyn<'borr,TOStateBox> for TOState {
             .deserialize_state()(s.get_rstr())
             .into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'borr>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
@@ -202,6 +206,10 @@ impl<'borr> DeserializeDyn<'borr,TOCommandBox<'static>> for TOCommand {
             .deserialize_command()(s.get_rstr())
             .into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'borr>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
@@ -241,6 +249,10 @@ impl<'borr> DeserializeDyn<'borr,TOReturnValueArc> for TOReturnValue {
             .deserialize_return_value()(s.get_rstr())
             .into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'borr>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
@@ -134,6 +134,10 @@ impl<'a> DeserializeEnum<'a,Command_NE> for Command_Interface{
     fn deserialize_enum(s: RawValueRef<'a>) -> Result<Command_NE, RBoxError>{
         ShopMod::get_module().unwrap().deserialize_command()(s.get_rstr()).into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'a>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 
@@ -293,6 +297,10 @@ impl<'a> DeserializeEnum<'a,ReturnVal_NE> for Command_Interface{
     fn deserialize_enum(s: RawValueRef<'a>) -> Result<ReturnVal_NE, RBoxError>{
         ShopMod::get_module().unwrap().deserialize_ret_val()(s.get_rstr()).into_result()
     }
+
+    fn proxy_input(s: &RawValueRef<'a>) -> Option<&str> {
+        Some(s.get())
+    }
 }
 
 